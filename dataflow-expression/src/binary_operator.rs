@@ -56,6 +56,27 @@ pub enum BinaryOperator {
     /// `/`
     Divide,
 
+    /// `%`
+    Modulo,
+
+    /// MySQL `DIV`, which discards any fractional part of the result of division
+    IntegerDivide,
+
+    /// `&`
+    BitwiseAnd,
+
+    /// `|`
+    BitwiseOr,
+
+    /// MySQL `^` or PostgreSQL `#`
+    BitwiseXor,
+
+    /// `<<`
+    ShiftLeft,
+
+    /// `>>`
+    ShiftRight,
+
     /// `?`
     JsonExists,
 
@@ -113,6 +134,18 @@ pub enum BinaryOperator {
 }
 
 impl BinaryOperator {
+    /// Returns true if this operator is one of the bitwise operators
+    pub fn is_bitwise(&self) -> bool {
+        matches!(
+            self,
+            Self::BitwiseAnd
+                | Self::BitwiseOr
+                | Self::BitwiseXor
+                | Self::ShiftLeft
+                | Self::ShiftRight
+        )
+    }
+
     /// Convert a [`nom_sql::BinaryOperator`] to a pair of `BinaryOperator` and a boolean indicating
     /// whether the result should be negated, within the context of a SQL [`Dialect`].
     pub fn from_sql_op(
@@ -140,6 +173,27 @@ impl BinaryOperator {
             HashSubtract => Ok((Self::JsonSubtractPath, false)),
             Multiply => Ok((Self::Multiply, false)),
             Divide => Ok((Self::Divide, false)),
+            Modulo => Ok((Self::Modulo, false)),
+            IntegerDivide => match dialect.engine() {
+                SqlEngine::MySQL => Ok((Self::IntegerDivide, false)),
+                SqlEngine::PostgreSQL => unsupported!("'{op}' not available in PostgreSQL"),
+            },
+            BitwiseAnd => Ok((Self::BitwiseAnd, false)),
+            BitwiseOr => Ok((Self::BitwiseOr, false)),
+            // `^` is exponentiation in PostgreSQL, which is lowered to a call to `power` before we
+            // get here
+            Caret => match dialect.engine() {
+                SqlEngine::MySQL => Ok((Self::BitwiseXor, false)),
+                SqlEngine::PostgreSQL => {
+                    unsupported!("'{op}' is not a binary operator in PostgreSQL")
+                }
+            },
+            Hash => match dialect.engine() {
+                SqlEngine::MySQL => unsupported!("'{op}' not available in MySQL"),
+                SqlEngine::PostgreSQL => Ok((Self::BitwiseXor, false)),
+            },
+            ShiftLeft => Ok((Self::ShiftLeft, false)),
+            ShiftRight => Ok((Self::ShiftRight, false)),
             Like => Ok((Self::Like, false)),
             NotLike => Ok((Self::Like, true)),
             ILike => Ok((Self::ILike, false)),
@@ -214,8 +268,12 @@ impl BinaryOperator {
 
         use BinaryOperator::*;
         match self {
-            Add | Subtract | Multiply | Divide | And | Or | Greater | GreaterOrEqual | Less
-            | LessOrEqual | Is => Ok((None, None)),
+            Add | Subtract | Multiply | Divide | Modulo | IntegerDivide | And | Or | Greater
+            | GreaterOrEqual | Less | LessOrEqual | Is => Ok((None, None)),
+
+            // The operands of bitwise operators are coerced during lowering, since that depends on
+            // the SQL dialect
+            BitwiseAnd | BitwiseOr | BitwiseXor | ShiftLeft | ShiftRight => Ok((None, None)),
 
             Like | ILike => Ok((
                 coerce_to_text_type(left_type),
@@ -282,7 +340,7 @@ impl BinaryOperator {
     pub(crate) fn output_type(
         &self,
         left_type: &DfType,
-        _right_type: &DfType,
+        right_type: &DfType,
     ) -> ReadySetResult<DfType> {
         // TODO: Maybe consider `right_type` in some cases too.
         // TODO: What is the correct return type for `And` and `Or`?
@@ -305,6 +363,36 @@ impl BinaryOperator {
             | Self::JsonKeyExtractText
             | Self::JsonKeyPathExtractText => Ok(DfType::DEFAULT_TEXT),
 
+            Self::IntegerDivide => Ok(DfType::BigInt),
+
+            // `interval + timestamp` has the type of the timestamp
+            Self::Add if *left_type == DfType::Interval => Ok(right_type.clone()),
+
+            Self::BitwiseAnd
+            | Self::BitwiseOr
+            | Self::BitwiseXor
+            | Self::ShiftLeft
+            | Self::ShiftRight
+                if left_type.is_any_bit_string() =>
+            {
+                Ok(left_type.clone())
+            }
+            // Bitwise operations involving unsigned integers are always performed on 64 bits
+            Self::BitwiseAnd
+            | Self::BitwiseOr
+            | Self::BitwiseXor
+            | Self::ShiftLeft
+            | Self::ShiftRight
+                if left_type.is_any_unsigned_int() || right_type.is_any_unsigned_int() =>
+            {
+                Ok(DfType::UnsignedBigInt)
+            }
+            Self::BitwiseAnd | Self::BitwiseOr | Self::BitwiseXor
+                if left_type.is_any_int() && right_type.is_any_int() && left_type != right_type =>
+            {
+                Ok(DfType::BigInt)
+            }
+
             _ => Ok(left_type.clone()),
         }
     }
@@ -328,6 +416,13 @@ impl fmt::Display for BinaryOperator {
             Self::JsonSubtractPath => "#-",
             Self::Multiply => "*",
             Self::Divide => "/",
+            Self::Modulo => "%",
+            Self::IntegerDivide => "DIV",
+            Self::BitwiseAnd => "&",
+            Self::BitwiseOr => "|",
+            Self::BitwiseXor => "^",
+            Self::ShiftLeft => "<<",
+            Self::ShiftRight => ">>",
             Self::JsonExists => "?",
             Self::JsonAnyExists => "?|",
             Self::JsonAllExists => "?&",
@@ -361,6 +456,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn integer_divide_lowering() {
+        assert_eq!(
+            BinaryOperator::from_sql_op(
+                SqlBinaryOperator::IntegerDivide,
+                Dialect::DEFAULT_MYSQL,
+                &DfType::Int,
+                &DfType::Int
+            )
+            .unwrap(),
+            (BinaryOperator::IntegerDivide, false)
+        );
+        BinaryOperator::from_sql_op(
+            SqlBinaryOperator::IntegerDivide,
+            Dialect::DEFAULT_POSTGRESQL,
+            &DfType::Int,
+            &DfType::Int,
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn caret_lowering() {
+        assert_eq!(
+            BinaryOperator::from_sql_op(
+                SqlBinaryOperator::Caret,
+                Dialect::DEFAULT_MYSQL,
+                &DfType::Int,
+                &DfType::Int
+            )
+            .unwrap(),
+            (BinaryOperator::BitwiseXor, false)
+        );
+        assert_eq!(
+            BinaryOperator::from_sql_op(
+                SqlBinaryOperator::Hash,
+                Dialect::DEFAULT_POSTGRESQL,
+                &DfType::Int,
+                &DfType::Int
+            )
+            .unwrap(),
+            (BinaryOperator::BitwiseXor, false)
+        );
+    }
+
     mod output_type {
         use super::*;
 
@@ -442,5 +582,44 @@ mod tests {
                 DfType::DEFAULT_TEXT,
             );
         }
+
+        #[test]
+        fn bitwise() {
+            use BinaryOperator::*;
+
+            for op in [BitwiseAnd, BitwiseOr, BitwiseXor, ShiftLeft, ShiftRight] {
+                assert_eq!(
+                    op.output_type(&DfType::Int, &DfType::UnsignedBigInt)
+                        .unwrap(),
+                    DfType::UnsignedBigInt
+                );
+                assert_eq!(
+                    op.output_type(&DfType::Int, &DfType::Int).unwrap(),
+                    DfType::Int
+                );
+                assert_eq!(
+                    op.output_type(&DfType::Bit(4), &DfType::Bit(4)).unwrap(),
+                    DfType::Bit(4)
+                );
+                assert_eq!(
+                    op.output_type(&DfType::VarBit(None), &DfType::UnsignedBigInt)
+                        .unwrap(),
+                    DfType::VarBit(None)
+                );
+            }
+
+            assert_eq!(
+                BitwiseAnd
+                    .output_type(&DfType::Int, &DfType::BigInt)
+                    .unwrap(),
+                DfType::BigInt
+            );
+            assert_eq!(
+                ShiftLeft
+                    .output_type(&DfType::Int, &DfType::BigInt)
+                    .unwrap(),
+                DfType::Int
+            );
+        }
     }
 }
@@ -1,7 +1,9 @@
 use std::borrow::Borrow;
 
+use bit_vec::BitVec;
 use readyset_data::{Array, ArrayD, DfValue, IxDyn};
-use readyset_errors::{invalid_err, unsupported, ReadySetError, ReadySetResult};
+use readyset_errors::{internal, invalid, invalid_err, unsupported, ReadySetError, ReadySetResult};
+use rust_decimal::prelude::ToPrimitive;
 use serde_json::Value as JsonValue;
 
 use crate::like::{CaseInsensitive, CaseSensitive, LikePattern};
@@ -19,6 +21,98 @@ macro_rules! non_null {
 mod builtins;
 mod json;
 
+/// Evaluate a bitwise operator on two non-null values.
+///
+/// Integers are operated on as unsigned 64-bit integers if either of the operands is unsigned, and
+/// as signed 64-bit integers otherwise.
+fn eval_bitwise_op(op: BinaryOperator, left: &DfValue, right: &DfValue) -> ReadySetResult<DfValue> {
+    use BinaryOperator::*;
+
+    if let DfValue::BitVector(bits) = left {
+        return eval_bit_string_op(op, bits, right);
+    }
+
+    let to_bits = |val: &DfValue| -> ReadySetResult<u64> {
+        Ok(match *val {
+            DfValue::UnsignedInt(u) => u,
+            DfValue::Int(i) => i as u64,
+            _ => i64::try_from(val)? as u64,
+        })
+    };
+    let (l, r) = (to_bits(left)?, to_bits(right)?);
+
+    if matches!(left, DfValue::UnsignedInt(_)) || matches!(right, DfValue::UnsignedInt(_)) {
+        // Shifting by 64 or more bits (or by a negative amount, which is a very large amount once
+        // interpreted as unsigned) shifts out all the bits
+        let shift_amount = u32::try_from(r).ok();
+        Ok(DfValue::UnsignedInt(match op {
+            BitwiseAnd => l & r,
+            BitwiseOr => l | r,
+            BitwiseXor => l ^ r,
+            ShiftLeft => shift_amount.and_then(|r| l.checked_shl(r)).unwrap_or(0),
+            ShiftRight => shift_amount.and_then(|r| l.checked_shr(r)).unwrap_or(0),
+            _ => internal!("'{op}' is not a bitwise operator"),
+        }))
+    } else {
+        let (l, r) = (l as i64, r as i64);
+        Ok(DfValue::Int(match op {
+            BitwiseAnd => l & r,
+            BitwiseOr => l | r,
+            BitwiseXor => l ^ r,
+            ShiftLeft => l.wrapping_shl(r as u32),
+            ShiftRight => l.wrapping_shr(r as u32),
+            _ => internal!("'{op}' is not a bitwise operator"),
+        }))
+    }
+}
+
+/// Evaluate a bitwise operator with a bit string on the left-hand side, following PostgreSQL's
+/// semantics
+fn eval_bit_string_op(
+    op: BinaryOperator,
+    bits: &BitVec,
+    right: &DfValue,
+) -> ReadySetResult<DfValue> {
+    use BinaryOperator::*;
+
+    let len = bits.len();
+    let res = match op {
+        BitwiseAnd | BitwiseOr | BitwiseXor => {
+            let DfValue::BitVector(other) = right else {
+                invalid!("cannot invoke '{op}' on a bit string and {}", right.infer_dataflow_type())
+            };
+            if other.len() != len {
+                invalid!("cannot invoke '{op}' on bit strings of different sizes");
+            }
+
+            let mut res = bits.clone();
+            match op {
+                BitwiseAnd => res.and(other),
+                BitwiseOr => res.or(other),
+                _ => res.xor(other),
+            };
+            res
+        }
+        ShiftLeft | ShiftRight => {
+            // Shifting bit strings preserves their length, and shifting by a negative amount
+            // shifts in the other direction
+            let amount = i64::try_from(right)?;
+            let left = (amount >= 0) == (op == ShiftLeft);
+            let amount = usize::try_from(amount.unsigned_abs()).unwrap_or(usize::MAX);
+            BitVec::from_fn(len, |i| {
+                if left {
+                    i.checked_add(amount).map_or(false, |j| j < len && bits[j])
+                } else {
+                    i.checked_sub(amount).map_or(false, |j| bits[j])
+                }
+            })
+        }
+        _ => internal!("'{op}' is not a bitwise operator"),
+    };
+
+    Ok(res.into())
+}
+
 fn eval_binary_op(op: BinaryOperator, left: &DfValue, right: &DfValue) -> ReadySetResult<DfValue> {
     use BinaryOperator::*;
 
@@ -40,6 +134,16 @@ fn eval_binary_op(op: BinaryOperator, left: &DfValue, right: &DfValue) -> ReadyS
         Subtract => Ok((non_null!(left) - non_null!(right))?),
         Multiply => Ok((non_null!(left) * non_null!(right))?),
         Divide => Ok((non_null!(left) / non_null!(right))?),
+        Modulo => Ok((non_null!(left) % non_null!(right))?),
+        IntegerDivide => Ok(match (non_null!(left) / non_null!(right))? {
+            DfValue::Float(f) => DfValue::Int(f.trunc() as i64),
+            DfValue::Double(f) => DfValue::Int(f.trunc() as i64),
+            DfValue::Numeric(d) => d.trunc().to_i64().into(),
+            quotient => quotient,
+        }),
+        BitwiseAnd | BitwiseOr | BitwiseXor | ShiftLeft | ShiftRight => {
+            eval_bitwise_op(op, non_null!(left), non_null!(right))
+        }
         And => Ok((non_null!(left).is_truthy() && non_null!(right).is_truthy()).into()),
         Or => Ok((non_null!(left).is_truthy() || non_null!(right).is_truthy()).into()),
         Equal => Ok((non_null!(left) == non_null!(right)).into()),
@@ -416,6 +520,91 @@ mod tests {
         );
     }
 
+    #[test]
+    fn eval_modulo() {
+        assert_eq!(eval_expr("7 % 3", MySQL), 1.into());
+        assert_eq!(eval_expr("7 MOD 3", MySQL), 1.into());
+        assert_eq!(eval_expr("-7 % 3", PostgreSQL), (-1).into());
+        assert_eq!(eval_expr("7.5 % 2", MySQL), 1.5.try_into().unwrap());
+        assert_eq!(eval_expr("7 % 0", MySQL), DfValue::None);
+        assert_eq!(eval_expr("null % 3", MySQL), DfValue::None);
+    }
+
+    #[test]
+    fn eval_integer_divide() {
+        assert_eq!(eval_expr("7 DIV 2", MySQL), 3.into());
+        assert_eq!(eval_expr("-7 DIV 2", MySQL), (-3).into());
+        assert_eq!(eval_expr("7.5 DIV 2", MySQL), 3.into());
+        assert_eq!(
+            eval_expr("CAST(7.5 AS DECIMAL(2, 1)) DIV 2", MySQL),
+            3.into()
+        );
+        assert_eq!(eval_expr("7 DIV 0", MySQL), DfValue::None);
+    }
+
+    #[test]
+    fn eval_bitwise_mysql() {
+        assert_eq!(eval_expr("5 & 3", MySQL), 1.into());
+        assert_eq!(eval_expr("5 | 3", MySQL), 7.into());
+        assert_eq!(eval_expr("5 ^ 3", MySQL), 6.into());
+        assert_eq!(eval_expr("1 << 4", MySQL), 16.into());
+        assert_eq!(eval_expr("256 >> 4", MySQL), 16.into());
+        assert_eq!(eval_expr("1 << 64", MySQL), 0.into());
+        assert_eq!(eval_expr("(-1) & 5", MySQL), 5.into());
+        assert_eq!(eval_expr("~0", MySQL), DfValue::UnsignedInt(u64::MAX));
+        assert_eq!(eval_expr("~5 & 7", MySQL), 2.into());
+        assert_eq!(eval_expr("5 & null", MySQL), DfValue::None);
+    }
+
+    #[test]
+    fn eval_bitwise_postgres() {
+        assert_eq!(eval_expr("5 & 3", PostgreSQL), 1.into());
+        assert_eq!(eval_expr("5 | 3", PostgreSQL), 7.into());
+        assert_eq!(eval_expr("5 # 3", PostgreSQL), 6.into());
+        assert_eq!(eval_expr("1 << 3", PostgreSQL), 8.into());
+        assert_eq!(eval_expr("(-5) | 2", PostgreSQL), (-5).into());
+        assert_eq!(eval_expr("~5", PostgreSQL), (-6).into());
+        assert_eq!(eval_expr("2 ^ 3", PostgreSQL), DfValue::Double(8.0));
+        assert_eq!(eval_expr("null # 3", PostgreSQL), DfValue::None);
+    }
+
+    #[test]
+    fn eval_bitwise_bit_strings() {
+        let bits = |s: &str| DfValue::from(s.chars().map(|c| c == '1').collect::<BitVec>());
+
+        assert_eq!(
+            eval_expr("B'11000000' & B'10100000'", PostgreSQL),
+            bits("10000000")
+        );
+        assert_eq!(
+            eval_expr("B'11000000' | B'10100000'", PostgreSQL),
+            bits("11100000")
+        );
+        assert_eq!(
+            eval_expr("B'11000000' # B'10100000'", PostgreSQL),
+            bits("01100000")
+        );
+        assert_eq!(eval_expr("B'11000000' << 1", PostgreSQL), bits("10000000"));
+        assert_eq!(eval_expr("B'11000000' >> 3", PostgreSQL), bits("00011000"));
+        assert_eq!(eval_expr("B'11000000' << -1", PostgreSQL), bits("01100000"));
+
+        let not = Expr::lower(
+            parse_expr(PostgreSQL, "~c").unwrap(),
+            Dialect::DEFAULT_POSTGRESQL,
+            resolve_columns(|_| Ok((0, DfType::Bit(8)))),
+        )
+        .unwrap();
+        assert_eq!(not.eval(&[bits("11000000")]).unwrap(), bits("00111111"));
+
+        let and = Expr::lower(
+            parse_expr(PostgreSQL, "c & B'11000000'").unwrap(),
+            Dialect::DEFAULT_POSTGRESQL,
+            resolve_columns(|_| Ok((0, DfType::VarBit(None)))),
+        )
+        .unwrap();
+        and.eval(&[bits("1100")]).unwrap_err();
+    }
+
     #[test]
     fn eval_json_exists() {
         let expr = Op {
@@ -3,11 +3,14 @@ use std::cmp::Ordering;
 use std::fmt::Write;
 use std::ops::{Add, Div, Mul, Sub};
 
-use chrono::{Datelike, LocalResult, Month, NaiveDate, NaiveDateTime, TimeZone, Timelike, Weekday};
+use chrono::{
+    Datelike, Duration, LocalResult, Month, NaiveDate, NaiveDateTime, TimeZone, Timelike, Weekday,
+};
 use chrono_tz::Tz;
 use itertools::Either;
 use mysql_time::MySqlTime;
-use readyset_data::{DfType, DfValue};
+use nom_sql::TimestampField;
+use readyset_data::{DfType, DfValue, TimestampTz};
 use readyset_errors::{invalid_err, ReadySetError, ReadySetResult};
 use readyset_util::math::integer_rnd;
 use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
@@ -108,84 +111,103 @@ fn addtime_times(time1: &MySqlTime, time2: &MySqlTime) -> MySqlTime {
     time1.add(*time2)
 }
 
-/// Format the given time value according to the given `format_string`, using the [MySQL date
-/// formatting rules][mysql-docs]. Since these rules don't match up well with anything available in
-/// the Rust crate ecosystem, this is done manually.
+/// Calcluate the week (and year!) number of a date-like value according to the ...algorithm...
+/// that MySQL uses. Returns a tuple of (week number, year), since in some operating modes a day
+/// may be part of the first week of the next year, or last week of the previous year.
 ///
-/// [mysql-docs]: https://dev.mysql.com/doc/refman/8.0/en/date-and-time-functions.html#function_date-format
-fn mysql_date_format<T>(time: T, format_string: &str) -> ReadySetResult<String>
+/// The actual algorithm here, and the arguments passed, are pretty close to a line-for-line
+/// translation of what MySQL uses, hence being quite impressively unidiomatic Rust: basically,
+/// I'd recommend thinking of this function and all of its callers as a black-box, at least for
+/// the time being (until we can dedicate the energy to actually understand what's going on
+/// here)
+fn week_and_year<T>(
+    time: &T,
+    monday_first: bool,
+    mut week_year: bool,
+    first_weekday: bool,
+) -> (u32, i32)
 where
-    T: Timelike + Datelike,
+    T: Datelike,
 {
-    /// Calcluate the week (and year!) number of a date-like value according to the ...algorithm...
-    /// that MySQL uses. Returns a tuple of (week number, year), since in some operating modes a day
-    /// may be part of the first week of the next year, or last week of the previous year.
-    ///
-    /// The actual algorithm here, and the arguments passed, are pretty close to a line-for-line
-    /// translation of what MySQL uses, hence being quite impressively unidiomatic Rust: basically,
-    /// I'd recommend thinking of this function and all of its callers as a black-box, at least for
-    /// the time being (until we can dedicate the energy to actually understand what's going on
-    /// here)
-    fn week_and_year<T>(
-        time: &T,
-        monday_first: bool,
-        mut week_year: bool,
-        first_weekday: bool,
-    ) -> (u32, i32)
-    where
-        T: Datelike,
-    {
-        fn days_in_year(year: i32) -> i32 {
-            if (year & 3) == 0 && ((year % 100 != 0) || (year % 400 == 0 && year != 0)) {
-                366
-            } else {
-                365
-            }
-        }
-
-        let mut days;
-        let daynr = time.num_days_from_ce();
-        let mut first_daynr = time
-            .with_day(1)
-            .unwrap()
-            .with_month(1)
-            .unwrap()
-            .num_days_from_ce();
-        let mut weekday = if monday_first {
-            time.weekday().num_days_from_monday()
+    fn days_in_year(year: i32) -> i32 {
+        if (year & 3) == 0 && ((year % 100 != 0) || (year % 400 == 0 && year != 0)) {
+            366
         } else {
-            time.weekday().num_days_from_sunday()
-        } as i32;
-        let mut year = time.year();
-
-        if time.month() == 1 && time.day() <= (7 - weekday) as u32 {
-            if !week_year && ((first_weekday && weekday != 0) || (!first_weekday && weekday >= 4)) {
-                return (0, year);
-            }
-            week_year = true;
-            year -= 1;
-            days = days_in_year(year);
-            first_daynr -= days;
-            weekday = (weekday + 53 * 7 - days) % 7;
+            365
         }
+    }
 
-        if (first_weekday && weekday != 0) || (!first_weekday && weekday >= 4) {
-            days = daynr - (first_daynr + (7 - weekday))
-        } else {
-            days = daynr - (first_daynr - weekday)
+    let mut days;
+    let daynr = time.num_days_from_ce();
+    let first_day = time.with_day(1).unwrap().with_month(1).unwrap();
+    let mut first_daynr = first_day.num_days_from_ce();
+    // Note that this is the weekday of the first day of the year, not of `time`
+    let mut weekday = if monday_first {
+        first_day.weekday().num_days_from_monday()
+    } else {
+        first_day.weekday().num_days_from_sunday()
+    } as i32;
+    let mut year = time.year();
+
+    if time.month() == 1 && time.day() <= (7 - weekday) as u32 {
+        if !week_year && ((first_weekday && weekday != 0) || (!first_weekday && weekday >= 4)) {
+            return (0, year);
         }
+        week_year = true;
+        year -= 1;
+        days = days_in_year(year);
+        first_daynr -= days;
+        weekday = (weekday + 53 * 7 - days) % 7;
+    }
 
-        if week_year && days >= 52 * 7 {
-            weekday = (weekday + days_in_year(year)) % 7;
-            if (!first_weekday && weekday < 4) || (first_weekday && weekday == 0) {
-                year += 1;
-                return (1, year);
-            }
+    if (first_weekday && weekday != 0) || (!first_weekday && weekday >= 4) {
+        days = daynr - (first_daynr + (7 - weekday))
+    } else {
+        days = daynr - (first_daynr - weekday)
+    }
+
+    if week_year && days >= 52 * 7 {
+        weekday = (weekday + days_in_year(year)) % 7;
+        if (!first_weekday && weekday < 4) || (first_weekday && weekday == 0) {
+            year += 1;
+            return (1, year);
         }
+    }
+
+    ((days / 7 + 1) as u32, year)
+}
 
-        ((days / 7 + 1) as u32, year)
+/// Calculate the week number of a date-like value according to the given `mode`, as per MySQL's
+/// [`WEEK`][mysql-docs] function
+///
+/// [mysql-docs]: https://dev.mysql.com/doc/refman/8.0/en/date-and-time-functions.html#function_week
+fn week<T>(time: &T, mode: i64) -> u32
+where
+    T: Datelike,
+{
+    // The modes which don't start the week on monday flip the meaning of the "first weekday" bit
+    let mut behaviour = mode & 7;
+    if behaviour & 1 == 0 {
+        behaviour ^= 4;
     }
+    week_and_year(
+        time,
+        behaviour & 1 != 0,
+        behaviour & 2 != 0,
+        behaviour & 4 != 0,
+    )
+    .0
+}
 
+/// Format the given time value according to the given `format_string`, using the [MySQL date
+/// formatting rules][mysql-docs]. Since these rules don't match up well with anything available in
+/// the Rust crate ecosystem, this is done manually.
+///
+/// [mysql-docs]: https://dev.mysql.com/doc/refman/8.0/en/date-and-time-functions.html#function_date-format
+fn mysql_date_format<T>(time: T, format_string: &str) -> ReadySetResult<String>
+where
+    T: Timelike + Datelike,
+{
     // | %a   | Abbreviated weekday name (Sun..Sat)
     // | %b   | Abbreviated month name (Jan..Dec)
     // | %c   | Month, numeric (0..12)
@@ -294,7 +316,7 @@ where
                     time.second()
                 )
                 .unwrap(),
-                'U' => write!(res, "{:02}", week_and_year(&time, false, false, false).0).unwrap(),
+                'U' => write!(res, "{:02}", week_and_year(&time, false, false, true).0).unwrap(),
                 'u' => write!(res, "{:02}", week_and_year(&time, true, false, false).0).unwrap(),
                 'V' => write!(res, "{:02}", week_and_year(&time, false, true, true).0).unwrap(),
                 'v' => write!(res, "{:02}", week_and_year(&time, true, true, false).0).unwrap(),
@@ -323,6 +345,531 @@ where
     Ok(res)
 }
 
+/// Attempts to interpret `value` as either a timestamp or a time of day, for the date and time
+/// functions which accept both. Returns [`None`] if it can't be interpreted as either.
+fn timestamp_or_time(value: &DfValue, from_ty: &DfType) -> Option<Either<TimestampTz, MySqlTime>> {
+    match value {
+        DfValue::TimestampTz(ts) => Some(Either::Left(*ts)),
+        DfValue::Time(time) => Some(Either::Right(*time)),
+        _ => match get_time_or_default(value, from_ty) {
+            DfValue::TimestampTz(ts) => Some(Either::Left(ts)),
+            DfValue::Time(time) => Some(Either::Right(time)),
+            _ => None,
+        },
+    }
+}
+
+/// Builds a timestamp in the same timezone as `original` from the given local date and time,
+/// returning [`None`] if that local time doesn't exist in the timezone
+fn timestamp_from_local(original: &TimestampTz, datetime: NaiveDateTime) -> Option<TimestampTz> {
+    if original.has_timezone() {
+        original
+            .to_chrono()
+            .offset()
+            .from_local_datetime(&datetime)
+            .single()
+            .map(TimestampTz::from)
+    } else {
+        Some(datetime.into())
+    }
+}
+
+/// Returns the value of the given `field` of a timestamp, with PostgreSQL's semantics. This is a
+/// decimal since some fields (such as `SECOND` or `EPOCH`) include fractional seconds.
+fn extract_from_timestamp(field: TimestampField, ts: &TimestampTz) -> Decimal {
+    let datetime = ts.to_chrono();
+    let local = datetime.naive_local();
+    let micros = local.nanosecond() / 1000;
+    let second_micros = i64::from(local.second()) * 1_000_000 + i64::from(micros);
+    match field {
+        TimestampField::Century => Decimal::from((local.year() + 99).div_euclid(100)),
+        TimestampField::Day => Decimal::from(local.day()),
+        TimestampField::Decade => Decimal::from(local.year().div_euclid(10)),
+        TimestampField::Dow => Decimal::from(local.weekday().num_days_from_sunday()),
+        TimestampField::Doy => Decimal::from(local.ordinal()),
+        TimestampField::Epoch => {
+            Decimal::new(datetime.timestamp() * 1_000_000 + i64::from(micros), 6)
+        }
+        TimestampField::Hour => Decimal::from(local.hour()),
+        TimestampField::IsoDow => Decimal::from(local.weekday().number_from_monday()),
+        TimestampField::IsoYear => Decimal::from(local.iso_week().year()),
+        TimestampField::Microsecond => Decimal::from(second_micros),
+        TimestampField::Millennium => Decimal::from((local.year() + 999).div_euclid(1000)),
+        TimestampField::Millisecond => Decimal::new(second_micros, 3),
+        TimestampField::Minute => Decimal::from(local.minute()),
+        TimestampField::Month => Decimal::from(local.month()),
+        TimestampField::Quarter => Decimal::from((local.month() - 1) / 3 + 1),
+        TimestampField::Second => Decimal::new(second_micros, 6),
+        TimestampField::Week => Decimal::from(local.iso_week().week()),
+        TimestampField::Year => Decimal::from(local.year()),
+    }
+}
+
+/// Returns the value of the given `field` of a time of day, or [`None`] if `field` is a date
+/// field
+fn extract_from_time(field: TimestampField, time: &MySqlTime) -> Option<Decimal> {
+    let second_micros = i64::from(time.seconds()) * 1_000_000 + i64::from(time.microseconds());
+    match field {
+        TimestampField::Hour => Some(Decimal::from(time.hour())),
+        TimestampField::Minute => Some(Decimal::from(time.minutes())),
+        TimestampField::Second => Some(Decimal::new(second_micros, 6)),
+        TimestampField::Microsecond => Some(Decimal::from(second_micros)),
+        TimestampField::Millisecond => Some(Decimal::new(second_micros, 3)),
+        TimestampField::Epoch => {
+            let total_micros = ((i64::from(time.hour()) * 60 + i64::from(time.minutes())) * 60)
+                * 1_000_000
+                + second_micros;
+            Some(Decimal::new(
+                if time.is_positive() {
+                    total_micros
+                } else {
+                    -total_micros
+                },
+                6,
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Truncates `datetime` to the precision given by `field`, as per PostgreSQL's `date_trunc`.
+/// Returns [`None`] if the result is out of range, or if `field` isn't a valid precision.
+fn date_trunc(field: TimestampField, datetime: NaiveDateTime) -> Option<NaiveDateTime> {
+    let date = datetime.date();
+    let first_of_month =
+        |year, month| NaiveDate::from_ymd_opt(year, month, 1).and_then(|d| d.and_hms_opt(0, 0, 0));
+    match field {
+        TimestampField::Microsecond => {
+            datetime.with_nanosecond(datetime.nanosecond() / 1_000 * 1_000)
+        }
+        TimestampField::Millisecond => {
+            datetime.with_nanosecond(datetime.nanosecond() / 1_000_000 * 1_000_000)
+        }
+        TimestampField::Second => datetime.with_nanosecond(0),
+        TimestampField::Minute => date.and_hms_opt(datetime.hour(), datetime.minute(), 0),
+        TimestampField::Hour => date.and_hms_opt(datetime.hour(), 0, 0),
+        TimestampField::Day => date.and_hms_opt(0, 0, 0),
+        // Weeks start on monday
+        TimestampField::Week => date
+            .checked_sub_signed(Duration::days(date.weekday().num_days_from_monday().into()))?
+            .and_hms_opt(0, 0, 0),
+        TimestampField::Month => first_of_month(date.year(), date.month()),
+        TimestampField::Quarter => first_of_month(date.year(), (date.month() - 1) / 3 * 3 + 1),
+        TimestampField::Year => first_of_month(date.year(), 1),
+        TimestampField::Decade => first_of_month(date.year().div_euclid(10) * 10, 1),
+        // Centuries and millennia start in years ending in 1
+        TimestampField::Century => first_of_month((date.year() - 1).div_euclid(100) * 100 + 1, 1),
+        TimestampField::Millennium => {
+            first_of_month((date.year() - 1).div_euclid(1000) * 1000 + 1, 1)
+        }
+        TimestampField::Dow
+        | TimestampField::Doy
+        | TimestampField::Epoch
+        | TimestampField::IsoDow
+        | TimestampField::IsoYear => None,
+    }
+}
+
+/// Adds `amount` of the given `unit` to `datetime`, as per MySQL's `DATE_ADD`. Fractional amounts
+/// are rounded, except for seconds. Adding months (or quarters, or years) clamps the day to the end
+/// of the resulting month, so eg one month after `2020-01-31` is `2020-02-29`.
+///
+/// Returns [`None`] if the result is out of range, or if `unit` isn't a valid interval unit.
+fn date_add(
+    datetime: NaiveDateTime,
+    amount: Decimal,
+    unit: TimestampField,
+) -> Option<NaiveDateTime> {
+    let add_months = |months: i64| {
+        let months = i64::from(datetime.year())
+            .checked_mul(12)?
+            .checked_add(i64::from(datetime.month0()))?
+            .checked_add(months)?;
+        let year = i32::try_from(months.div_euclid(12)).ok()?;
+        let month = months.rem_euclid(12) as u32 + 1;
+        let last_day = (28..=31)
+            .rev()
+            .find(|day| NaiveDate::from_ymd_opt(year, month, *day).is_some())?;
+        Some(
+            NaiveDate::from_ymd_opt(year, month, datetime.day().min(last_day))?
+                .and_time(datetime.time()),
+        )
+    };
+    let add_micros = |micros_per_unit: i64| {
+        let micros = amount.round().to_i64()?.checked_mul(micros_per_unit)?;
+        datetime.checked_add_signed(Duration::microseconds(micros))
+    };
+
+    match unit {
+        TimestampField::Year => add_months(amount.round().to_i64()?.checked_mul(12)?),
+        TimestampField::Quarter => add_months(amount.round().to_i64()?.checked_mul(3)?),
+        TimestampField::Month => add_months(amount.round().to_i64()?),
+        TimestampField::Week => add_micros(7 * 24 * 60 * 60 * 1_000_000),
+        TimestampField::Day => add_micros(24 * 60 * 60 * 1_000_000),
+        TimestampField::Hour => add_micros(60 * 60 * 1_000_000),
+        TimestampField::Minute => add_micros(60 * 1_000_000),
+        TimestampField::Second => {
+            let micros = amount
+                .checked_mul(Decimal::from(1_000_000))?
+                .round()
+                .to_i64()?;
+            datetime.checked_add_signed(Duration::microseconds(micros))
+        }
+        TimestampField::Microsecond => add_micros(1),
+        _ => None,
+    }
+}
+
+/// Converts a number of seconds since the epoch to a datetime, as per MySQL's `FROM_UNIXTIME`.
+/// Returns [`None`] for negative or out of range values.
+fn from_unixtime(seconds: Decimal) -> Option<NaiveDateTime> {
+    if seconds.is_sign_negative() {
+        return None;
+    }
+    let micros = seconds
+        .checked_mul(Decimal::from(1_000_000))?
+        .round()
+        .to_i64()?;
+    NaiveDateTime::from_timestamp_opt(
+        micros.div_euclid(1_000_000),
+        (micros.rem_euclid(1_000_000) * 1_000) as u32,
+    )
+}
+
+const WEEKDAY_NAMES: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// Returns the English ordinal suffix ("st", "nd", "rd" or "th") for the given number
+fn ordinal_suffix(n: u32) -> &'static str {
+    match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+/// Format the given time value according to the given `format_string`, using the [PostgreSQL
+/// template patterns for date and time formatting][pg-docs], as per `to_char`.
+///
+/// Numeric patterns are matched case-insensitively, whereas the case of patterns which output text
+/// (such as `MONTH`, `Month` or `month`) determines the case of the output. The `FM` prefix and
+/// `TH` suffix modifiers, as well as double-quoted literal text, are supported; time zone patterns
+/// are not.
+///
+/// [pg-docs]: https://www.postgresql.org/docs/current/functions-formatting.html#FUNCTIONS-FORMATTING-DATETIME-TABLE
+fn postgres_to_char<T>(time: &T, format_string: &str) -> String
+where
+    T: Timelike + Datelike,
+{
+    /// Patterns, ordered such that no pattern is preceded by a prefix of itself
+    const PATTERNS: [&str; 37] = [
+        "HH24", "HH12", "HH", "MI", "SSSSS", "SSSS", "SS", "MS", "US", "A.M.", "P.M.", "AM", "PM",
+        "YYYY", "YYY", "YY", "Y", "IYYY", "IYY", "IW", "ID", "IY", "I", "MONTH", "MON", "MM",
+        "DAY", "DDD", "DD", "DY", "D", "WW", "W", "Q", "CC", "J", "FM",
+    ];
+
+    // Applies the case of the pattern as written to `text`
+    let with_case = |pattern: &str, text: &str| {
+        if pattern.chars().all(|c| !c.is_ascii_lowercase()) {
+            text.to_uppercase()
+        } else if pattern.starts_with(|c: char| c.is_ascii_uppercase()) {
+            text.to_owned()
+        } else {
+            text.to_lowercase()
+        }
+    };
+
+    let hour12 = time.hour12().1;
+    let iso_year = time.iso_week().year();
+    let month_name = Month::from_u32(time.month()).unwrap().name();
+    let weekday_name = WEEKDAY_NAMES[time.weekday().num_days_from_monday() as usize];
+    let seconds_of_day = time.num_seconds_from_midnight();
+
+    let mut res = String::with_capacity(format_string.len().next_power_of_two());
+    let mut rest = format_string;
+    let mut fill_mode = false;
+    while !rest.is_empty() {
+        if let Some(quoted) = rest.strip_prefix('"') {
+            let end = quoted.find('"').unwrap_or(quoted.len());
+            res.push_str(&quoted[..end]);
+            rest = quoted.get(end + 1..).unwrap_or_default();
+            continue;
+        }
+
+        let Some(pattern) = PATTERNS.iter().copied().find(|pattern| {
+            rest.get(..pattern.len())
+                .map_or(false, |prefix| prefix.eq_ignore_ascii_case(pattern))
+        }) else {
+            let c = rest.chars().next().unwrap();
+            res.push(c);
+            rest = &rest[c.len_utf8()..];
+            fill_mode = false;
+            continue;
+        };
+        let written = &rest[..pattern.len()];
+        rest = &rest[pattern.len()..];
+
+        if pattern == "FM" {
+            fill_mode = true;
+            continue;
+        }
+
+        let (number, width) = match pattern {
+            "HH24" => (time.hour(), 2),
+            "HH12" | "HH" => (hour12, 2),
+            "MI" => (time.minute(), 2),
+            "SSSSS" | "SSSS" => (seconds_of_day, 0),
+            "SS" => (time.second(), 2),
+            "MS" => (time.nanosecond() / 1_000_000, 3),
+            "US" => (time.nanosecond() / 1_000, 6),
+            "YYYY" => (time.year().unsigned_abs(), 4),
+            "YYY" => (time.year().unsigned_abs() % 1000, 3),
+            "YY" => (time.year().unsigned_abs() % 100, 2),
+            "Y" => (time.year().unsigned_abs() % 10, 1),
+            "IYYY" => (iso_year.unsigned_abs(), 4),
+            "IYY" => (iso_year.unsigned_abs() % 1000, 3),
+            "IY" => (iso_year.unsigned_abs() % 100, 2),
+            "I" => (iso_year.unsigned_abs() % 10, 1),
+            "IW" => (time.iso_week().week(), 2),
+            "ID" => (time.weekday().number_from_monday(), 1),
+            "MM" => (time.month(), 2),
+            "DDD" => (time.ordinal(), 3),
+            "DD" => (time.day(), 2),
+            "D" => (time.weekday().number_from_sunday(), 1),
+            "WW" => ((time.ordinal() - 1) / 7 + 1, 2),
+            "W" => ((time.day() - 1) / 7 + 1, 1),
+            "Q" => ((time.month() - 1) / 3 + 1, 1),
+            "CC" => ((time.year().unsigned_abs() + 99) / 100, 2),
+            "J" => ((time.num_days_from_ce() + 1_721_425) as u32, 0),
+            _ => {
+                let text = match pattern {
+                    "A.M." | "P.M." => {
+                        if time.hour() >= 12 {
+                            "P.M."
+                        } else {
+                            "A.M."
+                        }
+                    }
+                    "AM" | "PM" => {
+                        if time.hour() >= 12 {
+                            "PM"
+                        } else {
+                            "AM"
+                        }
+                    }
+                    "MONTH" => month_name,
+                    "MON" => &month_name[..3],
+                    "DAY" => weekday_name,
+                    _ => &weekday_name[..3],
+                };
+                let text = with_case(written, text);
+                if matches!(pattern, "MONTH" | "DAY") && !fill_mode {
+                    write!(res, "{text:<9}").unwrap();
+                } else {
+                    res.push_str(&text);
+                }
+                fill_mode = false;
+                continue;
+            }
+        };
+
+        if fill_mode {
+            write!(res, "{number}").unwrap();
+        } else {
+            write!(res, "{number:0width$}").unwrap();
+        }
+        fill_mode = false;
+
+        if let Some(suffix) = rest.get(..2).filter(|s| s.eq_ignore_ascii_case("TH")) {
+            res.push_str(&with_case(suffix, ordinal_suffix(number)));
+            rest = &rest[2..];
+        }
+    }
+
+    res
+}
+
+/// Parses `string` according to the given `format_string`, using the same format specifiers as
+/// [`mysql_date_format`], as per MySQL's [`STR_TO_DATE`][mysql-docs]. Returns a date, a time, or
+/// a datetime depending on which parts the format contains, or [`None`] if the string doesn't
+/// match the format or describes an invalid (or incomplete) date.
+///
+/// [mysql-docs]: https://dev.mysql.com/doc/refman/8.0/en/date-and-time-functions.html#function_str-to-date
+fn mysql_str_to_date(string: &str, format_string: &str) -> Option<DfValue> {
+    #[derive(Default)]
+    struct Parts {
+        year: Option<i32>,
+        month: Option<u32>,
+        day: Option<u32>,
+        day_of_year: Option<u32>,
+        has_time: bool,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        microsecond: u32,
+        pm: Option<bool>,
+    }
+
+    /// Consumes between 1 and `max_digits` digits from the start of `input`
+    fn take_number(input: &mut &str, max_digits: usize) -> Option<u32> {
+        let len = input
+            .bytes()
+            .take(max_digits)
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        if len == 0 {
+            return None;
+        }
+        let number = input[..len].parse().ok()?;
+        *input = &input[len..];
+        Some(number)
+    }
+
+    /// Consumes one of `names` (case-insensitively) from the start of `input`, returning its
+    /// index
+    fn take_name<'a, I>(input: &mut &str, names: I) -> Option<usize>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let (idx, len) = names.into_iter().enumerate().find_map(|(idx, name)| {
+            input
+                .get(..name.len())
+                .filter(|prefix| prefix.eq_ignore_ascii_case(name))
+                .map(|_| (idx, name.len()))
+        })?;
+        *input = &input[len..];
+        Some(idx)
+    }
+
+    fn parse(parts: &mut Parts, input: &mut &str, format_string: &str) -> Option<()> {
+        let month_names = || (1..=12).map(|m| Month::from_u32(m).unwrap().name());
+        let mut chars = format_string.chars();
+        while let Some(c) = chars.next() {
+            // Whitespace in the input is skipped before each part of the format, and anything left
+            // in the format once the input runs out is ignored
+            *input = input.trim_start();
+            if input.is_empty() {
+                break;
+            }
+            if c.is_whitespace() {
+                continue;
+            }
+            if c != '%' {
+                *input = input.strip_prefix(c)?;
+                continue;
+            }
+
+            let Some(format_spec) = chars.next() else {
+                *input = input.strip_prefix('%')?;
+                break;
+            };
+            match format_spec {
+                'Y' => parts.year = Some(take_number(input, 4)? as i32),
+                'y' => {
+                    let year = take_number(input, 2)? as i32;
+                    parts.year = Some(if year < 70 { 2000 + year } else { 1900 + year });
+                }
+                'm' | 'c' => parts.month = Some(take_number(input, 2)?),
+                'M' => parts.month = Some(take_name(input, month_names())? as u32 + 1),
+                'b' => {
+                    parts.month = Some(take_name(input, month_names().map(|n| &n[..3]))? as u32 + 1)
+                }
+                'd' | 'e' => parts.day = Some(take_number(input, 2)?),
+                'D' => {
+                    parts.day = Some(take_number(input, 2)?);
+                    take_name(input, ["st", "nd", "rd", "th"])?;
+                }
+                'j' => parts.day_of_year = Some(take_number(input, 3)?),
+                'W' => {
+                    take_name(input, WEEKDAY_NAMES)?;
+                }
+                'a' => {
+                    take_name(input, WEEKDAY_NAMES.map(|n| &n[..3]))?;
+                }
+                'H' | 'k' | 'h' | 'I' | 'l' => {
+                    parts.hour = take_number(input, 2)?;
+                    parts.has_time = true;
+                }
+                'i' => {
+                    parts.minute = take_number(input, 2)?;
+                    parts.has_time = true;
+                }
+                'S' | 's' => {
+                    parts.second = take_number(input, 2)?;
+                    parts.has_time = true;
+                }
+                'f' => {
+                    let len = input
+                        .bytes()
+                        .take(6)
+                        .take_while(|b| b.is_ascii_digit())
+                        .count();
+                    // Fractional digits are right-padded, so `.5` is 500000 microseconds
+                    parts.microsecond = take_number(input, 6)? * 10u32.pow(6 - len as u32);
+                    parts.has_time = true;
+                }
+                'p' => {
+                    parts.pm = Some(take_name(input, ["AM", "PM"])? == 1);
+                    parts.has_time = true;
+                }
+                'w' => {
+                    take_number(input, 1)?;
+                }
+                'T' => parse(parts, input, "%H:%i:%s")?,
+                'r' => parse(parts, input, "%h:%i:%s %p")?,
+                '%' => *input = input.strip_prefix('%')?,
+                _ => return None,
+            }
+        }
+        Some(())
+    }
+
+    let mut parts = Parts::default();
+    let mut input = string;
+    parse(&mut parts, &mut input, format_string)?;
+
+    let hour = match parts.pm {
+        Some(_) if parts.hour == 0 || parts.hour > 12 => return None,
+        Some(pm) => parts.hour % 12 + if pm { 12 } else { 0 },
+        None => parts.hour,
+    };
+    if parts.minute > 59 || parts.second > 59 {
+        return None;
+    }
+
+    let date = match (parts.year, parts.month, parts.day, parts.day_of_year) {
+        (None, None, None, None) => None,
+        (Some(year), _, _, Some(day_of_year)) => Some(NaiveDate::from_yo_opt(year, day_of_year)?),
+        (Some(year), Some(month), Some(day), _) => Some(NaiveDate::from_ymd_opt(year, month, day)?),
+        _ => return None,
+    };
+
+    match (date, parts.has_time) {
+        (Some(date), true) => Some(
+            date.and_hms_micro_opt(hour, parts.minute, parts.second, parts.microsecond)?
+                .into(),
+        ),
+        (Some(date), false) => Some(date.into()),
+        (None, true) => Some(DfValue::Time(MySqlTime::from_hmsus(
+            true,
+            hour.try_into().ok()?,
+            parts.minute as u8,
+            parts.second as u8,
+            parts.microsecond.into(),
+        ))),
+        (None, false) => None,
+    }
+}
+
 fn greatest_or_least<F, D>(
     args: &Vec1<Expr>,
     record: &[D],
@@ -350,6 +897,254 @@ where
     Ok(try_cast_or_none!(res, ty, res_ty))
 }
 
+/// Evaluates `args` as floating-point numbers and applies `f` to them, coercing the result to `ty`.
+///
+/// Returns NULL if the result is not a finite number, which is what MySQL does for arguments
+/// outside of the domain of functions like `sqrt` or `ln` (PostgreSQL raises an error instead).
+fn float_function<D, F, const N: usize>(
+    args: [&Expr; N],
+    ty: &DfType,
+    record: &[D],
+    f: F,
+) -> ReadySetResult<DfValue>
+where
+    D: Borrow<DfValue>,
+    F: FnOnce([f64; N]) -> f64,
+{
+    let mut vals = [0f64; N];
+    for (val, arg) in vals.iter_mut().zip(args) {
+        *val = f64::try_from(&non_null!(arg.eval(record)?))?;
+    }
+
+    let res = f(vals);
+    if !res.is_finite() {
+        return Ok(DfValue::None);
+    }
+    DfValue::Double(res).coerce_to(ty, &DfType::Double)
+}
+
+/// Truncates `val` to `prec` digits after the decimal point, or to `-prec` digits before the
+/// decimal point if `prec` is negative
+fn truncate(val: DfValue, prec: i32) -> ReadySetResult<DfValue> {
+    fn truncate_integer(val: i128, prec: i32) -> i128 {
+        if prec >= 0 {
+            return val;
+        }
+        match 10i128.checked_pow(prec.unsigned_abs()) {
+            Some(factor) => val / factor * factor,
+            None => 0,
+        }
+    }
+
+    macro_rules! truncate_float {
+        ($val:expr, $float_type:ty) => {{
+            let factor = (10 as $float_type).powi(prec);
+            let scaled = $val * factor;
+            if !scaled.is_finite() {
+                $val
+            } else if factor <= 0.0 {
+                0.0
+            } else {
+                scaled.trunc() / factor
+            }
+        }};
+    }
+
+    Ok(match val {
+        DfValue::Int(i) => DfValue::Int(truncate_integer(i as i128, prec) as i64),
+        DfValue::UnsignedInt(u) => DfValue::UnsignedInt(truncate_integer(u as i128, prec) as u64),
+        DfValue::Float(f) => DfValue::Float(truncate_float!(f, f32)),
+        DfValue::Double(f) => DfValue::Double(truncate_float!(f, f64)),
+        DfValue::Numeric(d) => {
+            if prec >= 0 {
+                DfValue::from(
+                    d.round_dp_with_strategy(prec as u32, rust_decimal::RoundingStrategy::ToZero),
+                )
+            } else {
+                let mut factor = Decimal::ONE;
+                for _ in 0..prec.unsigned_abs() {
+                    factor = match factor.checked_mul(Decimal::from(10)) {
+                        Some(factor) => factor,
+                        None => return Ok(DfValue::from(Decimal::ZERO)),
+                    };
+                }
+                DfValue::from((*d / factor).trunc() * factor)
+            }
+        }
+        val => DfValue::Double(truncate_float!(f64::try_from(&val)?, f64)),
+    })
+}
+
+/// Which end(s) of a string to remove characters from, for the `trim` family of functions
+#[derive(Clone, Copy)]
+enum TrimEnd {
+    Both,
+    Leading,
+    Trailing,
+}
+
+fn trim<D>(
+    string: &Expr,
+    characters: Option<&Expr>,
+    end: TrimEnd,
+    record: &[D],
+) -> ReadySetResult<DfValue>
+where
+    D: Borrow<DfValue>,
+{
+    let string = non_null!(string.eval(record)?);
+    let characters = match characters {
+        Some(characters) => Some(non_null!(characters.eval(record)?)),
+        None => None,
+    };
+
+    let s = <&str>::try_from(&string)?;
+    let characters = match &characters {
+        Some(characters) => <&str>::try_from(characters)?.chars().collect(),
+        None => vec![' '],
+    };
+    let pat = |c: char| characters.contains(&c);
+    Ok(match end {
+        TrimEnd::Both => s.trim_matches(pat),
+        TrimEnd::Leading => s.trim_start_matches(pat),
+        TrimEnd::Trailing => s.trim_end_matches(pat),
+    }
+    .into())
+}
+
+/// The maximum length, in bytes, of the result of a string function which can make its input
+/// arbitrarily longer (`REPEAT`, `LPAD` and `RPAD`). Longer results are NULL, as they are in MySQL
+/// when the result would be longer than the default `max_allowed_packet` of 64MiB.
+const MAX_STRING_RESULT_LEN: usize = 64 * 1024 * 1024;
+
+fn pad<D>(
+    string: &Expr,
+    length: &Expr,
+    fill: &Expr,
+    null_on_invalid: bool,
+    left: bool,
+    record: &[D],
+) -> ReadySetResult<DfValue>
+where
+    D: Borrow<DfValue>,
+{
+    let string = non_null!(string.eval(record)?);
+    let length = i64::try_from(non_null!(length.eval(record)?))?;
+    let fill = non_null!(fill.eval(record)?);
+
+    let s = <&str>::try_from(&string)?;
+    let fill = <&str>::try_from(&fill)?;
+
+    if length < 0 {
+        return Ok(if null_on_invalid {
+            DfValue::None
+        } else {
+            "".into()
+        });
+    }
+
+    let length = length as usize;
+    let char_count = s.chars().count();
+    if char_count >= length {
+        return Ok(s.chars().take(length).collect::<String>().into());
+    }
+
+    if fill.is_empty() {
+        return Ok(if null_on_invalid {
+            DfValue::None
+        } else {
+            s.into()
+        });
+    }
+
+    let padding_chars = length - char_count;
+    let fill_chars = fill.chars().count();
+    let padding_len = (padding_chars / fill_chars)
+        .saturating_mul(fill.len())
+        .saturating_add(
+            fill.chars()
+                .take(padding_chars % fill_chars)
+                .map(char::len_utf8)
+                .sum(),
+        );
+    if s.len().saturating_add(padding_len) > MAX_STRING_RESULT_LEN {
+        return Ok(DfValue::None);
+    }
+
+    let padding = fill.chars().cycle().take(padding_chars);
+    Ok(if left {
+        padding.chain(s.chars()).collect::<String>()
+    } else {
+        s.chars().chain(padding).collect::<String>()
+    }
+    .into())
+}
+
+fn left_or_right<D>(
+    string: &Expr,
+    length: &Expr,
+    negative_from_end: bool,
+    left: bool,
+    record: &[D],
+) -> ReadySetResult<DfValue>
+where
+    D: Borrow<DfValue>,
+{
+    let string = non_null!(string.eval(record)?);
+    let length = i64::try_from(non_null!(length.eval(record)?))?;
+
+    let s = <&str>::try_from(&string)?;
+    let char_count = s.chars().count() as i64;
+    let n = match length.cmp(&0) {
+        Ordering::Less if negative_from_end => (char_count + length).max(0),
+        Ordering::Less => 0,
+        _ => length.min(char_count),
+    };
+
+    Ok(if left {
+        s.chars().take(n as usize).collect::<String>()
+    } else {
+        s.chars()
+            .skip((char_count - n) as usize)
+            .collect::<String>()
+    }
+    .into())
+}
+
+/// Returns the 1-based character position of the first occurrence of `substring` in `string` at or
+/// after the character position `start`, or 0 if there is none
+fn locate(substring: &str, string: &str, start: i64) -> i64 {
+    if start < 1 {
+        return 0;
+    }
+
+    let skip = (start - 1) as usize;
+    let offset = match string.char_indices().nth(skip) {
+        Some((offset, _)) => offset,
+        None if skip == string.chars().count() => string.len(),
+        None => return 0,
+    };
+
+    match string[offset..].find(substring) {
+        Some(idx) => start + string[offset..offset + idx].chars().count() as i64,
+        None => 0,
+    }
+}
+
+fn initcap(s: &str) -> String {
+    let mut res = String::with_capacity(s.len());
+    let mut in_word = false;
+    for c in s.chars() {
+        if in_word {
+            res.extend(c.to_lowercase());
+        } else {
+            res.extend(c.to_uppercase());
+        }
+        in_word = c.is_alphanumeric();
+    }
+    res
+}
+
 impl BuiltinFunction {
     pub(crate) fn eval<D>(&self, ty: &DfType, record: &[D]) -> ReadySetResult<DfValue>
     where
@@ -443,6 +1238,139 @@ impl BuiltinFunction {
                     Ok(DfValue::None)
                 }
             }
+            BuiltinFunction::Extract { field, expr } => {
+                let value = non_null!(expr.eval(record)?);
+                let res = match timestamp_or_time(&value, expr.ty()) {
+                    Some(Either::Left(ts)) => extract_from_timestamp(*field, &ts),
+                    Some(Either::Right(time)) => match extract_from_time(*field, &time) {
+                        Some(res) => res,
+                        None => return Ok(DfValue::None),
+                    },
+                    None => return Ok(DfValue::None),
+                };
+                DfValue::from(res).coerce_to(ty, &DfType::DEFAULT_NUMERIC)
+            }
+            BuiltinFunction::Week(arg1, arg2) => {
+                let date = NaiveDate::try_from(&non_null!(arg1.eval(record)?))?;
+                let mode = i64::try_from(&non_null!(arg2.eval(record)?))?;
+                Ok(DfValue::Int(week(&date, mode).into()))
+            }
+            BuiltinFunction::DateTrunc { field, expr } => {
+                let DfValue::TimestampTz(ts) = non_null!(expr.eval(record)?) else {
+                    return Ok(DfValue::None);
+                };
+                match date_trunc(*field, ts.to_chrono().naive_local())
+                    .and_then(|datetime| timestamp_from_local(&ts, datetime))
+                {
+                    Some(res) => DfValue::TimestampTz(res).coerce_to(ty, expr.ty()),
+                    None => Ok(DfValue::None),
+                }
+            }
+            BuiltinFunction::DateAdd {
+                expr,
+                interval,
+                unit,
+            } => {
+                let DfValue::TimestampTz(ts) = non_null!(expr.eval(record)?) else {
+                    return Ok(DfValue::None);
+                };
+                let amount = try_cast_or_none!(
+                    non_null!(interval.eval(record)?),
+                    &DfType::DEFAULT_NUMERIC,
+                    interval.ty()
+                );
+                let Some(datetime) = date_add(
+                    ts.to_chrono().naive_local(),
+                    Decimal::try_from(&amount)?,
+                    *unit,
+                ) else {
+                    return Ok(DfValue::None);
+                };
+
+                let is_date_unit = matches!(
+                    unit,
+                    TimestampField::Day
+                        | TimestampField::Week
+                        | TimestampField::Month
+                        | TimestampField::Quarter
+                        | TimestampField::Year
+                );
+                let res = if ts.has_date_only() && is_date_unit {
+                    DfValue::from(datetime.date())
+                } else {
+                    match timestamp_from_local(&ts, datetime) {
+                        Some(res) => DfValue::TimestampTz(res),
+                        None => return Ok(DfValue::None),
+                    }
+                };
+                res.coerce_to(ty, expr.ty())
+            }
+            BuiltinFunction::Datediff(arg1, arg2) => {
+                let date1 = NaiveDate::try_from(&non_null!(arg1.eval(record)?))?;
+                let date2 = NaiveDate::try_from(&non_null!(arg2.eval(record)?))?;
+                Ok(DfValue::Int((date1 - date2).num_days()))
+            }
+            BuiltinFunction::UnixTimestamp(expr) => {
+                let DfValue::TimestampTz(ts) = non_null!(expr.eval(record)?) else {
+                    return Ok(DfValue::None);
+                };
+                let datetime = ts.to_chrono();
+                // Timestamps before the epoch are out of range, for which MySQL returns 0
+                let seconds = Decimal::new(
+                    datetime.timestamp() * 1_000_000
+                        + i64::from(datetime.timestamp_subsec_micros()),
+                    6,
+                )
+                .max(Decimal::ZERO);
+                let seconds = match ty {
+                    DfType::Numeric { scale, .. } => seconds.round_dp((*scale).into()),
+                    _ => seconds,
+                };
+                DfValue::from(seconds).coerce_to(ty, &DfType::DEFAULT_NUMERIC)
+            }
+            BuiltinFunction::FromUnixtime(arg1, arg2) => {
+                let seconds = try_cast_or_none!(
+                    non_null!(arg1.eval(record)?),
+                    &DfType::DEFAULT_NUMERIC,
+                    arg1.ty()
+                );
+                let Some(datetime) = from_unixtime(Decimal::try_from(&seconds)?) else {
+                    return Ok(DfValue::None);
+                };
+                match arg2 {
+                    Some(arg2) => {
+                        let format_string_v = non_null!(arg2.eval(record)?);
+                        let format_str: &str = (&format_string_v).try_into()?;
+                        Ok(mysql_date_format(datetime, format_str)?.into())
+                    }
+                    None => DfValue::from(datetime).coerce_to(
+                        ty,
+                        &DfType::DateTime {
+                            subsecond_digits: 6,
+                        },
+                    ),
+                }
+            }
+            BuiltinFunction::ToChar(arg1, arg2) => {
+                let DfValue::TimestampTz(ts) = non_null!(arg1.eval(record)?) else {
+                    return Ok(DfValue::None);
+                };
+                let format_string_v = non_null!(arg2.eval(record)?);
+                let format_str: &str = (&format_string_v).try_into()?;
+                Ok(postgres_to_char(&ts.to_chrono().naive_local(), format_str).into())
+            }
+            BuiltinFunction::StrToDate(arg1, arg2) => {
+                let string_v = non_null!(arg1.eval(record)?);
+                let format_string_v = non_null!(arg2.eval(record)?);
+                let string: &str = (&string_v).try_into()?;
+                let format_str: &str = (&format_string_v).try_into()?;
+                match mysql_str_to_date(string, format_str) {
+                    Some(res) => Ok(res
+                        .coerce_to(ty, &res.infer_dataflow_type())
+                        .unwrap_or(DfValue::None)),
+                    None => Ok(DfValue::None),
+                }
+            }
             BuiltinFunction::Round(arg1, arg2) => {
                 let expr = arg1.eval(record)?;
                 let param2 = arg2.eval(record)?;
@@ -542,6 +1470,83 @@ impl BuiltinFunction {
                     }
                 }
             }
+            BuiltinFunction::Abs(expr) => Ok(match non_null!(expr.eval(record)?) {
+                DfValue::Int(i) => i.checked_abs().into(),
+                val @ DfValue::UnsignedInt(_) => val,
+                DfValue::Float(f) => DfValue::Float(f.abs()),
+                DfValue::Double(f) => DfValue::Double(f.abs()),
+                DfValue::Numeric(d) => DfValue::from(d.abs()),
+                val => DfValue::Double(f64::try_from(&val)?.abs()),
+            }),
+            BuiltinFunction::Ceil(expr) => Ok(match non_null!(expr.eval(record)?) {
+                val @ (DfValue::Int(_) | DfValue::UnsignedInt(_)) => val,
+                DfValue::Float(f) => DfValue::Float(f.ceil()),
+                DfValue::Double(f) => DfValue::Double(f.ceil()),
+                DfValue::Numeric(d) => DfValue::from(d.ceil()),
+                val => DfValue::Double(f64::try_from(&val)?.ceil()),
+            }),
+            BuiltinFunction::Floor(expr) => Ok(match non_null!(expr.eval(record)?) {
+                val @ (DfValue::Int(_) | DfValue::UnsignedInt(_)) => val,
+                DfValue::Float(f) => DfValue::Float(f.floor()),
+                DfValue::Double(f) => DfValue::Double(f.floor()),
+                DfValue::Numeric(d) => DfValue::from(d.floor()),
+                val => DfValue::Double(f64::try_from(&val)?.floor()),
+            }),
+            BuiltinFunction::Mod(arg1, arg2) => {
+                let dividend = non_null!(arg1.eval(record)?);
+                let divisor = non_null!(arg2.eval(record)?);
+                &dividend % &divisor
+            }
+            BuiltinFunction::Div(arg1, arg2) => {
+                let dividend = non_null!(arg1.eval(record)?);
+                let divisor = non_null!(arg2.eval(record)?);
+                // Discard the fractional part of the quotient, while keeping its type
+                Ok(match (&dividend / &divisor)? {
+                    DfValue::Float(f) => DfValue::Float(f.trunc()),
+                    DfValue::Double(f) => DfValue::Double(f.trunc()),
+                    DfValue::Numeric(d) => DfValue::from(d.trunc()),
+                    quotient => quotient,
+                })
+            }
+            BuiltinFunction::Power(base, exponent) => {
+                float_function([base, exponent], ty, record, |[base, exponent]| {
+                    base.powf(exponent)
+                })
+            }
+            BuiltinFunction::Sqrt(expr) => float_function([expr], ty, record, |[x]| x.sqrt()),
+            BuiltinFunction::Exp(expr) => float_function([expr], ty, record, |[x]| x.exp()),
+            BuiltinFunction::Ln(expr) => float_function([expr], ty, record, |[x]| x.ln()),
+            #[allow(clippy::float_cmp)]
+            BuiltinFunction::Log(base, expr) => {
+                float_function([base, expr], ty, record, |[base, x]| {
+                    // Special-case the most common bases, to avoid losing precision
+                    if base == 10.0 {
+                        x.log10()
+                    } else if base == 2.0 {
+                        x.log2()
+                    } else {
+                        x.ln() / base.ln()
+                    }
+                })
+            }
+            BuiltinFunction::Sign(expr) => {
+                let val = non_null!(expr.eval(record)?);
+                let sign = match &val {
+                    DfValue::Int(i) => i.cmp(&0),
+                    DfValue::UnsignedInt(u) => u.cmp(&0),
+                    DfValue::Numeric(d) => (**d).cmp(&Decimal::ZERO),
+                    _ => f64::try_from(&val)?
+                        .partial_cmp(&0.0)
+                        .unwrap_or(Ordering::Equal),
+                };
+                DfValue::Int(sign as i64).coerce_to(ty, &DfType::BigInt)
+            }
+            BuiltinFunction::Truncate(expr, prec) => {
+                let val = non_null!(expr.eval(record)?);
+                let prec = i64::try_from(non_null!(prec.eval(record)?))?
+                    .clamp(i32::MIN as i64, i32::MAX as i64) as i32;
+                truncate(val, prec)
+            }
             BuiltinFunction::JsonValid(expr) => {
                 let value = expr.eval(record)?;
 
@@ -793,6 +1798,116 @@ impl BuiltinFunction {
                     }
                 }
             }
+            BuiltinFunction::Lower(string) => {
+                let string = non_null!(string.eval(record)?);
+                Ok(<&str>::try_from(&string)?.to_lowercase().into())
+            }
+            BuiltinFunction::Upper(string) => {
+                let string = non_null!(string.eval(record)?);
+                Ok(<&str>::try_from(&string)?.to_uppercase().into())
+            }
+            BuiltinFunction::Length { expr, in_bytes } => {
+                let val = non_null!(expr.eval(record)?);
+                let len = match &val {
+                    DfValue::ByteArray(bytes) => bytes.len(),
+                    _ => {
+                        let s = <&str>::try_from(&val)?;
+                        if *in_bytes {
+                            s.len()
+                        } else {
+                            s.chars().count()
+                        }
+                    }
+                };
+                Ok(DfValue::Int(len as i64))
+            }
+            BuiltinFunction::Trim(string, characters) => {
+                trim(string, characters.as_ref(), TrimEnd::Both, record)
+            }
+            BuiltinFunction::LTrim(string, characters) => {
+                trim(string, characters.as_ref(), TrimEnd::Leading, record)
+            }
+            BuiltinFunction::RTrim(string, characters) => {
+                trim(string, characters.as_ref(), TrimEnd::Trailing, record)
+            }
+            BuiltinFunction::Replace(string, from, to) => {
+                let string = non_null!(string.eval(record)?);
+                let from = non_null!(from.eval(record)?);
+                let to = non_null!(to.eval(record)?);
+
+                let s = <&str>::try_from(&string)?;
+                let from = <&str>::try_from(&from)?;
+                if from.is_empty() {
+                    return Ok(string);
+                }
+                Ok(s.replace(from, <&str>::try_from(&to)?).into())
+            }
+            BuiltinFunction::Lpad {
+                string,
+                length,
+                fill,
+                null_on_invalid,
+            } => pad(string, length, fill, *null_on_invalid, true, record),
+            BuiltinFunction::Rpad {
+                string,
+                length,
+                fill,
+                null_on_invalid,
+            } => pad(string, length, fill, *null_on_invalid, false, record),
+            BuiltinFunction::Left {
+                string,
+                length,
+                negative_from_end,
+            } => left_or_right(string, length, *negative_from_end, true, record),
+            BuiltinFunction::Right {
+                string,
+                length,
+                negative_from_end,
+            } => left_or_right(string, length, *negative_from_end, false, record),
+            BuiltinFunction::Locate {
+                substring,
+                string,
+                start,
+            } => {
+                let substring = non_null!(substring.eval(record)?);
+                let string = non_null!(string.eval(record)?);
+                let start = match start {
+                    Some(start) => non_null!(start.eval(record)?).try_into()?,
+                    None => 1i64,
+                };
+
+                Ok(DfValue::Int(locate(
+                    (&substring).try_into()?,
+                    (&string).try_into()?,
+                    start,
+                )))
+            }
+            BuiltinFunction::Reverse(string) => {
+                let string = non_null!(string.eval(record)?);
+                Ok(<&str>::try_from(&string)?
+                    .chars()
+                    .rev()
+                    .collect::<String>()
+                    .into())
+            }
+            BuiltinFunction::Repeat(string, count) => {
+                let string = non_null!(string.eval(record)?);
+                let count = i64::try_from(non_null!(count.eval(record)?))?;
+                if count <= 0 {
+                    return Ok("".into());
+                }
+                let s = <&str>::try_from(&string)?;
+                match s.len().checked_mul(count as usize) {
+                    Some(len) if len <= MAX_STRING_RESULT_LEN => {
+                        Ok(s.repeat(count as usize).into())
+                    }
+                    _ => Ok(DfValue::None),
+                }
+            }
+            BuiltinFunction::Initcap(string) => {
+                let string = non_null!(string.eval(record)?);
+                Ok(initcap((&string).try_into()?).into())
+            }
             BuiltinFunction::Greatest { args, compare_as } => {
                 greatest_or_least(args, record, compare_as, ty, |v1, v2| v1 > v2)
             }
@@ -1646,6 +2761,297 @@ mod tests {
         );
     }
 
+    #[test]
+    fn lower_and_upper() {
+        for dialect in [MySQL, PostgreSQL] {
+            assert_eq!(eval_expr("lower('AbC é É')", dialect), "abc é é".into());
+            assert_eq!(eval_expr("upper('AbC é É')", dialect), "ABC É É".into());
+            assert_eq!(eval_expr("lower(null)", dialect), DfValue::None);
+        }
+        assert_eq!(eval_expr("lcase('AbC')", MySQL), "abc".into());
+        assert_eq!(eval_expr("ucase('AbC')", MySQL), "ABC".into());
+    }
+
+    #[test]
+    fn length_mysql() {
+        assert_eq!(eval_expr("length('abc')", MySQL), 3.into());
+        assert_eq!(eval_expr("length('é')", MySQL), 2.into());
+        assert_eq!(eval_expr("char_length('é')", MySQL), 1.into());
+        assert_eq!(eval_expr("character_length('é')", MySQL), 1.into());
+        assert_eq!(eval_expr("octet_length('é')", MySQL), 2.into());
+        assert_eq!(eval_expr("length(null)", MySQL), DfValue::None);
+    }
+
+    #[test]
+    fn length_postgres() {
+        assert_eq!(eval_expr("length('abc')", PostgreSQL), 3.into());
+        assert_eq!(eval_expr("length('é')", PostgreSQL), 1.into());
+        assert_eq!(eval_expr("char_length('é')", PostgreSQL), 1.into());
+        assert_eq!(eval_expr("octet_length('é')", PostgreSQL), 2.into());
+        assert_eq!(eval_expr("length(null)", PostgreSQL), DfValue::None);
+    }
+
+    #[test]
+    fn trim_functions() {
+        for dialect in [MySQL, PostgreSQL] {
+            assert_eq!(eval_expr("trim('  abc  ')", dialect), "abc".into());
+            assert_eq!(eval_expr("ltrim('  abc  ')", dialect), "abc  ".into());
+            assert_eq!(eval_expr("rtrim('  abc  ')", dialect), "  abc".into());
+            assert_eq!(eval_expr("trim('\tabc ')", dialect), "\tabc".into());
+            assert_eq!(eval_expr("trim(null)", dialect), DfValue::None);
+        }
+    }
+
+    #[test]
+    fn trim_characters_postgres() {
+        assert_eq!(
+            eval_expr("btrim('xyxabcyx', 'xy')", PostgreSQL),
+            "abc".into()
+        );
+        assert_eq!(
+            eval_expr("ltrim('xyxabcyx', 'xy')", PostgreSQL),
+            "abcyx".into()
+        );
+        assert_eq!(
+            eval_expr("rtrim('xyxabcyx', 'xy')", PostgreSQL),
+            "xyxabc".into()
+        );
+        assert_eq!(
+            eval_expr("btrim('xyxabcyx', null)", PostgreSQL),
+            DfValue::None
+        );
+    }
+
+    #[test]
+    fn replace() {
+        for dialect in [MySQL, PostgreSQL] {
+            assert_eq!(
+                eval_expr("replace('abcabc', 'b', 'xy')", dialect),
+                "axycaxyc".into()
+            );
+            assert_eq!(eval_expr("replace('abc', '', 'x')", dialect), "abc".into());
+            assert_eq!(
+                eval_expr("replace('abc', 'b', null)", dialect),
+                DfValue::None
+            );
+        }
+    }
+
+    #[test]
+    fn pad_mysql() {
+        assert_eq!(eval_expr("lpad('hi', 5, 'ab')", MySQL), "abahi".into());
+        assert_eq!(eval_expr("rpad('hi', 5, 'ab')", MySQL), "hiaba".into());
+        assert_eq!(eval_expr("lpad('hello', 2, 'ab')", MySQL), "he".into());
+        assert_eq!(eval_expr("rpad('hello', 2, 'ab')", MySQL), "he".into());
+        assert_eq!(eval_expr("lpad('hi', 5, '')", MySQL), DfValue::None);
+        assert_eq!(eval_expr("lpad('hi', -1, 'ab')", MySQL), DfValue::None);
+        assert_eq!(eval_expr("rpad('hi', 5, null)", MySQL), DfValue::None);
+    }
+
+    #[test]
+    fn pad_postgres() {
+        assert_eq!(eval_expr("lpad('hi', 5, 'ab')", PostgreSQL), "abahi".into());
+        assert_eq!(eval_expr("rpad('hi', 5, 'ab')", PostgreSQL), "hiaba".into());
+        assert_eq!(eval_expr("lpad('hi', 5)", PostgreSQL), "   hi".into());
+        assert_eq!(eval_expr("rpad('hi', 5)", PostgreSQL), "hi   ".into());
+        assert_eq!(eval_expr("lpad('héllo', 2)", PostgreSQL), "hé".into());
+        assert_eq!(eval_expr("lpad('hi', 5, '')", PostgreSQL), "hi".into());
+        assert_eq!(eval_expr("lpad('hi', -1, 'ab')", PostgreSQL), "".into());
+    }
+
+    #[test]
+    fn pad_too_long() {
+        for dialect in [MySQL, PostgreSQL] {
+            assert_eq!(
+                eval_expr("lpad('x', 2000000000, 'y')", dialect),
+                DfValue::None
+            );
+            assert_eq!(
+                eval_expr("rpad('x', 9223372036854775807, 'y')", dialect),
+                DfValue::None
+            );
+            // Multi-byte fill characters count towards the limit by their length in bytes
+            assert_eq!(
+                eval_expr("rpad('x', 40000000, 'é')", dialect),
+                DfValue::None
+            );
+        }
+    }
+
+    #[test]
+    fn left_and_right_mysql() {
+        assert_eq!(eval_expr("left('héllo', 2)", MySQL), "hé".into());
+        assert_eq!(eval_expr("right('héllo', 2)", MySQL), "lo".into());
+        assert_eq!(eval_expr("left('hello', 10)", MySQL), "hello".into());
+        assert_eq!(eval_expr("left('hello', -2)", MySQL), "".into());
+        assert_eq!(eval_expr("right('hello', -2)", MySQL), "".into());
+        assert_eq!(eval_expr("left(null, 2)", MySQL), DfValue::None);
+    }
+
+    #[test]
+    fn left_and_right_postgres() {
+        assert_eq!(eval_expr("left('héllo', 2)", PostgreSQL), "hé".into());
+        assert_eq!(eval_expr("right('héllo', 2)", PostgreSQL), "lo".into());
+        assert_eq!(eval_expr("left('hello', -2)", PostgreSQL), "hel".into());
+        assert_eq!(eval_expr("right('hello', -2)", PostgreSQL), "llo".into());
+        assert_eq!(eval_expr("left('hello', -10)", PostgreSQL), "".into());
+    }
+
+    #[test]
+    fn locate_mysql() {
+        assert_eq!(eval_expr("locate('bar', 'foobarbar')", MySQL), 4.into());
+        assert_eq!(eval_expr("locate('xbar', 'foobar')", MySQL), 0.into());
+        assert_eq!(eval_expr("locate('bar', 'foobarbar', 5)", MySQL), 7.into());
+        assert_eq!(eval_expr("locate('bar', 'foobarbar', 0)", MySQL), 0.into());
+        assert_eq!(eval_expr("locate('b', 'éab')", MySQL), 3.into());
+        assert_eq!(eval_expr("locate('', 'abc')", MySQL), 1.into());
+        assert_eq!(eval_expr("instr('foobarbar', 'bar')", MySQL), 4.into());
+        assert_eq!(eval_expr("position('bar' in 'foobarbar')", MySQL), 4.into());
+        assert_eq!(eval_expr("locate(null, 'abc')", MySQL), DfValue::None);
+    }
+
+    #[test]
+    fn position_postgres() {
+        assert_eq!(eval_expr("strpos('high', 'ig')", PostgreSQL), 2.into());
+        assert_eq!(eval_expr("strpos('high', 'x')", PostgreSQL), 0.into());
+        assert_eq!(eval_expr("position('ig' in 'high')", PostgreSQL), 2.into());
+        assert_eq!(eval_expr("strpos('éigh', 'ig')", PostgreSQL), 2.into());
+    }
+
+    #[test]
+    fn reverse() {
+        for dialect in [MySQL, PostgreSQL] {
+            assert_eq!(eval_expr("reverse('abcé')", dialect), "écba".into());
+            assert_eq!(eval_expr("reverse(null)", dialect), DfValue::None);
+        }
+    }
+
+    #[test]
+    fn repeat() {
+        for dialect in [MySQL, PostgreSQL] {
+            assert_eq!(eval_expr("repeat('ab', 3)", dialect), "ababab".into());
+            assert_eq!(eval_expr("repeat('ab', 0)", dialect), "".into());
+            assert_eq!(eval_expr("repeat('ab', -1)", dialect), "".into());
+            assert_eq!(eval_expr("repeat('ab', null)", dialect), DfValue::None);
+            assert_eq!(
+                eval_expr("repeat('', 9223372036854775807)", dialect),
+                "".into()
+            );
+            // Results longer than `MAX_STRING_RESULT_LEN` are NULL, rather than allocated
+            assert_eq!(
+                eval_expr("repeat('a', 9223372036854775807)", dialect),
+                DfValue::None
+            );
+            assert_eq!(eval_expr("repeat('ab', 33554433)", dialect), DfValue::None);
+        }
+    }
+
+    #[test]
+    fn initcap_postgres() {
+        assert_eq!(
+            eval_expr("initcap('hi THOMAS o''neil-smith 2nd')", PostgreSQL),
+            "Hi Thomas O'Neil-Smith 2nd".into()
+        );
+    }
+
+    #[test]
+    fn abs() {
+        for dialect in [MySQL, PostgreSQL] {
+            assert_eq!(eval_expr("abs(-3)", dialect), 3.into());
+            assert_eq!(eval_expr("abs(3)", dialect), 3.into());
+            assert_eq!(eval_expr("abs(-1.5)", dialect), DfValue::Double(1.5));
+            assert_eq!(eval_expr("abs(null)", dialect), DfValue::None);
+        }
+        assert_eq!(
+            eval_expr("abs(cast(-1.5 as decimal(2, 1)))", MySQL),
+            Decimal::new(15, 1).into()
+        );
+    }
+
+    #[test]
+    fn ceil_and_floor() {
+        for dialect in [MySQL, PostgreSQL] {
+            assert_eq!(eval_expr("ceil(1.2)", dialect), DfValue::Double(2.0));
+            assert_eq!(eval_expr("ceiling(-1.2)", dialect), DfValue::Double(-1.0));
+            assert_eq!(eval_expr("floor(1.8)", dialect), DfValue::Double(1.0));
+            assert_eq!(eval_expr("floor(-1.2)", dialect), DfValue::Double(-2.0));
+            assert_eq!(eval_expr("floor(null)", dialect), DfValue::None);
+        }
+        assert_eq!(eval_expr("ceil(5)", MySQL), 5.into());
+        assert_eq!(eval_expr("ceil(5)", PostgreSQL), DfValue::Double(5.0));
+        assert_eq!(
+            eval_expr("floor(cast(1.5 as decimal(2, 1)))", MySQL),
+            Decimal::ONE.into()
+        );
+    }
+
+    #[test]
+    fn mod_and_div() {
+        for dialect in [MySQL, PostgreSQL] {
+            assert_eq!(eval_expr("mod(10, 3)", dialect), 1.into());
+            assert_eq!(eval_expr("mod(-7, 3)", dialect), (-1).into());
+            assert_eq!(eval_expr("mod(5.5, 2)", dialect), DfValue::Double(1.5));
+            assert_eq!(eval_expr("mod(1, 0)", dialect), DfValue::None);
+            assert_eq!(eval_expr("mod(null, 2)", dialect), DfValue::None);
+        }
+        assert_eq!(eval_expr("div(7, 2)", PostgreSQL), 3.into());
+        assert_eq!(eval_expr("div(-7, 2)", PostgreSQL), (-3).into());
+        assert_eq!(eval_expr("div(7.5, 2)", PostgreSQL), DfValue::Double(3.0));
+    }
+
+    #[test]
+    fn power_and_roots() {
+        for dialect in [MySQL, PostgreSQL] {
+            assert_eq!(eval_expr("power(2, 10)", dialect), DfValue::Double(1024.0));
+            assert_eq!(eval_expr("pow(4, 0.5)", dialect), DfValue::Double(2.0));
+            assert_eq!(eval_expr("sqrt(16)", dialect), DfValue::Double(4.0));
+            assert_eq!(eval_expr("sqrt(-1)", dialect), DfValue::None);
+            assert_eq!(eval_expr("exp(0)", dialect), DfValue::Double(1.0));
+            assert_eq!(eval_expr("power(null, 2)", dialect), DfValue::None);
+        }
+    }
+
+    #[test]
+    fn logarithms() {
+        for dialect in [MySQL, PostgreSQL] {
+            assert_eq!(eval_expr("ln(1)", dialect), DfValue::Double(0.0));
+            assert_eq!(eval_expr("ln(0)", dialect), DfValue::None);
+            assert_eq!(eval_expr("log(2, 8)", dialect), DfValue::Double(3.0));
+            assert_eq!(eval_expr("log10(1000)", dialect), DfValue::Double(3.0));
+        }
+        assert_eq!(eval_expr("log2(8)", MySQL), DfValue::Double(3.0));
+        assert_eq!(eval_expr("log(100)", MySQL), DfValue::Double(100f64.ln()));
+        assert_eq!(eval_expr("log(100)", PostgreSQL), DfValue::Double(2.0));
+    }
+
+    #[test]
+    fn sign() {
+        assert_eq!(eval_expr("sign(-5)", MySQL), DfValue::Int(-1));
+        assert_eq!(eval_expr("sign(0)", MySQL), DfValue::Int(0));
+        assert_eq!(eval_expr("sign(2.5)", MySQL), DfValue::Int(1));
+        assert_eq!(eval_expr("sign(-2.5)", PostgreSQL), DfValue::Double(-1.0));
+        assert_eq!(eval_expr("sign(null)", PostgreSQL), DfValue::None);
+    }
+
+    #[test]
+    fn truncate() {
+        assert_eq!(eval_expr("truncate(1.234, 1)", MySQL), DfValue::Double(1.2));
+        assert_eq!(eval_expr("truncate(-1.7, 0)", MySQL), DfValue::Double(-1.0));
+        assert_eq!(eval_expr("truncate(1234, -2)", MySQL), 1200.into());
+        assert_eq!(eval_expr("truncate(-1234, -2)", MySQL), (-1200).into());
+        assert_eq!(eval_expr("truncate(1234, -20)", MySQL), 0.into());
+        assert_eq!(
+            eval_expr("truncate(cast(1.239 as decimal(4, 3)), 2)", MySQL),
+            Decimal::new(123, 2).into()
+        );
+        assert_eq!(eval_expr("trunc(-1.7)", PostgreSQL), DfValue::Double(-1.0));
+        assert_eq!(
+            eval_expr("trunc(1.25, 1)", PostgreSQL),
+            DfValue::Double(1.2)
+        );
+        assert_eq!(eval_expr("trunc(null, 1)", PostgreSQL), DfValue::None);
+    }
+
     #[track_caller]
     fn date_format(time: &str, fmt: &str) -> DfValue {
         lazy_static! {
@@ -1774,6 +3180,263 @@ mod tests {
             date_format("2002-01-01 12:15:45.123456", "%D %H %I %k %l %r %S %T %X"),
             "1st 12 12 12 12 12:15:45 PM 45 12:15:45 2001".into()
         );
+        assert_eq!(date_format("2008-02-20", "%U %u"), "07 08".into());
+    }
+
+    #[test]
+    fn eval_call_week() {
+        assert_eq!(eval_expr("week('2008-02-20')", MySQL), DfValue::Int(7));
+        assert_eq!(eval_expr("week('2008-02-20', 1)", MySQL), DfValue::Int(8));
+        assert_eq!(eval_expr("week('2008-12-31', 1)", MySQL), DfValue::Int(53));
+        assert_eq!(eval_expr("week('2000-01-01', 0)", MySQL), DfValue::Int(0));
+        assert_eq!(eval_expr("week('2000-01-01', 2)", MySQL), DfValue::Int(52));
+        assert_eq!(eval_expr("week(null)", MySQL), DfValue::None);
+    }
+
+    #[test]
+    fn eval_call_extract_mysql() {
+        let datetime =
+            DfValue::from(NaiveDate::from_ymd(2020, 5, 17).and_hms_micro(13, 45, 30, 500_123));
+        let time = DfValue::Time(MySqlTime::from_hmsus(true, 10, 5, 3, 0));
+        let eval = |expr: &str, value: &DfValue| {
+            parse_and_lower(expr, MySQL)
+                .eval::<DfValue>(&[value.clone()])
+                .unwrap()
+        };
+
+        assert_eq!(
+            eval_expr("extract(year from '2019-07-02')", MySQL),
+            DfValue::Int(2019)
+        );
+        assert_eq!(eval("extract(month from c0)", &datetime), DfValue::Int(5));
+        assert_eq!(
+            eval("extract(microsecond from c0)", &datetime),
+            DfValue::Int(500_123)
+        );
+        assert_eq!(eval("extract(week from c0)", &datetime), DfValue::Int(20));
+        assert_eq!(eval("quarter(c0)", &datetime), DfValue::Int(2));
+        assert_eq!(eval("dayofmonth(c0)", &datetime), DfValue::Int(17));
+        assert_eq!(eval("second(c0)", &datetime), DfValue::Int(30));
+        assert_eq!(eval("hour(c0)", &time), DfValue::Int(10));
+        assert_eq!(eval("minute(c0)", &time), DfValue::Int(5));
+        assert_eq!(eval("year(c0)", &time), DfValue::None);
+        assert_eq!(eval("year(c0)", &DfValue::None), DfValue::None);
+    }
+
+    #[test]
+    fn eval_call_extract_postgres() {
+        let datetime =
+            DfValue::from(NaiveDate::from_ymd(2020, 5, 17).and_hms_milli(13, 45, 30, 500));
+        let eval = |expr: &str| {
+            parse_and_lower(expr, PostgreSQL)
+                .eval::<DfValue>(&[datetime.clone()])
+                .unwrap()
+        };
+
+        assert_eq!(
+            eval("extract(doy from c0)"),
+            DfValue::from(Decimal::from(138))
+        );
+        assert_eq!(
+            eval("extract(isodow from c0)"),
+            DfValue::from(Decimal::from(7))
+        );
+        assert_eq!(
+            eval("extract(week from c0)"),
+            DfValue::from(Decimal::from(20))
+        );
+        assert_eq!(
+            eval("extract(century from c0)"),
+            DfValue::from(Decimal::from(21))
+        );
+        assert_eq!(
+            eval("extract(second from c0)"),
+            DfValue::from(Decimal::new(305, 1))
+        );
+        assert_eq!(
+            eval("extract(microseconds from c0)"),
+            DfValue::from(Decimal::from(30_500_000))
+        );
+        assert_eq!(
+            eval("extract(epoch from c0)"),
+            DfValue::from(Decimal::new(15897231305, 1))
+        );
+        assert_eq!(eval("date_part('dow', c0)"), DfValue::Double(0.0));
+    }
+
+    #[test]
+    fn eval_call_date_trunc() {
+        let datetime =
+            DfValue::from(NaiveDate::from_ymd(2020, 5, 17).and_hms_milli(13, 45, 30, 500));
+        let date_trunc = |field: &str| {
+            parse_and_lower(&format!("date_trunc('{field}', c0)"), PostgreSQL)
+                .eval::<DfValue>(&[datetime.clone()])
+                .unwrap()
+        };
+        let expected =
+            |y, m, d, h, min, s| DfValue::from(NaiveDate::from_ymd(y, m, d).and_hms(h, min, s));
+
+        assert_eq!(date_trunc("second"), expected(2020, 5, 17, 13, 45, 30));
+        assert_eq!(date_trunc("minute"), expected(2020, 5, 17, 13, 45, 0));
+        assert_eq!(date_trunc("hour"), expected(2020, 5, 17, 13, 0, 0));
+        assert_eq!(date_trunc("day"), expected(2020, 5, 17, 0, 0, 0));
+        assert_eq!(date_trunc("week"), expected(2020, 5, 11, 0, 0, 0));
+        assert_eq!(date_trunc("month"), expected(2020, 5, 1, 0, 0, 0));
+        assert_eq!(date_trunc("quarter"), expected(2020, 4, 1, 0, 0, 0));
+        assert_eq!(date_trunc("year"), expected(2020, 1, 1, 0, 0, 0));
+        assert_eq!(date_trunc("decade"), expected(2020, 1, 1, 0, 0, 0));
+        assert_eq!(date_trunc("century"), expected(2001, 1, 1, 0, 0, 0));
+        assert_eq!(date_trunc("millennium"), expected(2001, 1, 1, 0, 0, 0));
+    }
+
+    #[test]
+    fn eval_call_date_add() {
+        assert_eq!(
+            eval_expr("date_add('2020-01-31', interval 1 month)", MySQL),
+            DfValue::from(NaiveDate::from_ymd(2020, 2, 29))
+        );
+        assert_eq!(
+            eval_expr("date_add('2020-01-01', interval 2 quarter)", MySQL),
+            DfValue::from(NaiveDate::from_ymd(2020, 7, 1))
+        );
+        assert_eq!(
+            eval_expr("date_add('2020-12-31 23:59:59', interval 1 second)", MySQL),
+            DfValue::from(NaiveDate::from_ymd(2021, 1, 1).and_hms(0, 0, 0))
+        );
+        assert_eq!(
+            eval_expr(
+                "date_add('2020-01-01 00:00:00', interval 1.5 second)",
+                MySQL
+            ),
+            DfValue::from(NaiveDate::from_ymd(2020, 1, 1).and_hms_milli(0, 0, 1, 500))
+        );
+        assert_eq!(
+            eval_expr("date_sub('2020-03-01 00:00:00', interval 1 second)", MySQL),
+            DfValue::from(NaiveDate::from_ymd(2020, 2, 29).and_hms(23, 59, 59))
+        );
+        assert_eq!(
+            eval_expr("date_sub('2021-03-31', interval 1 year)", MySQL),
+            DfValue::from(NaiveDate::from_ymd(2020, 3, 31))
+        );
+        assert_eq!(
+            eval_expr("date_add(null, interval 1 day)", MySQL),
+            DfValue::None
+        );
+        assert_eq!(
+            eval_expr("date_add('2020-01-01', interval null day)", MySQL),
+            DfValue::None
+        );
+    }
+
+    #[test]
+    fn eval_call_datediff() {
+        assert_eq!(
+            eval_expr("datediff('2007-12-31 23:59:59', '2007-12-30')", MySQL),
+            DfValue::Int(1)
+        );
+        assert_eq!(
+            eval_expr("datediff('2010-11-30 23:59:59', '2010-12-31')", MySQL),
+            DfValue::Int(-31)
+        );
+        assert_eq!(
+            eval_expr("datediff('2010-11-30', null)", MySQL),
+            DfValue::None
+        );
+    }
+
+    #[test]
+    fn eval_call_unix_timestamp() {
+        assert_eq!(
+            eval_expr("unix_timestamp('2015-11-13 10:20:19')", MySQL),
+            DfValue::Int(1447410019)
+        );
+        assert_eq!(
+            eval_expr("unix_timestamp('1969-12-31')", MySQL),
+            DfValue::Int(0)
+        );
+        assert_eq!(eval_expr("unix_timestamp(null)", MySQL), DfValue::None);
+    }
+
+    #[test]
+    fn eval_call_from_unixtime() {
+        assert_eq!(
+            eval_expr("from_unixtime(1447430881)", MySQL),
+            DfValue::from(NaiveDate::from_ymd(2015, 11, 13).and_hms(16, 8, 1))
+        );
+        assert_eq!(
+            eval_expr("from_unixtime(1447430881, '%Y %D %M %h:%i:%s %x')", MySQL),
+            DfValue::from("2015 13th November 04:08:01 2015")
+        );
+        assert_eq!(eval_expr("from_unixtime(-1)", MySQL), DfValue::None);
+        assert_eq!(eval_expr("from_unixtime(null)", MySQL), DfValue::None);
+    }
+
+    #[test]
+    fn eval_call_to_char() {
+        let datetime =
+            DfValue::from(NaiveDate::from_ymd(2020, 5, 17).and_hms_milli(13, 45, 30, 500));
+        let to_char = |format: &str| {
+            parse_and_lower(&format!("to_char(c0, '{format}')"), PostgreSQL)
+                .eval::<DfValue>(&[datetime.clone()])
+                .unwrap()
+        };
+
+        assert_eq!(
+            to_char("YYYY-MM-DD HH24:MI:SS.MS"),
+            DfValue::from("2020-05-17 13:45:30.500")
+        );
+        assert_eq!(
+            to_char("FMMonth DDth, YYYY"),
+            DfValue::from("May 17th, 2020")
+        );
+        assert_eq!(
+            to_char("Day, HH12:MI am"),
+            DfValue::from("Sunday   , 01:45 pm")
+        );
+        assert_eq!(to_char("DY MON"), DfValue::from("SUN MAY"));
+        assert_eq!(to_char("IYYY-IW-ID"), DfValue::from("2020-20-7"));
+        assert_eq!(to_char("\"Quarter\" Q"), DfValue::from("Quarter 2"));
+    }
+
+    #[test]
+    fn eval_call_str_to_date() {
+        assert_eq!(
+            eval_expr("str_to_date('01,5,2013', '%d,%m,%Y')", MySQL),
+            DfValue::from(NaiveDate::from_ymd(2013, 5, 1))
+        );
+        assert_eq!(
+            eval_expr("str_to_date('May 1, 2013', '%M %d,%Y')", MySQL),
+            DfValue::from(NaiveDate::from_ymd(2013, 5, 1))
+        );
+        assert_eq!(
+            eval_expr("str_to_date('2013-05-01 5:06:07 PM', '%Y-%m-%d %r')", MySQL),
+            DfValue::from(NaiveDate::from_ymd(2013, 5, 1).and_hms(17, 6, 7))
+        );
+        assert_eq!(
+            eval_expr("str_to_date('09:30:17a', '%h:%i:%s')", MySQL),
+            DfValue::Time(MySqlTime::from_hmsus(true, 9, 30, 17, 0))
+        );
+        assert_eq!(
+            eval_expr("str_to_date('a09:30:17', '%h:%i:%s')", MySQL),
+            DfValue::None
+        );
+        assert_eq!(
+            eval_expr("str_to_date('2013-02-30', '%Y-%m-%d')", MySQL),
+            DfValue::None
+        );
+        assert_eq!(
+            eval_expr("str_to_date(null, '%Y-%m-%d')", MySQL),
+            DfValue::None
+        );
+    }
+
+    #[test]
+    fn eval_call_date() {
+        assert_eq!(
+            eval_expr("date('2003-12-31 01:02:03')", MySQL),
+            DfValue::from(NaiveDate::from_ymd(2003, 12, 31))
+        );
+        assert_eq!(eval_expr("date('not a date')", MySQL), DfValue::None);
     }
 
     mod json {
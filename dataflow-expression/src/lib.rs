@@ -10,6 +10,7 @@ pub mod utils;
 use std::fmt::{self, Display, Formatter};
 
 use itertools::Itertools;
+use nom_sql::TimestampField;
 pub use readyset_data::Dialect;
 use readyset_data::{DfType, DfValue};
 use serde::{Deserialize, Serialize};
@@ -38,8 +39,95 @@ pub enum BuiltinFunction {
     Addtime(Expr, Expr),
     /// [`date_format`](https://dev.mysql.com/doc/refman/8.0/en/date-and-time-functions.html#function_date-format)
     DateFormat(Expr, Expr),
+    /// `extract` and `date_part`, as well as the MySQL functions which extract a single field such
+    /// as `year`, `dayofmonth` or `hour`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/date-and-time-functions.html#function_extract)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-datetime.html#FUNCTIONS-DATETIME-EXTRACT)
+    ///
+    /// Fields are always extracted with PostgreSQL's semantics (so `MICROSECOND` includes the
+    /// seconds, for example); lowering adjusts for MySQL where the two differ.
+    Extract { field: TimestampField, expr: Expr },
+    /// [`week`](https://dev.mysql.com/doc/refman/8.0/en/date-and-time-functions.html#function_week)
+    Week(Expr, Expr),
+    /// [`date_trunc`](https://www.postgresql.org/docs/current/functions-datetime.html#FUNCTIONS-DATETIME-TRUNC)
+    DateTrunc { field: TimestampField, expr: Expr },
+    /// [`date_add` and `date_sub`](https://dev.mysql.com/doc/refman/8.0/en/date-and-time-functions.html#function_date-add),
+    /// with `date_sub` lowered to a negated `interval`
+    DateAdd {
+        expr: Expr,
+        interval: Expr,
+        unit: TimestampField,
+    },
+    /// [`datediff`](https://dev.mysql.com/doc/refman/8.0/en/date-and-time-functions.html#function_datediff)
+    Datediff(Expr, Expr),
+    /// [`unix_timestamp`](https://dev.mysql.com/doc/refman/8.0/en/date-and-time-functions.html#function_unix-timestamp)
+    UnixTimestamp(Expr),
+    /// [`from_unixtime`](https://dev.mysql.com/doc/refman/8.0/en/date-and-time-functions.html#function_from-unixtime)
+    FromUnixtime(Expr, Option<Expr>),
+    /// [`to_char`](https://www.postgresql.org/docs/current/functions-formatting.html)
+    ToChar(Expr, Expr),
+    /// [`str_to_date`](https://dev.mysql.com/doc/refman/8.0/en/date-and-time-functions.html#function_str-to-date)
+    StrToDate(Expr, Expr),
     /// [`round`](https://dev.mysql.com/doc/refman/8.0/en/mathematical-functions.html#function_round)
     Round(Expr, Expr),
+    /// `abs`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/mathematical-functions.html#function_abs)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-math.html)
+    Abs(Expr),
+    /// `ceil` and `ceiling`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/mathematical-functions.html#function_ceil)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-math.html)
+    Ceil(Expr),
+    /// `floor`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/mathematical-functions.html#function_floor)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-math.html)
+    Floor(Expr),
+    /// `mod`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/mathematical-functions.html#function_mod)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-math.html)
+    Mod(Expr, Expr),
+    /// [`div`](https://www.postgresql.org/docs/current/functions-math.html)
+    Div(Expr, Expr),
+    /// `power` and `pow`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/mathematical-functions.html#function_power)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-math.html)
+    Power(Expr, Expr),
+    /// `sqrt`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/mathematical-functions.html#function_sqrt)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-math.html)
+    Sqrt(Expr),
+    /// `exp`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/mathematical-functions.html#function_exp)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-math.html)
+    Exp(Expr),
+    /// `ln`, as well as `log` with a single argument in MySQL:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/mathematical-functions.html#function_ln)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-math.html)
+    Ln(Expr),
+    /// `log(base, x)`, as well as `log10`, `log2`, and `log` with a single argument in PostgreSQL:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/mathematical-functions.html#function_log)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-math.html)
+    Log(Expr, Expr),
+    /// `sign`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/mathematical-functions.html#function_sign)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-math.html)
+    Sign(Expr),
+    /// `truncate` (MySQL) and `trunc` (PostgreSQL):
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/mathematical-functions.html#function_truncate)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-math.html)
+    Truncate(Expr, Expr),
     /// [`json_depth`](https://dev.mysql.com/doc/refman/8.0/en/json-attribute-functions.html#function_json-depth)
     JsonDepth(Expr),
     /// [`json_valid`](https://dev.mysql.com/doc/refman/8.0/en/json-attribute-functions.html#function_json-valid)
@@ -84,6 +172,130 @@ pub enum BuiltinFunction {
     /// [`split_part`](https://www.postgresql.org/docs/current/functions-string.html)
     SplitPart(Expr, Expr, Expr),
 
+    /// `lower`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_lower)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    Lower(Expr),
+
+    /// `upper`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_upper)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    Upper(Expr),
+
+    /// `length`, `char_length` and `octet_length`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_length)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    Length {
+        expr: Expr,
+        /// Whether to count the length of the string in bytes rather than in characters. `length`
+        /// counts bytes in MySQL, but characters in PostgreSQL.
+        in_bytes: bool,
+    },
+
+    /// `trim` and `btrim`, with an optional set of characters to remove instead of spaces:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_trim)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    Trim(Expr, Option<Expr>),
+
+    /// `ltrim`, with an optional set of characters to remove instead of spaces:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_ltrim)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    LTrim(Expr, Option<Expr>),
+
+    /// `rtrim`, with an optional set of characters to remove instead of spaces:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_rtrim)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    RTrim(Expr, Option<Expr>),
+
+    /// `replace`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_replace)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    Replace(Expr, Expr, Expr),
+
+    /// `lpad`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_lpad)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    Lpad {
+        string: Expr,
+        length: Expr,
+        fill: Expr,
+        /// Whether to return NULL (as in MySQL) rather than an empty or unpadded string (as in
+        /// PostgreSQL) if `length` is negative or `fill` is empty.
+        null_on_invalid: bool,
+    },
+
+    /// `rpad`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_rpad)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    Rpad {
+        string: Expr,
+        length: Expr,
+        fill: Expr,
+        /// Whether to return NULL (as in MySQL) rather than an empty or unpadded string (as in
+        /// PostgreSQL) if `length` is negative or `fill` is empty.
+        null_on_invalid: bool,
+    },
+
+    /// `left`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_left)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    Left {
+        string: Expr,
+        length: Expr,
+        /// Whether a negative length `-n` returns all but the last `n` characters (as in
+        /// PostgreSQL), rather than an empty string (as in MySQL).
+        negative_from_end: bool,
+    },
+
+    /// `right`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_right)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    Right {
+        string: Expr,
+        length: Expr,
+        /// Whether a negative length `-n` returns all but the first `n` characters (as in
+        /// PostgreSQL), rather than an empty string (as in MySQL).
+        negative_from_end: bool,
+    },
+
+    /// `locate`, `instr`, `position` and `strpos`, which all return the 1-based character position
+    /// of the first occurrence of `substring` in `string` (optionally starting the search at
+    /// `start`), or 0 if there is none:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_locate)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    Locate {
+        substring: Expr,
+        string: Expr,
+        start: Option<Expr>,
+    },
+
+    /// `reverse`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_reverse)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    Reverse(Expr),
+
+    /// `repeat`:
+    ///
+    /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/string-functions.html#function_repeat)
+    /// * [PostgreSQL](https://www.postgresql.org/docs/current/functions-string.html)
+    Repeat(Expr, Expr),
+
+    /// [`initcap`](https://www.postgresql.org/docs/current/functions-string.html)
+    Initcap(Expr),
+
     /// `greatest`:
     ///
     /// * [MySQL](https://dev.mysql.com/doc/refman/8.0/en/comparison-operators.html#function_greatest)
@@ -121,7 +333,28 @@ impl BuiltinFunction {
             Timediff { .. } => "timediff",
             Addtime { .. } => "addtime",
             DateFormat { .. } => "date_format",
+            Extract { .. } => "extract",
+            Week { .. } => "week",
+            DateTrunc { .. } => "date_trunc",
+            DateAdd { .. } => "date_add",
+            Datediff { .. } => "datediff",
+            UnixTimestamp { .. } => "unix_timestamp",
+            FromUnixtime { .. } => "from_unixtime",
+            ToChar { .. } => "to_char",
+            StrToDate { .. } => "str_to_date",
             Round { .. } => "round",
+            Abs { .. } => "abs",
+            Ceil { .. } => "ceil",
+            Floor { .. } => "floor",
+            Mod { .. } => "mod",
+            Div { .. } => "div",
+            Power { .. } => "power",
+            Sqrt { .. } => "sqrt",
+            Exp { .. } => "exp",
+            Ln { .. } => "ln",
+            Log { .. } => "log",
+            Sign { .. } => "sign",
+            Truncate { .. } => "truncate",
             JsonDepth { .. } => "json_depth",
             JsonValid { .. } => "json_valid",
             JsonQuote { .. } => "json_quote",
@@ -138,6 +371,24 @@ impl BuiltinFunction {
             Concat { .. } => "concat",
             Substring { .. } => "substring",
             SplitPart { .. } => "split_part",
+            Lower { .. } => "lower",
+            Upper { .. } => "upper",
+            Length { in_bytes: true, .. } => "octet_length",
+            Length {
+                in_bytes: false, ..
+            } => "char_length",
+            Trim { .. } => "trim",
+            LTrim { .. } => "ltrim",
+            RTrim { .. } => "rtrim",
+            Replace { .. } => "replace",
+            Lpad { .. } => "lpad",
+            Rpad { .. } => "rpad",
+            Left { .. } => "left",
+            Right { .. } => "right",
+            Locate { .. } => "locate",
+            Reverse { .. } => "reverse",
+            Repeat { .. } => "repeat",
+            Initcap { .. } => "initcap",
             Greatest { .. } => "greatest",
             Least { .. } => "least",
             ArrayToString { .. } => "array_to_string",
@@ -173,9 +424,40 @@ impl Display for BuiltinFunction {
             DateFormat(arg1, arg2) => {
                 write!(f, "({}, {})", arg1, arg2)
             }
+            Extract { field, expr } => write!(f, "({field} from {expr})"),
+            Week(arg1, arg2)
+            | Datediff(arg1, arg2)
+            | ToChar(arg1, arg2)
+            | StrToDate(arg1, arg2) => {
+                write!(f, "({}, {})", arg1, arg2)
+            }
+            DateTrunc { field, expr } => write!(f, "('{field}', {expr})"),
+            DateAdd {
+                expr,
+                interval,
+                unit,
+            } => write!(f, "({expr}, INTERVAL {interval} {unit})"),
+            UnixTimestamp(arg) => write!(f, "({})", arg),
+            FromUnixtime(arg, format) => {
+                write!(f, "({arg}")?;
+                if let Some(format) = format {
+                    write!(f, ", {format}")?;
+                }
+                write!(f, ")")
+            }
             Round(arg1, precision) => {
                 write!(f, "({}, {})", arg1, precision)
             }
+            Abs(arg) | Ceil(arg) | Floor(arg) | Sqrt(arg) | Exp(arg) | Ln(arg) | Sign(arg) => {
+                write!(f, "({})", arg)
+            }
+            Mod(arg1, arg2)
+            | Div(arg1, arg2)
+            | Power(arg1, arg2)
+            | Log(arg1, arg2)
+            | Truncate(arg1, arg2) => {
+                write!(f, "({}, {})", arg1, arg2)
+            }
             JsonDepth(arg) | JsonValid(arg) | JsonQuote(arg) | JsonTypeof(arg)
             | JsonArrayLength(arg) | JsonStripNulls(arg) | JsonbPretty(arg) => {
                 write!(f, "({})", arg)
@@ -226,6 +508,44 @@ impl Display for BuiltinFunction {
                 write!(f, ")")
             }
             SplitPart(string, delimiter, field) => write!(f, "({string}, {delimiter}, {field})"),
+            Lower(arg) | Upper(arg) | Length { expr: arg, .. } | Reverse(arg) | Initcap(arg) => {
+                write!(f, "({})", arg)
+            }
+            Trim(string, characters) | LTrim(string, characters) | RTrim(string, characters) => {
+                write!(f, "({string}")?;
+                if let Some(characters) = characters {
+                    write!(f, ", {characters}")?;
+                }
+                write!(f, ")")
+            }
+            Replace(string, from, to) => write!(f, "({string}, {from}, {to})"),
+            Lpad {
+                string,
+                length,
+                fill,
+                ..
+            }
+            | Rpad {
+                string,
+                length,
+                fill,
+                ..
+            } => write!(f, "({string}, {length}, {fill})"),
+            Left { string, length, .. } | Right { string, length, .. } => {
+                write!(f, "({string}, {length})")
+            }
+            Locate {
+                substring,
+                string,
+                start,
+            } => {
+                write!(f, "({substring}, {string}")?;
+                if let Some(start) = start {
+                    write!(f, ", {start}")?;
+                }
+                write!(f, ")")
+            }
+            Repeat(string, count) => write!(f, "({string}, {count})"),
             Greatest { args, .. } | Least { args, .. } => {
                 write!(f, "({})", args.iter().join(", "))
             }
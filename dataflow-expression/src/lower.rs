@@ -1,15 +1,17 @@
 use std::iter;
 
+use bit_vec::BitVec;
 use nom_sql::{
     BinaryOperator as SqlBinaryOperator, Column, Expr as AstExpr, FunctionExpr, InValue, Relation,
-    UnaryOperator,
+    TimestampField, UnaryOperator,
 };
 use readyset_data::dialect::SqlEngine;
-use readyset_data::{DfType, DfValue};
+use readyset_data::{DfType, DfValue, Interval};
 use readyset_errors::{
     internal, internal_err, invalid, invalid_err, unsupported, ReadySetError, ReadySetResult,
 };
 use readyset_util::redacted::Sensitive;
+use rust_decimal::Decimal;
 use vec1::Vec1;
 
 use crate::{
@@ -125,7 +127,135 @@ fn mysql_least_greatest_compare_as(arg_types: Vec<&DfType>) -> DfType {
     DfType::VarBinary(u16::MAX)
 }
 
+/// Returns the type of the result of a string function called with an argument of the given type,
+/// preserving the argument's collation if it's a text type.
+fn text_type_for(ty: &DfType) -> DfType {
+    match ty {
+        DfType::Text(collation) | DfType::Char(_, collation) | DfType::VarChar(_, collation) => {
+            DfType::Text(*collation)
+        }
+        _ => DfType::DEFAULT_TEXT,
+    }
+}
+
+/// Returns the type that the arguments to a mathematical function with arguments of the given types
+/// should be coerced to: `DOUBLE` if any argument is a floating-point or non-numeric type,
+/// otherwise `NUMERIC` if any argument is `NUMERIC`, otherwise the arguments' integer type if they
+/// all have the same one, or `BIGINT` if they don't.
+///
+/// Arguments of unknown type are ignored, and if all the arguments are of unknown type, returns
+/// [`DfType::Unknown`].
+fn unify_numeric_types<'a, I>(types: I) -> DfType
+where
+    I: IntoIterator<Item = &'a DfType>,
+{
+    types
+        .into_iter()
+        .filter(|ty| ty.is_known())
+        .map(|ty| {
+            if ty.is_any_int() || ty.is_any_float() || matches!(ty, DfType::Numeric { .. }) {
+                ty.clone()
+            } else {
+                DfType::Double
+            }
+        })
+        .fold(DfType::Unknown, |acc, ty| match (acc, ty) {
+            (DfType::Unknown, ty) => ty,
+            (acc, ty) if acc == ty => acc,
+            (DfType::Float | DfType::Double, _) | (_, DfType::Float | DfType::Double) => {
+                DfType::Double
+            }
+            (numeric @ DfType::Numeric { .. }, _) | (_, numeric @ DfType::Numeric { .. }) => {
+                numeric
+            }
+            _ => DfType::BigInt,
+        })
+}
+
+/// Returns the type of the value returned by MySQL's `STR_TO_DATE` for the given format string,
+/// which depends on whether the format contains date parts, time parts, or both
+fn str_to_date_type(format: &str) -> DfType {
+    let mut has_date = false;
+    let mut has_time = false;
+    let mut has_micros = false;
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c == '%' {
+            match chars.next() {
+                Some(
+                    'a' | 'b' | 'c' | 'D' | 'd' | 'e' | 'j' | 'M' | 'm' | 'U' | 'u' | 'V' | 'v'
+                    | 'W' | 'w' | 'X' | 'x' | 'Y' | 'y',
+                ) => has_date = true,
+                Some('f') => {
+                    has_time = true;
+                    has_micros = true;
+                }
+                Some('H' | 'h' | 'I' | 'i' | 'k' | 'l' | 'p' | 'r' | 'S' | 's' | 'T') => {
+                    has_time = true
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let subsecond_digits = if has_micros { 6 } else { 0 };
+    match (has_date, has_time) {
+        (true, true) => DfType::DateTime { subsecond_digits },
+        (false, true) => DfType::Time { subsecond_digits },
+        _ => DfType::Date,
+    }
+}
+
 impl BuiltinFunction {
+    /// Lowers `EXTRACT(field FROM expr)`, which returns `NUMERIC` in PostgreSQL but `BIGINT` in
+    /// MySQL, where only a subset of the fields are valid
+    fn extract(
+        field: TimestampField,
+        expr: Expr,
+        dialect: Dialect,
+    ) -> ReadySetResult<(Self, DfType)> {
+        match dialect.engine() {
+            SqlEngine::PostgreSQL => Ok((Self::Extract { field, expr }, DfType::DEFAULT_NUMERIC)),
+            SqlEngine::MySQL => {
+                let func = match field {
+                    // MySQL's MICROSECOND is only the fractional part of the seconds
+                    TimestampField::Microsecond => Self::Mod(
+                        Expr::Call {
+                            func: Box::new(Self::Extract { field, expr }),
+                            ty: DfType::BigInt,
+                        },
+                        Expr::Literal {
+                            val: DfValue::Int(1_000_000),
+                            ty: DfType::BigInt,
+                        },
+                    ),
+                    // ...and its WEEK is `WEEK(expr)` with the default mode, rather than the ISO
+                    // week number
+                    TimestampField::Week => Self::Week(
+                        Expr::Cast {
+                            expr: Box::new(expr),
+                            ty: DfType::Date,
+                            null_on_failure: true,
+                        },
+                        Expr::Literal {
+                            val: DfValue::Int(0),
+                            ty: DfType::BigInt,
+                        },
+                    ),
+                    TimestampField::Day
+                    | TimestampField::Hour
+                    | TimestampField::Minute
+                    | TimestampField::Month
+                    | TimestampField::Quarter
+                    | TimestampField::Second
+                    | TimestampField::Year => Self::Extract { field, expr },
+                    _ => invalid!("{field} is not a valid unit for EXTRACT"),
+                };
+                Ok((func, DfType::BigInt))
+            }
+        }
+    }
+
     pub(crate) fn from_name_and_args<A>(
         name: &str,
         args: A,
@@ -168,11 +298,47 @@ impl BuiltinFunction {
                 }
             }
         };
+        // Casts `expr` to `ty`, unless the type of `ty` couldn't be inferred
+        let cast_if_known = |expr: Expr, ty: &DfType| {
+            if ty.is_known() {
+                cast(expr, ty.clone())
+            } else {
+                expr
+            }
+        };
+        // The type returned by floating-point mathematical functions such as `sqrt` or `ln` given
+        // arguments of the given types. PostgreSQL defines `NUMERIC` variants of these functions,
+        // whereas MySQL always returns `DOUBLE`.
+        let float_function_type =
+            |arg_tys: &[&DfType]| match unify_numeric_types(arg_tys.iter().copied()) {
+                ty @ DfType::Numeric { .. } if dialect.engine() == SqlEngine::PostgreSQL => ty,
+                _ => DfType::Double,
+            };
         let try_cast = |expr, ty| Expr::Cast {
             expr: Box::new(expr),
             ty,
             null_on_failure: true,
         };
+        // `date_part` and `date_trunc` take the name of a field as a string, which we require to be
+        // a literal so that the field is known ahead of time
+        let literal_field = |expr: Expr| -> ReadySetResult<TimestampField> {
+            let Expr::Literal { val, .. } = expr else {
+                unsupported!("{name} is only supported with a literal field name")
+            };
+            let field = <&str>::try_from(&val)?;
+            field
+                .parse()
+                .map_err(|_| invalid_err!("unit \"{field}\" not recognized"))
+        };
+        let is_timestamp = |ty: &DfType| {
+            matches!(
+                ty,
+                DfType::Date
+                    | DfType::DateTime { .. }
+                    | DfType::Timestamp { .. }
+                    | DfType::TimestampTz { .. }
+            )
+        };
 
         let result = match name {
             "convert_tz" => {
@@ -232,6 +398,162 @@ impl BuiltinFunction {
                 Self::DateFormat(next_arg()?, next_arg()?),
                 DfType::DEFAULT_TEXT,
             ),
+            "date_part" => {
+                let field = literal_field(next_arg()?)?;
+                (
+                    Self::Extract {
+                        field,
+                        expr: next_arg()?,
+                    },
+                    DfType::Double,
+                )
+            }
+            "year" | "quarter" | "day" | "dayofmonth" | "hour" | "minute" | "second" => {
+                let field = match name {
+                    "year" => TimestampField::Year,
+                    "quarter" => TimestampField::Quarter,
+                    "hour" => TimestampField::Hour,
+                    "minute" => TimestampField::Minute,
+                    "second" => TimestampField::Second,
+                    _ => TimestampField::Day,
+                };
+                (
+                    Self::Extract {
+                        field,
+                        expr: next_arg()?,
+                    },
+                    DfType::Int,
+                )
+            }
+            "week" => {
+                let date = try_cast(next_arg()?, DfType::Date);
+                let mode = args
+                    .next()
+                    .map(|mode| cast(mode, DfType::BigInt))
+                    .unwrap_or(Expr::Literal {
+                        val: DfValue::Int(0),
+                        ty: DfType::BigInt,
+                    });
+                (Self::Week(date, mode), DfType::Int)
+            }
+            "date_trunc" => {
+                let field = literal_field(next_arg()?)?;
+                if matches!(
+                    field,
+                    TimestampField::Dow
+                        | TimestampField::Doy
+                        | TimestampField::Epoch
+                        | TimestampField::IsoDow
+                        | TimestampField::IsoYear
+                ) {
+                    invalid!("unit \"{field}\" not supported for date_trunc")
+                }
+                let expr = next_arg()?;
+                // `date_trunc` is only defined for `timestamp` and `timestamptz`, and other types
+                // (including `date`) are implicitly cast to the latter
+                let ty = match expr.ty() {
+                    ty @ (DfType::Timestamp { .. } | DfType::TimestampTz { .. }) => ty.clone(),
+                    _ => DfType::TimestampTz {
+                        subsecond_digits: dialect.default_subsecond_digits(),
+                    },
+                };
+                (
+                    Self::DateTrunc {
+                        field,
+                        expr: cast(expr, ty.clone()),
+                    },
+                    ty,
+                )
+            }
+            "datediff" => (
+                Self::Datediff(
+                    try_cast(next_arg()?, DfType::Date),
+                    try_cast(next_arg()?, DfType::Date),
+                ),
+                DfType::BigInt,
+            ),
+            "unix_timestamp" => {
+                let Some(expr) = args.next() else {
+                    unsupported!("unix_timestamp() without arguments is not supported")
+                };
+                // The result has a fractional part if the argument does
+                let ty = match expr.ty().subsecond_digits() {
+                    Some(subsecond_digits) if subsecond_digits > 0 => DfType::Numeric {
+                        prec: 20,
+                        scale: subsecond_digits as u8,
+                    },
+                    _ => DfType::BigInt,
+                };
+                let expr = if is_timestamp(expr.ty()) {
+                    expr
+                } else {
+                    try_cast(
+                        expr,
+                        DfType::DateTime {
+                            subsecond_digits: 6,
+                        },
+                    )
+                };
+                (Self::UnixTimestamp(expr), ty)
+            }
+            "from_unixtime" => {
+                let expr = next_arg()?;
+                let format = args.next().map(|format| cast(format, DfType::DEFAULT_TEXT));
+                let ty = if format.is_some() {
+                    DfType::DEFAULT_TEXT
+                } else if expr.ty().is_any_int() {
+                    DfType::DateTime {
+                        subsecond_digits: 0,
+                    }
+                } else {
+                    DfType::DateTime {
+                        subsecond_digits: 6,
+                    }
+                };
+                (Self::FromUnixtime(expr, format), ty)
+            }
+            "to_char" => {
+                let expr = next_arg()?;
+                let expr = match expr.ty() {
+                    DfType::Date | DfType::Timestamp { .. } | DfType::TimestampTz { .. } => expr,
+                    ty if ty.is_known() && !ty.is_any_text() => {
+                        unsupported!("to_char is only supported for dates and timestamps")
+                    }
+                    _ => cast(
+                        expr,
+                        DfType::Timestamp {
+                            subsecond_digits: dialect.default_subsecond_digits(),
+                        },
+                    ),
+                };
+                (
+                    Self::ToChar(expr, cast(next_arg()?, DfType::DEFAULT_TEXT)),
+                    DfType::DEFAULT_TEXT,
+                )
+            }
+            "str_to_date" => {
+                let string = next_arg()?;
+                let format = next_arg()?;
+                // The type depends on the format, so unless that's a literal assume that it has
+                // both a date and a time
+                let ty = match &format {
+                    Expr::Literal { val, .. } => <&str>::try_from(val)
+                        .map(str_to_date_type)
+                        .unwrap_or(DfType::DateTime {
+                            subsecond_digits: 6,
+                        }),
+                    _ => DfType::DateTime {
+                        subsecond_digits: 6,
+                    },
+                };
+                (
+                    Self::StrToDate(
+                        cast(string, DfType::DEFAULT_TEXT),
+                        cast(format, DfType::DEFAULT_TEXT),
+                    ),
+                    ty,
+                )
+            }
             "round" => {
                 let expr = next_arg()?;
                 let prec = args.next().unwrap_or(Expr::Literal {
@@ -241,6 +563,134 @@ impl BuiltinFunction {
                 let ty = type_for_round(&expr, &prec);
                 (Self::Round(expr, prec), ty)
             }
+            "abs" => {
+                let expr = next_arg()?;
+                let ty = unify_numeric_types([expr.ty()]);
+                (Self::Abs(cast_if_known(expr, &ty)), ty)
+            }
+            "ceil" | "ceiling" | "floor" => {
+                let expr = next_arg()?;
+                let ty = match unify_numeric_types([expr.ty()]) {
+                    // PostgreSQL only defines `ceil` and `floor` for `NUMERIC` and `DOUBLE`
+                    // arguments
+                    ty if ty.is_any_int() && dialect.engine() == SqlEngine::PostgreSQL => {
+                        DfType::Double
+                    }
+                    ty => ty,
+                };
+                let expr = cast_if_known(expr, &ty);
+                (
+                    if name == "floor" {
+                        Self::Floor(expr)
+                    } else {
+                        Self::Ceil(expr)
+                    },
+                    ty,
+                )
+            }
+            "mod" | "div" => {
+                let arg1 = next_arg()?;
+                let arg2 = next_arg()?;
+                let ty = unify_numeric_types([arg1.ty(), arg2.ty()]);
+                let arg1 = cast_if_known(arg1, &ty);
+                let arg2 = cast_if_known(arg2, &ty);
+                (
+                    if name == "mod" {
+                        Self::Mod(arg1, arg2)
+                    } else {
+                        Self::Div(arg1, arg2)
+                    },
+                    ty,
+                )
+            }
+            "power" | "pow" => {
+                let base = next_arg()?;
+                let exponent = next_arg()?;
+                let ty = float_function_type(&[base.ty(), exponent.ty()]);
+                (
+                    Self::Power(cast(base, ty.clone()), cast(exponent, ty.clone())),
+                    ty,
+                )
+            }
+            "sqrt" | "exp" | "ln" | "log10" | "log2" => {
+                let expr = next_arg()?;
+                let ty = float_function_type(&[expr.ty()]);
+                let expr = cast(expr, ty.clone());
+                let log_base = |base: i64| Expr::Literal {
+                    val: base.into(),
+                    ty: DfType::BigInt,
+                };
+                (
+                    match name {
+                        "sqrt" => Self::Sqrt(expr),
+                        "exp" => Self::Exp(expr),
+                        "ln" => Self::Ln(expr),
+                        "log10" => Self::Log(log_base(10), expr),
+                        _ => Self::Log(log_base(2), expr),
+                    },
+                    ty,
+                )
+            }
+            "log" => {
+                let arg1 = next_arg()?;
+                match args.next() {
+                    Some(arg2) => {
+                        let ty = float_function_type(&[arg1.ty(), arg2.ty()]);
+                        (
+                            Self::Log(cast(arg1, ty.clone()), cast(arg2, ty.clone())),
+                            ty,
+                        )
+                    }
+                    None => {
+                        let ty = float_function_type(&[arg1.ty()]);
+                        let expr = cast(arg1, ty.clone());
+                        (
+                            // `log(x)` is the natural logarithm in MySQL, but the base 10
+                            // logarithm in PostgreSQL
+                            match dialect.engine() {
+                                SqlEngine::MySQL => Self::Ln(expr),
+                                SqlEngine::PostgreSQL => Self::Log(
+                                    Expr::Literal {
+                                        val: 10.into(),
+                                        ty: DfType::BigInt,
+                                    },
+                                    expr,
+                                ),
+                            },
+                            ty,
+                        )
+                    }
+                }
+            }
+            "sign" => {
+                let expr = next_arg()?;
+                // MySQL always returns an integer, whereas PostgreSQL returns a value of the same
+                // type as the argument
+                let (arg_ty, ty) = match dialect.engine() {
+                    SqlEngine::MySQL => (unify_numeric_types([expr.ty()]), DfType::BigInt),
+                    SqlEngine::PostgreSQL => {
+                        let ty = float_function_type(&[expr.ty()]);
+                        (ty.clone(), ty)
+                    }
+                };
+                (Self::Sign(cast_if_known(expr, &arg_ty)), ty)
+            }
+            "truncate" | "trunc" => {
+                let expr = next_arg()?;
+                let prec = match dialect.engine() {
+                    // The precision is required in MySQL
+                    SqlEngine::MySQL => next_arg()?,
+                    SqlEngine::PostgreSQL => args.next().unwrap_or(Expr::Literal {
+                        val: DfValue::Int(0),
+                        ty: DfType::Int,
+                    }),
+                };
+                let ty = type_for_round(&expr, &prec);
+                (
+                    Self::Truncate(cast_if_known(expr, &ty), cast(prec, DfType::BigInt)),
+                    ty,
+                )
+            }
             "json_depth" => (Self::JsonDepth(next_arg()?), DfType::Int),
             "json_valid" => (Self::JsonValid(next_arg()?), DfType::BigInt),
             "json_overlaps" => (Self::JsonOverlaps(next_arg()?, next_arg()?), DfType::BigInt),
@@ -373,6 +823,176 @@ impl BuiltinFunction {
                 ),
                 DfType::DEFAULT_TEXT,
             ),
+            "lower" | "lcase" | "upper" | "ucase" => {
+                let string = next_arg()?;
+                let ty = text_type_for(string.ty());
+                let string = cast(string, ty.clone());
+                (
+                    if matches!(name, "lower" | "lcase") {
+                        Self::Lower(string)
+                    } else {
+                        Self::Upper(string)
+                    },
+                    ty,
+                )
+            }
+            "length" | "char_length" | "character_length" | "octet_length" => {
+                let expr = next_arg()?;
+                // Binary strings are always measured in bytes
+                let is_binary = expr.ty().is_binary() || *expr.ty() == DfType::Blob;
+                let in_bytes = is_binary
+                    || name == "octet_length"
+                    // `length` counts bytes in MySQL, but characters in PostgreSQL
+                    || (name == "length" && dialect.engine() == SqlEngine::MySQL);
+                let expr = if is_binary {
+                    expr
+                } else {
+                    let ty = text_type_for(expr.ty());
+                    cast(expr, ty)
+                };
+                let ty = match dialect.engine() {
+                    SqlEngine::MySQL => DfType::BigInt,
+                    SqlEngine::PostgreSQL => DfType::Int,
+                };
+                (Self::Length { expr, in_bytes }, ty)
+            }
+            "trim" | "btrim" | "ltrim" | "rtrim" => {
+                let string = next_arg()?;
+                let ty = text_type_for(string.ty());
+                let string = cast(string, ty.clone());
+                // Only PostgreSQL supports specifying the set of characters to trim as a second
+                // argument
+                let characters = match dialect.engine() {
+                    SqlEngine::MySQL => None,
+                    SqlEngine::PostgreSQL => args.next().map(|arg| cast(arg, DfType::DEFAULT_TEXT)),
+                };
+                (
+                    match name {
+                        "ltrim" => Self::LTrim(string, characters),
+                        "rtrim" => Self::RTrim(string, characters),
+                        _ => Self::Trim(string, characters),
+                    },
+                    ty,
+                )
+            }
+            "replace" => {
+                let string = next_arg()?;
+                let ty = text_type_for(string.ty());
+                (
+                    Self::Replace(
+                        cast(string, ty.clone()),
+                        cast(next_arg()?, DfType::DEFAULT_TEXT),
+                        cast(next_arg()?, DfType::DEFAULT_TEXT),
+                    ),
+                    ty,
+                )
+            }
+            "lpad" | "rpad" => {
+                let string = next_arg()?;
+                let ty = text_type_for(string.ty());
+                let string = cast(string, ty.clone());
+                let length = cast(next_arg()?, DfType::BigInt);
+                let (fill, null_on_invalid) = match dialect.engine() {
+                    SqlEngine::MySQL => (cast(next_arg()?, DfType::DEFAULT_TEXT), true),
+                    // The fill argument is optional in PostgreSQL, and defaults to a space
+                    SqlEngine::PostgreSQL => (
+                        args.next()
+                            .map(|arg| cast(arg, DfType::DEFAULT_TEXT))
+                            .unwrap_or_else(|| Expr::Literal {
+                                val: " ".into(),
+                                ty: DfType::DEFAULT_TEXT,
+                            }),
+                        false,
+                    ),
+                };
+                (
+                    if name == "lpad" {
+                        Self::Lpad {
+                            string,
+                            length,
+                            fill,
+                            null_on_invalid,
+                        }
+                    } else {
+                        Self::Rpad {
+                            string,
+                            length,
+                            fill,
+                            null_on_invalid,
+                        }
+                    },
+                    ty,
+                )
+            }
+            "left" | "right" => {
+                let string = next_arg()?;
+                let ty = text_type_for(string.ty());
+                let string = cast(string, ty.clone());
+                let length = cast(next_arg()?, DfType::BigInt);
+                let negative_from_end = dialect.engine() == SqlEngine::PostgreSQL;
+                (
+                    if name == "left" {
+                        Self::Left {
+                            string,
+                            length,
+                            negative_from_end,
+                        }
+                    } else {
+                        Self::Right {
+                            string,
+                            length,
+                            negative_from_end,
+                        }
+                    },
+                    ty,
+                )
+            }
+            "locate" | "position" | "instr" | "strpos" => {
+                // `instr` and `strpos` take the string to search first, whereas `locate` and
+                // `position` take the substring to search for first
+                let (substring, string) = if matches!(name, "instr" | "strpos") {
+                    let string = next_arg()?;
+                    (next_arg()?, string)
+                } else {
+                    let substring = next_arg()?;
+                    (substring, next_arg()?)
+                };
+                let start = if name == "locate" {
+                    args.next().map(|arg| cast(arg, DfType::BigInt))
+                } else {
+                    None
+                };
+                let ty = match dialect.engine() {
+                    SqlEngine::MySQL => DfType::BigInt,
+                    SqlEngine::PostgreSQL => DfType::Int,
+                };
+                (
+                    Self::Locate {
+                        substring: cast(substring, DfType::DEFAULT_TEXT),
+                        string: cast(string, DfType::DEFAULT_TEXT),
+                        start,
+                    },
+                    ty,
+                )
+            }
+            "reverse" => {
+                let string = next_arg()?;
+                let ty = text_type_for(string.ty());
+                (Self::Reverse(cast(string, ty.clone())), ty)
+            }
+            "repeat" => {
+                let string = next_arg()?;
+                let ty = text_type_for(string.ty());
+                (
+                    Self::Repeat(cast(string, ty.clone()), cast(next_arg()?, DfType::BigInt)),
+                    ty,
+                )
+            }
+            "initcap" => {
+                let string = next_arg()?;
+                let ty = text_type_for(string.ty());
+                (Self::Initcap(cast(string, ty.clone())), ty)
+            }
             "greatest" | "least" => {
                 // The type inference rules for GREATEST and LEAST are the same, so this block
                 // covers both then dispatches for the actual function construction at the end
@@ -458,6 +1078,15 @@ impl Expr {
         C: LowerContext,
     {
         match expr {
+            // MySQL's `DATE(expr)` is equivalent to a cast to `DATE` which returns NULL on failure
+            AstExpr::Call(FunctionExpr::Call {
+                name,
+                mut arguments,
+            }) if name == "date" && arguments.len() == 1 => Ok(Self::Cast {
+                expr: Box::new(Self::lower(arguments.remove(0), dialect, context)?),
+                ty: DfType::Date,
+                null_on_failure: true,
+            }),
             AstExpr::Call(FunctionExpr::Call {
                 name: fname,
                 arguments,
@@ -489,6 +1118,92 @@ impl Expr {
 
                 Ok(Self::Call { func, ty })
             }
+            AstExpr::Call(FunctionExpr::Extract { field, expr }) => {
+                let expr = Self::lower(*expr, dialect, context)?;
+                let (func, ty) = BuiltinFunction::extract(field, expr, dialect)?;
+                Ok(Self::Call {
+                    func: Box::new(func),
+                    ty,
+                })
+            }
+            AstExpr::Call(FunctionExpr::DateAdd {
+                expr,
+                interval,
+                unit,
+                subtract,
+            }) => {
+                let date_unit = matches!(
+                    unit,
+                    TimestampField::Day
+                        | TimestampField::Week
+                        | TimestampField::Month
+                        | TimestampField::Quarter
+                        | TimestampField::Year
+                );
+                if !date_unit
+                    && !matches!(
+                        unit,
+                        TimestampField::Microsecond
+                            | TimestampField::Second
+                            | TimestampField::Minute
+                            | TimestampField::Hour
+                    )
+                {
+                    invalid!("{unit} is not a valid unit for INTERVAL")
+                }
+
+                let expr = Self::lower(*expr, dialect, context.clone())?;
+                let mut interval = Self::lower(*interval, dialect, context)?;
+                if subtract {
+                    // `DATE_SUB(x, INTERVAL n unit)` is `DATE_ADD(x, INTERVAL -n unit)`
+                    let ty = interval.ty().clone();
+                    interval = Self::Op {
+                        op: BinaryOperator::Multiply,
+                        left: Box::new(interval),
+                        right: Box::new(Self::Literal {
+                            val: DfValue::Int(-1),
+                            ty: DfType::Int,
+                        }),
+                        ty,
+                    };
+                }
+
+                let subsecond_digits = if unit == TimestampField::Microsecond {
+                    6
+                } else {
+                    expr.ty()
+                        .subsecond_digits()
+                        .unwrap_or_else(|| dialect.default_subsecond_digits())
+                };
+                let ty = match expr.ty() {
+                    DfType::Date if date_unit => DfType::Date,
+                    DfType::Timestamp { .. } => DfType::Timestamp { subsecond_digits },
+                    DfType::TimestampTz { .. } => DfType::TimestampTz { subsecond_digits },
+                    _ => DfType::DateTime { subsecond_digits },
+                };
+                let expr = match expr.ty() {
+                    DfType::Date
+                    | DfType::DateTime { .. }
+                    | DfType::Timestamp { .. }
+                    | DfType::TimestampTz { .. } => expr,
+                    _ => Self::Cast {
+                        expr: Box::new(expr),
+                        ty: DfType::DateTime {
+                            subsecond_digits: 6,
+                        },
+                        null_on_failure: true,
+                    },
+                };
+
+                Ok(Self::Call {
+                    func: Box::new(BuiltinFunction::DateAdd {
+                        expr,
+                        interval,
+                        unit,
+                    }),
+                    ty,
+                })
+            }
             AstExpr::Call(call) => internal!(
                 "Unexpected (aggregate?) call node in project expression: {:?}",
                 Sensitive(&call)
@@ -512,9 +1227,24 @@ impl Expr {
             AstExpr::BinaryOp { lhs, op, rhs } => {
                 let mut left = Box::new(Self::lower(*lhs, dialect, context.clone())?);
                 let mut right = Box::new(Self::lower(*rhs, dialect, context)?);
+
+                // `^` is the exponentiation operator in PostgreSQL
+                if op == SqlBinaryOperator::Caret && dialect.engine() == SqlEngine::PostgreSQL {
+                    let (func, ty) =
+                        BuiltinFunction::from_name_and_args("power", [*left, *right], dialect)?;
+                    return Ok(Self::Call {
+                        func: Box::new(func),
+                        ty,
+                    });
+                }
+
                 let (op, negated) =
                     BinaryOperator::from_sql_op(op, dialect, left.ty(), right.ty())?;
 
+                if op.is_bitwise() {
+                    return Self::lower_bitwise_op(op, *left, *right, dialect);
+                }
+
                 if matches!(
                     op,
                     BinaryOperator::JsonPathExtract | BinaryOperator::JsonPathExtractUnquote
@@ -587,6 +1317,37 @@ impl Expr {
                 expr: Box::new(Self::lower(*rhs, dialect, context)?),
                 ty: DfType::Bool, // type of NOT is always bool
             }),
+            AstExpr::UnaryOp {
+                op: UnaryOperator::BitwiseNot,
+                rhs,
+            } => {
+                // Bitwise NOT is the same as XOR with all bits set
+                let expr = Self::lower(*rhs, dialect, context)?;
+                let all_ones = match expr.ty() {
+                    DfType::Bit(len) => Self::Literal {
+                        val: BitVec::from_elem(*len as usize, true).into(),
+                        ty: DfType::Bit(*len),
+                    },
+                    DfType::VarBit(_) => {
+                        unsupported!("'~' not yet supported for variable-length bit strings")
+                    }
+                    _ => match dialect.engine() {
+                        SqlEngine::MySQL => Self::Literal {
+                            val: DfValue::UnsignedInt(u64::MAX),
+                            ty: DfType::UnsignedBigInt,
+                        },
+                        SqlEngine::PostgreSQL => Self::Literal {
+                            val: DfValue::Int(-1),
+                            ty: if expr.ty().is_any_int() && !expr.ty().is_any_unsigned_int() {
+                                expr.ty().clone()
+                            } else {
+                                DfType::BigInt
+                            },
+                        },
+                    },
+                };
+                Self::lower_bitwise_op(BinaryOperator::BitwiseXor, expr, all_ones, dialect)
+            }
             AstExpr::Cast {
                 expr, ty: to_type, ..
             } => {
@@ -730,8 +1491,40 @@ impl Expr {
                     ty,
                 })
             }
-            AstExpr::Exists(_) => unsupported!("EXISTS not currently supported"),
+            AstExpr::Interval { value, unit } => {
+                let val = match Self::lower(*value, dialect, context)? {
+                    Self::Literal { val, .. } => val,
+                    _ => unsupported!("Only literal values are supported in INTERVAL expressions"),
+                };
+                let interval = match unit {
+                    _ if val.is_none() => None,
+                    Some(unit) if !val.is_string() => {
+                        Some(Interval::from_field(Decimal::try_from(&val)?, unit)?)
+                    }
+                    // `INTERVAL '3' DAY` is three days, but `INTERVAL '1 day' DAY` is parsed like
+                    // any other interval string
+                    Some(unit) => {
+                        let s = <&str>::try_from(&val)?;
+                        match s.trim().parse::<Decimal>() {
+                            Ok(amount) => Some(Interval::from_field(amount, unit)?),
+                            Err(_) => Some(s.parse()?),
+                        }
+                    }
+                    None => Some(<&str>::try_from(&val)?.parse()?),
+                };
+
+                Ok(Self::Literal {
+                    val: interval.into(),
+                    ty: DfType::Interval,
+                })
+            }
+            AstExpr::Exists(_) => {
+                unsupported!("EXISTS is only supported at the top level of filter conditions")
+            }
             AstExpr::Variable(_) => unsupported!("Variables not currently supported"),
+            AstExpr::WindowFunction { .. } => {
+                unsupported!("Window functions are not supported in this position")
+            }
             AstExpr::Between { .. } | AstExpr::NestedSelect(_) | AstExpr::In { .. } => {
                 internal!(
                     "Expression should have been desugared earlier: {}",
@@ -741,6 +1534,87 @@ impl Expr {
         }
     }
 
+    /// Lower an application of a bitwise operator to the given (already lowered) operands.
+    ///
+    /// MySQL performs bitwise operations on unsigned 64-bit integers, whereas PostgreSQL operates
+    /// on signed integers and also on bit strings. Since evaluation uses unsigned arithmetic if
+    /// either of the operands is unsigned and signed arithmetic otherwise, this coerces the
+    /// operands to get the semantics of the given dialect.
+    fn lower_bitwise_op(
+        op: BinaryOperator,
+        left: Expr,
+        right: Expr,
+        dialect: Dialect,
+    ) -> ReadySetResult<Self> {
+        let cast = |expr: Expr, ty| Self::Cast {
+            expr: Box::new(expr),
+            ty,
+            null_on_failure: false,
+        };
+
+        if left.ty().is_any_bit_string() {
+            let right = if matches!(op, BinaryOperator::ShiftLeft | BinaryOperator::ShiftRight)
+                && !right.ty().is_any_int()
+            {
+                cast(right, DfType::BigInt)
+            } else {
+                right
+            };
+            let ty = op.output_type(left.ty(), right.ty())?;
+            return Ok(Self::Op {
+                op,
+                left: Box::new(left),
+                right: Box::new(right),
+                ty,
+            });
+        }
+        if right.ty().is_any_bit_string() {
+            invalid!(
+                "cannot invoke '{op}' on left-side operand type {}; expected a bit string",
+                left.ty()
+            );
+        }
+
+        let int_operand = |expr: Expr| {
+            let needs_cast = match dialect.engine() {
+                SqlEngine::MySQL => expr.ty().is_known() && !expr.ty().is_any_int(),
+                // Integer literals are unsigned, but PostgreSQL has no unsigned integer types
+                SqlEngine::PostgreSQL => !expr.ty().is_any_int() || expr.ty().is_any_unsigned_int(),
+            };
+            if needs_cast {
+                cast(expr, DfType::BigInt)
+            } else {
+                expr
+            }
+        };
+        let mut left = int_operand(left);
+        let right = int_operand(right);
+
+        if dialect.engine() == SqlEngine::MySQL
+            && !left.ty().is_any_unsigned_int()
+            && !right.ty().is_any_unsigned_int()
+        {
+            // Force unsigned arithmetic by OR-ing the left-hand side with an unsigned zero
+            left = Self::Op {
+                op: BinaryOperator::BitwiseOr,
+                left: Box::new(left),
+                right: Box::new(Self::Literal {
+                    val: DfValue::UnsignedInt(0),
+                    ty: DfType::UnsignedBigInt,
+                }),
+                ty: DfType::UnsignedBigInt,
+            };
+        }
+
+        let ty = op.output_type(left.ty(), right.ty())?;
+        Ok(Self::Op {
+            op,
+            left: Box::new(left),
+            right: Box::new(right),
+            ty,
+        })
+    }
+
     fn lower_op_any_or_all<C>(
         lhs: AstExpr,
         op: SqlBinaryOperator,
@@ -1150,6 +2024,352 @@ pub(crate) mod tests {
         assert_eq!(res.ty(), &DfType::DEFAULT_TEXT);
     }
 
+    #[test]
+    fn string_function_types() {
+        #[track_caller]
+        fn infers_type(expr: &str, dialect: ParserDialect, expected_ty: DfType) {
+            let input = parse_expr(dialect, expr).unwrap();
+            let expr_dialect = match dialect {
+                ParserDialect::MySQL => Dialect::DEFAULT_MYSQL,
+                ParserDialect::PostgreSQL => Dialect::DEFAULT_POSTGRESQL,
+            };
+            let result = Expr::lower(
+                input,
+                expr_dialect,
+                resolve_columns(|c| {
+                    if c == "col".into() {
+                        Ok((0, DfType::VarChar(10, Collation::Citext)))
+                    } else {
+                        internal!()
+                    }
+                }),
+            )
+            .unwrap();
+            assert_eq!(result.ty(), &expected_ty, "{expr}");
+        }
+
+        for dialect in [ParserDialect::MySQL, ParserDialect::PostgreSQL] {
+            infers_type("upper(col)", dialect, DfType::Text(Collation::Citext));
+            infers_type("trim(col)", dialect, DfType::Text(Collation::Citext));
+            infers_type("repeat(col, 3)", dialect, DfType::Text(Collation::Citext));
+            infers_type("replace(123, '2', '3')", dialect, DfType::DEFAULT_TEXT);
+        }
+
+        infers_type("length(col)", ParserDialect::MySQL, DfType::BigInt);
+        infers_type("locate('a', col)", ParserDialect::MySQL, DfType::BigInt);
+        infers_type("length(col)", ParserDialect::PostgreSQL, DfType::Int);
+        infers_type("strpos(col, 'a')", ParserDialect::PostgreSQL, DfType::Int);
+    }
+
+    #[test]
+    fn string_function_arity_depends_on_dialect() {
+        #[track_caller]
+        fn lower(expr: &str, dialect: ParserDialect) -> ReadySetResult<Expr> {
+            let input = parse_expr(dialect, expr).unwrap();
+            let expr_dialect = match dialect {
+                ParserDialect::MySQL => Dialect::DEFAULT_MYSQL,
+                ParserDialect::PostgreSQL => Dialect::DEFAULT_POSTGRESQL,
+            };
+            Expr::lower(input, expr_dialect, no_op_lower_context())
+        }
+
+        // Only PostgreSQL allows specifying the characters to trim as an argument
+        lower("ltrim('xxa', 'x')", ParserDialect::PostgreSQL).unwrap();
+        lower("ltrim('xxa', 'x')", ParserDialect::MySQL).unwrap_err();
+
+        // Only PostgreSQL allows omitting the fill argument to `lpad` and `rpad`
+        lower("lpad('a', 3)", ParserDialect::PostgreSQL).unwrap();
+        lower("rpad('a', 3)", ParserDialect::MySQL).unwrap_err();
+    }
+
+    #[test]
+    fn math_function_types() {
+        #[track_caller]
+        fn infers_type(expr: &str, dialect: ParserDialect, expected_ty: DfType) {
+            let input = parse_expr(dialect, expr).unwrap();
+            let expr_dialect = match dialect {
+                ParserDialect::MySQL => Dialect::DEFAULT_MYSQL,
+                ParserDialect::PostgreSQL => Dialect::DEFAULT_POSTGRESQL,
+            };
+            let result = Expr::lower(
+                input,
+                expr_dialect,
+                resolve_columns(|c| match c.name.as_str() {
+                    "i" => Ok((0, DfType::Int)),
+                    "n" => Ok((1, DfType::Numeric { prec: 10, scale: 2 })),
+                    "d" => Ok((2, DfType::Double)),
+                    _ => internal!(),
+                }),
+            )
+            .unwrap();
+            assert_eq!(result.ty(), &expected_ty, "{expr}");
+        }
+
+        for dialect in [ParserDialect::MySQL, ParserDialect::PostgreSQL] {
+            infers_type("abs(i)", dialect, DfType::Int);
+            infers_type("abs(n)", dialect, DfType::Numeric { prec: 10, scale: 2 });
+            infers_type("floor(d)", dialect, DfType::Double);
+            infers_type("mod(i, n)", dialect, DfType::Numeric { prec: 10, scale: 2 });
+            infers_type("mod(n, d)", dialect, DfType::Double);
+            infers_type("sqrt(i)", dialect, DfType::Double);
+            infers_type("power(i, d)", dialect, DfType::Double);
+        }
+
+        infers_type("ceil(i)", ParserDialect::MySQL, DfType::Int);
+        infers_type("ceil(i)", ParserDialect::PostgreSQL, DfType::Double);
+        infers_type("sign(d)", ParserDialect::MySQL, DfType::BigInt);
+        infers_type("sign(d)", ParserDialect::PostgreSQL, DfType::Double);
+        infers_type(
+            "sqrt(n)",
+            ParserDialect::PostgreSQL,
+            DfType::Numeric { prec: 10, scale: 2 },
+        );
+        infers_type("sqrt(n)", ParserDialect::MySQL, DfType::Double);
+    }
+
+    #[test]
+    fn date_time_function_types() {
+        fn lower(expr: &str, dialect: ParserDialect) -> ReadySetResult<Expr> {
+            let input = parse_expr(dialect, expr).unwrap();
+            let expr_dialect = match dialect {
+                ParserDialect::MySQL => Dialect::DEFAULT_MYSQL,
+                ParserDialect::PostgreSQL => Dialect::DEFAULT_POSTGRESQL,
+            };
+            Expr::lower(
+                input,
+                expr_dialect,
+                resolve_columns(|c| match c.name.as_str() {
+                    "d" => Ok((0, DfType::Date)),
+                    "dt" => Ok((
+                        1,
+                        DfType::DateTime {
+                            subsecond_digits: 0,
+                        },
+                    )),
+                    "ts" => Ok((
+                        2,
+                        DfType::Timestamp {
+                            subsecond_digits: 6,
+                        },
+                    )),
+                    "tz" => Ok((
+                        3,
+                        DfType::TimestampTz {
+                            subsecond_digits: 6,
+                        },
+                    )),
+                    "t" => Ok((4, DfType::DEFAULT_TEXT)),
+                    _ => internal!(),
+                }),
+            )
+        }
+
+        #[track_caller]
+        fn infers_type(expr: &str, dialect: ParserDialect, expected_ty: DfType) {
+            assert_eq!(lower(expr, dialect).unwrap().ty(), &expected_ty, "{expr}");
+        }
+
+        use ParserDialect::{MySQL, PostgreSQL};
+
+        infers_type("extract(year from dt)", MySQL, DfType::BigInt);
+        infers_type("extract(microsecond from dt)", MySQL, DfType::BigInt);
+        infers_type("year(dt)", MySQL, DfType::Int);
+        infers_type("week(d, 3)", MySQL, DfType::Int);
+        infers_type("date(dt)", MySQL, DfType::Date);
+        infers_type("datediff(dt, d)", MySQL, DfType::BigInt);
+        infers_type("unix_timestamp(dt)", MySQL, DfType::BigInt);
+        infers_type(
+            "unix_timestamp(ts)",
+            MySQL,
+            DfType::Numeric { prec: 20, scale: 6 },
+        );
+        infers_type(
+            "from_unixtime(1)",
+            MySQL,
+            DfType::DateTime {
+                subsecond_digits: 0,
+            },
+        );
+        infers_type("from_unixtime(1, '%Y')", MySQL, DfType::DEFAULT_TEXT);
+        infers_type("date_add(d, interval 1 day)", MySQL, DfType::Date);
+        infers_type(
+            "date_sub(dt, interval 1 microsecond)",
+            MySQL,
+            DfType::DateTime {
+                subsecond_digits: 6,
+            },
+        );
+        infers_type("str_to_date(t, '%Y-%m-%d')", MySQL, DfType::Date);
+        infers_type(
+            "str_to_date(t, '%H:%i:%s.%f')",
+            MySQL,
+            DfType::Time {
+                subsecond_digits: 6,
+            },
+        );
+        infers_type(
+            "str_to_date(t, '%Y-%m-%d %T')",
+            MySQL,
+            DfType::DateTime {
+                subsecond_digits: 0,
+            },
+        );
+
+        infers_type("extract(year from ts)", PostgreSQL, DfType::DEFAULT_NUMERIC);
+        infers_type("date_part('year', ts)", PostgreSQL, DfType::Double);
+        infers_type(
+            "date_trunc('day', ts)",
+            PostgreSQL,
+            DfType::Timestamp {
+                subsecond_digits: 6,
+            },
+        );
+        infers_type(
+            "date_trunc('day', tz)",
+            PostgreSQL,
+            DfType::TimestampTz {
+                subsecond_digits: 6,
+            },
+        );
+        infers_type("to_char(ts, 'YYYY')", PostgreSQL, DfType::DEFAULT_TEXT);
+
+        lower("date_trunc('dow', ts)", PostgreSQL).unwrap_err();
+        lower("date_trunc('fortnight', ts)", PostgreSQL).unwrap_err();
+        lower("extract(epoch from dt)", MySQL).unwrap_err();
+        lower("date_add(dt, interval 1 doy)", MySQL).unwrap_err();
+    }
+
+    #[test]
+    fn interval_literals() {
+        #[track_caller]
+        fn lower(expr: &str, dialect: ParserDialect) -> ReadySetResult<Expr> {
+            let input = parse_expr(dialect, expr).unwrap();
+            let expr_dialect = match dialect {
+                ParserDialect::MySQL => Dialect::DEFAULT_MYSQL,
+                ParserDialect::PostgreSQL => Dialect::DEFAULT_POSTGRESQL,
+            };
+            Expr::lower(
+                input,
+                expr_dialect,
+                resolve_columns(|c| match c.name.as_str() {
+                    "dt" => Ok((
+                        0,
+                        DfType::DateTime {
+                            subsecond_digits: 0,
+                        },
+                    )),
+                    "ts" => Ok((
+                        1,
+                        DfType::Timestamp {
+                            subsecond_digits: 6,
+                        },
+                    )),
+                    _ => internal!(),
+                }),
+            )
+        }
+
+        #[track_caller]
+        fn lowers_to(expr: &str, dialect: ParserDialect, expected: Interval) {
+            assert_eq!(
+                lower(expr, dialect).unwrap(),
+                Expr::Literal {
+                    val: expected.into(),
+                    ty: DfType::Interval,
+                },
+                "{expr}"
+            );
+        }
+
+        use ParserDialect::{MySQL, PostgreSQL};
+
+        lowers_to("interval 3 day", MySQL, Interval::new(0, 3, 0));
+        lowers_to(
+            "interval 1.5 hour",
+            MySQL,
+            Interval::new(0, 0, 5_400_000_000),
+        );
+        lowers_to("interval '2' month", MySQL, Interval::new(2, 0, 0));
+        lowers_to(
+            "interval '1 year 2 days 03:00'",
+            PostgreSQL,
+            Interval::new(12, 2, 10_800_000_000),
+        );
+        lowers_to(
+            "interval '10' minutes",
+            PostgreSQL,
+            Interval::new(0, 0, 600_000_000),
+        );
+
+        assert_eq!(
+            lower("ts + interval '1 day'", PostgreSQL).unwrap().ty(),
+            &DfType::Timestamp {
+                subsecond_digits: 6
+            }
+        );
+        assert_eq!(
+            lower("interval 1 day + dt", MySQL).unwrap().ty(),
+            &DfType::DateTime {
+                subsecond_digits: 0
+            }
+        );
+
+        lower("interval 'forever'", PostgreSQL).unwrap_err();
+        lower("interval 1 dow", MySQL).unwrap_err();
+        lower("interval dt day", MySQL).unwrap_err();
+    }
+
+    #[test]
+    fn bitwise_operators() {
+        #[track_caller]
+        fn lower(expr: &str, dialect: ParserDialect, col_ty: DfType) -> ReadySetResult<Expr> {
+            let input = parse_expr(dialect, expr).unwrap();
+            let expr_dialect = match dialect {
+                ParserDialect::MySQL => Dialect::DEFAULT_MYSQL,
+                ParserDialect::PostgreSQL => Dialect::DEFAULT_POSTGRESQL,
+            };
+            Expr::lower(
+                input,
+                expr_dialect,
+                resolve_columns(move |_| Ok((0, col_ty.clone()))),
+            )
+        }
+
+        // MySQL always operates on unsigned integers
+        let res = lower("x & 4", ParserDialect::MySQL, DfType::Int).unwrap();
+        assert_eq!(res.ty(), &DfType::UnsignedBigInt);
+        let res = lower("x | x", ParserDialect::MySQL, DfType::Int).unwrap();
+        assert_eq!(res.ty(), &DfType::UnsignedBigInt);
+
+        // PostgreSQL operates on signed integers, and bit strings
+        let res = lower("x & 4", ParserDialect::PostgreSQL, DfType::BigInt).unwrap();
+        assert_eq!(res.ty(), &DfType::BigInt);
+        let res = lower("~x", ParserDialect::PostgreSQL, DfType::SmallInt).unwrap();
+        assert_eq!(res.ty(), &DfType::SmallInt);
+        let res = lower("x << 2", ParserDialect::PostgreSQL, DfType::Bit(3)).unwrap();
+        assert_eq!(res.ty(), &DfType::Bit(3));
+        lower("~x", ParserDialect::PostgreSQL, DfType::VarBit(None)).unwrap_err();
+        lower("4 & x", ParserDialect::PostgreSQL, DfType::Bit(3)).unwrap_err();
+
+        // `^` is XOR in MySQL, but exponentiation in PostgreSQL
+        let res = lower("x ^ 2", ParserDialect::MySQL, DfType::Int).unwrap();
+        assert!(matches!(
+            res,
+            Expr::Op {
+                op: BinaryOperator::BitwiseXor,
+                ..
+            }
+        ));
+        let res = lower("x ^ 2", ParserDialect::PostgreSQL, DfType::Int).unwrap();
+        assert!(matches!(
+            res,
+            Expr::Call {
+                func: box BuiltinFunction::Power(..),
+                ..
+            }
+        ));
+    }
+
     #[test]
     fn greatest_inferred_type() {
         use Literal::Null;
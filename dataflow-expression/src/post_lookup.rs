@@ -3,16 +3,16 @@ use std::convert::TryFrom;
 use std::fmt::Debug;
 use std::sync::Arc;
 
-use nom_sql::OrderType;
+use nom_sql::{NullOrder, OrderType};
 use partial_map::InsertionOrder;
 use readyset_data::DfValue;
-use readyset_errors::{internal, ReadySetResult};
+use readyset_errors::{internal, unsupported, ReadySetError, ReadySetResult};
 use serde::{Deserialize, Serialize};
 
 /// Representation of an aggregate function
 // TODO(grfn): It would be really nice to deduplicate this somehow with the grouped operator itself
 #[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
-pub enum PostLookupAggregateFunction {
+pub enum PostLookupAggregateFunction<Column = usize> {
     /// Add together all the input numbers
     ///
     /// Note that this encapsulates both `SUM` *and* `COUNT` in base SQL, as re-aggregating counts
@@ -26,12 +26,42 @@ pub enum PostLookupAggregateFunction {
     Max,
     /// Take the minimum input value
     Min,
+    /// Divide the (already re-aggregated) values in the `sum` column by the values in the `count`
+    /// column.
+    ///
+    /// Averages can't be re-aggregated from their values alone, so queries computing them carry
+    /// the sum and count of the averaged values as separate columns, which are themselves
+    /// re-aggregated with [`Sum`](Self::Sum).
+    Avg { sum: Column, count: Column },
+}
+
+impl<Column> PostLookupAggregateFunction<Column> {
+    /// Transform all column references in self by applying a function
+    pub fn map_columns<F, C2, E>(self, mut f: F) -> Result<PostLookupAggregateFunction<C2>, E>
+    where
+        F: FnMut(Column) -> Result<C2, E>,
+    {
+        Ok(match self {
+            PostLookupAggregateFunction::Sum => PostLookupAggregateFunction::Sum,
+            PostLookupAggregateFunction::Product => PostLookupAggregateFunction::Product,
+            PostLookupAggregateFunction::GroupConcat { separator } => {
+                PostLookupAggregateFunction::GroupConcat { separator }
+            }
+            PostLookupAggregateFunction::Max => PostLookupAggregateFunction::Max,
+            PostLookupAggregateFunction::Min => PostLookupAggregateFunction::Min,
+            PostLookupAggregateFunction::Avg { sum, count } => PostLookupAggregateFunction::Avg {
+                sum: f(sum)?,
+                count: f(count)?,
+            },
+        })
+    }
 }
 
 impl PostLookupAggregateFunction {
     /// Apply this aggregate function to the two input values
     ///
-    /// This forms a semigroup.
+    /// This forms a semigroup. Averages are derived from other columns rather than from their own
+    /// values, so they can't be applied this way - see [`PostLookupAggregates::apply`].
     pub fn apply(&self, val1: &DfValue, val2: &DfValue) -> ReadySetResult<DfValue> {
         match self {
             PostLookupAggregateFunction::Sum => val1 + val2,
@@ -45,6 +75,9 @@ impl PostLookupAggregateFunction {
             .into()),
             PostLookupAggregateFunction::Max => Ok(cmp::max(val1, val2).clone()),
             PostLookupAggregateFunction::Min => Ok(cmp::min(val1, val2).clone()),
+            PostLookupAggregateFunction::Avg { .. } => {
+                internal!("Averages must be computed from their sum and count columns")
+            }
         }
     }
 }
@@ -55,7 +88,7 @@ pub struct PostLookupAggregate<Column = usize> {
     /// The column index in the result set containing the already-aggregated values
     pub column: Column,
     /// The aggregate function to perform
-    pub function: PostLookupAggregateFunction,
+    pub function: PostLookupAggregateFunction<Column>,
 }
 
 impl<Column> PostLookupAggregate<Column> {
@@ -66,7 +99,7 @@ impl<Column> PostLookupAggregate<Column> {
     {
         Ok(PostLookupAggregate {
             column: f(self.column)?,
-            function: self.function,
+            function: self.function.map_columns(f)?,
         })
     }
 }
@@ -103,6 +136,33 @@ impl<Column> PostLookupAggregates<Column> {
     }
 }
 
+impl PostLookupAggregates {
+    /// Re-aggregate `row` into `acc`, which must both belong to the same group.
+    ///
+    /// Averages are computed after all other aggregates, so that they're derived from the
+    /// already re-aggregated sum and count columns.
+    pub fn apply(&self, acc: &mut [DfValue], row: &[DfValue]) -> ReadySetResult<()> {
+        for agg in &self.aggregates {
+            if !matches!(agg.function, PostLookupAggregateFunction::Avg { .. }) {
+                acc[agg.column] = agg.function.apply(&acc[agg.column], &row[agg.column])?;
+            }
+        }
+
+        for agg in &self.aggregates {
+            if let PostLookupAggregateFunction::Avg { sum, count } = agg.function {
+                acc[agg.column] = if acc[sum].is_none() || acc[count] <= DfValue::from(0) {
+                    DfValue::None
+                } else {
+                    // Like the AVG operator itself, compute averages as doubles
+                    DfValue::try_from(f64::try_from(&acc[sum])? / f64::try_from(&acc[count])?)?
+                };
+            }
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Default, Eq, PartialEq)]
 /// Operations to perform on rows before insertion into a reader or after a lookup
 pub struct ReaderProcessing {
@@ -115,11 +175,12 @@ pub struct ReaderProcessing {
 impl ReaderProcessing {
     /// Constructs a new [`PostLookup`]
     pub fn new(
-        order_by: Option<Vec<(usize, OrderType)>>,
+        order_by: Option<Vec<(usize, OrderType, NullOrder)>>,
         limit: Option<usize>,
         returned_cols: Option<Vec<usize>>,
         default_row: Option<Vec<DfValue>>,
         aggregates: Option<PostLookupAggregates>,
+        subquery_row_counts: Vec<usize>,
     ) -> ReadySetResult<Self> {
         if let Some(cols) = &returned_cols {
             if cols.iter().enumerate().any(|(i, v)| i != *v) {
@@ -133,6 +194,7 @@ impl ReaderProcessing {
             returned_cols,
             default_row: default_row.map(|r| Arc::new(r.into_boxed_slice())),
             aggregates,
+            subquery_row_counts,
         };
 
         let pre_processing = PreInsertion {
@@ -163,10 +225,11 @@ impl ReaderProcessing {
 /// the desugared query rather than the original query.
 #[derive(Serialize, Deserialize, Debug, Clone, Default, Eq, PartialEq)]
 pub struct PostLookup {
-    /// Column indices to order by, and whether or not to reverse order on each index.
+    /// Column indices to order by, whether or not to reverse order on each index, and where to
+    /// place NULLs for each index.
     ///
     /// If an empty `Vec` is specified, rows are sorted in lexicographic order.
-    pub order_by: Option<Vec<(usize, OrderType)>>,
+    pub order_by: Option<Vec<(usize, OrderType, NullOrder)>>,
     /// Maximum number of records to return
     pub limit: Option<usize>,
     /// Indices of the columns requested in the query. Reader will filter out all other projected
@@ -176,18 +239,64 @@ pub struct PostLookup {
     pub default_row: Option<Arc<Box<[DfValue]>>>,
     /// Aggregates to perform on the result set *after* it's retrieved from the reader.
     ///
-    /// Note that currently these are only performed on each key individually, not the overall
-    /// result set returned by all keys in a multi-key lookup
+    /// These are performed on the overall result set returned by all keys in a lookup, so that
+    /// range lookups and multi-key lookups (eg for parameterized `IN`) return one row per group
+    /// rather than one row per group per key
     pub aggregates: Option<PostLookupAggregates>,
+    /// Indices of columns containing the number of rows returned by each scalar subquery in the
+    /// query for that row. Since scalar subqueries may return at most one row, lookups returning
+    /// any rows where one of these is greater than 1 return an error.
+    pub subquery_row_counts: Vec<usize>,
+}
+
+impl PostLookup {
+    /// Returns an error if any of the given rows has more than one row in any of the
+    /// [`subquery_row_counts`](Self::subquery_row_counts) columns
+    pub fn check_subquery_row_counts<'a, I>(&self, rows: I) -> ReadySetResult<()>
+    where
+        I: IntoIterator<Item = &'a [DfValue]>,
+    {
+        if self.subquery_row_counts.is_empty() {
+            return Ok(());
+        }
+
+        let one = DfValue::from(1);
+        for row in rows {
+            if self
+                .subquery_row_counts
+                .iter()
+                .any(|&idx| row.get(idx).map_or(false, |count| *count > one))
+            {
+                return Err(ReadySetError::SubqueryReturnedMultipleRows);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns an error if a lookup with the given limit and offset would need more rows than are
+    /// kept per key in the reader, according to [`limit`](Self::limit)
+    pub fn check_limit(&self, limit: Option<usize>, offset: Option<usize>) -> ReadySetResult<()> {
+        if let (Some(max), Some(limit)) = (self.limit, limit) {
+            let rows_needed = limit.saturating_add(offset.unwrap_or(0));
+            if rows_needed > max {
+                unsupported!(
+                    "LIMIT and OFFSET require {rows_needed} rows, but only {max} rows are cached \
+                     per key"
+                );
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default, Eq, PartialEq)]
 /// Operations to perform on a row before it is stored in the map in a reader.
 pub struct PreInsertion {
-    /// Column indices to order by, and whether or not to reverse order on each index.
+    /// Column indices to order by, whether or not to reverse order on each index, and where to
+    /// place NULLs for each index.
     ///
     /// If an empty `Vec` is specified, rows are sorted in lexicographic order.
-    order_by: Option<Vec<(usize, OrderType)>>,
+    order_by: Option<Vec<(usize, OrderType, NullOrder)>>,
     /// The set of column indices to group the aggregate by, `group_by` takes precedence over
     /// `order_by` when determining row order, so that aggregates are proccessed one by one.
     group_by: Option<Vec<usize>>,
@@ -214,7 +323,11 @@ impl InsertionOrder<Box<[DfValue]>> for PreInsertion {
             values.binary_search_by(|cur_row| {
                 indices
                     .iter()
-                    .map(|&(idx, order_type)| order_type.apply(cur_row[idx].cmp(&elem[idx])))
+                    .map(|&(idx, order_type, null_order)| {
+                        null_order
+                            .apply(cur_row[idx].is_none(), elem[idx].is_none())
+                            .unwrap_or_else(|| order_type.apply(cur_row[idx].cmp(&elem[idx])))
+                    })
                     .try_fold(Ordering::Equal, |acc, next| match acc {
                         Ordering::Equal => Ok(next),
                         ord => Err(ord),
@@ -227,3 +340,105 @@ impl InsertionOrder<Box<[DfValue]>> for PreInsertion {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_subquery_row_counts() {
+        let post_lookup = PostLookup {
+            subquery_row_counts: vec![1],
+            ..Default::default()
+        };
+
+        let ok_rows = [
+            vec![DfValue::from(1), DfValue::from(1)],
+            vec![DfValue::from(2), DfValue::None],
+        ];
+        post_lookup
+            .check_subquery_row_counts(ok_rows.iter().map(|r| r.as_slice()))
+            .unwrap();
+
+        let err_rows = [
+            vec![DfValue::from(1), DfValue::from(1)],
+            vec![DfValue::from(2), DfValue::from(2)],
+        ];
+        assert!(matches!(
+            post_lookup.check_subquery_row_counts(err_rows.iter().map(|r| r.as_slice())),
+            Err(ReadySetError::SubqueryReturnedMultipleRows)
+        ));
+    }
+
+    #[test]
+    fn aggregates_apply() {
+        let aggregates = PostLookupAggregates {
+            group_by: vec![0],
+            aggregates: vec![
+                PostLookupAggregate {
+                    column: 1,
+                    function: PostLookupAggregateFunction::Avg { sum: 2, count: 3 },
+                },
+                PostLookupAggregate {
+                    column: 2,
+                    function: PostLookupAggregateFunction::Sum,
+                },
+                PostLookupAggregate {
+                    column: 3,
+                    function: PostLookupAggregateFunction::Sum,
+                },
+                PostLookupAggregate {
+                    column: 4,
+                    function: PostLookupAggregateFunction::Max,
+                },
+            ],
+        };
+
+        // avg(1, 2, 3) = 2, avg(10) = 10
+        let mut acc = vec![
+            DfValue::from(1),
+            DfValue::from(2),
+            DfValue::from(6),
+            DfValue::from(3),
+            DfValue::from(3),
+        ];
+        let row = [
+            DfValue::from(1),
+            DfValue::from(10),
+            DfValue::from(10),
+            DfValue::from(1),
+            DfValue::from(10),
+        ];
+        aggregates.apply(&mut acc, &row).unwrap();
+
+        assert_eq!(acc[2], DfValue::from(16));
+        assert_eq!(acc[3], DfValue::from(4));
+        assert_eq!(acc[4], DfValue::from(10));
+        assert_eq!(f64::try_from(&acc[1]).unwrap(), 4.0);
+    }
+
+    #[test]
+    fn avg_cant_be_applied_pairwise() {
+        PostLookupAggregateFunction::Avg { sum: 0, count: 1 }
+            .apply(&DfValue::from(1), &DfValue::from(2))
+            .unwrap_err();
+    }
+
+    #[test]
+    fn check_limit() {
+        let post_lookup = PostLookup {
+            limit: Some(10),
+            ..Default::default()
+        };
+
+        post_lookup.check_limit(None, None).unwrap();
+        post_lookup.check_limit(Some(10), None).unwrap();
+        post_lookup.check_limit(Some(5), Some(5)).unwrap();
+        post_lookup.check_limit(Some(11), None).unwrap_err();
+        post_lookup.check_limit(Some(5), Some(6)).unwrap_err();
+
+        PostLookup::default()
+            .check_limit(Some(100), Some(100))
+            .unwrap();
+    }
+}
@@ -54,7 +54,9 @@ impl<'a> ReferredColumnsIter<'a> {
                 self.exprs_to_visit.push(lhs);
                 self.visit_expr(rhs)
             }
-            Expr::UnaryOp { rhs: expr, .. } | Expr::Cast { expr, .. } => self.visit_expr(expr),
+            Expr::UnaryOp { rhs: expr, .. }
+            | Expr::Cast { expr, .. }
+            | Expr::Interval { value: expr, .. } => self.visit_expr(expr),
             Expr::Exists { .. } => None,
             Expr::Between {
                 operand, min, max, ..
@@ -83,6 +85,16 @@ impl<'a> ReferredColumnsIter<'a> {
             }),
             Expr::NestedSelect(_) => None,
             Expr::Variable(_) => None,
+            Expr::WindowFunction {
+                function,
+                partition_by,
+                order_by,
+            } => {
+                self.exprs_to_visit.extend(partition_by);
+                self.exprs_to_visit
+                    .extend(order_by.iter().map(|(expr, _)| expr));
+                self.visit_function_expression(function)
+            }
         }
     }
 
@@ -97,6 +109,26 @@ impl<'a> ReferredColumnsIter<'a> {
             Max(arg) => self.visit_expr(arg),
             Min(arg) => self.visit_expr(arg),
             GroupConcat { expr, .. } => self.visit_expr(expr),
+            StdDev { expr, .. } | Variance { expr, .. } => self.visit_expr(expr),
+            BoolAnd(arg) | BoolOr(arg) | BitAnd(arg) | BitOr(arg) | BitXor(arg) => {
+                self.visit_expr(arg)
+            }
+            ApproxCountDistinct(expr) | ApproxPercentile { expr, .. } => self.visit_expr(expr),
+            ArrayAgg { expr, order_by } | JsonAgg { expr, order_by } => {
+                self.exprs_to_visit
+                    .extend(order_by.iter().map(|(expr, _)| expr));
+                self.visit_expr(expr)
+            }
+            JsonObjectAgg {
+                key,
+                value,
+                order_by,
+            } => {
+                self.exprs_to_visit.push(value);
+                self.exprs_to_visit
+                    .extend(order_by.iter().map(|(expr, _)| expr));
+                self.visit_expr(key)
+            }
             Call { arguments, .. } => arguments.first().and_then(|first_arg| {
                 if arguments.len() >= 2 {
                     self.exprs_to_visit.extend(arguments.iter().skip(1));
@@ -108,6 +140,11 @@ impl<'a> ReferredColumnsIter<'a> {
                 self.exprs_to_visit.extend(len.iter().map(|e| e.as_ref()));
                 self.visit_expr(string)
             }
+            Extract { expr, .. } => self.visit_expr(expr),
+            DateAdd { expr, interval, .. } => {
+                self.exprs_to_visit.push(interval);
+                self.visit_expr(expr)
+            }
         }
     }
 
@@ -167,7 +204,9 @@ impl<'a> ReferredColumnsMut<'a> {
                 self.exprs_to_visit.push(lhs);
                 self.visit_expr(rhs)
             }
-            Expr::UnaryOp { rhs: expr, .. } | Expr::Cast { expr, .. } => self.visit_expr(expr),
+            Expr::UnaryOp { rhs: expr, .. }
+            | Expr::Cast { expr, .. }
+            | Expr::Interval { value: expr, .. } => self.visit_expr(expr),
             Expr::Exists { .. } => None,
             Expr::Between {
                 operand, min, max, ..
@@ -192,6 +231,16 @@ impl<'a> ReferredColumnsMut<'a> {
             }),
             Expr::NestedSelect(_) => None,
             Expr::Variable(_) => None,
+            Expr::WindowFunction {
+                function,
+                partition_by,
+                order_by,
+            } => {
+                self.exprs_to_visit.extend(partition_by);
+                self.exprs_to_visit
+                    .extend(order_by.iter_mut().map(|(expr, _)| expr));
+                self.visit_function_expression(function)
+            }
         }
     }
 
@@ -206,6 +255,26 @@ impl<'a> ReferredColumnsMut<'a> {
             Max(arg) => self.visit_expr(arg),
             Min(arg) => self.visit_expr(arg),
             GroupConcat { expr, .. } => self.visit_expr(expr),
+            StdDev { expr, .. } | Variance { expr, .. } => self.visit_expr(expr),
+            BoolAnd(arg) | BoolOr(arg) | BitAnd(arg) | BitOr(arg) | BitXor(arg) => {
+                self.visit_expr(arg)
+            }
+            ApproxCountDistinct(expr) | ApproxPercentile { expr, .. } => self.visit_expr(expr),
+            ArrayAgg { expr, order_by } | JsonAgg { expr, order_by } => {
+                self.exprs_to_visit
+                    .extend(order_by.iter_mut().map(|(expr, _)| expr));
+                self.visit_expr(expr)
+            }
+            JsonObjectAgg {
+                key,
+                value,
+                order_by,
+            } => {
+                self.exprs_to_visit.push(value);
+                self.exprs_to_visit
+                    .extend(order_by.iter_mut().map(|(expr, _)| expr));
+                self.visit_expr(key)
+            }
             Call { arguments, .. } => arguments.split_first_mut().and_then(|(first_arg, args)| {
                 self.exprs_to_visit.extend(args);
                 self.visit_expr(first_arg)
@@ -217,6 +286,11 @@ impl<'a> ReferredColumnsMut<'a> {
                     .extend(len.iter_mut().map(|e| e.as_mut()));
                 self.visit_expr(string)
             }
+            Extract { expr, .. } => self.visit_expr(expr),
+            DateAdd { expr, interval, .. } => {
+                self.exprs_to_visit.push(interval);
+                self.visit_expr(expr)
+            }
         }
     }
 
@@ -315,7 +389,7 @@ impl SelectStatement {
                 })
             }))
             .chain(self.order.iter().flat_map(|oc| {
-                oc.order_by.iter().filter_map(|(f, _)| match f {
+                oc.order_by.iter().filter_map(|(f, _, _)| match f {
                     FieldReference::Expr(expr) => Some(expr),
                     _ => None,
                 })
@@ -338,8 +412,22 @@ pub fn is_aggregate(function: &FunctionExpr) -> bool {
         | FunctionExpr::Sum { .. }
         | FunctionExpr::Max(_)
         | FunctionExpr::Min(_)
-        | FunctionExpr::GroupConcat { .. } => true,
+        | FunctionExpr::GroupConcat { .. }
+        | FunctionExpr::StdDev { .. }
+        | FunctionExpr::Variance { .. }
+        | FunctionExpr::BoolAnd(_)
+        | FunctionExpr::BoolOr(_)
+        | FunctionExpr::BitAnd(_)
+        | FunctionExpr::BitOr(_)
+        | FunctionExpr::BitXor(_)
+        | FunctionExpr::ApproxCountDistinct(_)
+        | FunctionExpr::ApproxPercentile { .. }
+        | FunctionExpr::ArrayAgg { .. }
+        | FunctionExpr::JsonAgg { .. }
+        | FunctionExpr::JsonObjectAgg { .. } => true,
         FunctionExpr::Substring { .. }
+        | FunctionExpr::Extract { .. }
+        | FunctionExpr::DateAdd { .. }
         // For now, assume all "generic" function calls are not aggregates
         | FunctionExpr::Call { .. } => false,
     }
@@ -367,7 +455,9 @@ pub fn contains_aggregate(expr: &Expr) -> bool {
         | Expr::OpAny { lhs, rhs, .. }
         | Expr::OpSome { lhs, rhs, .. }
         | Expr::OpAll { lhs, rhs, .. } => contains_aggregate(lhs) || contains_aggregate(rhs),
-        Expr::UnaryOp { rhs: expr, .. } | Expr::Cast { expr, .. } => contains_aggregate(expr),
+        Expr::UnaryOp { rhs: expr, .. }
+        | Expr::Cast { expr, .. }
+        | Expr::Interval { value: expr, .. } => contains_aggregate(expr),
         Expr::Exists(_) => false,
         Expr::Between {
             operand, min, max, ..
@@ -382,6 +472,17 @@ pub fn contains_aggregate(expr: &Expr) -> bool {
         }
         Expr::Array(exprs) => exprs.iter().any(contains_aggregate),
         Expr::Variable(_) => false,
+        // A window function computes a value per row rather than collapsing rows, so only its
+        // arguments and window specification can contain an aggregate
+        Expr::WindowFunction {
+            function,
+            partition_by,
+            order_by,
+        } => function
+            .arguments()
+            .chain(partition_by)
+            .chain(order_by.iter().map(|(expr, _)| expr))
+            .any(contains_aggregate),
     }
 }
 
@@ -441,9 +542,9 @@ impl Expr {
             | Expr::OpAll { lhs, rhs, .. } => {
                 Box::new(vec![lhs, rhs].into_iter().map(AsRef::as_ref)) as _
             }
-            Expr::UnaryOp { rhs: expr, .. } | Expr::Cast { expr, .. } => {
-                Box::new(iter::once(expr.as_ref())) as _
-            }
+            Expr::UnaryOp { rhs: expr, .. }
+            | Expr::Cast { expr, .. }
+            | Expr::Interval { value: expr, .. } => Box::new(iter::once(expr.as_ref())) as _,
             Expr::CaseWhen {
                 branches,
                 else_expr,
@@ -467,6 +568,16 @@ impl Expr {
                 ..
             } => Box::new(iter::once(lhs.as_ref())) as _,
             Expr::Array(exprs) => Box::new(exprs.iter()),
+            Expr::WindowFunction {
+                function,
+                partition_by,
+                order_by,
+            } => Box::new(
+                function
+                    .arguments()
+                    .chain(partition_by)
+                    .chain(order_by.iter().map(|(expr, _)| expr)),
+            ) as _,
         }
     }
 
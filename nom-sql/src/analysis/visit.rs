@@ -452,7 +452,22 @@ pub fn walk_expr<'ast, V: Visitor<'ast>>(
             }
             Ok(())
         }
+        Expr::Interval { value, .. } => visitor.visit_expr(value.as_ref()),
         Expr::Variable(var) => visitor.visit_variable(var),
+        Expr::WindowFunction {
+            function,
+            partition_by,
+            order_by,
+        } => {
+            visitor.visit_function_expr(function)?;
+            for expr in partition_by {
+                visitor.visit_expr(expr)?;
+            }
+            for (expr, _) in order_by {
+                visitor.visit_expr(expr)?;
+            }
+            Ok(())
+        }
     }
 }
 
@@ -476,6 +491,34 @@ pub fn walk_function_expr<'ast, V: Visitor<'ast>>(
         FunctionExpr::Max(expr) => visitor.visit_expr(expr.as_ref()),
         FunctionExpr::Min(expr) => visitor.visit_expr(expr.as_ref()),
         FunctionExpr::GroupConcat { expr, .. } => visitor.visit_expr(expr.as_ref()),
+        FunctionExpr::StdDev { expr, .. } => visitor.visit_expr(expr.as_ref()),
+        FunctionExpr::Variance { expr, .. } => visitor.visit_expr(expr.as_ref()),
+        FunctionExpr::BoolAnd(expr)
+        | FunctionExpr::BoolOr(expr)
+        | FunctionExpr::BitAnd(expr)
+        | FunctionExpr::BitOr(expr)
+        | FunctionExpr::BitXor(expr)
+        | FunctionExpr::ApproxCountDistinct(expr)
+        | FunctionExpr::ApproxPercentile { expr, .. } => visitor.visit_expr(expr.as_ref()),
+        FunctionExpr::ArrayAgg { expr, order_by } | FunctionExpr::JsonAgg { expr, order_by } => {
+            visitor.visit_expr(expr.as_ref())?;
+            for (expr, _) in order_by {
+                visitor.visit_expr(expr)?;
+            }
+            Ok(())
+        }
+        FunctionExpr::JsonObjectAgg {
+            key,
+            value,
+            order_by,
+        } => {
+            visitor.visit_expr(key.as_ref())?;
+            visitor.visit_expr(value.as_ref())?;
+            for (expr, _) in order_by {
+                visitor.visit_expr(expr)?;
+            }
+            Ok(())
+        }
         FunctionExpr::Call { arguments, .. } => {
             for arg in arguments {
                 visitor.visit_expr(arg)?;
@@ -492,6 +535,11 @@ pub fn walk_function_expr<'ast, V: Visitor<'ast>>(
             }
             Ok(())
         }
+        FunctionExpr::Extract { expr, .. } => visitor.visit_expr(expr.as_ref()),
+        FunctionExpr::DateAdd { expr, interval, .. } => {
+            visitor.visit_expr(expr.as_ref())?;
+            visitor.visit_expr(interval.as_ref())
+        }
     }
 }
 
@@ -515,7 +563,11 @@ pub fn walk_common_table_expr<'ast, V: Visitor<'ast>>(
     cte: &'ast CommonTableExpr,
 ) -> Result<(), V::Error> {
     visitor.visit_sql_identifier(&cte.name)?;
-    visitor.visit_select_statement(&cte.statement)
+    visitor.visit_select_statement(&cte.statement)?;
+    if let Some((_, recursive_term)) = &cte.recursive_term {
+        visitor.visit_select_statement(recursive_term)?;
+    }
+    Ok(())
 }
 
 pub fn walk_field_definition_expr<'ast, V: Visitor<'ast>>(
@@ -591,7 +643,7 @@ pub fn walk_order_clause<'ast, V: Visitor<'ast>>(
     visitor: &mut V,
     order_clause: &'ast OrderClause,
 ) -> Result<(), V::Error> {
-    for (field, _) in &order_clause.order_by {
+    for (field, _, _) in &order_clause.order_by {
         visitor.visit_field_reference(field)?;
     }
     Ok(())
@@ -681,6 +733,9 @@ pub fn walk_select_statement<'ast, V: Visitor<'ast>>(
     for table_expr in &select_statement.tables {
         visitor.visit_table_expr(table_expr)?;
     }
+    for expr in &select_statement.distinct_on {
+        visitor.visit_expr(expr)?;
+    }
     for field in &select_statement.fields {
         visitor.visit_field_definition_expr(field)?;
     }
@@ -467,7 +467,22 @@ pub fn walk_expr<'ast, V: VisitorMut<'ast>>(
             }
             Ok(())
         }
+        Expr::Interval { value, .. } => visitor.visit_expr(value.as_mut()),
         Expr::Variable(var) => visitor.visit_variable(var),
+        Expr::WindowFunction {
+            function,
+            partition_by,
+            order_by,
+        } => {
+            visitor.visit_function_expr(function)?;
+            for expr in partition_by {
+                visitor.visit_expr(expr)?;
+            }
+            for (expr, _) in order_by {
+                visitor.visit_expr(expr)?;
+            }
+            Ok(())
+        }
     }
 }
 
@@ -491,6 +506,34 @@ pub fn walk_function_expr<'ast, V: VisitorMut<'ast>>(
         FunctionExpr::Max(expr) => visitor.visit_expr(expr.as_mut()),
         FunctionExpr::Min(expr) => visitor.visit_expr(expr.as_mut()),
         FunctionExpr::GroupConcat { expr, .. } => visitor.visit_expr(expr.as_mut()),
+        FunctionExpr::StdDev { expr, .. } => visitor.visit_expr(expr.as_mut()),
+        FunctionExpr::Variance { expr, .. } => visitor.visit_expr(expr.as_mut()),
+        FunctionExpr::BoolAnd(expr)
+        | FunctionExpr::BoolOr(expr)
+        | FunctionExpr::BitAnd(expr)
+        | FunctionExpr::BitOr(expr)
+        | FunctionExpr::BitXor(expr)
+        | FunctionExpr::ApproxCountDistinct(expr)
+        | FunctionExpr::ApproxPercentile { expr, .. } => visitor.visit_expr(expr.as_mut()),
+        FunctionExpr::ArrayAgg { expr, order_by } | FunctionExpr::JsonAgg { expr, order_by } => {
+            visitor.visit_expr(expr.as_mut())?;
+            for (expr, _) in order_by {
+                visitor.visit_expr(expr)?;
+            }
+            Ok(())
+        }
+        FunctionExpr::JsonObjectAgg {
+            key,
+            value,
+            order_by,
+        } => {
+            visitor.visit_expr(key.as_mut())?;
+            visitor.visit_expr(value.as_mut())?;
+            for (expr, _) in order_by {
+                visitor.visit_expr(expr)?;
+            }
+            Ok(())
+        }
         FunctionExpr::Call { arguments, .. } => {
             for arg in arguments {
                 visitor.visit_expr(arg)?;
@@ -507,6 +550,11 @@ pub fn walk_function_expr<'ast, V: VisitorMut<'ast>>(
             }
             Ok(())
         }
+        FunctionExpr::Extract { expr, .. } => visitor.visit_expr(expr.as_mut()),
+        FunctionExpr::DateAdd { expr, interval, .. } => {
+            visitor.visit_expr(expr.as_mut())?;
+            visitor.visit_expr(interval.as_mut())
+        }
     }
 }
 
@@ -530,7 +578,11 @@ pub fn walk_common_table_expr<'ast, V: VisitorMut<'ast>>(
     cte: &'ast mut CommonTableExpr,
 ) -> Result<(), V::Error> {
     visitor.visit_sql_identifier(&mut cte.name)?;
-    visitor.visit_select_statement(&mut cte.statement)
+    visitor.visit_select_statement(&mut cte.statement)?;
+    if let Some((_, recursive_term)) = &mut cte.recursive_term {
+        visitor.visit_select_statement(recursive_term)?;
+    }
+    Ok(())
 }
 
 pub fn walk_field_definition_expr<'ast, V: VisitorMut<'ast>>(
@@ -606,7 +658,7 @@ pub fn walk_order_clause<'ast, V: VisitorMut<'ast>>(
     visitor: &mut V,
     order_clause: &'ast mut OrderClause,
 ) -> Result<(), V::Error> {
-    for (field, _) in &mut order_clause.order_by {
+    for (field, _, _) in &mut order_clause.order_by {
         visitor.visit_field_reference(field)?;
     }
     Ok(())
@@ -698,6 +750,9 @@ pub fn walk_select_statement<'ast, V: VisitorMut<'ast>>(
     for table_expr in &mut select_statement.tables {
         visitor.visit_table_expr(table_expr)?;
     }
+    for expr in &mut select_statement.distinct_on {
+        visitor.visit_expr(expr)?;
+    }
     for field in &mut select_statement.fields {
         visitor.visit_field_definition_expr(field)?;
     }
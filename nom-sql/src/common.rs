@@ -8,7 +8,7 @@ use itertools::Itertools;
 use nom::branch::alt;
 use nom::bytes::complete::{tag, tag_no_case, take_until};
 use nom::character::complete::{digit1, line_ending};
-use nom::combinator::{map, map_res, opt};
+use nom::combinator::{map, map_res, opt, value, verify};
 use nom::error::{ErrorKind, ParseError};
 use nom::multi::{separated_list0, separated_list1};
 use nom::sequence::{delimited, pair, preceded, separated_pair, terminated, tuple};
@@ -19,10 +19,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::column::Column;
 use crate::dialect::Dialect;
-use crate::expression::expression;
+use crate::expression::{expression, timestamp_field, window_order_field};
+use crate::literal::literal;
+use crate::order::OrderType;
 use crate::table::Relation;
 use crate::whitespace::{whitespace0, whitespace1};
-use crate::{Expr, FunctionExpr, Literal, NomSqlResult, SqlIdentifier};
+use crate::{Double, Expr, FunctionExpr, Literal, NomSqlResult, SqlIdentifier};
 
 #[cfg(feature = "debug")]
 pub fn debug_print(tag: &str, i: &[u8]) {
@@ -502,6 +504,308 @@ fn substring(dialect: Dialect) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[
     }
 }
 
+/// Parses calls to the `LEFT` and `RIGHT` string functions, whose names are reserved keywords and
+/// so can't be parsed by [`function_call`]
+fn left_right(
+    dialect: Dialect,
+) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], FunctionExpr> {
+    move |i| {
+        let (i, name) = alt((
+            value("left", tag_no_case("left")),
+            value("right", tag_no_case("right")),
+        ))(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, arguments) = delim_fx_args(dialect)(i)?;
+
+        Ok((
+            i,
+            FunctionExpr::Call {
+                name: name.into(),
+                arguments,
+            },
+        ))
+    }
+}
+
+/// Parses the SQL-standard `POSITION(substring IN string)` syntax into a call to the equivalent
+/// function in the given dialect - `LOCATE(substring, string)` in MySQL, and `STRPOS(string,
+/// substring)` in PostgreSQL
+fn position(dialect: Dialect) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], FunctionExpr> {
+    move |i| {
+        let (i, _) = tag_no_case("position")(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, _) = tag("(")(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, substring) = expression(dialect)(i)?;
+        let (i, _) = tuple((whitespace1, tag_no_case("in"), whitespace1))(i)?;
+        let (i, string) = expression(dialect)(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, _) = tag(")")(i)?;
+
+        Ok((
+            i,
+            match dialect {
+                Dialect::MySQL => FunctionExpr::Call {
+                    name: "locate".into(),
+                    arguments: vec![substring, string],
+                },
+                Dialect::PostgreSQL => FunctionExpr::Call {
+                    name: "strpos".into(),
+                    arguments: vec![string, substring],
+                },
+            },
+        ))
+    }
+}
+
+/// Parses the SQL-standard `EXTRACT(field FROM expr)` syntax
+fn extract(dialect: Dialect) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], FunctionExpr> {
+    move |i| {
+        let (i, _) = tag_no_case("extract")(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, _) = tag("(")(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, field) = timestamp_field(i)?;
+        let (i, _) = tuple((whitespace1, tag_no_case("from"), whitespace1))(i)?;
+        let (i, expr) = expression(dialect)(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, _) = tag(")")(i)?;
+
+        Ok((
+            i,
+            FunctionExpr::Extract {
+                field,
+                expr: Box::new(expr),
+            },
+        ))
+    }
+}
+
+/// Parses MySQL's `DATE_ADD(expr, INTERVAL interval unit)` and `DATE_SUB(expr, INTERVAL interval
+/// unit)`, whose second argument isn't an expression in its own right
+fn date_add(dialect: Dialect) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], FunctionExpr> {
+    move |i| {
+        let (i, subtract) = alt((
+            value(false, tag_no_case("date_add")),
+            value(true, tag_no_case("date_sub")),
+        ))(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, _) = tag("(")(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, expr) = expression(dialect)(i)?;
+        let (i, _) = ws_sep_comma(i)?;
+        let (i, _) = tag_no_case("interval")(i)?;
+        let (i, _) = whitespace1(i)?;
+        let (i, interval) = expression(dialect)(i)?;
+        let (i, _) = whitespace1(i)?;
+        let (i, unit) = timestamp_field(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, _) = tag(")")(i)?;
+
+        Ok((
+            i,
+            FunctionExpr::DateAdd {
+                expr: Box::new(expr),
+                interval: Box::new(interval),
+                unit,
+                subtract,
+            },
+        ))
+    }
+}
+
+/// Parses the standard deviation and variance aggregate functions.
+///
+/// Note that `STD`, `STDDEV` and `VARIANCE` compute the population standard deviation and variance
+/// in MySQL, but the sample standard deviation and variance in PostgreSQL.
+fn stddev_variance(
+    dialect: Dialect,
+) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], FunctionExpr> {
+    move |i| {
+        let sample_by_default = dialect == Dialect::PostgreSQL;
+        let (i, (stddev, sample)) = alt((
+            value((true, false), tag_no_case("stddev_pop")),
+            value((true, true), tag_no_case("stddev_samp")),
+            value((true, sample_by_default), tag_no_case("stddev")),
+            value((true, sample_by_default), tag_no_case("std")),
+            value((false, false), tag_no_case("var_pop")),
+            value((false, true), tag_no_case("var_samp")),
+            value((false, sample_by_default), tag_no_case("variance")),
+        ))(i)?;
+        // DISTINCT is not supported for these functions
+        let (i, (expr, _)) = verify(agg_fx_args(dialect), |(_, distinct): &(Expr, bool)| {
+            !distinct
+        })(i)?;
+        let expr = Box::new(expr);
+
+        Ok((
+            i,
+            if stddev {
+                FunctionExpr::StdDev { expr, sample }
+            } else {
+                FunctionExpr::Variance { expr, sample }
+            },
+        ))
+    }
+}
+
+/// Parses the boolean (`BOOL_AND`, `EVERY`, `BOOL_OR`) and bitwise (`BIT_AND`, `BIT_OR`,
+/// `BIT_XOR`) aggregate functions.
+fn bool_bit_aggregate(
+    dialect: Dialect,
+) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], FunctionExpr> {
+    move |i| {
+        type MakeFn = fn(Box<Expr>) -> FunctionExpr;
+        let (i, make_fn) = alt((
+            value(FunctionExpr::BoolAnd as MakeFn, tag_no_case("bool_and")),
+            value(FunctionExpr::BoolAnd as MakeFn, tag_no_case("every")),
+            value(FunctionExpr::BoolOr as MakeFn, tag_no_case("bool_or")),
+            value(FunctionExpr::BitAnd as MakeFn, tag_no_case("bit_and")),
+            value(FunctionExpr::BitOr as MakeFn, tag_no_case("bit_or")),
+            value(FunctionExpr::BitXor as MakeFn, tag_no_case("bit_xor")),
+        ))(i)?;
+        // DISTINCT doesn't change the result of any of these functions, so we don't support it
+        let (i, (expr, _)) = verify(agg_fx_args(dialect), |(_, distinct): &(Expr, bool)| {
+            !distinct
+        })(i)?;
+
+        Ok((i, make_fn(Box::new(expr))))
+    }
+}
+
+/// Parses the arguments to one of the collecting aggregate functions: a parenthesized,
+/// comma-separated list of `num_args` expressions followed by an optional `ORDER BY` clause.
+fn collect_agg_args(
+    dialect: Dialect,
+    num_args: usize,
+) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], (Vec<Expr>, Vec<(Expr, OrderType)>)> {
+    move |i| {
+        let (i, _) = tag("(")(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, args) = verify(
+            separated_list1(ws_sep_comma, expression(dialect)),
+            |args: &Vec<Expr>| args.len() == num_args,
+        )(i)?;
+        let (i, order_by) = opt(preceded(
+            tuple((
+                whitespace1,
+                tag_no_case("order"),
+                whitespace1,
+                tag_no_case("by"),
+                whitespace1,
+            )),
+            separated_list1(ws_sep_comma, window_order_field(dialect)),
+        ))(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, _) = tag(")")(i)?;
+
+        Ok((i, (args, order_by.unwrap_or_default())))
+    }
+}
+
+/// Parses the aggregate functions which collect all the values in a group into an array or JSON
+/// value (`ARRAY_AGG`, `JSON_AGG`/`JSON_ARRAYAGG` and `JSON_OBJECT_AGG`/`JSON_OBJECTAGG`)
+fn collect_aggregate(
+    dialect: Dialect,
+) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], FunctionExpr> {
+    move |i| {
+        alt((
+            map(
+                preceded(tag_no_case("array_agg"), collect_agg_args(dialect, 1)),
+                |(mut args, order_by)| FunctionExpr::ArrayAgg {
+                    expr: Box::new(args.remove(0)),
+                    order_by,
+                },
+            ),
+            map(
+                preceded(
+                    alt((tag_no_case("json_agg"), tag_no_case("json_arrayagg"))),
+                    collect_agg_args(dialect, 1),
+                ),
+                |(mut args, order_by)| FunctionExpr::JsonAgg {
+                    expr: Box::new(args.remove(0)),
+                    order_by,
+                },
+            ),
+            map(
+                preceded(
+                    alt((
+                        tag_no_case("json_object_agg"),
+                        tag_no_case("json_objectagg"),
+                    )),
+                    collect_agg_args(dialect, 2),
+                ),
+                |(mut args, order_by)| {
+                    let value = Box::new(args.remove(1));
+                    FunctionExpr::JsonObjectAgg {
+                        key: Box::new(args.remove(0)),
+                        value,
+                        order_by,
+                    }
+                },
+            ),
+        ))(i)
+    }
+}
+
+/// Parses the approximate aggregate functions (`APPROX_COUNT_DISTINCT`, `APPROX_PERCENTILE` and
+/// `APPROX_MEDIAN`)
+fn approx_aggregate(
+    dialect: Dialect,
+) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], FunctionExpr> {
+    move |i| {
+        let single_arg = || {
+            map(
+                verify(agg_fx_args(dialect), |(_, distinct): &(Expr, bool)| {
+                    !distinct
+                }),
+                |(expr, _)| Box::new(expr),
+            )
+        };
+        let percentile = verify(literal(dialect), |lit: &Literal| {
+            matches!(
+                lit,
+                Literal::Integer(_)
+                    | Literal::UnsignedInteger(_)
+                    | Literal::Float(_)
+                    | Literal::Double(_)
+                    | Literal::Numeric(..)
+            )
+        });
+
+        alt((
+            map(
+                preceded(tag_no_case("approx_count_distinct"), single_arg()),
+                FunctionExpr::ApproxCountDistinct,
+            ),
+            map(
+                preceded(tag_no_case("approx_median"), single_arg()),
+                |expr| FunctionExpr::ApproxPercentile {
+                    expr,
+                    percentile: Literal::Double(Double {
+                        value: 0.5,
+                        precision: 1,
+                    }),
+                },
+            ),
+            map(
+                preceded(
+                    tag_no_case("approx_percentile"),
+                    delimited(
+                        pair(tag("("), whitespace0),
+                        separated_pair(expression(dialect), ws_sep_comma, percentile),
+                        pair(whitespace0, tag(")")),
+                    ),
+                ),
+                |(expr, percentile)| FunctionExpr::ApproxPercentile {
+                    expr: Box::new(expr),
+                    percentile,
+                },
+            ),
+        ))(i)
+    }
+}
+
 fn function_call(
     dialect: Dialect,
 ) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], FunctionExpr> {
@@ -569,6 +873,14 @@ pub fn function_expr(
                 },
             ),
             substring(dialect),
+            left_right(dialect),
+            position(dialect),
+            extract(dialect),
+            date_add(dialect),
+            stddev_variance(dialect),
+            bool_bit_aggregate(dialect),
+            collect_aggregate(dialect),
+            approx_aggregate(dialect),
             function_call(dialect),
             function_call_without_parens,
         ))(i)
@@ -835,7 +1147,7 @@ pub fn field_reference_list(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{to_nom_result, SqlType};
+    use crate::{to_nom_result, SqlType, TimestampField};
 
     fn test_opt_delimited_fn_call(i: &str) -> IResult<&[u8], &[u8]> {
         opt_delimited(tag("("), tag("abc"), tag(")"))(i.as_bytes())
@@ -883,6 +1195,236 @@ mod tests {
         assert_eq!(res.unwrap().1, expected);
     }
 
+    #[test]
+    fn stddev_and_variance() {
+        let x = || Box::new(Expr::Column(Column::from("x")));
+        for (dialect, sql, expected) in [
+            (
+                Dialect::MySQL,
+                "stddev_pop(x)",
+                FunctionExpr::StdDev {
+                    expr: x(),
+                    sample: false,
+                },
+            ),
+            (
+                Dialect::MySQL,
+                "STDDEV_SAMP(x)",
+                FunctionExpr::StdDev {
+                    expr: x(),
+                    sample: true,
+                },
+            ),
+            (
+                Dialect::MySQL,
+                "std(x)",
+                FunctionExpr::StdDev {
+                    expr: x(),
+                    sample: false,
+                },
+            ),
+            (
+                Dialect::MySQL,
+                "variance(x)",
+                FunctionExpr::Variance {
+                    expr: x(),
+                    sample: false,
+                },
+            ),
+            (
+                Dialect::PostgreSQL,
+                "stddev(x)",
+                FunctionExpr::StdDev {
+                    expr: x(),
+                    sample: true,
+                },
+            ),
+            (
+                Dialect::PostgreSQL,
+                "variance(x)",
+                FunctionExpr::Variance {
+                    expr: x(),
+                    sample: true,
+                },
+            ),
+            (
+                Dialect::PostgreSQL,
+                "var_pop(x)",
+                FunctionExpr::Variance {
+                    expr: x(),
+                    sample: false,
+                },
+            ),
+        ] {
+            let res = test_parse!(function_expr(dialect), sql.as_bytes());
+            assert_eq!(res, expected, "{sql}");
+
+            // Round-trip through the dialect-independent display
+            let displayed = res.display(dialect).to_string();
+            assert_eq!(
+                test_parse!(function_expr(dialect), displayed.as_bytes()),
+                expected,
+                "{displayed}"
+            );
+        }
+    }
+
+    #[test]
+    fn bool_and_bit_aggregates() {
+        let x = || Box::new(Expr::Column(Column::from("x")));
+        for (sql, expected) in [
+            ("bool_and(x)", FunctionExpr::BoolAnd(x())),
+            ("EVERY(x)", FunctionExpr::BoolAnd(x())),
+            ("bool_or(x)", FunctionExpr::BoolOr(x())),
+            ("BIT_AND(x)", FunctionExpr::BitAnd(x())),
+            ("bit_or(x)", FunctionExpr::BitOr(x())),
+            ("bit_xor(x)", FunctionExpr::BitXor(x())),
+        ] {
+            for dialect in [Dialect::MySQL, Dialect::PostgreSQL] {
+                let res = test_parse!(function_expr(dialect), sql.as_bytes());
+                assert_eq!(res, expected, "{sql}");
+
+                let displayed = res.display(dialect).to_string();
+                assert_eq!(
+                    test_parse!(function_expr(dialect), displayed.as_bytes()),
+                    expected,
+                    "{displayed}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn collect_aggregates() {
+        let col = |c: &str| Expr::Column(Column::from(c));
+        for (dialect, sql, expected) in [
+            (
+                Dialect::PostgreSQL,
+                "array_agg(x)",
+                FunctionExpr::ArrayAgg {
+                    expr: Box::new(col("x")),
+                    order_by: vec![],
+                },
+            ),
+            (
+                Dialect::PostgreSQL,
+                "array_agg(x ORDER BY y DESC, z)",
+                FunctionExpr::ArrayAgg {
+                    expr: Box::new(col("x")),
+                    order_by: vec![
+                        (col("y"), OrderType::OrderDescending),
+                        (col("z"), OrderType::OrderAscending),
+                    ],
+                },
+            ),
+            (
+                Dialect::PostgreSQL,
+                "json_agg(x order by y)",
+                FunctionExpr::JsonAgg {
+                    expr: Box::new(col("x")),
+                    order_by: vec![(col("y"), OrderType::OrderAscending)],
+                },
+            ),
+            (
+                Dialect::MySQL,
+                "JSON_ARRAYAGG(x)",
+                FunctionExpr::JsonAgg {
+                    expr: Box::new(col("x")),
+                    order_by: vec![],
+                },
+            ),
+            (
+                Dialect::PostgreSQL,
+                "json_object_agg(k, v ORDER BY k)",
+                FunctionExpr::JsonObjectAgg {
+                    key: Box::new(col("k")),
+                    value: Box::new(col("v")),
+                    order_by: vec![(col("k"), OrderType::OrderAscending)],
+                },
+            ),
+            (
+                Dialect::MySQL,
+                "json_objectagg(k, v)",
+                FunctionExpr::JsonObjectAgg {
+                    key: Box::new(col("k")),
+                    value: Box::new(col("v")),
+                    order_by: vec![],
+                },
+            ),
+        ] {
+            let res = test_parse!(function_expr(dialect), sql.as_bytes());
+            assert_eq!(res, expected, "{sql}");
+
+            let displayed = res.display(dialect).to_string();
+            assert_eq!(
+                test_parse!(function_expr(dialect), displayed.as_bytes()),
+                expected,
+                "{displayed}"
+            );
+        }
+    }
+
+    #[test]
+    fn approx_aggregates() {
+        let x = || Box::new(Expr::Column(Column::from("x")));
+        let double = |value, precision| Literal::Double(Double { value, precision });
+        for (sql, expected) in [
+            (
+                "approx_count_distinct(x)",
+                FunctionExpr::ApproxCountDistinct(x()),
+            ),
+            (
+                "APPROX_PERCENTILE(x, 0.95)",
+                FunctionExpr::ApproxPercentile {
+                    expr: x(),
+                    percentile: double(0.95, 2),
+                },
+            ),
+            (
+                "approx_percentile(x,1)",
+                FunctionExpr::ApproxPercentile {
+                    expr: x(),
+                    percentile: Literal::UnsignedInteger(1),
+                },
+            ),
+            (
+                "approx_median(x)",
+                FunctionExpr::ApproxPercentile {
+                    expr: x(),
+                    percentile: double(0.5, 1),
+                },
+            ),
+        ] {
+            for dialect in [Dialect::MySQL, Dialect::PostgreSQL] {
+                let res = test_parse!(function_expr(dialect), sql.as_bytes());
+                assert_eq!(res, expected, "{sql}");
+
+                let displayed = res.display(dialect).to_string();
+                assert_eq!(
+                    test_parse!(function_expr(dialect), displayed.as_bytes()),
+                    expected,
+                    "{displayed}"
+                );
+            }
+        }
+
+        // The percentile must be a numeric literal
+        let res = test_parse!(
+            function_expr(Dialect::MySQL),
+            "approx_percentile(x, y)".as_bytes()
+        );
+        assert!(matches!(res, FunctionExpr::Call { .. }));
+    }
+
+    #[test]
+    fn collect_aggregates_wrong_number_of_arguments() {
+        // These fall back to being parsed as generic function calls
+        for sql in ["array_agg(x, y)", "json_object_agg(k)"] {
+            let res = test_parse!(function_expr(Dialect::PostgreSQL), sql.as_bytes());
+            assert!(matches!(res, FunctionExpr::Call { .. }), "{sql}");
+        }
+    }
+
     #[test]
     fn simple_generic_function() {
         let qlist = [
@@ -971,6 +1513,116 @@ mod tests {
         assert_eq!(*rem, b"");
     }
 
+    #[test]
+    fn left_and_right() {
+        for dialect in [Dialect::MySQL, Dialect::PostgreSQL] {
+            for (sql, name) in [("LEFT(a, 2)", "left"), ("right (a, 2)", "right")] {
+                let res = test_parse!(function_expr(dialect), sql.as_bytes());
+                assert_eq!(
+                    res,
+                    FunctionExpr::Call {
+                        name: name.into(),
+                        arguments: vec![Expr::Column("a".into()), Expr::Literal(2u32.into())],
+                    }
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn position_in() {
+        let res = test_parse!(
+            function_expr(Dialect::MySQL),
+            "position('b' IN a)".as_bytes()
+        );
+        assert_eq!(
+            res,
+            FunctionExpr::Call {
+                name: "locate".into(),
+                arguments: vec![Expr::Literal("b".into()), Expr::Column("a".into())],
+            }
+        );
+        assert_eq!(res.display(Dialect::MySQL).to_string(), "locate('b', `a`)");
+
+        let res = test_parse!(
+            function_expr(Dialect::PostgreSQL),
+            "position('b' in a)".as_bytes()
+        );
+        assert_eq!(
+            res,
+            FunctionExpr::Call {
+                name: "strpos".into(),
+                arguments: vec![Expr::Column("a".into()), Expr::Literal("b".into())],
+            }
+        );
+    }
+
+    #[test]
+    fn extract_from() {
+        let res = test_parse!(
+            function_expr(Dialect::PostgreSQL),
+            b"extract(epoch FROM created_at)"
+        );
+        assert_eq!(
+            res,
+            FunctionExpr::Extract {
+                field: TimestampField::Epoch,
+                expr: Box::new(Expr::Column("created_at".into())),
+            }
+        );
+        assert_eq!(
+            res.display(Dialect::PostgreSQL).to_string(),
+            "extract(EPOCH from \"created_at\")"
+        );
+
+        let res = test_parse!(
+            function_expr(Dialect::PostgreSQL),
+            b"EXTRACT( microseconds from t )"
+        );
+        assert_eq!(
+            res,
+            FunctionExpr::Extract {
+                field: TimestampField::Microsecond,
+                expr: Box::new(Expr::Column("t".into())),
+            }
+        );
+    }
+
+    #[test]
+    fn date_add_and_sub() {
+        let res = test_parse!(
+            function_expr(Dialect::MySQL),
+            b"date_add(created_at, INTERVAL 1 day)"
+        );
+        assert_eq!(
+            res,
+            FunctionExpr::DateAdd {
+                expr: Box::new(Expr::Column("created_at".into())),
+                interval: Box::new(Expr::Literal(1u32.into())),
+                unit: TimestampField::Day,
+                subtract: false,
+            }
+        );
+        assert_eq!(
+            res.display(Dialect::MySQL).to_string(),
+            "date_add(`created_at`, INTERVAL 1 DAY)"
+        );
+
+        let res = test_parse!(
+            function_expr(Dialect::MySQL),
+            b"DATE_SUB(created_at,interval n MONTH)"
+        );
+        assert_eq!(
+            res,
+            FunctionExpr::DateAdd {
+                expr: Box::new(Expr::Column("created_at".into())),
+                interval: Box::new(Expr::Column("n".into())),
+                unit: TimestampField::Month,
+                subtract: true,
+            }
+        );
+    }
+
     #[test]
     fn substr_from_for() {
         let res = test_parse!(function_expr(Dialect::MySQL), b"substr(a from 1 for 7)");
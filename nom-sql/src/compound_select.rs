@@ -15,12 +15,14 @@ use crate::select::{limit_offset_clause, nested_selection, LimitClause, SelectSt
 use crate::whitespace::{whitespace0, whitespace1};
 use crate::{Dialect, NomSqlResult};
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Deserialize, Serialize)]
 pub enum CompoundSelectOperator {
     Union,
     DistinctUnion,
     Intersect,
+    IntersectAll,
     Except,
+    ExceptAll,
 }
 
 impl fmt::Display for CompoundSelectOperator {
@@ -29,7 +31,9 @@ impl fmt::Display for CompoundSelectOperator {
             CompoundSelectOperator::Union => write!(f, "UNION"),
             CompoundSelectOperator::DistinctUnion => write!(f, "UNION DISTINCT"),
             CompoundSelectOperator::Intersect => write!(f, "INTERSECT"),
+            CompoundSelectOperator::IntersectAll => write!(f, "INTERSECT ALL"),
             CompoundSelectOperator::Except => write!(f, "EXCEPT"),
+            CompoundSelectOperator::ExceptAll => write!(f, "EXCEPT ALL"),
         }
     }
 }
@@ -64,8 +68,23 @@ impl CompoundSelectStatement {
     }
 }
 
+// Parse an optional ALL or DISTINCT after a compound operator, returning true for ALL
+fn all_or_distinct(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], bool> {
+    map(
+        opt(preceded(
+            whitespace1,
+            alt((
+                map(tag_no_case("all"), |_| true),
+                map(tag_no_case("distinct"), |_| false),
+            )),
+        )),
+        // DISTINCT is the default
+        |all| all.unwrap_or(false),
+    )(i)
+}
+
 // Parse compound operator
-fn compound_op(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], CompoundSelectOperator> {
+pub(crate) fn compound_op(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], CompoundSelectOperator> {
     alt((
         map(
             preceded(
@@ -90,10 +109,20 @@ fn compound_op(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], CompoundSelectOpera
                 }
             },
         ),
-        map(tag_no_case("intersect"), |_| {
-            CompoundSelectOperator::Intersect
+        map(preceded(tag_no_case("intersect"), all_or_distinct), |all| {
+            if all {
+                CompoundSelectOperator::IntersectAll
+            } else {
+                CompoundSelectOperator::Intersect
+            }
+        }),
+        map(preceded(tag_no_case("except"), all_or_distinct), |all| {
+            if all {
+                CompoundSelectOperator::ExceptAll
+            } else {
+                CompoundSelectOperator::Except
+            }
         }),
-        map(tag_no_case("except"), |_| CompoundSelectOperator::Except),
     ))(i)
 }
 
@@ -319,6 +348,33 @@ mod tests {
         assert_eq!(res.unwrap().1, expected);
     }
 
+    #[test]
+    fn intersect_and_except() {
+        let qstr = "SELECT id FROM Vote INTERSECT ALL SELECT id FROM Rating \
+                    EXCEPT SELECT id FROM Vote EXCEPT ALL SELECT id FROM Rating \
+                    INTERSECT DISTINCT SELECT id FROM Vote;";
+        let res = nested_compound_selection(Dialect::MySQL)(LocatedSpan::new(qstr.as_bytes()));
+
+        let select = |table: &str| SelectStatement {
+            tables: vec![TableExpr::from(Relation::from(table))],
+            fields: vec![FieldDefinitionExpr::from(Column::from("id"))],
+            ..Default::default()
+        };
+        let expected = CompoundSelectStatement {
+            selects: vec![
+                (None, select("Vote")),
+                (Some(CompoundSelectOperator::IntersectAll), select("Rating")),
+                (Some(CompoundSelectOperator::Except), select("Vote")),
+                (Some(CompoundSelectOperator::ExceptAll), select("Rating")),
+                (Some(CompoundSelectOperator::Intersect), select("Vote")),
+            ],
+            order: None,
+            limit_clause: LimitClause::default(),
+        };
+
+        assert_eq!(res.unwrap().1, expected);
+    }
+
     #[test]
     #[ignore]
     fn union_flarum_1() {
@@ -1,14 +1,15 @@
 use std::fmt::{self, Display};
-use std::{iter, mem};
+use std::str::FromStr;
+use std::{iter, mem, str};
 
 use concrete_iter::concrete_iter;
 use derive_more::From;
 use itertools::Itertools;
 use nom::branch::alt;
-use nom::bytes::complete::{tag, tag_no_case};
+use nom::bytes::complete::{tag, tag_no_case, take_while1};
 use nom::character::complete::char;
-use nom::combinator::{complete, map, opt, value};
-use nom::multi::{many0, many1, separated_list0};
+use nom::combinator::{complete, map, map_res, opt, value};
+use nom::multi::{many0, many1, separated_list0, separated_list1};
 use nom::sequence::{delimited, pair, preceded, terminated, tuple};
 use nom::Parser;
 use nom_locate::LocatedSpan;
@@ -19,6 +20,7 @@ use test_strategy::Arbitrary;
 
 use crate::common::{column_identifier_no_alias, function_expr, ws_sep_comma};
 use crate::literal::literal;
+use crate::order::{order_type, OrderType};
 use crate::select::nested_selection;
 use crate::set::{variable_scope_prefix, Variable};
 use crate::sql_type::{mysql_int_cast_targets, type_identifier};
@@ -49,6 +51,58 @@ pub enum FunctionExpr {
     /// `GROUP_CONCAT` aggregation. The second argument is the separator
     GroupConcat { expr: Box<Expr>, separator: String },
 
+    /// `STDDEV_POP` or `STDDEV_SAMP` aggregation. `sample` is `true` for `STDDEV_SAMP`
+    StdDev { expr: Box<Expr>, sample: bool },
+
+    /// `VAR_POP` or `VAR_SAMP` aggregation. `sample` is `true` for `VAR_SAMP`
+    Variance { expr: Box<Expr>, sample: bool },
+
+    /// `BOOL_AND` (or `EVERY`) aggregation
+    BoolAnd(Box<Expr>),
+
+    /// `BOOL_OR` aggregation
+    BoolOr(Box<Expr>),
+
+    /// `BIT_AND` aggregation
+    BitAnd(Box<Expr>),
+
+    /// `BIT_OR` aggregation
+    BitOr(Box<Expr>),
+
+    /// `BIT_XOR` aggregation
+    BitXor(Box<Expr>),
+
+    /// `ARRAY_AGG` aggregation, with an optional `ORDER BY` clause
+    ArrayAgg {
+        expr: Box<Expr>,
+        order_by: Vec<(Expr, OrderType)>,
+    },
+
+    /// `JSON_AGG` (PostgreSQL) or `JSON_ARRAYAGG` (MySQL) aggregation, with an optional `ORDER BY`
+    /// clause
+    JsonAgg {
+        expr: Box<Expr>,
+        order_by: Vec<(Expr, OrderType)>,
+    },
+
+    /// `JSON_OBJECT_AGG` (PostgreSQL) or `JSON_OBJECTAGG` (MySQL) aggregation, with an optional
+    /// `ORDER BY` clause
+    JsonObjectAgg {
+        key: Box<Expr>,
+        value: Box<Expr>,
+        order_by: Vec<(Expr, OrderType)>,
+    },
+
+    /// `APPROX_COUNT_DISTINCT` aggregation, which estimates the number of distinct values
+    ApproxCountDistinct(Box<Expr>),
+
+    /// `APPROX_PERCENTILE` (or `APPROX_MEDIAN`) aggregation, which estimates the value at the
+    /// given percentile. The percentile is a numeric literal between 0 and 1
+    ApproxPercentile {
+        expr: Box<Expr>,
+        percentile: Literal,
+    },
+
     /// The SQL `SUBSTRING`/`SUBSTR` function.
     ///
     /// The supported syntax is one of:
@@ -64,6 +118,21 @@ pub enum FunctionExpr {
         len: Option<Box<Expr>>,
     },
 
+    /// The SQL `EXTRACT(field FROM expr)` function
+    Extract {
+        field: TimestampField,
+        expr: Box<Expr>,
+    },
+
+    /// MySQL's `DATE_ADD(expr, INTERVAL interval unit)` and `DATE_SUB(expr, INTERVAL interval
+    /// unit)` functions. `subtract` is true for `DATE_SUB`
+    DateAdd {
+        expr: Box<Expr>,
+        interval: Box<Expr>,
+        unit: TimestampField,
+        subtract: bool,
+    },
+
     /// Generic function call expression
     Call {
         name: SqlIdentifier,
@@ -73,7 +142,7 @@ pub enum FunctionExpr {
 
 impl FunctionExpr {
     /// Returns an iterator over all the direct arguments passed to the given function call
-    /// expression
+    /// expression, including the expressions in the `ORDER BY` clause of an aggregate
     #[concrete_iter]
     pub fn arguments<'a>(&'a self) -> impl Iterator<Item = &'a Expr> {
         match self {
@@ -82,9 +151,29 @@ impl FunctionExpr {
             | FunctionExpr::Sum { expr: arg, .. }
             | FunctionExpr::Max(arg)
             | FunctionExpr::Min(arg)
-            | FunctionExpr::GroupConcat { expr: arg, .. } => {
+            | FunctionExpr::GroupConcat { expr: arg, .. }
+            | FunctionExpr::StdDev { expr: arg, .. }
+            | FunctionExpr::Variance { expr: arg, .. }
+            | FunctionExpr::BoolAnd(arg)
+            | FunctionExpr::BoolOr(arg)
+            | FunctionExpr::BitAnd(arg)
+            | FunctionExpr::BitOr(arg)
+            | FunctionExpr::BitXor(arg)
+            | FunctionExpr::ApproxCountDistinct(arg)
+            | FunctionExpr::ApproxPercentile { expr: arg, .. } => {
                 concrete_iter!(iter::once(arg.as_ref()))
             }
+            FunctionExpr::ArrayAgg { expr, order_by }
+            | FunctionExpr::JsonAgg { expr, order_by } => {
+                concrete_iter!(iter::once(expr.as_ref()).chain(order_by.iter().map(|(e, _)| e)))
+            }
+            FunctionExpr::JsonObjectAgg {
+                key,
+                value,
+                order_by,
+            } => concrete_iter!(iter::once(key.as_ref())
+                .chain(iter::once(value.as_ref()))
+                .chain(order_by.iter().map(|(e, _)| e))),
             FunctionExpr::CountStar => concrete_iter!(iter::empty()),
             FunctionExpr::Call { arguments, .. } => concrete_iter!(arguments),
             FunctionExpr::Substring { string, pos, len } => {
@@ -92,10 +181,35 @@ impl FunctionExpr {
                     .chain(pos.iter().map(|p| p.as_ref()))
                     .chain(len.iter().map(|p| p.as_ref())))
             }
+            FunctionExpr::Extract { expr, .. } => concrete_iter!(iter::once(expr.as_ref())),
+            FunctionExpr::DateAdd { expr, interval, .. } => {
+                concrete_iter!(iter::once(expr.as_ref()).chain(iter::once(interval.as_ref())))
+            }
         }
     }
 }
 
+/// Display the `ORDER BY` clause within the arguments of an aggregate function, including the
+/// leading space, or nothing if `order_by` is empty
+fn display_aggregate_order_by(
+    order_by: &[(Expr, OrderType)],
+    dialect: Dialect,
+) -> impl fmt::Display + Copy + '_ {
+    fmt_with(move |f| {
+        if !order_by.is_empty() {
+            write!(
+                f,
+                " ORDER BY {}",
+                order_by
+                    .iter()
+                    .map(|(e, ot)| format!("{} {}", e.display(dialect), ot))
+                    .join(", ")
+            )?;
+        }
+        Ok(())
+    })
+}
+
 impl FunctionExpr {
     pub fn display(&self, dialect: Dialect) -> impl fmt::Display + Copy + '_ {
         fmt_with(move |f| match self {
@@ -125,6 +239,59 @@ impl FunctionExpr {
                     separator
                 )
             }
+            FunctionExpr::StdDev { expr, sample: true } => {
+                write!(f, "stddev_samp({})", expr.display(dialect))
+            }
+            FunctionExpr::StdDev { expr, .. } => write!(f, "stddev_pop({})", expr.display(dialect)),
+            FunctionExpr::Variance { expr, sample: true } => {
+                write!(f, "var_samp({})", expr.display(dialect))
+            }
+            FunctionExpr::Variance { expr, .. } => write!(f, "var_pop({})", expr.display(dialect)),
+            FunctionExpr::BoolAnd(expr) => write!(f, "bool_and({})", expr.display(dialect)),
+            FunctionExpr::BoolOr(expr) => write!(f, "bool_or({})", expr.display(dialect)),
+            FunctionExpr::BitAnd(expr) => write!(f, "bit_and({})", expr.display(dialect)),
+            FunctionExpr::BitOr(expr) => write!(f, "bit_or({})", expr.display(dialect)),
+            FunctionExpr::BitXor(expr) => write!(f, "bit_xor({})", expr.display(dialect)),
+            FunctionExpr::ApproxCountDistinct(expr) => {
+                write!(f, "approx_count_distinct({})", expr.display(dialect))
+            }
+            FunctionExpr::ApproxPercentile { expr, percentile } => write!(
+                f,
+                "approx_percentile({}, {})",
+                expr.display(dialect),
+                percentile
+            ),
+            FunctionExpr::ArrayAgg { expr, order_by } => write!(
+                f,
+                "array_agg({}{})",
+                expr.display(dialect),
+                display_aggregate_order_by(order_by, dialect)
+            ),
+            FunctionExpr::JsonAgg { expr, order_by } => write!(
+                f,
+                "{}({}{})",
+                match dialect {
+                    Dialect::PostgreSQL => "json_agg",
+                    Dialect::MySQL => "json_arrayagg",
+                },
+                expr.display(dialect),
+                display_aggregate_order_by(order_by, dialect)
+            ),
+            FunctionExpr::JsonObjectAgg {
+                key,
+                value,
+                order_by,
+            } => write!(
+                f,
+                "{}({}, {}{})",
+                match dialect {
+                    Dialect::PostgreSQL => "json_object_agg",
+                    Dialect::MySQL => "json_objectagg",
+                },
+                key.display(dialect),
+                value.display(dialect),
+                display_aggregate_order_by(order_by, dialect)
+            ),
             FunctionExpr::Call { name, arguments } => {
                 write!(
                     f,
@@ -146,10 +313,124 @@ impl FunctionExpr {
 
                 write!(f, ")")
             }
+            FunctionExpr::Extract { field, expr } => {
+                write!(f, "extract({} from {})", field, expr.display(dialect))
+            }
+            FunctionExpr::DateAdd {
+                expr,
+                interval,
+                unit,
+                subtract,
+            } => write!(
+                f,
+                "{}({}, INTERVAL {} {})",
+                if *subtract { "date_sub" } else { "date_add" },
+                expr.display(dialect),
+                interval.display(dialect),
+                unit
+            ),
+        })
+    }
+}
+
+/// A field of a date or time value, such as the `YEAR` in `EXTRACT(YEAR FROM x)` or the `DAY` in
+/// `DATE_ADD(x, INTERVAL 1 DAY)`.
+///
+/// Not every field is supported by every dialect - `DOW`, `EPOCH` and friends only exist in
+/// PostgreSQL, for example - so it's up to the consumer to reject the ones that don't make sense.
+#[derive(
+    Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Serialize, Deserialize, Arbitrary,
+)]
+pub enum TimestampField {
+    Century,
+    Day,
+    Decade,
+    /// The day of the week, from 0 (Sunday) to 6 (Saturday)
+    Dow,
+    /// The day of the year, from 1 to 366
+    Doy,
+    /// The number of seconds since `1970-01-01 00:00:00 UTC`
+    Epoch,
+    Hour,
+    /// The day of the week, from 1 (Monday) to 7 (Sunday)
+    IsoDow,
+    /// The ISO 8601 week-numbering year
+    IsoYear,
+    Microsecond,
+    Millennium,
+    Millisecond,
+    Minute,
+    Month,
+    Quarter,
+    Second,
+    Week,
+    Year,
+}
+
+impl Display for TimestampField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Century => "CENTURY",
+            Self::Day => "DAY",
+            Self::Decade => "DECADE",
+            Self::Dow => "DOW",
+            Self::Doy => "DOY",
+            Self::Epoch => "EPOCH",
+            Self::Hour => "HOUR",
+            Self::IsoDow => "ISODOW",
+            Self::IsoYear => "ISOYEAR",
+            Self::Microsecond => "MICROSECOND",
+            Self::Millennium => "MILLENNIUM",
+            Self::Millisecond => "MILLISECOND",
+            Self::Minute => "MINUTE",
+            Self::Month => "MONTH",
+            Self::Quarter => "QUARTER",
+            Self::Second => "SECOND",
+            Self::Week => "WEEK",
+            Self::Year => "YEAR",
         })
     }
 }
 
+impl FromStr for TimestampField {
+    type Err = &'static str;
+
+    /// Parses the name of a field case-insensitively, accepting both singular and plural forms
+    /// where PostgreSQL does
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "century" | "centuries" => Ok(Self::Century),
+            "day" | "days" => Ok(Self::Day),
+            "decade" | "decades" => Ok(Self::Decade),
+            "dow" => Ok(Self::Dow),
+            "doy" => Ok(Self::Doy),
+            "epoch" => Ok(Self::Epoch),
+            "hour" | "hours" => Ok(Self::Hour),
+            "isodow" => Ok(Self::IsoDow),
+            "isoyear" => Ok(Self::IsoYear),
+            "microsecond" | "microseconds" => Ok(Self::Microsecond),
+            "millennium" | "millennia" => Ok(Self::Millennium),
+            "millisecond" | "milliseconds" => Ok(Self::Millisecond),
+            "minute" | "minutes" => Ok(Self::Minute),
+            "month" | "months" => Ok(Self::Month),
+            "quarter" => Ok(Self::Quarter),
+            "second" | "seconds" => Ok(Self::Second),
+            "week" | "weeks" => Ok(Self::Week),
+            "year" | "years" => Ok(Self::Year),
+            _ => Err("unknown date or time field"),
+        }
+    }
+}
+
+/// Parses the name of a [`TimestampField`]
+pub(crate) fn timestamp_field(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], TimestampField> {
+    map_res(take_while1(|c: u8| c.is_ascii_alphabetic()), |s| {
+        str::from_utf8(&s)
+            .map_err(|_| "invalid utf-8")
+            .and_then(TimestampField::from_str)
+    })(i)
+}
+
 /// Binary infix operators with [`Expr`] on both the left- and right-hand sides
 ///
 /// This type is used as the operator in [`Expr::BinaryOp`].
@@ -204,6 +485,36 @@ pub enum BinaryOperator {
     /// `/`
     Divide,
 
+    /// `%` or `MOD`
+    Modulo,
+
+    /// `DIV`
+    ///
+    /// MySQL-specific integer division operator, which discards any fractional part of the result
+    IntegerDivide,
+
+    /// `&`
+    BitwiseAnd,
+
+    /// `|`
+    BitwiseOr,
+
+    /// `^`
+    ///
+    /// This represents bitwise XOR in MySQL, but exponentiation in Postgres.
+    Caret,
+
+    /// `#`
+    ///
+    /// Postgres-specific bitwise XOR operator.
+    Hash,
+
+    /// `<<`
+    ShiftLeft,
+
+    /// `>>`
+    ShiftRight,
+
     /// `?`
     ///
     /// Postgres-specific JSONB operator. Looks for the given string as an object key or an array
@@ -310,6 +621,14 @@ impl Display for BinaryOperator {
             Self::HashSubtract => "#-",
             Self::Multiply => "*",
             Self::Divide => "/",
+            Self::Modulo => "%",
+            Self::IntegerDivide => "DIV",
+            Self::BitwiseAnd => "&",
+            Self::BitwiseOr => "|",
+            Self::Caret => "^",
+            Self::Hash => "#",
+            Self::ShiftLeft => "<<",
+            Self::ShiftRight => ">>",
             Self::QuestionMark => "?",
             Self::QuestionMarkPipe => "?|",
             Self::QuestionMarkAnd => "?&",
@@ -329,6 +648,8 @@ impl Display for BinaryOperator {
 pub enum UnaryOperator {
     Neg,
     Not,
+    /// `~`
+    BitwiseNot,
 }
 
 impl Display for UnaryOperator {
@@ -336,6 +657,7 @@ impl Display for UnaryOperator {
         match self {
             UnaryOperator::Neg => write!(f, "-"),
             UnaryOperator::Not => write!(f, "NOT"),
+            UnaryOperator::BitwiseNot => write!(f, "~"),
         }
     }
 }
@@ -475,8 +797,24 @@ pub enum Expr {
     /// `ARRAY[expr1, expr2, ...]`
     Array(Vec<Expr>),
 
+    /// An interval literal, either MySQL's `INTERVAL expr unit` or PostgreSQL's
+    /// `INTERVAL 'value' [unit]`
+    #[from(ignore)]
+    Interval {
+        value: Box<Expr>,
+        unit: Option<TimestampField>,
+    },
+
     /// A variable reference
     Variable(Variable),
+
+    /// A window function call, eg `ROW_NUMBER() OVER (PARTITION BY x ORDER BY y DESC)`
+    #[from(ignore)]
+    WindowFunction {
+        function: FunctionExpr,
+        partition_by: Vec<Expr>,
+        order_by: Vec<(Expr, OrderType)>,
+    },
 }
 
 impl Expr {
@@ -523,9 +861,9 @@ impl Expr {
                 rhs.display(dialect)
             ),
             Expr::UnaryOp {
-                op: UnaryOperator::Neg,
+                op: op @ (UnaryOperator::Neg | UnaryOperator::BitwiseNot),
                 rhs,
-            } => write!(f, "(-{})", rhs.display(dialect)),
+            } => write!(f, "({op}{})", rhs.display(dialect)),
             Expr::UnaryOp { op, rhs } => write!(f, "({op} {})", rhs.display(dialect)),
             Expr::Exists(statement) => write!(f, "EXISTS ({})", statement.display(dialect)),
 
@@ -595,7 +933,42 @@ impl Expr {
                 }
                 write!(f, "]")
             }
+            Expr::Interval { value, unit } => {
+                write!(f, "INTERVAL {}", value.display(dialect))?;
+                if let Some(unit) = unit {
+                    write!(f, " {}", unit)?;
+                }
+                Ok(())
+            }
             Expr::Variable(var) => write!(f, "{}", var),
+            Expr::WindowFunction {
+                function,
+                partition_by,
+                order_by,
+            } => {
+                write!(f, "{} OVER (", function.display(dialect))?;
+                if !partition_by.is_empty() {
+                    write!(
+                        f,
+                        "PARTITION BY {}",
+                        partition_by.iter().map(|e| e.display(dialect)).join(", ")
+                    )?;
+                    if !order_by.is_empty() {
+                        write!(f, " ")?;
+                    }
+                }
+                if !order_by.is_empty() {
+                    write!(
+                        f,
+                        "ORDER BY {}",
+                        order_by
+                            .iter()
+                            .map(|(e, ot)| format!("{} {}", e.display(dialect), ot))
+                            .join(", ")
+                    )?;
+                }
+                write!(f, ")")
+            }
         })
     }
 }
@@ -711,6 +1084,9 @@ fn binary_operator_no_and_or(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], Binar
             Ok((i, BinaryOperator::IsNot))
         },
         map(pair(tag_no_case("is"), whitespace1), |_| BinaryOperator::Is),
+        // These need to come before `<` and `>`
+        map(tag("<<"), |_| BinaryOperator::ShiftLeft),
+        map(tag(">>"), |_| BinaryOperator::ShiftRight),
         // Sigils are separated due to `alt` limit.
         //
         // NOTE: The order here matters or else some of these will be incorrectly partially parsed,
@@ -739,6 +1115,18 @@ fn binary_operator_no_and_or(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], Binar
             map(tag("#>"), |_| BinaryOperator::HashArrow1),
         )),
         map(tag("#-"), |_| BinaryOperator::HashSubtract),
+        // Must come after all other operators starting with `#` or `|`
+        map(char('#'), |_| BinaryOperator::Hash),
+        map(char('|'), |_| BinaryOperator::BitwiseOr),
+        map(char('&'), |_| BinaryOperator::BitwiseAnd),
+        map(char('^'), |_| BinaryOperator::Caret),
+        map(char('%'), |_| BinaryOperator::Modulo),
+        map(terminated(tag_no_case("mod"), whitespace1), |_| {
+            BinaryOperator::Modulo
+        }),
+        map(terminated(tag_no_case("div"), whitespace1), |_| {
+            BinaryOperator::IntegerDivide
+        }),
     ))(i)
 }
 
@@ -766,6 +1154,7 @@ fn prefix(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], TokenTree> {
     map(
         alt((
             map(complete(char('-')), |_| UnaryOperator::Neg),
+            map(complete(char('~')), |_| UnaryOperator::BitwiseNot),
             map(terminated(tag_no_case("not"), whitespace1), |_| {
                 UnaryOperator::Not
             }),
@@ -914,8 +1303,11 @@ fn token_tree_no_and_or(
 /// A [`pratt`] operator-precedence parser for [`Expr`]s.
 ///
 /// This type exists only to hold the implementation of the [`PrattParser`] trait for operator
-/// precedence of expressions, and otherwise contains no data
-struct ExprParser;
+/// precedence of expressions, and otherwise only contains the dialect whose precedence rules to
+/// use
+struct ExprParser {
+    dialect: Dialect,
+}
 
 impl<I> PrattParser<I> for ExprParser
 where
@@ -952,12 +1344,33 @@ where
             Infix(LessOrEqual) => Affix::Infix(Precedence(7), Associativity::Right),
             Infix(Is) => Affix::Infix(Precedence(7), Associativity::Right),
             Infix(IsNot) => Affix::Infix(Precedence(7), Associativity::Right),
-            Infix(Add) => Affix::Infix(Precedence(11), Associativity::Right),
-            Infix(Subtract) => Affix::Infix(Precedence(11), Associativity::Right),
-            Infix(Multiply) => Affix::Infix(Precedence(12), Associativity::Right),
-            Infix(Divide) => Affix::Infix(Precedence(12), Associativity::Right),
+            // PostgreSQL gives the bitwise operators the same precedence as all other
+            // non-arithmetic operators (such as the JSON operators below), whereas in MySQL each
+            // has its own precedence level.
+            //
+            // https://www.postgresql.org/docs/current/sql-syntax-lexical.html#SQL-PRECEDENCE
+            Infix(BitwiseOr | Hash | BitwiseAnd | ShiftLeft | ShiftRight)
+                if self.dialect == Dialect::PostgreSQL =>
+            {
+                Affix::Infix(Precedence(8), Associativity::Left)
+            }
+            Infix(BitwiseOr) => Affix::Infix(Precedence(9), Associativity::Left),
+            Infix(Hash) => Affix::Infix(Precedence(9), Associativity::Left),
+            Infix(BitwiseAnd) => Affix::Infix(Precedence(10), Associativity::Left),
+            Infix(ShiftLeft) => Affix::Infix(Precedence(11), Associativity::Left),
+            Infix(ShiftRight) => Affix::Infix(Precedence(11), Associativity::Left),
+            Infix(Add) => Affix::Infix(Precedence(12), Associativity::Right),
+            Infix(Subtract) => Affix::Infix(Precedence(12), Associativity::Right),
+            Infix(Multiply) => Affix::Infix(Precedence(13), Associativity::Right),
+            Infix(Divide) => Affix::Infix(Precedence(13), Associativity::Right),
+            Infix(Modulo) => Affix::Infix(Precedence(13), Associativity::Right),
+            Infix(IntegerDivide) => Affix::Infix(Precedence(13), Associativity::Right),
+            // `^` binds more tightly than multiplication both as MySQL's bitwise XOR and as
+            // PostgreSQL's exponentiation operator
+            Infix(Caret) => Affix::Infix(Precedence(14), Associativity::Left),
             Prefix(Not) => Affix::Prefix(Precedence(6)),
             Prefix(Neg) => Affix::Prefix(Precedence(5)),
+            Prefix(BitwiseNot) => Affix::Prefix(Precedence(15)),
             Primary(_) => Affix::Nilfix,
             Group(_) => Affix::Nilfix,
             PgsqlCast(..) => Affix::Nilfix,
@@ -1114,7 +1527,7 @@ fn between_max(dialect: Dialect) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<
     move |i| {
         alt((
             map(token_tree_no_and_or(dialect), |tt| {
-                ExprParser.parse(&mut tt.into_iter()).unwrap()
+                ExprParser { dialect }.parse(&mut tt.into_iter()).unwrap()
             }),
             simple_expr(dialect),
         ))(i)
@@ -1311,6 +1724,94 @@ fn array_expr(dialect: Dialect) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&
     }
 }
 
+/// Parses an interval literal. In MySQL this is `INTERVAL expr unit`, where the unit is required
+/// and the value can be any expression; in PostgreSQL it's `INTERVAL 'value' [unit]`, where the
+/// value must be a string literal.
+fn interval_expr(dialect: Dialect) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], Expr> {
+    move |i| {
+        let (i, _) = tag_no_case("interval")(i)?;
+        let (i, _) = whitespace1(i)?;
+        let (i, (value, unit)) = match dialect {
+            Dialect::MySQL => {
+                let (i, value) = expression(dialect)(i)?;
+                let (i, _) = whitespace1(i)?;
+                let (i, unit) = timestamp_field(i)?;
+                (i, (value, Some(unit)))
+            }
+            Dialect::PostgreSQL => {
+                let (i, value) = dialect.utf8_string_literal()(i)?;
+                let (i, unit) = opt(preceded(whitespace1, timestamp_field))(i)?;
+                (i, (Expr::Literal(Literal::String(value)), unit))
+            }
+        };
+
+        Ok((
+            i,
+            Expr::Interval {
+                value: Box::new(value),
+                unit,
+            },
+        ))
+    }
+}
+
+pub(crate) fn window_order_field(
+    dialect: Dialect,
+) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], (Expr, OrderType)> {
+    move |i| {
+        let (i, expr) = expression(dialect)(i)?;
+        let (i, ord) = opt(preceded(whitespace1, order_type))(i)?;
+        Ok((i, (expr, ord.unwrap_or(OrderType::OrderAscending))))
+    }
+}
+
+fn window_function_expr(
+    dialect: Dialect,
+) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], Expr> {
+    move |i| {
+        let (i, function) = function_expr(dialect)(i)?;
+        let (i, _) = whitespace1(i)?;
+        let (i, _) = tag_no_case("over")(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, _) = char('(')(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, partition_by) = opt(terminated(
+            preceded(
+                tuple((
+                    tag_no_case("partition"),
+                    whitespace1,
+                    tag_no_case("by"),
+                    whitespace1,
+                )),
+                separated_list1(ws_sep_comma, expression(dialect)),
+            ),
+            whitespace0,
+        ))(i)?;
+        let (i, order_by) = opt(terminated(
+            preceded(
+                tuple((
+                    tag_no_case("order"),
+                    whitespace1,
+                    tag_no_case("by"),
+                    whitespace1,
+                )),
+                separated_list1(ws_sep_comma, window_order_field(dialect)),
+            ),
+            whitespace0,
+        ))(i)?;
+        let (i, _) = char(')')(i)?;
+
+        Ok((
+            i,
+            Expr::WindowFunction {
+                function,
+                partition_by: partition_by.unwrap_or_default(),
+                order_by: order_by.unwrap_or_default(),
+            },
+        ))
+    }
+}
+
 // Expressions without (binary or unary) operators
 pub(crate) fn simple_expr(
     dialect: Dialect,
@@ -1322,10 +1823,12 @@ pub(crate) fn simple_expr(
             exists_expr(dialect),
             between_expr(dialect),
             in_expr(dialect),
+            window_function_expr(dialect),
             map(function_expr(dialect), Expr::Call),
             map(literal(dialect), Expr::Literal),
             case_when_expr(dialect),
             array_expr(dialect),
+            interval_expr(dialect),
             map(column_identifier_no_alias(dialect), Expr::Column),
             cast(dialect),
             map(scoped_var(dialect), Expr::Variable),
@@ -1339,7 +1842,7 @@ pub(crate) fn expression(
     move |i| {
         alt((
             map(token_tree(dialect), |tt| {
-                ExprParser.parse(&mut tt.into_iter()).unwrap()
+                ExprParser { dialect }.parse(&mut tt.into_iter()).unwrap()
             }),
             simple_expr(dialect),
         ))(i)
@@ -1563,6 +2066,79 @@ mod tests {
             );
         }
 
+        #[test]
+        fn modulo_and_integer_division() {
+            for (cond, op) in [
+                ("x % 3", BinaryOperator::Modulo),
+                ("x MOD 3", BinaryOperator::Modulo),
+                ("x div 3", BinaryOperator::IntegerDivide),
+            ] {
+                let res = expression(Dialect::MySQL)(LocatedSpan::new(cond.as_bytes()));
+                assert_eq!(res.unwrap().1, x_operator_value(op, 3_u32.into()), "{cond}");
+            }
+        }
+
+        #[test]
+        fn modulo_precedence() {
+            let res = test_parse!(expression(Dialect::MySQL), b"x % 3 = 1");
+            assert_eq!(
+                res,
+                Expr::BinaryOp {
+                    op: BinaryOperator::Equal,
+                    lhs: Box::new(x_operator_value(BinaryOperator::Modulo, 3_u32.into())),
+                    rhs: Box::new(Expr::Literal(1_u32.into()))
+                }
+            );
+            assert_eq!(res.display(Dialect::MySQL).to_string(), "((`x` % 3) = 1)");
+        }
+
+        #[test]
+        fn bitwise_operators() {
+            let res = test_parse!(expression(Dialect::MySQL), b"x & 4 = 4");
+            assert_eq!(
+                res,
+                Expr::BinaryOp {
+                    op: BinaryOperator::Equal,
+                    lhs: Box::new(x_operator_value(BinaryOperator::BitwiseAnd, 4_u32.into())),
+                    rhs: Box::new(Expr::Literal(4_u32.into()))
+                }
+            );
+
+            for (input, expected) in [
+                ("x | y & z", "(`x` | (`y` & `z`))"),
+                ("x & y | z", "((`x` & `y`) | `z`)"),
+                ("1 << 2 + 1", "(1 << (2 + 1))"),
+                ("x << 1 >> 2", "((`x` << 1) >> 2)"),
+                ("x ^ y * 2", "((`x` ^ `y`) * 2)"),
+                ("~x & y", "((~`x`) & `y`)"),
+                ("x || y", "(`x` || `y`)"),
+                ("x < y", "(`x` < `y`)"),
+            ] {
+                let res = test_parse!(expression(Dialect::MySQL), input.as_bytes());
+                assert_eq!(res.display(Dialect::MySQL).to_string(), expected, "{input}");
+            }
+
+            // In PostgreSQL, all the bitwise operators have the same precedence
+            for (input, expected) in [
+                ("1 | 2 & 0", "((1 | 2) & 0)"),
+                ("x & y | z", "((\"x\" & \"y\") | \"z\")"),
+                ("x | y << 1", "((\"x\" | \"y\") << 1)"),
+                ("x # y & z", "((\"x\" # \"y\") & \"z\")"),
+                ("x + 1 | y", "((\"x\" + 1) | \"y\")"),
+                ("x | y = z", "((\"x\" | \"y\") = \"z\")"),
+                ("x # y", "(\"x\" # \"y\")"),
+                ("x #> y", "(\"x\" #> \"y\")"),
+                ("x #- y", "(\"x\" #- \"y\")"),
+            ] {
+                let res = test_parse!(expression(Dialect::PostgreSQL), input.as_bytes());
+                assert_eq!(
+                    res.display(Dialect::PostgreSQL).to_string(),
+                    expected,
+                    "{input}"
+                );
+            }
+        }
+
         #[test]
         fn expression_with_arithmetics() {
             let cond = "x * 3 = 21";
@@ -2029,6 +2605,66 @@ mod tests {
         }
     }
 
+    mod window_function {
+        use super::*;
+
+        #[test]
+        fn row_number_partition_and_order() {
+            let qs = b"row_number() over (partition by x, y order by z desc)";
+            let expected = Expr::WindowFunction {
+                function: FunctionExpr::Call {
+                    name: "row_number".into(),
+                    arguments: vec![],
+                },
+                partition_by: vec![Expr::Column("x".into()), Expr::Column("y".into())],
+                order_by: vec![(Expr::Column("z".into()), OrderType::OrderDescending)],
+            };
+            let (remaining, result) =
+                to_nom_result(expression(Dialect::MySQL)(LocatedSpan::new(qs))).unwrap();
+            assert_eq!(std::str::from_utf8(remaining).unwrap(), "");
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn running_sum_order_only() {
+            let qs = b"SUM(x) OVER (ORDER BY y)";
+            let expected = Expr::WindowFunction {
+                function: FunctionExpr::Sum {
+                    expr: Box::new(Expr::Column("x".into())),
+                    distinct: false,
+                },
+                partition_by: vec![],
+                order_by: vec![(Expr::Column("y".into()), OrderType::OrderAscending)],
+            };
+            let (remaining, result) =
+                to_nom_result(expression(Dialect::PostgreSQL)(LocatedSpan::new(qs))).unwrap();
+            assert_eq!(std::str::from_utf8(remaining).unwrap(), "");
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn empty_window() {
+            let qs = b"count(*) over ()";
+            let expected = Expr::WindowFunction {
+                function: FunctionExpr::CountStar,
+                partition_by: vec![],
+                order_by: vec![],
+            };
+            let (remaining, result) =
+                to_nom_result(expression(Dialect::MySQL)(LocatedSpan::new(qs))).unwrap();
+            assert_eq!(std::str::from_utf8(remaining).unwrap(), "");
+            assert_eq!(result, expected);
+        }
+
+        #[test]
+        fn display_round_trip() {
+            let qs = "rank() OVER (PARTITION BY `x` ORDER BY `y` ASC)";
+            let (_, expr) =
+                to_nom_result(expression(Dialect::MySQL)(LocatedSpan::new(qs.as_bytes()))).unwrap();
+            assert_eq!(expr.display(Dialect::MySQL).to_string(), qs);
+        }
+    }
+
     mod negation {
         use super::*;
         use crate::to_nom_result;
@@ -2127,6 +2763,35 @@ mod tests {
             );
         }
 
+        #[test]
+        fn interval() {
+            let res = test_parse!(expression(Dialect::MySQL), b"created_at + INTERVAL 2 day");
+            assert_eq!(
+                res,
+                Expr::BinaryOp {
+                    lhs: Box::new(Expr::Column("created_at".into())),
+                    op: BinaryOperator::Add,
+                    rhs: Box::new(Expr::Interval {
+                        value: Box::new(Expr::Literal(2_u32.into())),
+                        unit: Some(TimestampField::Day),
+                    }),
+                }
+            );
+            assert_eq!(
+                res.display(Dialect::MySQL).to_string(),
+                "(`created_at` + INTERVAL 2 DAY)"
+            );
+
+            let res = test_parse!(expression(Dialect::MySQL), b"interval n month");
+            assert_eq!(
+                res,
+                Expr::Interval {
+                    value: Box::new(Expr::Column("n".into())),
+                    unit: Some(TimestampField::Month),
+                }
+            );
+        }
+
         #[test]
         fn not_in_comparison() {
             let qs1 = b"id not in (1,2)";
@@ -2380,6 +3045,48 @@ mod tests {
             );
         }
 
+        #[test]
+        fn interval() {
+            let res = test_parse!(
+                expression(Dialect::PostgreSQL),
+                b"created_at - interval '1 day 02:00'"
+            );
+            assert_eq!(
+                res,
+                Expr::BinaryOp {
+                    lhs: Box::new(Expr::Column("created_at".into())),
+                    op: BinaryOperator::Subtract,
+                    rhs: Box::new(Expr::Interval {
+                        value: Box::new(Expr::Literal("1 day 02:00".into())),
+                        unit: None,
+                    }),
+                }
+            );
+            assert_eq!(
+                res.display(Dialect::PostgreSQL).to_string(),
+                "(\"created_at\" - INTERVAL '1 day 02:00')"
+            );
+
+            let res = test_parse!(expression(Dialect::PostgreSQL), b"INTERVAL '3' hours");
+            assert_eq!(
+                res,
+                Expr::Interval {
+                    value: Box::new(Expr::Literal("3".into())),
+                    unit: Some(TimestampField::Hour),
+                }
+            );
+            assert_eq!(
+                res.display(Dialect::PostgreSQL).to_string(),
+                "INTERVAL '3' HOUR"
+            );
+        }
+
+        #[test]
+        fn interval_as_column_name() {
+            let res = test_parse!(expression(Dialect::PostgreSQL), b"interval");
+            assert_eq!(res, Expr::Column("interval".into()));
+        }
+
         #[test]
         fn not_in_comparison() {
             let qs1 = b"id not in (1,2)";
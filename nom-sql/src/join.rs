@@ -49,6 +49,12 @@ pub enum JoinOperator {
     LeftOuterJoin,
     #[weight(0)]
     RightJoin,
+    #[weight(0)]
+    RightOuterJoin,
+    #[weight(0)]
+    FullJoin,
+    #[weight(0)]
+    FullOuterJoin,
     InnerJoin,
     #[weight(0)]
     CrossJoin,
@@ -69,6 +75,9 @@ impl fmt::Display for JoinOperator {
             JoinOperator::LeftJoin => write!(f, "LEFT JOIN")?,
             JoinOperator::LeftOuterJoin => write!(f, "LEFT OUTER JOIN")?,
             JoinOperator::RightJoin => write!(f, "RIGHT JOIN")?,
+            JoinOperator::RightOuterJoin => write!(f, "RIGHT OUTER JOIN")?,
+            JoinOperator::FullJoin => write!(f, "FULL JOIN")?,
+            JoinOperator::FullOuterJoin => write!(f, "FULL OUTER JOIN")?,
             JoinOperator::InnerJoin => write!(f, "INNER JOIN")?,
             JoinOperator::CrossJoin => write!(f, "CROSS JOIN")?,
             JoinOperator::StraightJoin => write!(f, "STRAIGHT JOIN")?,
@@ -107,6 +116,13 @@ pub fn join_operator(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], JoinOperator>
             JoinOperator::LeftOuterJoin
         }),
         map(tag_no_case("right join"), |_| JoinOperator::RightJoin),
+        map(tag_no_case("right outer join"), |_| {
+            JoinOperator::RightOuterJoin
+        }),
+        map(tag_no_case("full join"), |_| JoinOperator::FullJoin),
+        map(tag_no_case("full outer join"), |_| {
+            JoinOperator::FullOuterJoin
+        }),
         map(tag_no_case("inner join"), |_| JoinOperator::InnerJoin),
         map(tag_no_case("cross join"), |_| JoinOperator::CrossJoin),
         map(tag_no_case("straight_join"), |_| JoinOperator::StraightJoin),
@@ -152,6 +168,31 @@ mod tests {
             assert_eq!(q, expected_stmt);
             assert_eq!(expected, q.display(Dialect::MySQL).to_string());
         }
+
+        #[test]
+        fn outer_join_operators() {
+            for (input, op) in [
+                ("right join", JoinOperator::RightJoin),
+                ("RIGHT OUTER JOIN", JoinOperator::RightOuterJoin),
+                ("full join", JoinOperator::FullJoin),
+                ("FULL OUTER JOIN", JoinOperator::FullOuterJoin),
+            ] {
+                let res = join_operator(LocatedSpan::new(input.as_bytes()));
+                assert_eq!(res.unwrap().1, op);
+                assert_eq!(op.to_string(), input.to_uppercase());
+            }
+        }
+
+        #[test]
+        fn full_outer_join() {
+            let qstring = "SELECT * FROM a FULL OUTER JOIN b ON (a.x = b.y)";
+            let expected = "SELECT * FROM `a` FULL OUTER JOIN `b` ON (`a`.`x` = `b`.`y`)";
+
+            let res = selection(Dialect::MySQL)(LocatedSpan::new(qstring.as_bytes()));
+            let q = res.unwrap().1;
+            assert_eq!(q.join[0].operator, JoinOperator::FullOuterJoin);
+            assert_eq!(expected, q.display(Dialect::MySQL).to_string());
+        }
     }
 
     mod postgres {
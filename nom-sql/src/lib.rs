@@ -30,7 +30,7 @@ pub use self::drop::{
 };
 pub use self::explain::ExplainStatement;
 pub use self::expression::{
-    BinaryOperator, CaseWhenBranch, Expr, FunctionExpr, InValue, UnaryOperator,
+    BinaryOperator, CaseWhenBranch, Expr, FunctionExpr, InValue, TimestampField, UnaryOperator,
 };
 pub use self::insert::InsertStatement;
 pub use self::join::{JoinConstraint, JoinOperator, JoinRightSide};
@@ -38,7 +38,7 @@ pub use self::literal::{
     embedded_literal, literal, raw_string_literal, utf8_string_literal, Double, Float,
     ItemPlaceholder, Literal, QuotingStyle,
 };
-pub use self::order::{OrderClause, OrderType};
+pub use self::order::{NullOrder, OrderClause, OrderType};
 pub use self::parser::*;
 pub use self::select::{CommonTableExpr, GroupByClause, JoinClause, LimitClause, SelectStatement};
 pub use self::set::{
@@ -274,6 +274,11 @@ impl Literal {
             SqlType::Time => arbitrary_naive_time()
                 .prop_map(|nt| Self::String(nt.format("%H:%M:%S").to_string()))
                 .boxed(),
+            SqlType::Interval => (any::<i16>(), any::<i16>(), any::<i32>())
+                .prop_map(|(months, days, seconds)| {
+                    Self::String(format!("{months} mons {days} days {seconds} secs"))
+                })
+                .boxed(),
             SqlType::Enum(_) => unimplemented!("Enums aren't implemented yet"),
             SqlType::Json | SqlType::Jsonb => arbitrary_json()
                 .prop_map(|v| Self::String(v.to_string()))
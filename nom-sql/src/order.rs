@@ -45,9 +45,58 @@ impl fmt::Display for OrderType {
     }
 }
 
+/// Where NULL values are placed relative to non-NULL values when ordering, as specified by `NULLS
+/// FIRST` or `NULLS LAST`
+#[derive(
+    Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Serialize, Deserialize, Arbitrary,
+)]
+pub enum NullOrder {
+    NullsFirst,
+    NullsLast,
+}
+
+impl NullOrder {
+    /// Returns the placement of NULLs for the given [`OrderType`] if NULL is considered to be
+    /// smaller than every other value, as in MySQL (and in the ordering of `DfValue`).
+    pub fn default_for(order_type: OrderType) -> Self {
+        match order_type {
+            OrderType::OrderAscending => NullOrder::NullsFirst,
+            OrderType::OrderDescending => NullOrder::NullsLast,
+        }
+    }
+
+    /// Compare two values given only whether each of them is NULL, placing NULLs according to this
+    /// [`NullOrder`]. Returns [`None`] if neither value is NULL, in which case the values
+    /// themselves need to be compared.
+    #[inline(always)]
+    pub fn apply(&self, a_is_null: bool, b_is_null: bool) -> Option<Ordering> {
+        match (a_is_null, b_is_null) {
+            (false, false) => None,
+            (true, true) => Some(Ordering::Equal),
+            (true, false) => Some(match self {
+                NullOrder::NullsFirst => Ordering::Less,
+                NullOrder::NullsLast => Ordering::Greater,
+            }),
+            (false, true) => Some(match self {
+                NullOrder::NullsFirst => Ordering::Greater,
+                NullOrder::NullsLast => Ordering::Less,
+            }),
+        }
+    }
+}
+
+impl fmt::Display for NullOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            NullOrder::NullsFirst => write!(f, "NULLS FIRST"),
+            NullOrder::NullsLast => write!(f, "NULLS LAST"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Serialize, Deserialize)]
 pub struct OrderClause {
-    pub order_by: Vec<(FieldReference, Option<OrderType>)>,
+    pub order_by: Vec<(FieldReference, Option<OrderType>, Option<NullOrder>)>,
 }
 
 impl OrderClause {
@@ -58,13 +107,18 @@ impl OrderClause {
                 "ORDER BY {}",
                 self.order_by
                     .iter()
-                    .map(|(c, o)| format!(
-                        "{}{}",
+                    .map(|(c, o, n)| format!(
+                        "{}{}{}",
                         c.display(dialect),
                         if let Some(ot) = o {
                             format!(" {}", ot)
                         } else {
                             "".to_owned()
+                        },
+                        if let Some(no) = n {
+                            format!(" {}", no)
+                        } else {
+                            "".to_owned()
                         }
                     ))
                     .join(", ")
@@ -80,13 +134,26 @@ pub fn order_type(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], OrderType> {
     ))(i)
 }
 
+pub fn null_order(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], NullOrder> {
+    let (i, _) = tag_no_case("nulls")(i)?;
+    let (i, _) = whitespace1(i)?;
+    alt((
+        map(tag_no_case("first"), |_| NullOrder::NullsFirst),
+        map(tag_no_case("last"), |_| NullOrder::NullsLast),
+    ))(i)
+}
+
+#[allow(clippy::type_complexity)]
 fn order_field(
     dialect: Dialect,
-) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], (FieldReference, Option<OrderType>)> {
+) -> impl Fn(
+    LocatedSpan<&[u8]>,
+) -> NomSqlResult<&[u8], (FieldReference, Option<OrderType>, Option<NullOrder>)> {
     move |i| {
         let (i, field) = field_reference(dialect)(i)?;
         let (i, ord_typ) = opt(preceded(whitespace1, order_type))(i)?;
-        Ok((i, (field, ord_typ)))
+        let (i, null_ord) = opt(preceded(whitespace1, null_order))(i)?;
+        Ok((i, (field, ord_typ, null_ord)))
     }
 }
 
@@ -122,6 +189,7 @@ mod tests {
             order_by: vec![(
                 FieldReference::Expr(Expr::Column("name".into())),
                 Some(OrderType::OrderDescending),
+                None,
             )],
         };
         let expected_ord2 = OrderClause {
@@ -129,15 +197,21 @@ mod tests {
                 (
                     FieldReference::Expr(Expr::Column("name".into())),
                     Some(OrderType::OrderAscending),
+                    None,
                 ),
                 (
                     FieldReference::Expr(Expr::Column("age".into())),
                     Some(OrderType::OrderDescending),
+                    None,
                 ),
             ],
         };
         let expected_ord3 = OrderClause {
-            order_by: vec![(FieldReference::Expr(Expr::Column("name".into())), None)],
+            order_by: vec![(
+                FieldReference::Expr(Expr::Column("name".into())),
+                None,
+                None,
+            )],
         };
 
         let res1 = selection(Dialect::MySQL)(LocatedSpan::new(qstring1.as_bytes()));
@@ -148,6 +222,54 @@ mod tests {
         assert_eq!(res3.unwrap().1.order, Some(expected_ord3));
     }
 
+    #[test]
+    fn order_clause_null_order() {
+        let qstring = "select * from users order by name desc nulls last, age nulls first\n";
+        let expected = OrderClause {
+            order_by: vec![
+                (
+                    FieldReference::Expr(Expr::Column("name".into())),
+                    Some(OrderType::OrderDescending),
+                    Some(NullOrder::NullsLast),
+                ),
+                (
+                    FieldReference::Expr(Expr::Column("age".into())),
+                    None,
+                    Some(NullOrder::NullsFirst),
+                ),
+            ],
+        };
+
+        let res = selection(Dialect::PostgreSQL)(LocatedSpan::new(qstring.as_bytes()));
+        let order = res.unwrap().1.order.unwrap();
+        assert_eq!(order, expected);
+        assert_eq!(
+            order.display(Dialect::PostgreSQL).to_string(),
+            "ORDER BY \"name\" DESC NULLS LAST, \"age\" NULLS FIRST"
+        );
+    }
+
+    #[test]
+    fn null_order_apply() {
+        assert_eq!(NullOrder::NullsFirst.apply(false, false), None);
+        assert_eq!(
+            NullOrder::NullsFirst.apply(true, false),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            NullOrder::NullsLast.apply(true, false),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            NullOrder::NullsLast.apply(false, true),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            NullOrder::NullsLast.apply(true, true),
+            Some(Ordering::Equal)
+        );
+    }
+
     mod mysql {
         use super::*;
 
@@ -157,6 +279,7 @@ mod tests {
                 order_by: vec![(
                     FieldReference::Expr(Expr::Column("t.n".into())),
                     Some(OrderType::OrderDescending),
+                    None,
                 )],
             };
             assert_eq!(
@@ -175,6 +298,7 @@ mod tests {
                 order_by: vec![(
                     FieldReference::Expr(Expr::Column("t.n".into())),
                     Some(OrderType::OrderDescending),
+                    None,
                 )],
             };
             assert_eq!(
@@ -16,6 +16,7 @@ use crate::common::{
     field_definition_expr, field_list, field_reference_list, terminated_with_statement_terminator,
     ws_sep_comma, FieldDefinitionExpr,
 };
+use crate::compound_select::{compound_op, CompoundSelectOperator};
 use crate::expression::expression;
 use crate::join::{join_operator, JoinConstraint, JoinOperator, JoinRightSide};
 use crate::literal::literal;
@@ -72,6 +73,10 @@ impl JoinClause {
 pub struct CommonTableExpr {
     pub name: SqlIdentifier,
     pub statement: SelectStatement,
+    /// For a common table expression in a `WITH RECURSIVE` clause, the recursive term of the
+    /// body, along with the operator used to combine it with the non-recursive term in
+    /// `statement`
+    pub recursive_term: Option<(CompoundSelectOperator, Box<SelectStatement>)>,
 }
 
 impl CommonTableExpr {
@@ -79,10 +84,14 @@ impl CommonTableExpr {
         fmt_with(move |f| {
             write!(
                 f,
-                "{} AS ({})",
+                "{} AS ({}",
                 dialect.quote_identifier(&self.name),
                 self.statement.display(dialect)
-            )
+            )?;
+            if let Some((op, recursive_term)) = &self.recursive_term {
+                write!(f, " {} {}", op, recursive_term.display(dialect))?;
+            }
+            write!(f, ")")
         })
     }
 }
@@ -171,6 +180,9 @@ impl fmt::Display for LimitClause {
 pub struct SelectStatement {
     pub ctes: Vec<CommonTableExpr>,
     pub distinct: bool,
+    /// Expressions in the `DISTINCT ON (...)` clause (PostgreSQL only), or empty if the query has
+    /// no such clause. `distinct` is false for queries with a `DISTINCT ON` clause.
+    pub distinct_on: Vec<Expr>,
     pub fields: Vec<FieldDefinitionExpr>,
     pub tables: Vec<TableExpr>,
     pub join: Vec<JoinClause>,
@@ -194,6 +206,18 @@ impl SelectStatement {
                         | FunctionExpr::Max(_)
                         | FunctionExpr::Min(_)
                         | FunctionExpr::GroupConcat { .. }
+                        | FunctionExpr::StdDev { .. }
+                        | FunctionExpr::Variance { .. }
+                        | FunctionExpr::BoolAnd(_)
+                        | FunctionExpr::BoolOr(_)
+                        | FunctionExpr::BitAnd(_)
+                        | FunctionExpr::BitOr(_)
+                        | FunctionExpr::BitXor(_)
+                        | FunctionExpr::ApproxCountDistinct(_)
+                        | FunctionExpr::ApproxPercentile { .. }
+                        | FunctionExpr::ArrayAgg { .. }
+                        | FunctionExpr::JsonAgg { .. }
+                        | FunctionExpr::JsonObjectAgg { .. }
                 ),
                 Expr::NestedSelect(select) => select.contains_aggregate_select(),
                 _ => false,
@@ -207,9 +231,13 @@ impl SelectStatement {
     pub fn display(&self, dialect: Dialect) -> impl fmt::Display + Copy + '_ {
         fmt_with(move |f| {
             if !self.ctes.is_empty() {
+                write!(f, "WITH ")?;
+                if self.ctes.iter().any(|cte| cte.recursive_term.is_some()) {
+                    write!(f, "RECURSIVE ")?;
+                }
                 write!(
                     f,
-                    "WITH {} ",
+                    "{} ",
                     self.ctes.iter().map(|cte| cte.display(dialect)).join(", ")
                 )?;
             }
@@ -218,6 +246,16 @@ impl SelectStatement {
             if self.distinct {
                 write!(f, "DISTINCT ")?;
             }
+            if !self.distinct_on.is_empty() {
+                write!(
+                    f,
+                    "DISTINCT ON ({}) ",
+                    self.distinct_on
+                        .iter()
+                        .map(|expr| expr.display(dialect))
+                        .join(", ")
+                )?;
+            }
 
             write!(
                 f,
@@ -528,7 +566,10 @@ fn from_clause(dialect: Dialect) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<
     }
 }
 
-fn cte(dialect: Dialect) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], CommonTableExpr> {
+fn cte(
+    dialect: Dialect,
+    recursive: bool,
+) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], CommonTableExpr> {
     move |i| {
         let (i, name) = dialect.identifier()(i)?;
         let (i, _) = whitespace1(i)?;
@@ -538,10 +579,31 @@ fn cte(dialect: Dialect) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], C
         let (i, _) = tag("(")(i)?;
         let (i, _) = whitespace0(i)?;
         let (i, statement) = nested_selection(dialect)(i)?;
+        // Only CTEs in a `WITH RECURSIVE` clause can have a recursive term
+        let (i, recursive_term) = if recursive {
+            opt(map(
+                tuple((
+                    whitespace0,
+                    compound_op,
+                    whitespace1,
+                    nested_selection(dialect),
+                )),
+                |(_, op, _, recursive_term)| (op, Box::new(recursive_term)),
+            ))(i)?
+        } else {
+            (i, None)
+        };
         let (i, _) = whitespace0(i)?;
         let (i, _) = tag(")")(i)?;
 
-        Ok((i, CommonTableExpr { name, statement }))
+        Ok((
+            i,
+            CommonTableExpr {
+                name,
+                statement,
+                recursive_term,
+            },
+        ))
     }
 }
 
@@ -551,13 +613,37 @@ fn ctes(
     move |i| {
         let (i, _) = tag_no_case("with")(i)?;
         let (i, _) = whitespace1(i)?;
-        let (i, ctes) = separated_list1(ws_sep_comma, cte(dialect))(i)?;
+        let (i, ctes) = alt((
+            preceded(
+                terminated(tag_no_case("recursive"), whitespace1),
+                separated_list1(ws_sep_comma, cte(dialect, true)),
+            ),
+            separated_list1(ws_sep_comma, cte(dialect, false)),
+        ))(i)?;
         let (i, _) = whitespace0(i)?;
 
         Ok((i, ctes))
     }
 }
 
+/// Parses the `ON (...)` part of a PostgreSQL `DISTINCT ON` clause, following the `DISTINCT`
+fn distinct_on_clause(
+    dialect: Dialect,
+) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], Vec<Expr>> {
+    move |i| {
+        let (i, _) = whitespace1(i)?;
+        let (i, _) = tag_no_case("on")(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, _) = tag("(")(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, exprs) = separated_list1(ws_sep_comma, expression(dialect))(i)?;
+        let (i, _) = whitespace0(i)?;
+        let (i, _) = tag(")")(i)?;
+
+        Ok((i, exprs))
+    }
+}
+
 pub fn nested_selection(
     dialect: Dialect,
 ) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], SelectStatement> {
@@ -566,6 +652,11 @@ pub fn nested_selection(
         let (i, _) = tag_no_case("select")(i)?;
         let (i, _) = whitespace1(i)?;
         let (i, distinct) = opt(tag_no_case("distinct"))(i)?;
+        let (i, distinct_on) = if distinct.is_some() && dialect == Dialect::PostgreSQL {
+            opt(distinct_on_clause(dialect))(i)?
+        } else {
+            (i, None)
+        };
         let (i, _) = whitespace0(i)?;
         let (i, fields) = field_definition_expr(dialect)(i)?;
 
@@ -594,7 +685,8 @@ pub fn nested_selection(
 
         let mut result = SelectStatement {
             ctes: ctes.unwrap_or_default(),
-            distinct: distinct.is_some(),
+            distinct: distinct.is_some() && distinct_on.is_none(),
+            distinct_on: distinct_on.unwrap_or_default(),
             fields,
             ..Default::default()
         };
@@ -990,6 +1082,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn distinct_on() {
+        let qstring = "SELECT DISTINCT ON (user_id, topic) user_id, body FROM posts \
+                       ORDER BY user_id, topic, created_at DESC";
+        let res = test_parse!(selection(Dialect::PostgreSQL), qstring.as_bytes());
+        assert!(!res.distinct);
+        assert_eq!(
+            res.distinct_on,
+            vec![Expr::Column("user_id".into()), Expr::Column("topic".into())]
+        );
+        assert_eq!(res.fields, columns(&["user_id", "body"]));
+
+        let displayed = res.display(Dialect::PostgreSQL).to_string();
+        assert!(
+            displayed.starts_with(r#"SELECT DISTINCT ON ("user_id", "topic") "user_id", "body""#),
+            "{displayed}"
+        );
+        assert_eq!(
+            test_parse!(selection(Dialect::PostgreSQL), displayed.as_bytes()),
+            res
+        );
+    }
+
+    #[test]
+    fn distinct_column_starting_with_on() {
+        for dialect in [Dialect::MySQL, Dialect::PostgreSQL] {
+            let res = test_parse!(
+                selection(dialect),
+                "SELECT DISTINCT on_time FROM flights".as_bytes()
+            );
+            assert!(res.distinct);
+            assert!(res.distinct_on.is_empty());
+            assert_eq!(res.fields, columns(&["on_time"]));
+        }
+    }
+
     #[test]
     fn simple_condition_expr() {
         let qstring = "select infoJson from PaperStorage where paperId=? and paperStorageId=?;";
@@ -1354,6 +1482,7 @@ mod tests {
                 order: Some(OrderClause {
                     order_by: vec![(
                         FieldReference::Expr(Expr::Column("item.i_title".into())),
+                        None,
                         None
                     )],
                 }),
@@ -1406,7 +1535,11 @@ mod tests {
                 }),
             }],
             order: Some(OrderClause {
-                order_by: vec![(FieldReference::Expr(Expr::Column("contactId".into())), None)],
+                order_by: vec![(
+                    FieldReference::Expr(Expr::Column("contactId".into())),
+                    None,
+                    None,
+                )],
             }),
             ..Default::default()
         };
@@ -1771,6 +1904,45 @@ mod tests {
         assert_eq!(query.ctes[1].name, "min_val");
     }
 
+    #[test]
+    fn recursive_cte() {
+        let qstr = b"WITH RECURSIVE reports AS (
+              SELECT id, manager_id FROM employees WHERE id = 1
+              UNION ALL
+              SELECT employees.id, employees.manager_id FROM employees
+              JOIN reports ON employees.manager_id = reports.id
+            )
+            SELECT id FROM reports";
+        let res = selection(Dialect::MySQL)(LocatedSpan::new(qstr));
+        assert!(res.is_ok(), "error parsing query: {}", res.err().unwrap());
+        let (rem, query) = res.unwrap();
+        assert!(rem.is_empty());
+        assert_eq!(query.ctes.len(), 1);
+        let cte = &query.ctes[0];
+        assert_eq!(cte.name, "reports");
+        assert_eq!(
+            cte.statement.tables,
+            vec![TableExpr::from(Relation::from("employees"))]
+        );
+        let (op, recursive_term) = cte.recursive_term.as_ref().unwrap();
+        assert_eq!(*op, CompoundSelectOperator::Union);
+        assert_eq!(recursive_term.join.len(), 1);
+
+        let round_tripped = selection(Dialect::MySQL)(LocatedSpan::new(
+            query.display(Dialect::MySQL).to_string().as_bytes(),
+        ))
+        .unwrap()
+        .1;
+        assert_eq!(round_tripped, query);
+    }
+
+    #[test]
+    fn compound_cte_without_recursive() {
+        let qstr = b"WITH t AS (SELECT x FROM t1 UNION SELECT x FROM t2) SELECT x FROM t";
+        let res = selection(Dialect::MySQL)(LocatedSpan::new(qstr));
+        assert!(res.is_err());
+    }
+
     mod mysql {
         use super::*;
         use crate::column::Column;
@@ -1897,7 +2069,7 @@ mod tests {
             assert_eq!(
                 res.order,
                 Some(OrderClause {
-                    order_by: vec![(FieldReference::Numeric(1), None)]
+                    order_by: vec![(FieldReference::Numeric(1), None, None)]
                 })
             )
         }
@@ -1915,6 +2087,7 @@ mod tests {
                         tables: vec![TableExpr::from(Relation::from("t"))],
                         ..Default::default()
                     },
+                    recursive_term: None,
                 }],
                 fields: vec![FieldDefinitionExpr::Expr {
                     expr: Expr::Column("x".into()),
@@ -2089,6 +2262,7 @@ mod tests {
                         tables: vec![TableExpr::from(Relation::from("t"))],
                         ..Default::default()
                     },
+                    recursive_term: None,
                 }],
                 fields: vec![FieldDefinitionExpr::Expr {
                     expr: Expr::Column("x".into()),
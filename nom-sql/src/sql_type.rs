@@ -58,6 +58,7 @@ pub enum SqlType {
     Time,
     Timestamp,
     TimestampTz,
+    Interval,
     Binary(Option<u16>),
     VarBinary(u16),
     Enum(EnumVariants),
@@ -164,6 +165,7 @@ impl Arbitrary for SqlType {
                 Just(Serial).boxed(),
                 Just(BigSerial).boxed(),
                 Just(TimestampTz).boxed(),
+                Just(Interval).boxed(),
                 Just(Citext).boxed(),
                 Just(QuotedChar).boxed(),
             ]);
@@ -312,6 +314,7 @@ impl SqlType {
                 SqlType::Time => write!(f, "TIME"),
                 SqlType::Timestamp => write!(f, "TIMESTAMP"),
                 SqlType::TimestampTz => write!(f, "TIMESTAMP WITH TIME ZONE"),
+                SqlType::Interval => write!(f, "INTERVAL"),
                 SqlType::Binary(len) => write_with_len(f, "BINARY", len),
                 SqlType::VarBinary(len) => write!(f, "VARBINARY({})", len),
                 SqlType::Enum(ref variants) => {
@@ -564,6 +567,37 @@ fn opt_without_time_zone(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], ()> {
     )(i)
 }
 
+fn interval_field(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], LocatedSpan<&[u8]>> {
+    alt((
+        tag_no_case("year"),
+        tag_no_case("month"),
+        tag_no_case("day"),
+        tag_no_case("hour"),
+        tag_no_case("minute"),
+        tag_no_case("second"),
+    ))(i)
+}
+
+/// Parse the postgresql `INTERVAL [ fields ] [ (p) ]` type. Both the fields restriction and the
+/// precision are parsed and dropped, since we don't currently enforce either.
+fn interval_type(i: LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], SqlType> {
+    let (i, _) = tag_no_case("interval")(i)?;
+    let (i, _) = opt(preceded(
+        whitespace1,
+        tuple((
+            interval_field,
+            opt(tuple((
+                whitespace1,
+                tag_no_case("to"),
+                whitespace1,
+                interval_field,
+            ))),
+        )),
+    ))(i)?;
+    let (i, _) = opt(preceded(whitespace0, delim_digit))(i)?;
+    Ok((i, SqlType::Interval))
+}
+
 fn enum_type(dialect: Dialect) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], SqlType> {
     move |i| {
         let (i, _) = tag_no_case("enum")(i)?;
@@ -585,6 +619,8 @@ fn type_identifier_part1(
 ) -> impl Fn(LocatedSpan<&[u8]>) -> NomSqlResult<&[u8], SqlType> {
     move |i| {
         alt((
+            // Must come before `int`, which would otherwise match the prefix of `interval`
+            interval_type,
             |i| int_type("tinyint", SqlType::UnsignedTinyInt, SqlType::TinyInt, i),
             |i| int_type("smallint", SqlType::UnsignedSmallInt, SqlType::SmallInt, i),
             |i| int_type("integer", SqlType::UnsignedInt, SqlType::Int, i),
@@ -1099,6 +1135,24 @@ mod tests {
             assert_eq!(res, SqlType::TimestampTz);
         }
 
+        #[test]
+        fn interval_types() {
+            for input in [
+                &b"interval"[..],
+                b"INTERVAL(3)",
+                b"interval day",
+                b"interval day to second",
+                b"interval day to second(2)",
+            ] {
+                let res = test_parse!(type_identifier(Dialect::PostgreSQL), input);
+                assert_eq!(res, SqlType::Interval);
+            }
+            assert_eq!(
+                SqlType::Interval.display(Dialect::PostgreSQL).to_string(),
+                "INTERVAL"
+            );
+        }
+
         #[test]
         fn serial_type() {
             let res = test_parse!(type_identifier(Dialect::PostgreSQL), b"serial");
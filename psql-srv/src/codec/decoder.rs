@@ -8,7 +8,7 @@ use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
 use cidr::IpInet;
 use eui48::MacAddress;
 use postgres_types::{FromSql, Kind, Type};
-use readyset_data::{Array, Collation};
+use readyset_data::{Array, Collation, Interval};
 use rust_decimal::prelude::FromStr;
 use rust_decimal::Decimal;
 use tokio_util::codec::Decoder;
@@ -378,6 +378,7 @@ fn get_binary_value(src: &mut Bytes, t: &Type) -> Result<Value, Error> {
             Type::TEXT => Ok(Value::Text(<&str>::from_sql(t, buf)?.into())),
             Type::DATE => Ok(Value::Date(NaiveDate::from_sql(t, buf)?)),
             Type::TIME => Ok(Value::Time(NaiveTime::from_sql(t, buf)?)),
+            Type::INTERVAL => Ok(Value::Interval(Interval::from_sql(t, buf)?)),
             Type::TIMESTAMP => Ok(Value::Timestamp(NaiveDateTime::from_sql(t, buf)?)),
             Type::TIMESTAMPTZ => Ok(Value::TimestampTz(DateTime::<FixedOffset>::from_sql(
                 t, buf,
@@ -456,6 +457,10 @@ fn get_text_value(src: &mut Bytes, t: &Type) -> Result<Value, Error> {
             text_str,
             TIMESTAMP_TZ_FORMAT,
         )?)),
+        Type::INTERVAL => text_str
+            .parse::<Interval>()
+            .map_err(|e| DecodeError::InvalidTextIntervalValue(e.to_string()))
+            .map(Value::Interval),
         Type::BYTEA => {
             let bytes = hex::decode(text_str).map_err(InvalidTextByteArrayValue)?;
             Ok(Value::ByteArray(bytes))
@@ -1144,6 +1149,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decode_binary_interval() {
+        let mut buf = BytesMut::new();
+        buf.put_i32(16); // size
+        buf.put_i64(4_000_005); // microseconds
+        buf.put_i32(-3); // days
+        buf.put_i32(14); // months
+        match get_binary_value(&mut buf.freeze(), &Type::INTERVAL).unwrap() {
+            DataValue::Interval(interval) => {
+                assert_eq!(interval.months(), 14);
+                assert_eq!(interval.days(), -3);
+                assert_eq!(interval.microseconds(), 4_000_005);
+            }
+            v => panic!("Expected an interval, got {v:?}"),
+        }
+    }
+
     #[test]
     fn test_decode_binary_bytes() {
         let bytes = vec![0, 8, 39, 92, 100, 128];
@@ -1414,6 +1436,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_decode_text_interval() {
+        let mut buf = BytesMut::new();
+        buf.put_i32(26); // size
+        buf.extend_from_slice(b"1 year 2 mons -3 days 4.5s"); // value
+        match get_text_value(&mut buf.freeze(), &Type::INTERVAL).unwrap() {
+            DataValue::Interval(interval) => {
+                assert_eq!(interval.months(), 14);
+                assert_eq!(interval.days(), -3);
+                assert_eq!(interval.microseconds(), 4_500_000);
+            }
+            v => panic!("Expected an interval, got {v:?}"),
+        }
+
+        let mut buf = BytesMut::new();
+        buf.put_i32(10); // size
+        buf.extend_from_slice(b"1 eternity"); // value
+        get_text_value(&mut buf.freeze(), &Type::INTERVAL).unwrap_err();
+    }
+
     #[test]
     fn test_decode_text_bytes() {
         let mut buf = BytesMut::new();
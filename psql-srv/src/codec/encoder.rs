@@ -439,6 +439,9 @@ fn put_binary_value(val: Value, dst: &mut BytesMut) -> Result<(), Error> {
         Value::Time(v) => {
             v.to_sql(&Type::TIME, dst)?;
         }
+        Value::Interval(v) => {
+            v.to_sql(&Type::INTERVAL, dst)?;
+        }
         Value::ByteArray(b) => {
             b.to_sql(&Type::BYTEA, dst)?;
         }
@@ -547,6 +550,9 @@ fn put_text_value(val: Value, dst: &mut BytesMut) -> Result<(), Error> {
         Value::Time(v) => {
             write!(dst, "{}", v.format(TIME_FORMAT))?;
         }
+        Value::Interval(v) => {
+            write!(dst, "{}", v)?;
+        }
         Value::ByteArray(b) => {
             write!(
                 dst,
@@ -1262,6 +1268,19 @@ mod tests {
         assert_eq!(buf, exp);
     }
 
+    #[test]
+    fn test_encode_binary_interval() {
+        let interval = readyset_data::Interval::new(14, 3, 4_000_005);
+        let mut buf = BytesMut::new();
+        put_binary_value(DataValue::Interval(interval), &mut buf).unwrap();
+        let mut exp = BytesMut::new();
+        exp.put_i32(16); // length
+        exp.put_i64(4_000_005); // microseconds
+        exp.put_i32(3); // days
+        exp.put_i32(14); // months
+        assert_eq!(buf, exp);
+    }
+
     #[test]
     fn test_encode_binary_bytea() {
         let mut buf = BytesMut::new();
@@ -1508,6 +1527,20 @@ mod tests {
         assert_eq!(buf, exp);
     }
 
+    #[test]
+    fn test_encode_text_interval() {
+        let mut buf = BytesMut::new();
+        put_text_value(
+            DataValue::Interval(readyset_data::Interval::new(14, -3, 4_000_005)),
+            &mut buf,
+        )
+        .unwrap();
+        let mut exp = BytesMut::new();
+        exp.put_i32(38); // length
+        exp.extend_from_slice(b"1 year 2 mons -3 days +00:00:04.000005"); // value
+        assert_eq!(buf, exp);
+    }
+
     #[test]
     fn test_encode_text_bytea() {
         let mut buf = BytesMut::new();
@@ -58,6 +58,9 @@ pub enum DecodeError {
     #[error("invalid text bit vector value: {0}")]
     InvalidTextBitVectorValue(String),
 
+    #[error("invalid text interval value: {0}")]
+    InvalidTextIntervalValue(String),
+
     #[error("unknown enum variant: {0}")]
     UnknownEnumVariant(String),
 
@@ -2,7 +2,7 @@ use bit_vec::BitVec;
 use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
 use cidr::IpInet;
 use eui48::MacAddress;
-use readyset_data::{Array, Text};
+use readyset_data::{Array, Interval, Text};
 use rust_decimal::Decimal;
 use uuid::Uuid;
 
@@ -27,6 +27,7 @@ pub enum Value {
     TimestampTz(DateTime<FixedOffset>),
     Date(NaiveDate),
     Time(NaiveTime),
+    Interval(Interval),
     ByteArray(Vec<u8>),
     MacAddress(MacAddress),
     Inet(IpInet),
@@ -97,7 +97,7 @@ use proptest::strategy::{BoxedStrategy, Strategy};
 use rand::distributions::{Distribution, Standard};
 use rand::seq::SliceRandom;
 use rand::{thread_rng, Rng, RngCore};
-use readyset_data::{DfType, DfValue, Dialect};
+use readyset_data::{DfType, DfValue, Dialect, Interval};
 use readyset_sql_passes::outermost_table_exprs;
 use readyset_util::intervals::{BoundPair, IterBoundPair};
 use rust_decimal::Decimal;
@@ -163,6 +163,7 @@ pub fn value_of_type(typ: &SqlType) -> DfValue {
         ),
         SqlType::Time => NaiveTime::from_hms(12, 30, 45).into(),
         SqlType::Date => NaiveDate::from_ymd(2020, 1, 1).into(),
+        SqlType::Interval => Interval::new(1, 2, 3_000_000).into(),
         SqlType::Bool => 1i32.into(),
         SqlType::Enum(_) => unimplemented!(),
         SqlType::Json | SqlType::Jsonb => "{}".into(),
@@ -289,6 +290,11 @@ where
         SqlType::Inet => DfValue::from(
             IpAddr::V4(Ipv4Addr::new(rng.gen(), rng.gen(), rng.gen(), rng.gen())).to_string(),
         ),
+        SqlType::Interval => DfValue::from(Interval::new(
+            rng.gen_range(-1200..1200),
+            rng.gen_range(-365..365),
+            rng.gen(),
+        )),
         SqlType::Uuid => {
             let mut bytes = [0_u8, 16];
             rng.fill(&mut bytes);
@@ -397,6 +403,7 @@ pub fn unique_value_of_type(typ: &SqlType, idx: u32) -> DfValue {
         SqlType::Bool => unimplemented!(),
         SqlType::ByteArray => unimplemented!(),
         SqlType::Time => (NaiveTime::from_hms(0, 0, 0) + Duration::seconds(idx as _)).into(),
+        SqlType::Interval => Interval::new(0, idx as _, 0).into(),
         SqlType::Json | SqlType::Jsonb => DfValue::from(format!("{{\"k\": {}}}", idx)),
         SqlType::MacAddr => {
             let b1: u8 = ((idx >> 24) & 0xff) as u8;
@@ -2323,7 +2330,7 @@ impl QueryOperation {
             QueryOperation::Distinct => {
                 query.distinct = true;
                 if let Some(order) = &query.order {
-                    for (field, _) in &order.order_by {
+                    for (field, _, _) in &order.order_by {
                         let expr = match field {
                             FieldReference::Numeric(_) => {
                                 unreachable!(
@@ -2554,6 +2561,7 @@ impl QueryOperation {
                     order_by: vec![(
                         FieldReference::Expr(Expr::Column(column.clone())),
                         Some(*order_type),
+                        None,
                     )],
                 });
 
@@ -2591,6 +2599,7 @@ impl QueryOperation {
                     order_by: vec![(
                         FieldReference::Expr(Expr::Column(column.clone())),
                         Some(*order_type),
+                        None,
                     )],
                 });
 
@@ -2915,6 +2924,7 @@ impl Subquery {
                 query.ctes.push(CommonTableExpr {
                     name: subquery_name.clone(),
                     statement: subquery,
+                    recursive_term: None,
                 });
                 (
                     JoinRightSide::Table(TableExpr::from(Relation {
@@ -3102,7 +3112,7 @@ impl QuerySeed {
             }
 
             if let Some(order) = &query.order {
-                for (field, _) in &order.order_by {
+                for (field, _, _) in &order.order_by {
                     let expr = match field {
                         FieldReference::Expr(expr) => expr,
                         FieldReference::Numeric(_) => unreachable!(
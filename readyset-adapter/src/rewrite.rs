@@ -6,8 +6,10 @@ use std::{iter, mem};
 
 use itertools::{Either, Itertools};
 use nom_sql::analysis::visit_mut::{self, VisitorMut};
+use nom_sql::analysis::{contains_aggregate, is_aggregate};
 use nom_sql::{
-    BinaryOperator, Expr, InValue, ItemPlaceholder, LimitClause, Literal, SelectStatement,
+    BinaryOperator, Expr, FieldDefinitionExpr, FunctionExpr, InValue, ItemPlaceholder, LimitClause,
+    Literal, SelectStatement,
 };
 use readyset_data::{DfType, DfValue};
 use readyset_errors::{invalid_err, unsupported, ReadySetError, ReadySetResult};
@@ -40,8 +42,6 @@ struct AdapterPaginationParams {
 /// post-processing path.
 fn use_fallback_pagination(server_supports_pagination: bool, limit_clause: &LimitClause) -> bool {
     if server_supports_pagination &&
-        // Can't handle parameterized LIMIT even if support is enabled
-        !matches!(limit_clause.limit(), Some(Literal::Placeholder(_))) &&
         // Can't handle bare OFFSET
         !(limit_clause.limit().is_none() && limit_clause.offset().is_some())
     {
@@ -69,7 +69,7 @@ pub fn process_query(
     let limit_clause = mem::take(&mut query.limit_clause);
 
     let force_paginate_in_adapter =
-        use_fallback_pagination(server_supports_pagination, &query.limit_clause);
+        use_fallback_pagination(server_supports_pagination, &limit_clause);
 
     if !force_paginate_in_adapter {
         // If adapter pagination shouldn't be used reinstate the limit clause
@@ -92,7 +92,10 @@ pub fn process_query(
 
 impl ProcessedQueryParams {
     /// If the query has values for OFFSET or LIMIT, get their values, returning a tuple of `limit,
-    /// offset`
+    /// offset`.
+    ///
+    /// These are always passed along to the reader, which applies them post-lookup. Readers which
+    /// paginate in the dataflow graph ignore the offset, since it's already part of the lookup key.
     pub(crate) fn limit_offset_params(
         &self,
         params: &[DfValue],
@@ -135,29 +138,18 @@ impl ProcessedQueryParams {
             }
         };
 
-        let AdapterPaginationParams {
-            limit_clause,
-            force_paginate_in_adapter,
-        } = &self.pagination_parameters;
-
-        let (limit, offset) = match limit_clause {
+        match &self.pagination_parameters.limit_clause {
             LimitClause::LimitOffset { limit, offset } => {
                 let offset = offset.as_ref().map(&mut get_param).transpose()?;
                 let limit = limit.as_ref().map(&mut get_param).transpose()?;
-                (limit, offset)
+                Ok((limit, offset))
             }
             LimitClause::OffsetCommaLimit { offset, limit } => {
                 // Get the limit first, since with this syntax, it's the last param.
                 let limit = get_param(limit)?;
                 let offset = get_param(offset)?;
-                (Some(limit), Some(offset))
+                Ok((Some(limit), Some(offset)))
             }
-        };
-
-        if *force_paginate_in_adapter || limit == Some(0) {
-            Ok((limit, offset))
-        } else {
-            Ok((None, None))
         }
     }
 
@@ -335,30 +327,81 @@ impl<'ast> VisitorMut<'ast> for CollapseWhereInVisitor {
 /// by regular filter nodes in dataflow
 fn collapse_where_in(query: &mut SelectStatement) -> ReadySetResult<Vec<RewrittenIn>> {
     let mut res = vec![];
-    let distinct = query.distinct;
-    let has_aggregates = query.contains_aggregate_select();
+    let supports_multi_key_lookup = supports_multi_key_lookup(query);
 
     if let Some(ref mut w) = query.where_clause {
         let mut visitor = CollapseWhereInVisitor::default();
         visitor.visit_expr(w)?;
         res = visitor.out;
 
-        // When a `SELECT` statement contains aggregates, such as `SUM` or `COUNT` (or `DISTINCT`,
-        // which is implemented via COUNT),  we can't use placeholders, as those will aggregate key
-        // lookups into a multi row response, as opposed to a single row response required by
-        // aggregates. We could support this pretty easily, but for now it's not in-scope
-        if !res.is_empty() {
-            if has_aggregates {
-                unsupported!("Aggregates with parameterized IN are not supported");
-            }
-            if distinct {
-                unsupported!("DISTINCT with parameterized IN is not supported");
-            }
+        if !res.is_empty() && !supports_multi_key_lookup {
+            unsupported!("Aggregates with parameterized IN must be re-aggregatable");
         }
     }
     Ok(res)
 }
 
+/// Returns true if the results of the given query for several lookup keys can be combined into the
+/// results for the query as a whole, which is required to look up multiple keys at once (as we do
+/// for parameterized IN).
+///
+/// The reader combines the results for multiple keys by re-aggregating them (and `DISTINCT` is just
+/// an aggregate without any aggregate functions), which is only possible if every aggregate is
+/// projected directly, rather than as part of a larger expression or a `HAVING` condition, and can
+/// be re-aggregated from its values for each key.
+fn supports_multi_key_lookup(query: &SelectStatement) -> bool {
+    // DISTINCT ON keeps one row per group for each key, so the results for several keys can have
+    // more than one row for the same group
+    if !query.distinct_on.is_empty() {
+        return false;
+    }
+
+    // If a LIMIT is applied to the results for each key, those results may be missing rows for some
+    // of the groups being re-aggregated
+    if (query.distinct || (query.group_by.is_some() && query.contains_aggregate_select()))
+        && query.limit_clause.limit().is_some()
+    {
+        return false;
+    }
+
+    // Distinct values may be shared between keys, so DISTINCT aggregates for several keys can't
+    // be combined
+    let can_re_aggregate = |function: &FunctionExpr| {
+        matches!(
+            function,
+            FunctionExpr::Avg {
+                distinct: false,
+                ..
+            } | FunctionExpr::Count {
+                distinct: false,
+                ..
+            } | FunctionExpr::CountStar
+                | FunctionExpr::Sum {
+                    distinct: false,
+                    ..
+                }
+                | FunctionExpr::Max(_)
+                | FunctionExpr::Min(_)
+                | FunctionExpr::GroupConcat { .. }
+                | FunctionExpr::BoolAnd(_)
+                | FunctionExpr::BoolOr(_)
+        )
+    };
+
+    query.fields.iter().all(|field| match field {
+        FieldDefinitionExpr::Expr {
+            expr: Expr::Call(function),
+            ..
+        } if is_aggregate(function) => can_re_aggregate(function),
+        FieldDefinitionExpr::Expr {
+            expr: Expr::NestedSelect(select),
+            ..
+        } => !select.contains_aggregate_select(),
+        FieldDefinitionExpr::Expr { expr, .. } => !contains_aggregate(expr),
+        FieldDefinitionExpr::All | FieldDefinitionExpr::AllInTable(_) => true,
+    }) && !query.having.iter().any(contains_aggregate)
+}
+
 /// Given a slice of parameters provided by the user and the list of [`RewrittenIn`] returned by
 /// [`collapse_where_in`] on a query, construct a vector of lookup keys for executing that query
 fn explode_params<'param, 'a, T>(
@@ -528,7 +571,7 @@ pub fn number_placeholders(query: &mut SelectStatement) -> ReadySetResult<()> {
 #[derive(Default)]
 struct AutoParametrizeVisitor {
     out: Vec<(usize, Literal)>,
-    has_aggregates: bool,
+    supports_multi_key_lookup: bool,
     in_supported_position: bool,
     param_index: usize,
     query_depth: u8,
@@ -606,7 +649,7 @@ impl<'ast> VisitorMut<'ast> for AutoParametrizeVisitor {
                         e,
                         Expr::Literal(lit) if !matches!(lit, Literal::Placeholder(_))
                     )
-                }) && !self.has_aggregates =>
+                }) && self.supports_multi_key_lookup =>
                 {
                     let exprs = mem::replace(
                         exprs,
@@ -686,7 +729,7 @@ pub fn auto_parametrize_query(query: &mut SelectStatement) -> Vec<(usize, Litera
     }
 
     let mut visitor = AutoParametrizeVisitor {
-        has_aggregates: query.contains_aggregate_select(),
+        supports_multi_key_lookup: supports_multi_key_lookup(query),
         ..Default::default()
     };
     #[allow(clippy::unwrap_used)] // error is !, which can never be returned
@@ -948,6 +991,46 @@ mod tests {
             );
         }
 
+        #[test]
+        fn collapse_where_in_with_aggregates() {
+            let mut q =
+                parse_select_statement("SELECT count(*), avg(y) FROM x WHERE x.id IN (?, ?)");
+            let rewritten = collapse_where_in(&mut q).unwrap();
+            assert_eq!(
+                rewritten,
+                vec![RewrittenIn {
+                    first_param_index: 0,
+                    literals: vec![ItemPlaceholder::QuestionMark; 2]
+                }]
+            );
+            assert_eq!(
+                q,
+                parse_select_statement("SELECT count(*), avg(y) FROM x WHERE x.id = ?")
+            );
+
+            let mut q = parse_select_statement("SELECT DISTINCT y FROM x WHERE x.id IN (?, ?)");
+            collapse_where_in(&mut q).unwrap();
+
+            for query in [
+                "SELECT stddev(y) FROM x WHERE x.id IN (?, ?)",
+                "SELECT count(DISTINCT y) FROM x WHERE x.id IN (?, ?)",
+                "SELECT sum(DISTINCT y) FROM x WHERE x.id IN (?, ?)",
+                "SELECT avg(DISTINCT y) FROM x WHERE x.id IN (?, ?)",
+                "SELECT count(*) + 1 FROM x WHERE x.id IN (?, ?)",
+                "SELECT y FROM x WHERE x.id IN (?, ?) GROUP BY y HAVING count(*) > 1",
+                "SELECT y, count(*) FROM x WHERE x.id IN (?, ?) GROUP BY y LIMIT 3",
+            ] {
+                collapse_where_in(&mut parse_select_statement(query)).unwrap_err();
+            }
+
+            let mut q = nom_sql::parse_select_statement(
+                Dialect::PostgreSQL,
+                "SELECT DISTINCT ON (y) y, z FROM x WHERE x.id IN (?, ?) ORDER BY y",
+            )
+            .unwrap();
+            collapse_where_in(&mut q).unwrap_err();
+        }
+
         #[test]
         fn collapse_multiple_where_in() {
             let mut q = parse_select_statement("SELECT * FROM t WHERE x IN (?,?) AND y IN (?,?)");
@@ -1159,7 +1242,21 @@ mod tests {
         fn in_with_aggregates() {
             test_auto_parametrize(
                 "SELECT count(*) FROM users WHERE id = 1 AND x IN (1, 2)",
-                "SELECT count(*) FROM users WHERE id = ? AND x IN (1, 2)",
+                "SELECT count(*) FROM users WHERE id = ? AND x IN (?, ?)",
+                vec![(0, 1_u32.into()), (1, 1_u32.into()), (2, 2_u32.into())],
+            );
+        }
+
+        #[test]
+        fn in_with_aggregates_that_cant_be_re_aggregated() {
+            test_auto_parametrize(
+                "SELECT stddev(age) FROM users WHERE id = 1 AND x IN (1, 2)",
+                "SELECT stddev(age) FROM users WHERE id = ? AND x IN (1, 2)",
+                vec![(0, 1_u32.into())],
+            );
+            test_auto_parametrize(
+                "SELECT count(*) + 1 FROM users WHERE id = 1 AND x IN (1, 2)",
+                "SELECT count(*) + 1 FROM users WHERE id = ? AND x IN (1, 2)",
                 vec![(0, 1_u32.into())],
             );
         }
@@ -1534,5 +1631,60 @@ mod tests {
                 (Some(4), Some(2))
             );
         }
+
+        #[test]
+        fn parametrized_limit_with_server_pagination() {
+            let process = |q: &str| -> (ProcessedQueryParams, SelectStatement) {
+                let mut query = parse_select_statement(q);
+                let proc = process_query(&mut query, true).unwrap();
+                (proc, query)
+            };
+
+            let (proc, query) = process("SELECT * FROM t WHERE x = $1 LIMIT $2 OFFSET $3");
+            assert_eq!(
+                query,
+                parse_select_statement("SELECT * FROM t WHERE x = $1 LIMIT $2 OFFSET $3")
+            );
+            assert_eq!(
+                proc.limit_offset_params(&[1.into(), 2.into(), 3.into()])
+                    .unwrap(),
+                (Some(2), Some(3))
+            );
+
+            let (proc, _) = process("SELECT * FROM t WHERE x = $1 LIMIT 2 OFFSET 3");
+            assert_eq!(
+                proc.limit_offset_params(&[1.into()]).unwrap(),
+                (Some(2), Some(3))
+            );
+
+            // Constant LIMIT with a parametrized OFFSET is passed along too; readers keyed on a
+            // page number drop the offset
+            let (proc, _) = process("SELECT * FROM t WHERE x = $1 LIMIT 2 OFFSET $2");
+            assert_eq!(
+                proc.limit_offset_params(&[1.into(), 2.into()]).unwrap(),
+                (Some(2), Some(2))
+            );
+
+            // Range queries keep the OFFSET in the query, and apply both post-lookup
+            let (proc, query) = process("SELECT * FROM t WHERE x > $1 LIMIT 2 OFFSET $2");
+            assert_eq!(
+                query,
+                parse_select_statement("SELECT * FROM t WHERE x > $1 LIMIT 2 OFFSET $2")
+            );
+            assert_eq!(
+                proc.limit_offset_params(&[1.into(), 2.into()]).unwrap(),
+                (Some(2), Some(2))
+            );
+
+            let (proc, query) = process("SELECT * FROM t WHERE x = $1 OFFSET $2");
+            assert_eq!(
+                query,
+                parse_select_statement("SELECT * FROM t WHERE x = $1")
+            );
+            assert_eq!(
+                proc.limit_offset_params(&[1.into(), 2.into()]).unwrap(),
+                (None, Some(2))
+            );
+        }
     }
 }
@@ -1749,6 +1749,14 @@ impl ReaderHandle {
 
         trace!(?keys, ?filters, "Built view query");
 
+        // Views keyed on a page number have already applied the OFFSET in the dataflow graph
+        let offset = offset.filter(|_| {
+            !self
+                .key_map()
+                .iter()
+                .any(|(placeholder, _)| matches!(placeholder, ViewPlaceholder::PageNumber { .. }))
+        });
+
         Ok(Some(ViewQuery {
             key_comparisons: keys,
             block: blocking_read,
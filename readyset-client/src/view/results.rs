@@ -2,7 +2,7 @@ use std::cmp::Ordering;
 use std::sync::Arc;
 
 use dataflow_expression::{Expr, PostLookup, PostLookupAggregates};
-use nom_sql::OrderType;
+use nom_sql::{NullOrder, OrderType};
 use readyset_data::DfValue;
 use readyset_util::nonmaxusize::NonMaxUsize;
 use smallvec::SmallVec;
@@ -121,20 +121,30 @@ struct MergeIterator {
     inner: StreamingTournament<SingleKeyIterator, RowComparator>,
 }
 
+/// Compare two rows by the given columns, directions, and placements of NULLs
+fn compare_rows(
+    order_by: &[(usize, OrderType, NullOrder)],
+    a: &[DfValue],
+    b: &[DfValue],
+) -> Ordering {
+    order_by
+        .iter()
+        .map(|&(idx, order_type, null_order)| {
+            null_order
+                .apply(a[idx].is_none(), b[idx].is_none())
+                .unwrap_or_else(|| order_type.apply(a[idx].cmp(&b[idx])))
+        })
+        .fold(Ordering::Equal, |acc, next| acc.then(next))
+}
+
 #[derive(Clone, Debug)]
 struct RowComparator {
-    order_by: Arc<[(usize, OrderType)]>,
+    order_by: Arc<[(usize, OrderType, NullOrder)]>,
 }
 
-impl<T> Comparator<[T]> for RowComparator
-where
-    T: Ord,
-{
-    fn cmp(&self, a: &[T], b: &[T]) -> Ordering {
-        self.order_by
-            .iter()
-            .map(|&(idx, order_type)| order_type.apply(a[idx].cmp(&b[idx])))
-            .fold(Ordering::Equal, |acc, next| acc.then(next))
+impl Comparator<[DfValue]> for RowComparator {
+    fn cmp(&self, a: &[DfValue], b: &[DfValue]) -> Ordering {
+        compare_rows(&self.order_by, a, b)
     }
 }
 
@@ -170,6 +180,10 @@ impl ResultIterator {
         } = post_lookup;
 
         let limit = adapter_limit.or(*limit); // Limit specifies total number of results to return
+        let cols = returned_cols
+            .as_ref()
+            .map(|r| r.len())
+            .unwrap_or(usize::MAX);
 
         let inner = match (order_by, aggregates) {
             // No specific order is required, simply iterate over each result set one by one
@@ -202,7 +216,7 @@ impl ResultIterator {
                         order_by: aggregates
                             .group_by
                             .iter()
-                            .map(|&col| (col, OrderType::OrderAscending))
+                            .map(|&col| (col, OrderType::OrderAscending, NullOrder::NullsFirst))
                             .collect(),
                     };
 
@@ -223,12 +237,13 @@ impl ResultIterator {
             (Some(order_by), Some(aggregates)) => {
                 // When both aggregates and order by are specified it is tricky to lazily evaluate
                 // rows, so sadly we end up having to collect all of the rows, aggregate, then sort
-                // them
+                // them. The limit and offset apply to the sorted rows, so they can only be applied
+                // once we have all of them
                 let comparator = RowComparator {
                     order_by: aggregates
                         .group_by
                         .iter()
-                        .map(|&col| (col, OrderType::OrderAscending))
+                        .map(|&col| (col, OrderType::OrderAscending, NullOrder::NullsFirst))
                         .collect(),
                 };
 
@@ -245,7 +260,7 @@ impl ResultIterator {
                         aggregate: aggregates.clone(),
                         filter: filter.take(),
                     }),
-                    limit,
+                    limit: None,
                     offset: None,
                     default_row: default_row.clone(),
                     non_empty: false,
@@ -254,21 +269,21 @@ impl ResultIterator {
                 };
 
                 let mut results = temp_iter.into_vec();
-                results.sort_by(|a, b| {
-                    order_by
-                        .iter()
-                        .map(|&(idx, order_type)| order_type.apply(a[idx].cmp(&b[idx])))
-                        .fold(Ordering::Equal, |acc, next| acc.then(next))
-                });
-
-                if let Some(offset) = offset {
-                    results.drain(offset..);
-                }
-
-                return ResultIterator::owned(vec![Results {
-                    results,
-                    stats: None,
-                }]);
+                results.sort_by(|a, b| compare_rows(order_by, a, b));
+
+                let results = results
+                    .into_iter()
+                    .skip(offset.unwrap_or(0))
+                    .take(limit.unwrap_or(usize::MAX))
+                    .collect();
+
+                return ResultIterator {
+                    cols,
+                    ..ResultIterator::owned(vec![Results {
+                        results,
+                        stats: None,
+                    }])
+                };
             }
         };
 
@@ -281,10 +296,7 @@ impl ResultIterator {
             // When aggregates (group_by) is present, filtering is processed by the inner
             // aggregating iterator, and its value here would be `None`.
             filter,
-            cols: returned_cols
-                .as_ref()
-                .map(|r| r.len())
-                .unwrap_or(usize::MAX),
+            cols,
         }
     }
 
@@ -506,13 +518,9 @@ impl StreamingIterator for AggregateIterator {
                 break;
             }
 
-            for agg in &self.aggregate.aggregates {
-                let col = agg.column;
-                aggregate_row[col] = agg
-                    .function
-                    .apply(&aggregate_row[col], &row[col])
-                    .expect("no fail");
-            }
+            self.aggregate
+                .apply(&mut aggregate_row, row)
+                .expect("no fail");
 
             self.advance_filtered();
         }
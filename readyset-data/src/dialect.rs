@@ -1,5 +1,6 @@
 use std::fmt;
 
+use nom_sql::{NullOrder, OrderType};
 use serde::{Deserialize, Serialize};
 
 use crate::DfType;
@@ -84,6 +85,23 @@ impl Dialect {
         }
     }
 
+    /// Returns where NULLs are placed when ordering in the given direction, if the query doesn't
+    /// specify `NULLS FIRST` or `NULLS LAST`.
+    ///
+    /// MySQL considers NULL to be smaller than every other value, whereas PostgreSQL considers it
+    /// to be larger than every other value.
+    pub fn default_null_order(self, order_type: OrderType) -> NullOrder {
+        match self.engine {
+            SqlEngine::MySQL => NullOrder::default_for(order_type),
+            // https://www.postgresql.org/docs/current/queries-order.html
+            // "By default, null values sort as if larger than any non-null value"
+            SqlEngine::PostgreSQL => match order_type {
+                OrderType::OrderAscending => NullOrder::NullsLast,
+                OrderType::OrderDescending => NullOrder::NullsFirst,
+            },
+        }
+    }
+
     /// Return the [`DfType`] corresponding to the SQL `FLOAT` type for this dialect
     pub(crate) fn float_type(&self) -> DfType {
         match self.engine {
@@ -182,6 +182,7 @@ where
         | DfType::MacAddr
         | DfType::Inet
         | DfType::Uuid
+        | DfType::Interval
         | DfType::Bit(_)
         | DfType::VarBit(_)
         | DfType::Array(_) => Err(ReadySetError::DfValueConversionError {
@@ -0,0 +1,638 @@
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use bytes::{BufMut, BytesMut};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime};
+use nom_sql::TimestampField;
+use readyset_errors::{invalid_err, ReadySetError, ReadySetResult};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use test_strategy::Arbitrary;
+use tokio_postgres::types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+const MICROS_PER_SECOND: i64 = 1_000_000;
+const MICROS_PER_MINUTE: i64 = 60 * MICROS_PER_SECOND;
+const MICROS_PER_HOUR: i64 = 60 * MICROS_PER_MINUTE;
+const MICROS_PER_DAY: i64 = 24 * MICROS_PER_HOUR;
+/// Intervals consider a month to be 30 days when comparing or spilling fractional months, as per
+/// PostgreSQL
+const DAYS_PER_MONTH: i64 = 30;
+
+/// A span of time, as represented by the SQL `INTERVAL` type.
+///
+/// Like PostgreSQL, the month, day, and sub-day components of an interval are stored separately,
+/// since the number of days in a month and the number of hours in a day both vary depending on the
+/// date (and time zone) the interval is applied to. Intervals are compared by their total length,
+/// assuming 30-day months and 24-hour days, so (for example) `1 mon` is equal to `30 days`.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, Arbitrary)]
+pub struct Interval {
+    months: i32,
+    days: i32,
+    microseconds: i64,
+}
+
+impl Interval {
+    /// Construct a new [`Interval`] from its month, day, and microsecond components
+    pub fn new(months: i32, days: i32, microseconds: i64) -> Self {
+        Self {
+            months,
+            days,
+            microseconds,
+        }
+    }
+
+    /// Construct an [`Interval`] of `amount` of the given `field`, as for MySQL's `INTERVAL expr
+    /// unit` or PostgreSQL's `INTERVAL 'n' unit`.
+    ///
+    /// Fractional amounts spill over into smaller units, so `1.5 DAY` is one day and twelve hours.
+    pub fn from_field(amount: Decimal, field: TimestampField) -> ReadySetResult<Self> {
+        let unit = match field {
+            TimestampField::Millennium => Unit::Months(12_000),
+            TimestampField::Century => Unit::Months(1_200),
+            TimestampField::Decade => Unit::Months(120),
+            TimestampField::Year => Unit::Months(12),
+            TimestampField::Quarter => Unit::Months(3),
+            TimestampField::Month => Unit::Months(1),
+            TimestampField::Week => Unit::Days(7),
+            TimestampField::Day => Unit::Days(1),
+            TimestampField::Hour => Unit::Micros(MICROS_PER_HOUR),
+            TimestampField::Minute => Unit::Micros(MICROS_PER_MINUTE),
+            TimestampField::Second => Unit::Micros(MICROS_PER_SECOND),
+            TimestampField::Millisecond => Unit::Micros(1_000),
+            TimestampField::Microsecond => Unit::Micros(1),
+            _ => return Err(invalid_err!("{field} is not a valid unit for an interval")),
+        };
+
+        let mk_err = || invalid_err!("Interval out of range: {amount} {field}");
+        let int = amount.trunc().to_i64().ok_or_else(mk_err)?;
+        let frac = amount.fract().to_f64().ok_or_else(mk_err)?;
+        let mut acc = Accumulator::default();
+        acc.add(int, frac, unit).ok_or_else(mk_err)?;
+        acc.finish().ok_or_else(mk_err)
+    }
+
+    /// Returns the number of months in this interval
+    pub fn months(&self) -> i32 {
+        self.months
+    }
+
+    /// Returns the number of days in this interval, not including any days in [`Self::months`]
+    pub fn days(&self) -> i32 {
+        self.days
+    }
+
+    /// Returns the number of microseconds in this interval, not including any microseconds in
+    /// [`Self::months`] or [`Self::days`]
+    pub fn microseconds(&self) -> i64 {
+        self.microseconds
+    }
+
+    /// Returns the total length of this interval in microseconds, assuming 30-day months and
+    /// 24-hour days. This is what intervals are compared by.
+    fn span(&self) -> i128 {
+        (i128::from(self.months) * i128::from(DAYS_PER_MONTH) + i128::from(self.days))
+            * i128::from(MICROS_PER_DAY)
+            + i128::from(self.microseconds)
+    }
+
+    /// Negates each of the components of this interval, returning [`None`] on overflow
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(Self {
+            months: self.months.checked_neg()?,
+            days: self.days.checked_neg()?,
+            microseconds: self.microseconds.checked_neg()?,
+        })
+    }
+
+    /// Adds two intervals component-wise, returning [`None`] on overflow
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(Self {
+            months: self.months.checked_add(other.months)?,
+            days: self.days.checked_add(other.days)?,
+            microseconds: self.microseconds.checked_add(other.microseconds)?,
+        })
+    }
+
+    /// Subtracts two intervals component-wise, returning [`None`] on overflow
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        self.checked_add(&other.checked_neg()?)
+    }
+
+    /// Adds this interval to the given datetime, returning [`None`] if the result is out of range.
+    ///
+    /// Months are added first, clamping the day of the month to the last day of the resulting
+    /// month if necessary (so `2023-01-31 + 1 mon` is `2023-02-28`), followed by days and then
+    /// microseconds.
+    pub fn checked_add_to(&self, datetime: NaiveDateTime) -> Option<NaiveDateTime> {
+        let datetime = if self.months != 0 {
+            let months = i64::from(datetime.year())
+                .checked_mul(12)?
+                .checked_add(i64::from(datetime.month0()))?
+                .checked_add(i64::from(self.months))?;
+            let year = i32::try_from(months.div_euclid(12)).ok()?;
+            let month = months.rem_euclid(12) as u32 + 1;
+            let last_day = (28..=31)
+                .rev()
+                .find(|day| NaiveDate::from_ymd_opt(year, month, *day).is_some())?;
+            NaiveDate::from_ymd_opt(year, month, datetime.day().min(last_day))?
+                .and_time(datetime.time())
+        } else {
+            datetime
+        };
+
+        datetime
+            .checked_add_signed(Duration::days(i64::from(self.days)))?
+            .checked_add_signed(Duration::microseconds(self.microseconds))
+    }
+}
+
+impl PartialEq for Interval {
+    fn eq(&self, other: &Self) -> bool {
+        self.span() == other.span()
+    }
+}
+
+impl Eq for Interval {}
+
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Interval {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.span().cmp(&other.span())
+    }
+}
+
+impl Hash for Interval {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.span().hash(state)
+    }
+}
+
+/// Formats intervals the same way as PostgreSQL's default `IntervalStyle` of `postgres`, eg
+/// `1 year 2 mons 3 days 04:05:06.789`
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut is_zero = true;
+        let mut is_before = false;
+        let mut write_part = |f: &mut fmt::Formatter<'_>, value: i64, unit: &str| -> fmt::Result {
+            if value == 0 {
+                return Ok(());
+            }
+            write!(
+                f,
+                "{}{}{} {}{}",
+                if is_zero { "" } else { " " },
+                if is_before && value > 0 { "+" } else { "" },
+                value,
+                unit,
+                if value != 1 { "s" } else { "" }
+            )?;
+            is_before = value < 0;
+            is_zero = false;
+            Ok(())
+        };
+
+        write_part(f, i64::from(self.months / 12), "year")?;
+        write_part(f, i64::from(self.months % 12), "mon")?;
+        write_part(f, i64::from(self.days), "day")?;
+
+        if is_zero || self.microseconds != 0 {
+            let sign = if self.microseconds < 0 {
+                "-"
+            } else if is_before {
+                "+"
+            } else {
+                ""
+            };
+            let micros = self.microseconds.unsigned_abs();
+            let hours = micros / MICROS_PER_HOUR as u64;
+            let minutes = micros % MICROS_PER_HOUR as u64 / MICROS_PER_MINUTE as u64;
+            let seconds = micros % MICROS_PER_MINUTE as u64 / MICROS_PER_SECOND as u64;
+            let fraction = micros % MICROS_PER_SECOND as u64;
+
+            write!(
+                f,
+                "{}{sign}{hours:02}:{minutes:02}:{seconds:02}",
+                if is_zero { "" } else { " " }
+            )?;
+            if fraction != 0 {
+                write!(f, ".{}", format!("{fraction:06}").trim_end_matches('0'))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The unit of a component of an interval, along with how many of the corresponding interval
+/// field one of that unit is worth
+#[derive(Debug, Clone, Copy)]
+enum Unit {
+    Months(i64),
+    Days(i64),
+    Micros(i64),
+}
+
+impl Unit {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name.to_ascii_lowercase().as_str() {
+            "millennium" | "millennia" | "millenniums" | "mil" | "mils" => Unit::Months(12_000),
+            "century" | "centuries" | "cent" | "c" => Unit::Months(1_200),
+            "decade" | "decades" | "dec" | "decs" => Unit::Months(120),
+            "year" | "years" | "yr" | "yrs" | "y" => Unit::Months(12),
+            "month" | "months" | "mon" | "mons" => Unit::Months(1),
+            "week" | "weeks" | "w" => Unit::Days(7),
+            "day" | "days" | "d" => Unit::Days(1),
+            "hour" | "hours" | "hr" | "hrs" | "h" => Unit::Micros(MICROS_PER_HOUR),
+            "minute" | "minutes" | "min" | "mins" | "m" => Unit::Micros(MICROS_PER_MINUTE),
+            "second" | "seconds" | "sec" | "secs" | "s" => Unit::Micros(MICROS_PER_SECOND),
+            "millisecond" | "milliseconds" | "ms" | "msec" | "msecs" => Unit::Micros(1_000),
+            "microsecond" | "microseconds" | "us" | "usec" | "usecs" => Unit::Micros(1),
+            _ => return None,
+        })
+    }
+}
+
+/// Running totals of each of the fields of an interval while parsing, with enough headroom to
+/// detect overflow once at the end
+#[derive(Default)]
+struct Accumulator {
+    months: i64,
+    days: i64,
+    micros: i128,
+}
+
+impl Accumulator {
+    /// Add `int + frac` of the given unit, spilling any fractional months into days and any
+    /// fractional days into microseconds
+    fn add(&mut self, int: i64, frac: f64, unit: Unit) -> Option<()> {
+        let spill_days = |acc: &mut Self, days: f64| {
+            acc.days = acc.days.checked_add(days.trunc() as i64)?;
+            acc.micros += (days.fract() * MICROS_PER_DAY as f64).round() as i128;
+            Some(())
+        };
+
+        match unit {
+            Unit::Months(n) => {
+                self.months = self.months.checked_add(int.checked_mul(n)?)?;
+                let months = frac * n as f64;
+                self.months = self.months.checked_add(months.trunc() as i64)?;
+                spill_days(self, months.fract() * DAYS_PER_MONTH as f64)?;
+            }
+            Unit::Days(n) => {
+                self.days = self.days.checked_add(int.checked_mul(n)?)?;
+                spill_days(self, frac * n as f64)?;
+            }
+            Unit::Micros(n) => {
+                self.micros += i128::from(int) * i128::from(n) + (frac * n as f64).round() as i128;
+            }
+        }
+        Some(())
+    }
+
+    fn finish(self) -> Option<Interval> {
+        Some(Interval {
+            months: self.months.try_into().ok()?,
+            days: self.days.try_into().ok()?,
+            microseconds: self.micros.try_into().ok()?,
+        })
+    }
+}
+
+/// Splits a number off the front of `s`, returning its integer part, its (signed) fractional part,
+/// and the rest of the string
+fn split_number(s: &str) -> Option<(i64, f64, &str)> {
+    let digits_start = usize::from(s.starts_with(['+', '-']));
+    let end = s[digits_start..]
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .map_or(s.len(), |i| i + digits_start);
+    let (number, rest) = s.split_at(end);
+    let negative = number.starts_with('-');
+    let (int, frac) = number[digits_start..]
+        .split_once('.')
+        .unwrap_or((&number[digits_start..], ""));
+    if int.is_empty() && frac.is_empty() {
+        return None;
+    }
+
+    let int = if int.is_empty() {
+        0
+    } else {
+        int.parse::<i64>().ok()?
+    };
+    let frac = if frac.is_empty() {
+        0.0
+    } else {
+        format!("0.{frac}").parse::<f64>().ok()?
+    };
+
+    if negative {
+        Some((-int, -frac, rest))
+    } else {
+        Some((int, frac, rest))
+    }
+}
+
+/// Parses a time of the form `[+-]hh:mm[:ss[.ffffff]]` into a number of microseconds
+fn parse_time(s: &str) -> Option<i128> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let mut parts = s.splitn(3, ':');
+    let hours = parts.next()?.parse::<u64>().ok()?;
+    let minutes = parts.next()?.parse::<u64>().ok()?;
+    let (seconds, fraction) = match parts.next() {
+        Some(seconds) => seconds.split_once('.').unwrap_or((seconds, "")),
+        None => ("0", ""),
+    };
+    let seconds = seconds.parse::<u64>().ok()?;
+    if !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let fraction = format!("{:0<6}", fraction.get(..6).unwrap_or(fraction))
+        .parse::<u64>()
+        .ok()?;
+
+    let micros = i128::from(hours) * i128::from(MICROS_PER_HOUR)
+        + i128::from(minutes) * i128::from(MICROS_PER_MINUTE)
+        + i128::from(seconds) * i128::from(MICROS_PER_SECOND)
+        + i128::from(fraction);
+    Some(if negative { -micros } else { micros })
+}
+
+/// Parses the part of an ISO 8601 duration after the leading `P`, eg `1Y2M3DT4H5M6S`
+fn parse_iso8601(s: &str) -> Option<Interval> {
+    let mut acc = Accumulator::default();
+    let mut rest = s;
+    let mut in_time = false;
+    while !rest.is_empty() {
+        if let Some(r) = rest.strip_prefix(['T', 't']) {
+            in_time = true;
+            rest = r;
+            continue;
+        }
+
+        let (int, frac, r) = split_number(rest)?;
+        let mut chars = r.chars();
+        let unit = match (in_time, chars.next()?.to_ascii_uppercase()) {
+            (false, 'Y') => Unit::Months(12),
+            (false, 'M') => Unit::Months(1),
+            (false, 'W') => Unit::Days(7),
+            (false, 'D') => Unit::Days(1),
+            (true, 'H') => Unit::Micros(MICROS_PER_HOUR),
+            (true, 'M') => Unit::Micros(MICROS_PER_MINUTE),
+            (true, 'S') => Unit::Micros(MICROS_PER_SECOND),
+            _ => return None,
+        };
+        acc.add(int, frac, unit)?;
+        rest = chars.as_str();
+    }
+    acc.finish()
+}
+
+/// Parses intervals in any of the formats accepted as input by PostgreSQL, other than the SQL
+/// standard `y-m d h:m:s` format:
+///
+/// - PostgreSQL's verbose format, eg `1 year 2 mons -3 days 04:05:06.789`, optionally prefixed with
+///   `@` and suffixed with `ago`
+/// - ISO 8601 durations, eg `P1Y2M3DT4H5M6S`
+///
+/// Numbers without a unit are interpreted as seconds.
+impl FromStr for Interval {
+    type Err = ReadySetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mk_err = || invalid_err!("Invalid interval value: {s}");
+        let input = s.trim();
+
+        if let Some(iso) = input.strip_prefix(['P', 'p']) {
+            return parse_iso8601(iso).ok_or_else(mk_err);
+        }
+
+        let input = input.strip_prefix('@').unwrap_or(input);
+        if input.trim().is_empty() {
+            return Err(mk_err());
+        }
+
+        let mut acc = Accumulator::default();
+        let mut ago = false;
+        let mut tokens = input.split_whitespace().peekable();
+        while let Some(token) = tokens.next() {
+            if ago {
+                // `ago` must be the last token
+                return Err(mk_err());
+            }
+
+            if token.eq_ignore_ascii_case("ago") {
+                ago = true;
+            } else if token.contains(':') {
+                acc.micros += parse_time(token).ok_or_else(mk_err)?;
+            } else {
+                let (int, frac, unit) = split_number(token).ok_or_else(mk_err)?;
+                let unit = if !unit.is_empty() {
+                    Unit::from_name(unit).ok_or_else(mk_err)?
+                } else if let Some(unit) = tokens.peek().and_then(|t| Unit::from_name(t)) {
+                    tokens.next();
+                    unit
+                } else {
+                    Unit::Micros(MICROS_PER_SECOND)
+                };
+                acc.add(int, frac, unit).ok_or_else(mk_err)?;
+            }
+        }
+
+        if ago {
+            acc.months = -acc.months;
+            acc.days = -acc.days;
+            acc.micros = -acc.micros;
+        }
+
+        acc.finish().ok_or_else(mk_err)
+    }
+}
+
+/// Intervals are sent over the PostgreSQL binary protocol as a 64-bit count of microseconds,
+/// followed by a 32-bit count of days and a 32-bit count of months
+impl ToSql for Interval {
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        out.put_i64(self.microseconds);
+        out.put_i32(self.days);
+        out.put_i32(self.months);
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::INTERVAL
+    }
+
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Interval {
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        if raw.len() != 16 {
+            return Err(format!("Invalid length {} for binary interval value", raw.len()).into());
+        }
+        Ok(Self {
+            microseconds: i64::from_be_bytes(raw[..8].try_into()?),
+            days: i32::from_be_bytes(raw[8..12].try_into()?),
+            months: i32::from_be_bytes(raw[12..].try_into()?),
+        })
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::INTERVAL
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use test_strategy::proptest;
+
+    use super::*;
+
+    #[test]
+    fn parse_postgres_format() {
+        assert_eq!(
+            "1 year 2 mons 3 days 04:05:06.789"
+                .parse::<Interval>()
+                .unwrap()
+                .to_string(),
+            "1 year 2 mons 3 days 04:05:06.789"
+        );
+
+        let interval: Interval = "-1 days +02:03:00".parse().unwrap();
+        assert_eq!(interval.days(), -1);
+        assert_eq!(
+            interval.microseconds(),
+            2 * MICROS_PER_HOUR + 3 * MICROS_PER_MINUTE
+        );
+
+        let interval: Interval = "@ 1 day 2 hours ago".parse().unwrap();
+        assert_eq!(interval.days(), -1);
+        assert_eq!(interval.microseconds(), -2 * MICROS_PER_HOUR);
+
+        assert_eq!("7 days".parse::<Interval>().unwrap().days(), 7);
+        assert_eq!("2 weeks".parse::<Interval>().unwrap().days(), 14);
+        assert_eq!(
+            "3h".parse::<Interval>().unwrap().microseconds(),
+            3 * MICROS_PER_HOUR
+        );
+        assert_eq!(
+            "10".parse::<Interval>().unwrap().microseconds(),
+            10 * MICROS_PER_SECOND
+        );
+    }
+
+    #[test]
+    fn parse_fractional_units() {
+        let interval: Interval = "1.5 years".parse().unwrap();
+        assert_eq!(interval.months(), 18);
+
+        let interval: Interval = "1.5 days".parse().unwrap();
+        assert_eq!(interval.days(), 1);
+        assert_eq!(interval.microseconds(), 12 * MICROS_PER_HOUR);
+
+        let interval: Interval = "0.5 mon".parse().unwrap();
+        assert_eq!(interval.months(), 0);
+        assert_eq!(interval.days(), 15);
+    }
+
+    #[test]
+    fn parse_iso8601_format() {
+        let interval: Interval = "P1Y2M3DT4H5M6S".parse().unwrap();
+        assert_eq!(interval.months(), 14);
+        assert_eq!(interval.days(), 3);
+        assert_eq!(
+            interval.microseconds(),
+            4 * MICROS_PER_HOUR + 5 * MICROS_PER_MINUTE + 6 * MICROS_PER_SECOND
+        );
+        assert_eq!("P2W".parse::<Interval>().unwrap().days(), 14);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!("".parse::<Interval>().is_err());
+        assert!("1 fortnight".parse::<Interval>().is_err());
+        assert!("ago 1 day".parse::<Interval>().is_err());
+        assert!("1:xx".parse::<Interval>().is_err());
+        assert!("P1X".parse::<Interval>().is_err());
+        assert!("3000000000 days".parse::<Interval>().is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Interval::default().to_string(), "00:00:00");
+        assert_eq!(Interval::new(1, 1, 0).to_string(), "1 mon 1 day");
+        assert_eq!(Interval::new(-13, 0, 0).to_string(), "-1 years -1 mons");
+        assert_eq!(
+            Interval::new(0, -1, 2 * MICROS_PER_HOUR).to_string(),
+            "-1 days +02:00:00"
+        );
+        assert_eq!(
+            Interval::new(0, 0, -(MICROS_PER_MINUTE + 500_000)).to_string(),
+            "-00:01:00.5"
+        );
+    }
+
+    #[proptest]
+    fn display_parse_round_trip(interval: Interval) {
+        let rt = interval.to_string().parse::<Interval>().unwrap();
+        assert_eq!(rt.months(), interval.months());
+        assert_eq!(rt.days(), interval.days());
+        assert_eq!(rt.microseconds(), interval.microseconds());
+    }
+
+    #[test]
+    fn compares_by_span() {
+        assert_eq!(Interval::new(1, 0, 0), Interval::new(0, 30, 0));
+        assert_eq!(Interval::new(0, 1, 0), Interval::new(0, 0, MICROS_PER_DAY));
+        assert!(Interval::new(0, 0, MICROS_PER_DAY + 1) > Interval::new(0, 1, 0));
+    }
+
+    #[test]
+    fn from_field() {
+        let interval = Interval::from_field(Decimal::from(3), TimestampField::Quarter).unwrap();
+        assert_eq!(interval.months(), 9);
+        let interval = Interval::from_field(Decimal::new(15, 1), TimestampField::Second).unwrap();
+        assert_eq!(interval.microseconds(), 1_500_000);
+        assert!(Interval::from_field(Decimal::ONE, TimestampField::Dow).is_err());
+    }
+
+    #[test]
+    fn add_to_datetime() {
+        let dt = NaiveDate::from_ymd(2023, 1, 31).and_hms(12, 0, 0);
+        assert_eq!(
+            Interval::new(1, 0, 0).checked_add_to(dt).unwrap(),
+            NaiveDate::from_ymd(2023, 2, 28).and_hms(12, 0, 0)
+        );
+        assert_eq!(
+            Interval::new(-1, 1, -MICROS_PER_HOUR)
+                .checked_add_to(dt)
+                .unwrap(),
+            NaiveDate::from_ymd(2023, 1, 1).and_hms(11, 0, 0)
+        );
+        assert!(Interval::new(i32::MAX, 0, 0).checked_add_to(dt).is_none());
+    }
+
+    #[test]
+    fn binary_round_trip() {
+        let interval = Interval::new(14, -3, 123_456_789);
+        let mut buf = BytesMut::new();
+        interval.to_sql(&Type::INTERVAL, &mut buf).unwrap();
+        let rt = Interval::from_sql(&Type::INTERVAL, &buf).unwrap();
+        assert_eq!(rt.months(), 14);
+        assert_eq!(rt.days(), -3);
+        assert_eq!(rt.microseconds(), 123_456_789);
+    }
+}
@@ -5,7 +5,7 @@ use std::cmp::Ordering;
 use std::convert::{TryFrom, TryInto};
 use std::error::Error;
 use std::hash::{Hash, Hasher};
-use std::ops::{Add, Div, Mul, Sub};
+use std::ops::{Add, Div, Mul, Rem, Sub};
 use std::sync::Arc;
 use std::{fmt, io, str};
 
@@ -36,6 +36,7 @@ pub mod dialect;
 mod r#enum;
 mod float;
 mod integer;
+mod interval;
 mod serde;
 mod text;
 mod timestamp;
@@ -46,6 +47,7 @@ pub use ndarray::{ArrayD, IxDyn};
 pub use crate::array::Array;
 pub use crate::collation::Collation;
 pub use crate::dialect::Dialect;
+pub use crate::interval::Interval;
 pub use crate::r#type::{DfType, PgEnumMetadata, PgTypeCategory};
 pub use crate::text::{Text, TinyText};
 pub use crate::timestamp::{TimestampTz, TIMESTAMP_FORMAT, TIMESTAMP_PARSE_FORMAT};
@@ -110,6 +112,8 @@ pub enum DfValue {
     Array(Arc<Array>),
     /// Container type for arbitrary unserialized, unsupported types
     PassThrough(Arc<PassThrough>),
+    /// A span of time, as represented by the SQL `INTERVAL` type.
+    Interval(Arc<Interval>),
     /// A sentinel maximal value.
     ///
     /// This value is always greater than all other [`DfValue`]s, except itself.
@@ -123,7 +127,9 @@ pub enum DfValue {
     // - make sure to always keep Max last - we use the order of the variants to compare
     // - remember to add that variant to:
     //   - The `proptest::Arbitrary` impl for `DfValue`,
-    //   - The `example_row` in `src/serde.rs`
+    //   - The `example_row` in `src/serde.rs`, if the serde version is being bumped anyway
+    //     (otherwise, add a round-trip test for the variant to `src/serde.rs`, since the reference
+    //     row for the current serde version can't change)
 }
 
 impl Eq for DfValue {}
@@ -161,6 +167,7 @@ impl fmt::Display for DfValue {
             DfValue::PassThrough(ref p) => {
                 write!(f, "[{}:{:x?}]", p.ty.name(), p.data)
             }
+            DfValue::Interval(ref i) => write!(f, "{}", i),
             DfValue::Max => f.write_str("MAX"),
         }
     }
@@ -221,6 +228,7 @@ impl DfValue {
                 ty: p.ty.clone(),
                 data: [].into(),
             })),
+            DfValue::Interval(_) => DfValue::from(Interval::new(i32::MIN, i32::MIN, i64::MIN)),
             DfValue::Max => DfValue::None,
         }
     }
@@ -240,6 +248,7 @@ impl DfValue {
             DfValue::UnsignedInt(_) => DfValue::UnsignedInt(u64::max_value()),
             DfValue::Time(_) => DfValue::Time(MySqlTime::max_value()),
             DfValue::Numeric(_) => DfValue::from(Decimal::MAX),
+            DfValue::Interval(_) => DfValue::from(Interval::new(i32::MAX, i32::MAX, i64::MAX)),
             DfValue::TinyText(_)
             | DfValue::Text(_)
             | DfValue::ByteArray(_)
@@ -319,6 +328,7 @@ impl DfValue {
             DfValue::ByteArray(ref array) => !array.is_empty(),
             DfValue::Numeric(ref d) => !d.is_zero(),
             DfValue::BitVector(ref bits) => !bits.is_empty(),
+            DfValue::Interval(ref i) => **i != Interval::default(),
             // Truthiness only matters for mysql, and mysql doesn't have arrays, so we can kind of
             // pick whatever we want here - but it makes the most sense to try to limit falsiness to
             // only the things that mysql considers falsey
@@ -385,6 +395,7 @@ impl DfValue {
             Self::ByteArray(_) => Some(ByteArray),
             Self::Numeric(_) => Some(Numeric(None)),
             Self::BitVector(_) => Some(VarBit(None)),
+            Self::Interval(_) => Some(Interval),
             // TODO: Once this returns DfType instead of SqlType, an empty array and an array of
             // null should be Array(Unknown) not Unknown.
             Self::Array(vs) => Some(SqlType::Array(Box::new(
@@ -420,6 +431,7 @@ impl DfValue {
             Self::ByteArray(_) => Blob,
             Self::Numeric(_) => DfType::DEFAULT_NUMERIC,
             Self::BitVector(_) => VarBit(None),
+            Self::Interval(_) => Interval,
             Self::Array(array) => Array(Box::new(
                 array
                     .values()
@@ -545,6 +557,13 @@ impl DfValue {
                     Err(mk_err())
                 }
             }
+            DfValue::Interval(i) => {
+                if let DfType::Text(collation) = to_ty {
+                    Ok(DfValue::from_str_and_collation(&i.to_string(), *collation))
+                } else {
+                    Err(mk_err())
+                }
+            }
             DfValue::BitVector(vec) => match to_ty {
                 DfType::VarBit(None) => Ok(self.clone()),
                 DfType::VarBit(max_size_opt) => match max_size_opt {
@@ -836,6 +855,7 @@ impl PartialEq for DfValue {
                 bits_a.as_ref() == bits_b.as_ref()
             }
             (DfValue::Array(vs_a), DfValue::Array(vs_b)) => vs_a == vs_b,
+            (DfValue::Interval(ia), DfValue::Interval(ib)) => ia == ib,
             (&DfValue::None, &DfValue::None) => true,
             (&DfValue::Max, &DfValue::Max) => true,
             _ => false,
@@ -988,6 +1008,7 @@ impl Ord for DfValue {
             (DfValue::ByteArray(array_a), DfValue::ByteArray(array_b)) => array_a.cmp(array_b),
             (DfValue::BitVector(bits_a), DfValue::BitVector(bits_b)) => bits_a.cmp(bits_b),
             (DfValue::Array(vs_a), DfValue::Array(vs_b)) => vs_a.cmp(vs_b),
+            (DfValue::Interval(ia), DfValue::Interval(ib)) => ia.cmp(ib),
 
             // for all other kinds of data types, just compare the variants in order
             (_, _) => DfValueKind::from(self).cmp(&DfValueKind::from(other)),
@@ -1023,6 +1044,7 @@ impl Hash for DfValue {
             DfValue::BitVector(ref bits) => bits.hash(state),
             DfValue::Array(ref vs) => vs.hash(state),
             DfValue::PassThrough(ref p) => p.hash(state),
+            DfValue::Interval(ref i) => i.hash(state),
         }
     }
 }
@@ -1179,6 +1201,27 @@ impl<'a> TryFrom<&'a DfValue> for BitVec {
     }
 }
 
+impl From<Interval> for DfValue {
+    fn from(i: Interval) -> Self {
+        DfValue::Interval(Arc::new(i))
+    }
+}
+
+impl<'a> TryFrom<&'a DfValue> for Interval {
+    type Error = ReadySetError;
+
+    fn try_from(dt: &'a DfValue) -> Result<Self, Self::Error> {
+        match dt {
+            DfValue::Interval(ref i) => Ok(**i),
+            _ => Err(Self::Error::DfValueConversionError {
+                src_type: "DfValue".to_string(),
+                target_type: "Interval".to_string(),
+                details: "".to_string(),
+            }),
+        }
+    }
+}
+
 /// Booleans are represented as `u32`s which are equal to either 0 or 1
 impl From<bool> for DfValue {
     fn from(b: bool) -> Self {
@@ -1250,7 +1293,7 @@ impl TryFrom<DfValue> for Literal {
             DfValue::TimestampTz(_) => Ok(Literal::String(String::try_from(
                 value.coerce_to(&DfType::DEFAULT_TEXT, &DfType::Unknown)?,
             )?)),
-            DfValue::Time(_) => Ok(Literal::String(String::try_from(
+            DfValue::Time(_) | DfValue::Interval(_) => Ok(Literal::String(String::try_from(
                 value.coerce_to(&DfType::DEFAULT_TEXT, &DfType::Unknown)?,
             )?)),
             DfValue::ByteArray(ref array) => Ok(Literal::ByteArray(array.as_ref().clone())),
@@ -1769,6 +1812,7 @@ impl ToSql for DfValue {
             (Self::BitVector(ref bits), _) => bits.as_ref().to_sql(ty, out),
             (Self::Array(ref array), _) => array.as_ref().to_sql(ty, out),
             (Self::PassThrough(p), _) => p.data.as_ref().to_sql(&p.ty, out),
+            (Self::Interval(i), _) => i.to_sql(ty, out),
         }
     }
 
@@ -1829,6 +1873,7 @@ impl<'a> FromSql<'a> for DfValue {
                 Type::FLOAT8 => mk_from_sql!(f64),
                 Type::DATE => mk_from_sql!(NaiveDate),
                 Type::TIME => mk_from_sql!(NaiveTime),
+                Type::INTERVAL => mk_from_sql!(Interval),
                 Type::BYTEA => mk_from_sql!(Vec<u8>),
                 Type::NUMERIC => {
                     // rust-decimal has a bug whereby it will successfully deserialize from the
@@ -1957,6 +2002,7 @@ impl TryFrom<&DfValue> for mysql_common::value::Value {
             }
             DfValue::BitVector(_) => internal!("MySQL does not support bit vector types"),
             DfValue::Array(_) => internal!("MySQL does not support array types"),
+            DfValue::Interval(_) => internal!("MySQL does not support interval types"),
         }
     }
 }
@@ -2066,7 +2112,14 @@ impl<'a, 'b> Add<&'b DfValue> for &'a DfValue {
     type Output = ReadySetResult<DfValue>;
 
     fn add(self, other: &'b DfValue) -> Self::Output {
-        Ok(arithmetic_operation!(+, checked_add, self, other))
+        match (self, other) {
+            (DfValue::TimestampTz(ts), DfValue::Interval(i))
+            | (DfValue::Interval(i), DfValue::TimestampTz(ts)) => Ok(ts
+                .checked_add_interval(i)
+                .map_or(DfValue::None, DfValue::TimestampTz)),
+            (DfValue::Interval(a), DfValue::Interval(b)) => Ok(a.checked_add(b).into()),
+            _ => Ok(arithmetic_operation!(+, checked_add, self, other)),
+        }
     }
 }
 
@@ -2074,7 +2127,14 @@ impl<'a, 'b> Sub<&'b DfValue> for &'a DfValue {
     type Output = ReadySetResult<DfValue>;
 
     fn sub(self, other: &'b DfValue) -> Self::Output {
-        Ok(arithmetic_operation!(-, checked_sub, self, other))
+        match (self, other) {
+            (DfValue::TimestampTz(ts), DfValue::Interval(i)) => Ok(i
+                .checked_neg()
+                .and_then(|i| ts.checked_add_interval(&i))
+                .map_or(DfValue::None, DfValue::TimestampTz)),
+            (DfValue::Interval(a), DfValue::Interval(b)) => Ok(a.checked_sub(b).into()),
+            _ => Ok(arithmetic_operation!(-, checked_sub, self, other)),
+        }
     }
 }
 
@@ -2094,6 +2154,14 @@ impl<'a, 'b> Div<&'b DfValue> for &'a DfValue {
     }
 }
 
+impl<'a, 'b> Rem<&'b DfValue> for &'a DfValue {
+    type Output = ReadySetResult<DfValue>;
+
+    fn rem(self, other: &'b DfValue) -> Self::Output {
+        Ok(arithmetic_operation!(%, checked_rem, self, other))
+    }
+}
+
 impl Arbitrary for DfValue {
     type Parameters = Option<DfValueKind>;
     type Strategy = proptest::strategy::BoxedStrategy<DfValue>;
@@ -2126,6 +2194,7 @@ impl Arbitrary for DfValue {
                 .prop_map(|bs| DfValue::BitVector(Arc::new(BitVec::from_bytes(&bs))))
                 .boxed(),
             Some(DfValueKind::Array) => any::<Array>().prop_map(DfValue::from).boxed(),
+            Some(DfValueKind::Interval) => any::<Interval>().prop_map(DfValue::from).boxed(),
             Some(DfValueKind::PassThrough) => any::<(u32, Vec<u8>)>()
                 .prop_map(|(oid, data)| {
                     DfValue::PassThrough(Arc::new(PassThrough {
@@ -2568,6 +2637,53 @@ mod tests {
         assert_eq!((&DfValue::Int(4) / &DfValue::from(2)).unwrap(), 2.into());
     }
 
+    #[test]
+    fn remainder_data_types() {
+        assert_arithmetic!(%, 7, 2, 1);
+        assert_arithmetic!(%, -7, 2, -1);
+        assert_arithmetic!(%, 7.5_f32, 2, 1.5_f32);
+        assert_arithmetic!(%, 7.5_f64, 2, 1.5_f64);
+        assert_arithmetic!(%, Decimal::new(75, 1), 2, Decimal::new(15, 1));
+        assert_arithmetic!(%, 7, Decimal::new(25, 1), Decimal::new(20, 1));
+        assert_arithmetic!(%, 3.5_f64, Decimal::new(20, 1), Decimal::new(15, 1));
+        assert_arithmetic!(%, 1, 0, None::<i64>);
+        assert_arithmetic!(%, Decimal::ONE, Decimal::ZERO, None::<Decimal>);
+        assert_eq!((&DfValue::Int(5) % &DfValue::from(3)).unwrap(), 2.into());
+    }
+
+    #[test]
+    fn interval_arithmetic() {
+        let ts = DfValue::from(NaiveDate::from_ymd(2023, 1, 31).and_hms(12, 0, 0));
+        let month = DfValue::from(Interval::new(1, 0, 0));
+        let hour = DfValue::from(Interval::new(0, 0, 3_600_000_000));
+        assert_eq!(
+            (&ts + &month).unwrap(),
+            DfValue::from(NaiveDate::from_ymd(2023, 2, 28).and_hms(12, 0, 0))
+        );
+        assert_eq!(
+            (&hour + &ts).unwrap(),
+            DfValue::from(NaiveDate::from_ymd(2023, 1, 31).and_hms(13, 0, 0))
+        );
+        assert_eq!(
+            (&ts - &hour).unwrap(),
+            DfValue::from(NaiveDate::from_ymd(2023, 1, 31).and_hms(11, 0, 0))
+        );
+        assert_eq!(
+            (&month + &hour).unwrap(),
+            DfValue::from(Interval::new(1, 0, 3_600_000_000))
+        );
+        assert_eq!(
+            (&month - &hour).unwrap(),
+            DfValue::from(Interval::new(1, 0, -3_600_000_000))
+        );
+
+        let date = DfValue::from(NaiveDate::from_ymd(2023, 1, 31));
+        assert_eq!((&date + &month).unwrap().to_string(), "2023-02-28");
+        assert_eq!((&date + &hour).unwrap().to_string(), "2023-01-31 01:00:00");
+
+        (&hour - &ts).unwrap_err();
+    }
+
     #[test]
     fn invalid_arithmetic_returns_error() {
         (&DfValue::from(0) + &DfValue::from("abc")).unwrap_err();
@@ -510,6 +510,11 @@ pub(crate) trait TextCoerce: Sized + Clone + Into<DfValue> {
                 }
             }
 
+            DfType::Interval => str
+                .parse::<crate::Interval>()
+                .map_err(|e| Self::coerce_err(to_ty, e))
+                .map(DfValue::from),
+
             DfType::Time { .. } => match str.parse::<mysql_time::MySqlTime>() {
                 Ok(t) => Ok(DfValue::Time(t)),
                 Err(mysql_time::ConvertError::ParseError) => Ok(DfValue::Time(Default::default())),
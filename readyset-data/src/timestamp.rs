@@ -7,7 +7,7 @@ use proptest::arbitrary::Arbitrary;
 use readyset_errors::{ReadySetError, ReadySetResult};
 use serde::{Deserialize, Serialize};
 
-use crate::{DfType, DfValue};
+use crate::{DfType, DfValue, Interval};
 
 /// The format for timestamps when parsed as text
 pub const TIMESTAMP_PARSE_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.f";
@@ -76,7 +76,7 @@ impl TimestampTz {
 
     /// Returns true if should be displayed as date only
     #[inline(always)]
-    fn has_date_only(&self) -> bool {
+    pub fn has_date_only(&self) -> bool {
         self.extra[2] & TimestampTz::DATE_FLAG != 0
     }
 
@@ -256,6 +256,22 @@ impl TimestampTz {
         self.into()
     }
 
+    /// Adds the given [`Interval`] to this timestamp, returning [`None`] if the result is out of
+    /// range.
+    ///
+    /// For timestamps with a time zone, the interval is added to the local time. Date-only values
+    /// stay dates unless the interval has a time component.
+    pub fn checked_add_interval(&self, interval: &Interval) -> Option<TimestampTz> {
+        let local = interval.checked_add_to(self.to_chrono().naive_local())?;
+        let mut ts = *self;
+        ts.datetime =
+            local.checked_sub_signed(chrono::Duration::seconds(self.get_offset().into()))?;
+        if interval.microseconds() != 0 {
+            ts.extra[2] &= !TimestampTz::DATE_FLAG;
+        }
+        Some(ts)
+    }
+
     // MySQL can cast a timestamp into a signed/unsigned integer
     // where the fields up to seconds are decimal digits. i.e.
     // +--------------------------------------------------------------+
@@ -405,6 +421,7 @@ impl TimestampTz {
             | DfType::MacAddr
             | DfType::Inet
             | DfType::Uuid
+            | DfType::Interval
             | DfType::Bit(_)
             | DfType::VarBit(_)
             | DfType::Array(_) => Err(ReadySetError::DfValueConversionError {
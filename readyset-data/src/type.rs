@@ -160,6 +160,9 @@ pub enum DfType {
     /// [PostgreSQL `timestamptz`/`timestamp with timezone`](https://www.postgresql.org/docs/current/datatype-datetime.html).
     TimestampTz { subsecond_digits: u16 },
 
+    /// [PostgreSQL `interval`](https://www.postgresql.org/docs/current/datatype-datetime.html).
+    Interval,
+
     /// [PostgreSQL `macaddr`](https://www.postgresql.org/docs/current/datatype-net-types.html).
     MacAddr,
 
@@ -297,6 +300,7 @@ impl DfType {
             TimestampTz => Self::TimestampTz {
                 subsecond_digits: dialect.default_subsecond_digits(),
             },
+            Interval => Self::Interval,
 
             Uuid => Self::Uuid,
             MacAddr => Self::MacAddr,
@@ -351,6 +355,7 @@ impl DfType {
             | DfType::Time { .. }
             | DfType::Timestamp { .. }
             | DfType::TimestampTz { .. } => PgTypeCategory::DateTime,
+            DfType::Interval => PgTypeCategory::Timespan,
             DfType::MacAddr | DfType::Inet => PgTypeCategory::NetworkAddress,
             DfType::Uuid | DfType::Enum { .. } | DfType::Json | DfType::Jsonb => {
                 PgTypeCategory::UserDefined
@@ -457,6 +462,24 @@ impl DfType {
         )
     }
 
+    /// Returns `true` if this is any unsigned `*int` type.
+    #[inline]
+    pub fn is_any_unsigned_int(&self) -> bool {
+        matches!(
+            *self,
+            Self::UnsignedTinyInt
+                | Self::UnsignedSmallInt
+                | Self::UnsignedInt
+                | Self::UnsignedBigInt
+        )
+    }
+
+    /// Returns `true` if this is any bit string type, either `bit` or `varbit`.
+    #[inline]
+    pub fn is_any_bit_string(&self) -> bool {
+        matches!(self, Self::Bit(_) | Self::VarBit(_))
+    }
+
     /// Returns `true` if this is any `text` type
     #[inline]
     pub fn is_any_text(&self) -> bool {
@@ -608,6 +631,7 @@ impl fmt::Display for DfType {
             | Self::Blob
             | Self::VarBit(None)
             | Self::Date
+            | Self::Interval
             | Self::Inet
             | Self::MacAddr
             | Self::Uuid
@@ -3,11 +3,14 @@ use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 
+use nom_sql::Double;
 pub use nom_sql::{BinaryOperator, Literal, SqlType};
-use readyset_data::{Collation, DfType};
+use readyset_data::dialect::SqlEngine;
+use readyset_data::{Collation, DfType, Dialect};
 use readyset_errors::{invariant, ReadySetResult};
 use serde::{Deserialize, Serialize};
 
+use crate::ops::grouped::sketch::{HyperLogLog, QuantileSketch};
 use crate::ops::grouped::{GroupedOperation, GroupedOperator};
 use crate::prelude::*;
 
@@ -20,6 +23,37 @@ pub enum Aggregation {
     Sum,
     /// Average the value of the `over` column. Maintains count and sum in HashMap
     Avg,
+    /// Standard deviation of the value of the `over` column. Maintains count, sum and sum of
+    /// squares in HashMap. Computes the sample standard deviation if `sample` is true, and the
+    /// population standard deviation otherwise.
+    StdDev { sample: bool },
+    /// Variance of the value of the `over` column. Maintains count, sum and sum of squares in
+    /// HashMap. Computes the sample variance if `sample` is true, and the population variance
+    /// otherwise.
+    Variance { sample: bool },
+    /// Returns true if all the non-null values of the `over` column are truthy. Maintains
+    /// per-group counts in HashMap, so that retractions can be handled.
+    BoolAnd,
+    /// Returns true if any of the non-null values of the `over` column are truthy. Maintains
+    /// per-group counts in HashMap, so that retractions can be handled.
+    BoolOr,
+    /// Bitwise AND of the non-null values of the `over` column. If there are none, returns all
+    /// bits set in MySQL, and NULL in PostgreSQL. Maintains per-bit counts in HashMap, so that
+    /// retractions can be handled.
+    BitAnd,
+    /// Bitwise OR of the non-null values of the `over` column. If there are none, returns 0 in
+    /// MySQL, and NULL in PostgreSQL. Maintains per-bit counts in HashMap, so that retractions can
+    /// be handled.
+    BitOr,
+    /// Bitwise XOR of the non-null values of the `over` column. If there are none, returns 0 in
+    /// MySQL, and NULL in PostgreSQL. Maintains per-bit counts in HashMap.
+    BitXor,
+    /// Approximate number of distinct non-null values of the `over` column. Maintains a
+    /// bounded-size HyperLogLog sketch in HashMap, rather than all the distinct values.
+    ApproxCountDistinct,
+    /// Approximate value of the `over` column at the given percentile (between 0 and 1).
+    /// Maintains a bounded-size quantile sketch in HashMap, rather than all the values.
+    ApproxPercentile { percentile: Double },
     /// Concatenates using the given separator between values.
     GroupConcat { separator: String },
 }
@@ -29,16 +63,18 @@ impl Aggregation {
     ///
     /// The aggregation will aggregate the value in column number `over` from its inputs (i.e.,
     /// from the `src` node in the graph), and use the columns in the `group_by` array as a group
-    /// identifier.
+    /// identifier. The `dialect` determines the semantics of aggregates which differ between
+    /// databases.
     pub fn over(
         self,
         src: NodeIndex,
         over: usize,
         group_by: &[usize],
         over_col_ty: &DfType,
+        dialect: Dialect,
     ) -> ReadySetResult<GroupedOperator<Aggregator>> {
         let out_ty = match &self {
-            Aggregation::Count { .. } => DfType::BigInt,
+            Aggregation::Count { .. } | Aggregation::ApproxCountDistinct => DfType::BigInt,
             // The SUM() and AVG() functions return a DECIMAL value for exact-value arguments
             // (integer or DECIMAL), and a DOUBLE value for approximate-value arguments (FLOAT or
             // DOUBLE).
@@ -49,6 +85,19 @@ impl Aggregation {
                     DfType::DEFAULT_NUMERIC
                 }
             }
+            Aggregation::StdDev { .. }
+            | Aggregation::Variance { .. }
+            | Aggregation::ApproxPercentile { .. } => DfType::Double,
+            Aggregation::BoolAnd | Aggregation::BoolOr => DfType::Bool,
+            // Bitwise aggregates are computed over 64-bit integers. MySQL always returns them as
+            // unsigned integers, whereas PostgreSQL returns the integer type of the argument
+            Aggregation::BitAnd | Aggregation::BitOr | Aggregation::BitXor => {
+                match dialect.engine() {
+                    SqlEngine::MySQL => DfType::UnsignedBigInt,
+                    SqlEngine::PostgreSQL if over_col_ty.is_any_int() => over_col_ty.clone(),
+                    SqlEngine::PostgreSQL => DfType::BigInt,
+                }
+            }
             Aggregation::GroupConcat { .. } => DfType::Text(/* TODO */ Collation::default()),
         };
 
@@ -59,8 +108,12 @@ impl Aggregation {
                 over,
                 group: group_by.into(),
                 count_sum_map: RefCell::new(Default::default()),
+                variance_map: RefCell::new(Default::default()),
+                bit_counts_map: RefCell::new(Default::default()),
+                sketch_map: RefCell::new(Default::default()),
                 over_else: None,
                 out_ty,
+                dialect,
             },
         ))
     }
@@ -87,9 +140,20 @@ pub struct Aggregator {
     // We skip serde since we don't want the state, just the configuration.
     #[serde(skip)]
     count_sum_map: RefCell<HashMap<GroupHash, AverageDataPair>>,
+    // only needed for STDDEV and VARIANCE. Stores count, sum and sum of squares.
+    #[serde(skip)]
+    variance_map: RefCell<HashMap<GroupHash, VarianceData>>,
+    // only needed for the boolean and bitwise aggregates. Stores per-bit counts.
+    #[serde(skip)]
+    bit_counts_map: RefCell<HashMap<GroupHash, BitCounts>>,
+    // only needed for the approximate aggregates.
+    #[serde(skip)]
+    sketch_map: RefCell<HashMap<GroupHash, Sketch>>,
     over_else: Option<Literal>,
     // Output type of this column
     out_ty: DfType,
+    // Dialect whose semantics to use, for aggregates where they differ
+    dialect: Dialect,
 }
 
 /// Diff type for numerical aggregations.
@@ -130,6 +194,108 @@ impl AverageDataPair {
     }
 }
 
+/// For storing (Count, Sum, Sum of squares) in additional state for StdDev and Variance.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct VarianceData {
+    count: u64,
+    sum: f64,
+    sum_of_squares: f64,
+}
+
+impl VarianceData {
+    /// Apply the given diff, and return the new variance, or `None` if the variance is undefined
+    /// for the current number of values
+    fn apply_diff(&mut self, d: NumericalDiff, sample: bool) -> ReadySetResult<Option<f64>> {
+        let value = f64::try_from(&d.value)?;
+        if d.positive {
+            self.count += 1;
+            self.sum += value;
+            self.sum_of_squares += value * value;
+        } else {
+            self.count = self.count.saturating_sub(1);
+            self.sum -= value;
+            self.sum_of_squares -= value * value;
+        }
+
+        let degrees_of_freedom = if sample {
+            self.count.saturating_sub(1)
+        } else {
+            self.count
+        };
+        if degrees_of_freedom == 0 {
+            return Ok(None);
+        }
+
+        let mean = self.sum / self.count as f64;
+        // Floating point error can make this very slightly negative when all values are equal
+        let variance =
+            ((self.sum_of_squares - self.sum * mean) / degrees_of_freedom as f64).max(0.0);
+        Ok(Some(variance))
+    }
+}
+
+/// For storing the number of values, and the number of values with each bit set, in additional
+/// state for the boolean and bitwise aggregates. Boolean values are stored as a single bit.
+#[derive(Debug, Clone)]
+struct BitCounts {
+    count: u64,
+    set: [u64; 64],
+}
+
+impl Default for BitCounts {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            set: [0; 64],
+        }
+    }
+}
+
+impl BitCounts {
+    /// Apply a diff with the given bits, and return the new bits for the given aggregation, or
+    /// `None` if there are no values left
+    fn apply_diff(&mut self, bits: u64, positive: bool, op: &Aggregation) -> Option<u64> {
+        for (bit, count) in self.set.iter_mut().enumerate() {
+            if bits & (1 << bit) != 0 {
+                if positive {
+                    *count += 1;
+                } else {
+                    *count = count.saturating_sub(1);
+                }
+            }
+        }
+        if positive {
+            self.count += 1;
+        } else {
+            self.count = self.count.saturating_sub(1);
+        }
+
+        if self.count == 0 {
+            return None;
+        }
+
+        let bit_is_set = |count: u64| match op {
+            Aggregation::BoolAnd | Aggregation::BitAnd => count == self.count,
+            Aggregation::BitXor => count % 2 == 1,
+            _ => count > 0,
+        };
+        Some(
+            self.set
+                .iter()
+                .enumerate()
+                .filter(|(_, count)| bit_is_set(**count))
+                .fold(0, |bits, (bit, _)| bits | (1 << bit)),
+        )
+    }
+}
+
+/// Sketch stored in additional state for the approximate aggregates
+#[derive(Debug, Clone)]
+enum Sketch {
+    Distinct(HyperLogLog),
+    Quantile(QuantileSketch),
+}
+
 impl Aggregator {
     fn group_hash(&self, rec: &[DfValue]) -> GroupHash {
         let mut hasher = DefaultHasher::new();
@@ -143,7 +309,35 @@ impl Aggregator {
         hasher.finish()
     }
 
+    /// Returns the value of the bitwise aggregates when there are no non-null values, which is the
+    /// identity of the bitwise operation in MySQL, and NULL in PostgreSQL
+    fn empty_bits(&self) -> Option<DfValue> {
+        match self.op {
+            Aggregation::BitAnd | Aggregation::BitOr | Aggregation::BitXor
+                if self.dialect.engine() == SqlEngine::PostgreSQL =>
+            {
+                Some(DfValue::None)
+            }
+            Aggregation::BitAnd => Some(DfValue::UnsignedInt(u64::MAX)),
+            Aggregation::BitOr | Aggregation::BitXor => Some(DfValue::UnsignedInt(0)),
+            _ => None,
+        }
+    }
+
     fn new_data(&self) -> ReadySetResult<DfValue> {
+        if let Some(bits) = self.empty_bits() {
+            return Ok(bits);
+        }
+
+        if matches!(
+            self.op,
+            Aggregation::BoolAnd | Aggregation::BoolOr | Aggregation::ApproxPercentile { .. }
+        ) {
+            // These are computed entirely from their additional state, so they're NULL until a
+            // non-null value is seen
+            return Ok(DfValue::None);
+        }
+
         match &self.out_ty {
             DfType::BigInt => Ok(DfValue::Int(Default::default())),
             DfType::Double => Ok(DfValue::Double(Default::default())),
@@ -215,6 +409,66 @@ impl GroupedOperation for Aggregator {
                 .apply_diff(diff)
         };
 
+        let apply_variance =
+            |_curr, diff: Self::Diff, sample: bool, stddev: bool| -> ReadySetResult<DfValue> {
+                let variance = self
+                    .variance_map
+                    .borrow_mut()
+                    .entry(diff.group_hash)
+                    .or_default()
+                    .apply_diff(diff, sample)?;
+                Ok(match variance {
+                    Some(variance) if stddev => DfValue::Double(variance.sqrt()),
+                    Some(variance) => DfValue::Double(variance),
+                    None => DfValue::None,
+                })
+            };
+
+        let apply_bits = |_curr, diff: Self::Diff| -> ReadySetResult<DfValue> {
+            let bits = match (&self.op, &diff.value) {
+                (Aggregation::BoolAnd | Aggregation::BoolOr, v) => v.is_truthy() as u64,
+                (_, DfValue::Int(v)) => *v as u64,
+                (_, DfValue::UnsignedInt(v)) => *v,
+                (_, v) => u64::try_from(v.coerce_to(&DfType::UnsignedBigInt, &DfType::Unknown)?)?,
+            };
+            let mut bit_counts_map = self.bit_counts_map.borrow_mut();
+            let bit_counts = bit_counts_map.entry(diff.group_hash).or_default();
+            let res = bit_counts.apply_diff(bits, diff.positive, &self.op);
+            if bit_counts.count == 0 {
+                bit_counts_map.remove(&diff.group_hash);
+            }
+            Ok(match (res, &self.out_ty) {
+                (None, _) => self.empty_bits().unwrap_or(DfValue::None),
+                (Some(bits), DfType::Bool) => (bits != 0).into(),
+                (Some(bits), DfType::UnsignedBigInt) => DfValue::UnsignedInt(bits),
+                // Signed arguments are sign-extended to 64 bits, so the result is too
+                (Some(bits), _) => DfValue::Int(bits as i64),
+            })
+        };
+
+        let apply_sketch = |_curr, diff: Self::Diff| -> ReadySetResult<DfValue> {
+            let mut sketches = self.sketch_map.borrow_mut();
+            let sketch = sketches
+                .entry(diff.group_hash)
+                .or_insert_with(|| match self.op {
+                    Aggregation::ApproxPercentile { .. } => Sketch::Quantile(Default::default()),
+                    _ => Sketch::Distinct(Default::default()),
+                });
+            Ok(match (sketch, &self.op) {
+                (Sketch::Distinct(hll), _) => {
+                    hll.apply(&diff.value, diff.positive);
+                    DfValue::Int(hll.estimate() as i64)
+                }
+                (Sketch::Quantile(sketch), Aggregation::ApproxPercentile { percentile }) => {
+                    sketch.apply(f64::try_from(&diff.value)?, diff.positive);
+                    sketch
+                        .quantile(percentile.value)
+                        .map_or(DfValue::None, DfValue::Double)
+                }
+                (Sketch::Quantile(_), _) => internal!("Quantile sketch for {:?}", self.op),
+            })
+        };
+
         let apply_diff =
             |curr: ReadySetResult<DfValue>, diff: Self::Diff| -> ReadySetResult<DfValue> {
                 if diff.value.is_none() {
@@ -225,6 +479,16 @@ impl GroupedOperation for Aggregator {
                     Aggregation::Count { .. } => apply_count(curr?, diff),
                     Aggregation::Sum => apply_sum(curr?, diff),
                     Aggregation::Avg => apply_avg(curr?, diff),
+                    Aggregation::StdDev { sample } => apply_variance(curr?, diff, sample, true),
+                    Aggregation::Variance { sample } => apply_variance(curr?, diff, sample, false),
+                    Aggregation::BoolAnd
+                    | Aggregation::BoolOr
+                    | Aggregation::BitAnd
+                    | Aggregation::BitOr
+                    | Aggregation::BitXor => apply_bits(curr?, diff),
+                    Aggregation::ApproxCountDistinct | Aggregation::ApproxPercentile { .. } => {
+                        apply_sketch(curr?, diff)
+                    }
                     Aggregation::GroupConcat { separator: _ } => internal!(
                         "GroupConcats are separate from the other aggregations in the dataflow."
                     ),
@@ -242,6 +506,15 @@ impl GroupedOperation for Aggregator {
                 Aggregation::Count { .. } => "+".to_owned(),
                 Aggregation::Sum => "𝛴".to_owned(),
                 Aggregation::Avg => "Avg".to_owned(),
+                Aggregation::StdDev { .. } => "StdDev".to_owned(),
+                Aggregation::Variance { .. } => "Var".to_owned(),
+                Aggregation::BoolAnd => "BoolAnd".to_owned(),
+                Aggregation::BoolOr => "BoolOr".to_owned(),
+                Aggregation::BitAnd => "&".to_owned(),
+                Aggregation::BitOr => "|".to_owned(),
+                Aggregation::BitXor => "^".to_owned(),
+                Aggregation::ApproxCountDistinct => "~|*|".to_owned(),
+                Aggregation::ApproxPercentile { .. } => "~%".to_owned(),
                 Aggregation::GroupConcat { separator: ref s } => {
                     format!("||({})", s)
                 }
@@ -252,6 +525,19 @@ impl GroupedOperation for Aggregator {
             Aggregation::Count { .. } => "|*|".to_owned(),
             Aggregation::Sum => format!("𝛴({})", self.over),
             Aggregation::Avg => format!("Avg({})", self.over),
+            Aggregation::StdDev { sample: true } => format!("StdDevSamp({})", self.over),
+            Aggregation::StdDev { sample: false } => format!("StdDevPop({})", self.over),
+            Aggregation::Variance { sample: true } => format!("VarSamp({})", self.over),
+            Aggregation::Variance { sample: false } => format!("VarPop({})", self.over),
+            Aggregation::BoolAnd => format!("BoolAnd({})", self.over),
+            Aggregation::BoolOr => format!("BoolOr({})", self.over),
+            Aggregation::BitAnd => format!("&({})", self.over),
+            Aggregation::BitOr => format!("|({})", self.over),
+            Aggregation::BitXor => format!("^({})", self.over),
+            Aggregation::ApproxCountDistinct => format!("~|{}|", self.over),
+            Aggregation::ApproxPercentile { ref percentile } => {
+                format!("~%({}, {})", self.over, percentile.value)
+            }
             Aggregation::GroupConcat { separator: ref s } => format!("||({}, {})", s, self.over),
         };
         let group_cols = self
@@ -273,16 +559,20 @@ impl GroupedOperation for Aggregator {
 
     fn empty_value(&self) -> Option<DfValue> {
         match self.op {
-            Aggregation::Count { .. } => Some(0.into()),
+            Aggregation::Count { .. } | Aggregation::ApproxCountDistinct => Some(0.into()),
+            Aggregation::BitAnd | Aggregation::BitOr | Aggregation::BitXor => self.empty_bits(),
             _ => None,
         }
     }
 
     fn emit_empty(&self) -> bool {
         match self.op {
-            Aggregation::Count { .. } | Aggregation::GroupConcat { .. } => {
-                self.group_by().is_empty()
-            }
+            Aggregation::Count { .. }
+            | Aggregation::ApproxCountDistinct
+            | Aggregation::BitAnd
+            | Aggregation::BitOr
+            | Aggregation::BitXor
+            | Aggregation::GroupConcat { .. } => self.group_by().is_empty(),
             _ => false,
         }
     }
@@ -305,7 +595,13 @@ mod tests {
             "identity",
             &["x", "ys"],
             aggregation
-                .over(s.as_global(), 1, &[0], &DfType::Double)
+                .over(
+                    s.as_global(),
+                    1,
+                    &[0],
+                    &DfType::Double,
+                    Dialect::DEFAULT_MYSQL,
+                )
                 .unwrap(),
             mat,
         );
@@ -319,7 +615,13 @@ mod tests {
             "identity",
             &["x", "z", "ys"],
             aggregation
-                .over(s.as_global(), 1, &[0, 2], &DfType::Double)
+                .over(
+                    s.as_global(),
+                    1,
+                    &[0, 2],
+                    &DfType::Double,
+                    Dialect::DEFAULT_MYSQL,
+                )
                 .unwrap(),
             mat,
         );
@@ -331,19 +633,24 @@ mod tests {
         let src = 0.into();
 
         let c = Aggregation::Count
-            .over(src, 1, &[0, 2], &DfType::Unknown)
+            .over(src, 1, &[0, 2], &DfType::Unknown, Dialect::DEFAULT_MYSQL)
             .unwrap();
         assert_eq!(c.description(true), "|*| γ[0, 2]");
 
         let s = Aggregation::Sum
-            .over(src, 1, &[2, 0], &DfType::Unknown)
+            .over(src, 1, &[2, 0], &DfType::Unknown, Dialect::DEFAULT_MYSQL)
             .unwrap();
         assert_eq!(s.description(true), "𝛴(1) γ[2, 0]");
 
         let a = Aggregation::Avg
-            .over(src, 1, &[2, 0], &DfType::Unknown)
+            .over(src, 1, &[2, 0], &DfType::Unknown, Dialect::DEFAULT_MYSQL)
             .unwrap();
         assert_eq!(a.description(true), "Avg(1) γ[2, 0]");
+
+        let v = Aggregation::Variance { sample: true }
+            .over(src, 1, &[0], &DfType::Unknown, Dialect::DEFAULT_MYSQL)
+            .unwrap();
+        assert_eq!(v.description(true), "VarSamp(1) γ[0]");
     }
 
     /// Testing count emits correct records with single column group and single over column
@@ -1116,4 +1423,307 @@ mod tests {
             .into()
         );
     }
+
+    #[test]
+    fn stddev_and_variance() {
+        let values = [2, 4, 4, 4, 5, 5, 7, 9];
+        let rows = || {
+            values
+                .iter()
+                .map(|v| (vec![1.into(), (*v).into()], true))
+                .collect::<Vec<_>>()
+        };
+
+        for (aggregation, expected) in [
+            (Aggregation::Variance { sample: false }, 4.0),
+            (Aggregation::Variance { sample: true }, 32.0 / 7.0),
+            (Aggregation::StdDev { sample: false }, 2.0),
+            (Aggregation::StdDev { sample: true }, (32.0f64 / 7.0).sqrt()),
+        ] {
+            let mut c = setup(aggregation.clone(), true);
+            let out = c.narrow_one(rows(), true);
+            assert_eq!(
+                out,
+                vec![vec![1.into(), DfValue::Double(expected), 8.into()]].into(),
+                "{aggregation:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn sample_variance_of_one_value() {
+        let mut c = setup(Aggregation::Variance { sample: true }, true);
+        let out = c.narrow_one_row(vec![1.into(), 3.into()], true);
+        assert_eq!(out, vec![vec![1.into(), DfValue::None, 1.into()]].into());
+
+        let out = c.narrow_one_row(vec![1.into(), 5.into()], true);
+        assert_eq!(
+            out,
+            vec![
+                (vec![1.into(), DfValue::None, 1.into()], false),
+                (vec![1.into(), DfValue::Double(2.0), 2.into()], true)
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn bool_or_retractions() {
+        let mut c = setup(Aggregation::BoolOr, true);
+        let out = c.narrow_one_row(vec![1.into(), 0.into()], true);
+        assert_eq!(out, vec![vec![1.into(), false.into(), 1.into()]].into());
+
+        let out = c.narrow_one_row(vec![1.into(), 1.into()], true);
+        assert_eq!(
+            out,
+            vec![
+                (vec![1.into(), false.into(), 1.into()], false),
+                (vec![1.into(), true.into(), 2.into()], true)
+            ]
+            .into()
+        );
+
+        // Retracting the only true value makes the result false again
+        let out = c.narrow_one_row((vec![1.into(), 1.into()], false), true);
+        assert_eq!(
+            out,
+            vec![
+                (vec![1.into(), true.into(), 2.into()], false),
+                (vec![1.into(), false.into(), 1.into()], true)
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn bool_and() {
+        let mut c = setup(Aggregation::BoolAnd, true);
+        let out = c.narrow_one(
+            vec![
+                (vec![1.into(), 1.into()], true),
+                (vec![1.into(), 1.into()], true),
+                (vec![2.into(), 1.into()], true),
+                (vec![2.into(), 0.into()], true),
+            ],
+            true,
+        );
+        assert!(out.has_positive(&[1.into(), true.into(), 2.into()][..]));
+        assert!(out.has_positive(&[2.into(), false.into(), 2.into()][..]));
+    }
+
+    #[test]
+    fn bitwise_retractions() {
+        for (aggregation, expected, after_retraction) in [
+            (Aggregation::BitOr, 0b1110u64, 0b0110u64),
+            (Aggregation::BitAnd, 0b0100, 0b0110),
+            (Aggregation::BitXor, 0b1010, 0b0010),
+        ] {
+            let mut c = setup(aggregation.clone(), true);
+            let out = c.narrow_one(
+                vec![
+                    (vec![1.into(), 0b0110.into()], true),
+                    (vec![1.into(), 0b1100.into()], true),
+                ],
+                true,
+            );
+            assert_eq!(
+                out,
+                vec![vec![1.into(), DfValue::UnsignedInt(expected), 2.into()]].into(),
+                "{aggregation:?}"
+            );
+
+            let out = c.narrow_one_row((vec![1.into(), 0b1100.into()], false), true);
+            assert!(
+                out.has_positive(&[1.into(), DfValue::UnsignedInt(after_retraction), 1.into()][..]),
+                "{aggregation:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn bitwise_all_null_group() {
+        for (aggregation, expected) in [
+            (Aggregation::BitAnd, u64::MAX),
+            (Aggregation::BitOr, 0),
+            (Aggregation::BitXor, 0),
+        ] {
+            let mut c = setup(aggregation.clone(), true);
+            let out = c.narrow_one_row(vec![1.into(), DfValue::None], true);
+            assert_eq!(
+                out,
+                vec![vec![1.into(), DfValue::UnsignedInt(expected), 1.into()]].into(),
+                "{aggregation:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn bitwise_empty_group() {
+        for (aggregation, expected) in [
+            (Aggregation::BitAnd, u64::MAX),
+            (Aggregation::BitOr, 0),
+            (Aggregation::BitXor, 0),
+        ] {
+            let op = aggregation
+                .clone()
+                .over(0.into(), 1, &[0], &DfType::BigInt, Dialect::DEFAULT_MYSQL)
+                .unwrap();
+            let row = vec![1.into(), 0b0110.into()];
+            let diffs = vec![
+                op.inner.to_diff(&row, true).unwrap(),
+                op.inner.to_diff(&row, false).unwrap(),
+            ];
+            let res = op.inner.apply(None, &mut diffs.into_iter()).unwrap();
+            assert_eq!(res, Some(DfValue::UnsignedInt(expected)), "{aggregation:?}");
+            // The state for the now-empty group is removed
+            assert!(
+                op.inner.bit_counts_map.borrow().is_empty(),
+                "{aggregation:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn bitwise_negative_values() {
+        for (aggregation, expected) in [
+            (Aggregation::BitAnd, 0b0110u64),
+            (Aggregation::BitOr, u64::MAX),
+            (Aggregation::BitXor, !0b0110u64),
+        ] {
+            let mut c = setup(aggregation.clone(), true);
+            let out = c.narrow_one(
+                vec![
+                    (vec![1.into(), (-1).into()], true),
+                    (vec![1.into(), 0b0110.into()], true),
+                ],
+                true,
+            );
+            assert_eq!(
+                out,
+                vec![vec![1.into(), DfValue::UnsignedInt(expected), 2.into()]].into(),
+                "{aggregation:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn bitwise_postgresql() {
+        for (aggregation, expected) in [
+            (Aggregation::BitAnd, 0b0110i64),
+            (Aggregation::BitOr, -1),
+            (Aggregation::BitXor, !0b0110i64),
+        ] {
+            let op = aggregation
+                .clone()
+                .over(0.into(), 1, &[0], &DfType::Int, Dialect::DEFAULT_POSTGRESQL)
+                .unwrap();
+            // PostgreSQL returns the type of the argument, and NULL for empty groups
+            assert_eq!(op.output_col_type(), DfType::Int, "{aggregation:?}");
+            assert_eq!(
+                op.inner.empty_value(),
+                Some(DfValue::None),
+                "{aggregation:?}"
+            );
+
+            let mut g = ops::test::MockGraph::new();
+            let s = g.add_base("source", &["x", "y"]);
+            g.set_op(
+                "identity",
+                &["x", "ys"],
+                aggregation
+                    .clone()
+                    .over(
+                        s.as_global(),
+                        1,
+                        &[0],
+                        &DfType::Int,
+                        Dialect::DEFAULT_POSTGRESQL,
+                    )
+                    .unwrap(),
+                true,
+            );
+            let out = g.narrow_one(
+                vec![
+                    (vec![1.into(), (-1).into()], true),
+                    (vec![1.into(), 0b0110.into()], true),
+                ],
+                true,
+            );
+            assert_eq!(
+                out,
+                vec![vec![1.into(), DfValue::Int(expected), 2.into()]].into(),
+                "{aggregation:?}"
+            );
+
+            let out = g.narrow_one_row(vec![2.into(), DfValue::None], true);
+            assert_eq!(
+                out,
+                vec![vec![2.into(), DfValue::None, 1.into()]].into(),
+                "{aggregation:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn approx_count_distinct() {
+        let mut c = setup(Aggregation::ApproxCountDistinct, true);
+        let out = c.narrow_one(
+            vec![
+                (vec![1.into(), 1.into()], true),
+                (vec![1.into(), 2.into()], true),
+                (vec![1.into(), 2.into()], true),
+                (vec![1.into(), 3.into()], true),
+            ],
+            true,
+        );
+        assert_eq!(out, vec![vec![1.into(), 3.into(), 4.into()]].into());
+
+        // Retracting one of the duplicates doesn't change the count
+        let out = c.narrow_one_row((vec![1.into(), 2.into()], false), true);
+        assert!(out.has_positive(&[1.into(), 3.into(), 3.into()][..]));
+
+        let out = c.narrow_one_row((vec![1.into(), 2.into()], false), true);
+        assert!(out.has_positive(&[1.into(), 2.into(), 2.into()][..]));
+    }
+
+    #[test]
+    fn approx_percentile() {
+        let median = |out: Records| -> f64 {
+            let rows = out
+                .into_iter()
+                .filter(|r| r.is_positive())
+                .map(|r| r.into_row())
+                .collect::<Vec<_>>();
+            assert_eq!(rows.len(), 1);
+            f64::try_from(&rows[0][1]).unwrap()
+        };
+
+        let mut c = setup(
+            Aggregation::ApproxPercentile {
+                percentile: Double {
+                    value: 0.5,
+                    precision: 1,
+                },
+            },
+            true,
+        );
+        let out = c.narrow_one(
+            (1..=5)
+                .map(|v| (vec![1.into(), v.into()], true))
+                .collect::<Vec<_>>(),
+            true,
+        );
+        let value = median(out);
+        assert!((value - 3.0).abs() <= 3.0 * 0.01, "{value}");
+
+        let out = c.narrow_one(
+            vec![
+                (vec![1.into(), 4.into()], false),
+                (vec![1.into(), 5.into()], false),
+            ],
+            true,
+        );
+        let value = median(out);
+        assert!((value - 2.0).abs() <= 2.0 * 0.01, "{value}");
+    }
 }
@@ -0,0 +1,420 @@
+//! Aggregates which collect all the values in a group into a single array or JSON value, such as
+//! `ARRAY_AGG`, `JSON_AGG`/`JSON_ARRAYAGG` and `JSON_OBJECT_AGG`/`JSON_OBJECTAGG`.
+
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use common::DfValue;
+use itertools::Itertools;
+use nom_sql::OrderType;
+use readyset_data::DfType;
+use readyset_errors::{invariant, invariant_eq};
+use readyset_util::Indices;
+use serde::{Deserialize, Serialize};
+use serde_json::{Number as JsonNumber, Value as JsonValue};
+
+use crate::node::Node;
+use crate::ops::grouped::{GroupedOperation, GroupedOperator};
+use crate::ops::utils::Order;
+use crate::prelude::*;
+
+/// The kind of value a [`Collect`] operator collects the values of a group into
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CollectKind {
+    /// An array of the values, as computed by `ARRAY_AGG`
+    Array,
+    /// The text of a JSON array of the values, as computed by `JSON_AGG` or `JSON_ARRAYAGG`
+    JsonArray,
+    /// The text of a JSON object mapping keys to values, as computed by `JSON_OBJECT_AGG` or
+    /// `JSON_OBJECTAGG`
+    JsonObject,
+}
+
+/// The last stored state for a given group.
+#[derive(Clone, Debug, Default)]
+struct LastState {
+    /// The value we last emitted for this group.
+    output: DfValue,
+    /// The collected items for this group, kept in order. Each item consists of the values of the
+    /// `order_by` columns, followed by the collected value, followed by the key for
+    /// [`CollectKind::JsonObject`].
+    items: Vec<Vec<DfValue>>,
+}
+
+/// `Collect` implements the aggregate functions which collect all the values of a column within a
+/// group into a single array or JSON value, optionally ordered by a set of other columns.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Collect {
+    kind: CollectKind,
+    /// Which column to aggregate.
+    source_col: usize,
+    /// For [`CollectKind::JsonObject`], the column containing the keys of the object.
+    key_col: Option<usize>,
+    /// The columns to order the collected values by, within each group.
+    order_by: Vec<(usize, OrderType)>,
+    /// The columns to group by.
+    group_by: Vec<usize>,
+    /// The type of the column being aggregated.
+    over_col_ty: DfType,
+    /// The order of the stored items, which start with the values of the `order_by` columns.
+    item_order: Order,
+    /// Cached state for each group (set of data corresponding to the columns of `group_by`).
+    // We skip serde since we don't want the state of the node, just the configuration.
+    #[serde(skip)]
+    last_state: RefCell<HashMap<Vec<DfValue>, LastState>>,
+}
+
+impl Collect {
+    /// Construct a new `Collect`, aggregating the provided `source_col` (keyed by `key_col` for
+    /// [`CollectKind::JsonObject`]) ordered by the given `order_by` columns.
+    pub fn new(
+        src: NodeIndex,
+        kind: CollectKind,
+        source_col: usize,
+        key_col: Option<usize>,
+        order_by: Vec<(usize, OrderType)>,
+        group_by: Vec<usize>,
+        over_col_ty: DfType,
+    ) -> ReadySetResult<GroupedOperator<Collect>> {
+        invariant_eq!(
+            key_col.is_some(),
+            kind == CollectKind::JsonObject,
+            "only JSON object aggregates have a key column"
+        );
+        let item_order = order_by
+            .iter()
+            .enumerate()
+            .map(|(i, (_, order_type))| (i, *order_type))
+            .collect::<Vec<_>>()
+            .into();
+
+        Ok(GroupedOperator::new(
+            src,
+            Collect {
+                kind,
+                source_col,
+                key_col,
+                order_by,
+                group_by,
+                over_col_ty,
+                item_order,
+                last_state: RefCell::new(HashMap::new()),
+            },
+        ))
+    }
+
+    /// Compute the output value for a group from its (ordered) items
+    fn output(&self, items: &[Vec<DfValue>]) -> ReadySetResult<DfValue> {
+        let value_idx = self.order_by.len();
+        let item_value = |item: &[DfValue]| {
+            item.get(value_idx)
+                .cloned()
+                .ok_or_else(|| internal_err!("Invalid collected item"))
+        };
+
+        match self.kind {
+            CollectKind::Array => Ok(items
+                .iter()
+                .map(|item| item_value(item))
+                .collect::<ReadySetResult<Vec<_>>>()?
+                .into()),
+            CollectKind::JsonArray => {
+                let values = items
+                    .iter()
+                    .map(|item| to_json(&item_value(item)?, &self.over_col_ty))
+                    .collect::<ReadySetResult<Vec<_>>>()?;
+                Ok(values.into())
+            }
+            CollectKind::JsonObject => {
+                let mut object = serde_json::Map::new();
+                for item in items {
+                    let key = item
+                        .get(value_idx + 1)
+                        .ok_or_else(|| internal_err!("Invalid collected item"))?;
+                    // JSON objects can't have NULL keys
+                    if key.is_none() {
+                        continue;
+                    }
+                    object.insert(
+                        key.to_string(),
+                        to_json(&item_value(item)?, &self.over_col_ty)?,
+                    );
+                }
+                Ok(object.into())
+            }
+        }
+    }
+}
+
+/// Convert a value of the given type to JSON. Values of JSON types are embedded as JSON rather
+/// than as strings, so that JSON aggregates can be nested.
+fn to_json(value: &DfValue, ty: &DfType) -> ReadySetResult<JsonValue> {
+    Ok(match value {
+        DfValue::None => JsonValue::Null,
+        _ if ty.is_any_json() => value.to_json()?,
+        _ if ty.is_bool() => JsonValue::Bool(value.is_truthy()),
+        DfValue::Int(n) => (*n).into(),
+        DfValue::UnsignedInt(n) => (*n).into(),
+        DfValue::Float(f) => {
+            JsonNumber::from_f64(f64::from(*f)).map_or(JsonValue::Null, Into::into)
+        }
+        DfValue::Double(f) => JsonNumber::from_f64(*f).map_or(JsonValue::Null, Into::into),
+        DfValue::Numeric(d) => d.to_string().parse()?,
+        DfValue::Array(arr) => {
+            let elem_ty = match ty {
+                DfType::Array(elem_ty) => elem_ty.as_ref(),
+                _ => &DfType::Unknown,
+            };
+            JsonValue::Array(
+                arr.values()
+                    .map(|v| to_json(v, elem_ty))
+                    .collect::<ReadySetResult<_>>()?,
+            )
+        }
+        _ => JsonValue::String(value.to_string()),
+    })
+}
+
+pub struct CollectDiff {
+    item: Vec<DfValue>,
+    is_positive: bool,
+    group_by: Vec<DfValue>,
+}
+
+impl GroupedOperation for Collect {
+    type Diff = CollectDiff;
+
+    fn setup(&mut self, parent: &Node) -> ReadySetResult<()> {
+        invariant!(
+            self.source_col < parent.columns().len(),
+            "cannot aggregate over non-existing column"
+        );
+        Ok(())
+    }
+
+    fn group_by(&self) -> &[usize] {
+        &self.group_by
+    }
+
+    fn to_diff(&self, record: &[DfValue], is_positive: bool) -> ReadySetResult<Self::Diff> {
+        let item = record
+            .cloned_indices(
+                self.order_by
+                    .iter()
+                    .map(|(col, _)| *col)
+                    .chain(Some(self.source_col))
+                    .chain(self.key_col),
+            )
+            .map_err(|_| ReadySetError::InvalidRecordLength)?;
+        // We need this to figure out which state to use.
+        let group_by = record
+            .cloned_indices(self.group_by.iter().cloned())
+            .map_err(|_| ReadySetError::InvalidRecordLength)?;
+        Ok(CollectDiff {
+            item,
+            is_positive,
+            group_by,
+        })
+    }
+
+    fn apply(
+        &self,
+        current: Option<&DfValue>,
+        diffs: &mut dyn Iterator<Item = Self::Diff>,
+    ) -> ReadySetResult<Option<DfValue>> {
+        let mut diffs = diffs.peekable();
+
+        let first_diff = diffs
+            .peek()
+            .ok_or_else(|| internal_err!("collect got no diffs"))?;
+        let group = first_diff.group_by.clone();
+
+        let last_state = self.last_state.borrow_mut().remove(&group);
+        let mut state = match (current, last_state) {
+            // if state matches, use it
+            (Some(current), Some(state)) if *current == state.output => state,
+            // if state doesn't match, need to recreate it
+            (Some(_), _) => return Ok(None),
+            // if we're recreating or this is the first record for the group, make a new state
+            (None, _) => LastState::default(),
+        };
+
+        for CollectDiff {
+            item,
+            is_positive,
+            group_by,
+        } in diffs
+        {
+            invariant_eq!(group_by, group);
+            if is_positive {
+                // Insert after all the items which are equal in the order, so that items are
+                // otherwise kept in the order they were added
+                let pos = state.items.partition_point(|other| {
+                    self.item_order.cmp(other, &item) != Ordering::Greater
+                });
+                state.items.insert(pos, item);
+            } else {
+                let pos = state
+                    .items
+                    .iter()
+                    .rposition(|other| *other == item)
+                    .ok_or_else(|| internal_err!("collect couldn't remove value from data"))?;
+                state.items.remove(pos);
+            }
+        }
+
+        let output = self.output(&state.items)?;
+        state.output = output.clone();
+        self.last_state.borrow_mut().insert(group, state);
+        Ok(Some(output))
+    }
+
+    fn description(&self, detailed: bool) -> String {
+        let name = match self.kind {
+            CollectKind::Array => "ArrayAgg",
+            CollectKind::JsonArray => "JsonAgg",
+            CollectKind::JsonObject => "JsonObjectAgg",
+        };
+        if !detailed {
+            return name.to_owned();
+        }
+
+        let mut args = self.key_col.iter().chain(Some(&self.source_col)).join(", ");
+        if !self.order_by.is_empty() {
+            args.push_str(&format!(" ORDER BY {}", Order::from(self.order_by.clone())));
+        }
+        format!("{}({}) γ{:?}", name, args, self.group_by)
+    }
+
+    fn over_column(&self) -> usize {
+        self.source_col
+    }
+
+    fn output_col_type(&self) -> DfType {
+        match self.kind {
+            CollectKind::Array => DfType::Array(Box::new(self.over_col_ty.clone())),
+            CollectKind::JsonArray | CollectKind::JsonObject => DfType::Json,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops;
+
+    fn setup(
+        kind: CollectKind,
+        key_col: Option<usize>,
+        order_by: Vec<(usize, OrderType)>,
+    ) -> ops::test::MockGraph {
+        let mut g = ops::test::MockGraph::new();
+        let s = g.add_base("source", &["x", "y", "z"]);
+
+        let c = Collect::new(
+            s.as_global(),
+            kind,
+            1,
+            key_col,
+            order_by,
+            vec![0],
+            DfType::BigInt,
+        )
+        .unwrap();
+
+        g.set_op("collect", &["x", "ys"], c, true);
+        g
+    }
+
+    fn row(x: i64, y: i64, z: &str) -> Vec<DfValue> {
+        vec![x.into(), y.into(), z.into()]
+    }
+
+    #[test]
+    fn it_describes() {
+        let c = setup(
+            CollectKind::JsonObject,
+            Some(2),
+            vec![(2, OrderType::OrderDescending)],
+        );
+        assert_eq!(
+            c.node().description(true),
+            "JsonObjectAgg(2, 1 ORDER BY >2) γ[0]"
+        );
+    }
+
+    #[test]
+    fn array_agg_in_insertion_order() {
+        let mut c = setup(CollectKind::Array, None, vec![]);
+        let out = c.narrow_one_row(row(1, 3, "a"), true);
+        assert_eq!(
+            out,
+            vec![vec![1.into(), vec![DfValue::from(3)].into(), 1.into()]].into()
+        );
+
+        let out = c.narrow_one_row(row(1, 1, "b"), true);
+        assert!(out.has_positive(
+            &[
+                1.into(),
+                vec![DfValue::from(3), DfValue::from(1)].into(),
+                2.into()
+            ][..]
+        ));
+
+        let out = c.narrow_one_row((row(1, 3, "a"), false), true);
+        assert!(out.has_positive(&[1.into(), vec![DfValue::from(1)].into(), 1.into()][..]));
+    }
+
+    #[test]
+    fn ordered_array_agg() {
+        let mut c = setup(
+            CollectKind::Array,
+            None,
+            vec![(2, OrderType::OrderDescending)],
+        );
+        c.narrow_one(vec![row(1, 1, "a"), row(1, 3, "c")], true);
+        let out = c.narrow_one_row(row(1, 2, "b"), true);
+        assert!(out.has_positive(
+            &[
+                1.into(),
+                vec![DfValue::from(3), DfValue::from(2), DfValue::from(1)].into(),
+                3.into()
+            ][..]
+        ));
+    }
+
+    #[test]
+    fn json_agg() {
+        let mut c = setup(
+            CollectKind::JsonArray,
+            None,
+            vec![(1, OrderType::OrderAscending)],
+        );
+        let out = c.narrow_one(
+            vec![
+                row(1, 2, "a"),
+                vec![1.into(), DfValue::None, "b".into()],
+                row(1, 1, "c"),
+            ],
+            true,
+        );
+        assert_eq!(
+            out,
+            vec![vec![1.into(), "[null,1,2]".into(), 3.into()]].into()
+        );
+    }
+
+    #[test]
+    fn json_object_agg() {
+        let mut c = setup(CollectKind::JsonObject, Some(2), vec![]);
+        let out = c.narrow_one(vec![row(1, 1, "a"), row(1, 2, "b")], true);
+        assert_eq!(
+            out,
+            vec![vec![1.into(), r#"{"a":1,"b":2}"#.into(), 2.into()]].into()
+        );
+
+        let out = c.narrow_one_row((row(1, 1, "a"), false), true);
+        assert!(out.has_positive(&[1.into(), r#"{"b":2}"#.into(), 1.into()][..]));
+    }
+}
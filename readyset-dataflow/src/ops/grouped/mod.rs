@@ -13,8 +13,10 @@ use crate::processing::{ColumnSource, IngredientLookupResult, LookupIndex, Looku
 
 // pub mod latest;
 pub mod aggregate;
+pub mod collect;
 pub mod concat;
 pub mod extremum;
+mod sketch;
 
 /// Trait for implementing operations that collapse a group of records into a single record.
 ///
@@ -0,0 +1,375 @@
+//! Bounded-size sketches used to compute approximate aggregates.
+//!
+//! Unlike the sketches usually used for these aggregates, both of the sketches here support
+//! removing values as well as adding them, since records can be retracted from a group at any
+//! time. Removing a value which was previously added exactly reverses the change to the counts
+//! made when adding it, so the sketches don't drift as records are added and removed.
+
+use std::collections::btree_map::Entry;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+
+/// Number of bits of each hash used to select a register in a [`HyperLogLog`]
+const HLL_PRECISION: u32 = 12;
+
+/// Number of registers in a [`HyperLogLog`]
+const HLL_REGISTERS: usize = 1 << HLL_PRECISION;
+
+/// Maximum rank of a value in a [`HyperLogLog`]: one more than the number of hash bits left after
+/// selecting a register
+const HLL_MAX_RANK: u8 = (u64::BITS - HLL_PRECISION + 1) as u8;
+
+/// A [HyperLogLog] sketch, for estimating the number of distinct values in a group with a standard
+/// error of about 1.6%.
+///
+/// To support removing values, rather than only storing the maximum rank seen for each register,
+/// we store the number of values seen with each rank for each register. This means the size of
+/// the sketch is bounded by the number of registers multiplied by the number of possible ranks,
+/// rather than by the number of distinct values.
+///
+/// [HyperLogLog]: https://en.wikipedia.org/wiki/HyperLogLog
+#[derive(Debug, Clone)]
+pub(super) struct HyperLogLog {
+    /// Number of values with each (register, rank) pair
+    counts: BTreeMap<(u16, u8), u64>,
+    /// The sum of `2^-rank` over the maximum rank of each register (with empty registers having a
+    /// rank of 0), scaled by `2^64` so that it can be maintained exactly as registers change
+    inverse_sum: u128,
+    /// Number of registers with no values
+    empty_registers: usize,
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self {
+            counts: Default::default(),
+            inverse_sum: HLL_REGISTERS as u128 * Self::inverse(0),
+            empty_registers: HLL_REGISTERS,
+        }
+    }
+}
+
+impl HyperLogLog {
+    /// `2^-rank`, scaled by `2^64`
+    fn inverse(rank: u8) -> u128 {
+        1 << (u64::BITS - rank as u32)
+    }
+
+    fn max_rank(&self, register: u16) -> u8 {
+        self.counts
+            .range((register, 0)..=(register, u8::MAX))
+            .next_back()
+            .map_or(0, |((_, rank), _)| *rank)
+    }
+
+    /// Add a value to the sketch if `positive` is true, or remove a previously-added value from
+    /// the sketch otherwise
+    pub(super) fn apply<T: Hash>(&mut self, value: &T, positive: bool) {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        let register = (hash >> (u64::BITS - HLL_PRECISION)) as u16;
+        let rank = ((hash << HLL_PRECISION).leading_zeros() + 1).min(HLL_MAX_RANK as u32) as u8;
+
+        let old_max = self.max_rank(register);
+        match self.counts.entry((register, rank)) {
+            Entry::Vacant(e) if positive => {
+                e.insert(1);
+            }
+            Entry::Occupied(mut e) if positive => *e.get_mut() += 1,
+            Entry::Occupied(mut e) => {
+                *e.get_mut() -= 1;
+                if *e.get() == 0 {
+                    e.remove();
+                }
+            }
+            // Removing a value that was never added; nothing to do
+            Entry::Vacant(_) => return,
+        }
+        let new_max = self.max_rank(register);
+
+        if old_max != new_max {
+            self.inverse_sum = self.inverse_sum - Self::inverse(old_max) + Self::inverse(new_max);
+            if old_max == 0 {
+                self.empty_registers -= 1;
+            } else if new_max == 0 {
+                self.empty_registers += 1;
+            }
+        }
+    }
+
+    /// Returns the estimated number of distinct values in the sketch
+    pub(super) fn estimate(&self) -> u64 {
+        if self.empty_registers == HLL_REGISTERS {
+            return 0;
+        }
+
+        let m = HLL_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let estimate = alpha * m * m / (self.inverse_sum as f64 / Self::inverse(0) as f64);
+
+        // Linear counting is much more accurate for small cardinalities
+        if estimate <= 2.5 * m && self.empty_registers > 0 {
+            return (m * (m / self.empty_registers as f64).ln()).round() as u64;
+        }
+        estimate.round() as u64
+    }
+}
+
+/// Relative accuracy of the values returned by a [`QuantileSketch`]
+const QUANTILE_RELATIVE_ACCURACY: f64 = 0.01;
+
+/// Maximum number of buckets stored for each of the positive and negative values in a
+/// [`QuantileSketch`]
+const QUANTILE_MAX_BUCKETS: usize = 2048;
+
+/// Counts of values in logarithmically-sized buckets, keyed by bucket index
+#[derive(Debug, Clone, Default)]
+struct Buckets {
+    counts: BTreeMap<i32, u64>,
+    /// Values whose bucket index is lower than this are counted in this bucket instead. Set (and
+    /// then raised) when there are too many buckets to store
+    min_index: Option<i32>,
+}
+
+impl Buckets {
+    fn apply(&mut self, index: i32, positive: bool) {
+        let index = self.min_index.map_or(index, |min| index.max(min));
+        if positive {
+            *self.counts.entry(index).or_default() += 1;
+            if self.counts.len() > QUANTILE_MAX_BUCKETS {
+                // Merge the lowest bucket into the next lowest one. Any value which is removed
+                // later is mapped to the same bucket, so the counts stay exact.
+                if let Some((_, count)) = self.counts.pop_first() {
+                    if let Some(mut lowest) = self.counts.first_entry() {
+                        *lowest.get_mut() += count;
+                        self.min_index = Some(*lowest.key());
+                    }
+                }
+            }
+        } else if let Entry::Occupied(mut e) = self.counts.entry(index) {
+            *e.get_mut() -= 1;
+            if *e.get() == 0 {
+                e.remove();
+            }
+        }
+    }
+
+    fn total(&self) -> u64 {
+        self.counts.values().sum()
+    }
+}
+
+/// A sketch for estimating quantiles of the values in a group, based on [DDSketch].
+///
+/// Values are counted in buckets whose bounds grow exponentially, such that every quantile
+/// returned by the sketch is within 1% of the actual value (relative to that value), as long as no
+/// more than 2048 buckets are needed to store the positive or negative values. Past that point,
+/// the buckets closest to zero are merged together.
+///
+/// [DDSketch]: https://arxiv.org/abs/1908.10693
+#[derive(Debug, Clone, Default)]
+pub(super) struct QuantileSketch {
+    positive: Buckets,
+    /// Buckets for the absolute values of negative values
+    negative: Buckets,
+    zeros: u64,
+}
+
+impl QuantileSketch {
+    fn gamma() -> f64 {
+        (1.0 + QUANTILE_RELATIVE_ACCURACY) / (1.0 - QUANTILE_RELATIVE_ACCURACY)
+    }
+
+    fn index(value: f64) -> i32 {
+        (value.ln() / Self::gamma().ln()).ceil() as i32
+    }
+
+    /// Returns the value represented by the bucket with the given index, which is within the
+    /// relative accuracy of every value in that bucket
+    fn value(index: i32) -> f64 {
+        2.0 * Self::gamma().powi(index) / (Self::gamma() + 1.0)
+    }
+
+    /// Add a value to the sketch if `positive` is true, or remove a previously-added value from
+    /// the sketch otherwise. Non-finite values are ignored.
+    pub(super) fn apply(&mut self, value: f64, positive: bool) {
+        if !value.is_finite() {
+            return;
+        }
+
+        if value > f64::MIN_POSITIVE {
+            self.positive.apply(Self::index(value), positive)
+        } else if value < -f64::MIN_POSITIVE {
+            self.negative.apply(Self::index(-value), positive)
+        } else if positive {
+            self.zeros += 1;
+        } else {
+            self.zeros = self.zeros.saturating_sub(1);
+        }
+    }
+
+    /// Returns the approximate value at the given quantile, which must be between 0 and 1, or
+    /// `None` if the sketch is empty
+    pub(super) fn quantile(&self, quantile: f64) -> Option<f64> {
+        let negative = self.negative.total();
+        let count = negative + self.zeros + self.positive.total();
+        if count == 0 {
+            return None;
+        }
+
+        let rank = (quantile.clamp(0.0, 1.0) * (count - 1) as f64).round() as u64;
+        if rank < negative {
+            // Negative values are ordered by decreasing magnitude
+            let mut seen = 0;
+            for (index, n) in self.negative.counts.iter().rev() {
+                seen += n;
+                if rank < seen {
+                    return Some(-Self::value(*index));
+                }
+            }
+        }
+        if rank < negative + self.zeros {
+            return Some(0.0);
+        }
+        let mut seen = negative + self.zeros;
+        for (index, n) in &self.positive.counts {
+            seen += n;
+            if rank < seen {
+                return Some(Self::value(*index));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hyperloglog_empty() {
+        assert_eq!(HyperLogLog::default().estimate(), 0);
+    }
+
+    #[test]
+    fn hyperloglog_small_cardinalities() {
+        let mut hll = HyperLogLog::default();
+        for i in 0..20 {
+            hll.apply(&i, true);
+            // Duplicates don't change the estimate
+            hll.apply(&i, true);
+        }
+        let estimate = hll.estimate();
+        assert!((18..=22).contains(&estimate), "estimate: {estimate}");
+    }
+
+    #[test]
+    fn hyperloglog_large_cardinalities() {
+        let mut hll = HyperLogLog::default();
+        for i in 0..100_000 {
+            hll.apply(&i, true);
+        }
+        let estimate = hll.estimate() as f64;
+        assert!(
+            (estimate - 100_000.0).abs() / 100_000.0 < 0.05,
+            "estimate: {estimate}"
+        );
+    }
+
+    #[test]
+    fn hyperloglog_removals() {
+        let mut hll = HyperLogLog::default();
+        for i in 0..10_000 {
+            hll.apply(&i, true);
+        }
+        let with_half = {
+            let mut hll = HyperLogLog::default();
+            for i in 0..5_000 {
+                hll.apply(&i, true);
+            }
+            hll.estimate()
+        };
+
+        for i in 5_000..10_000 {
+            hll.apply(&i, false);
+        }
+        assert_eq!(hll.estimate(), with_half);
+
+        for i in 0..5_000 {
+            hll.apply(&i, false);
+        }
+        assert_eq!(hll.estimate(), 0);
+        assert!(hll.counts.is_empty());
+    }
+
+    #[test]
+    fn quantile_sketch_empty() {
+        assert_eq!(QuantileSketch::default().quantile(0.5), None);
+    }
+
+    #[test]
+    fn quantile_sketch_accuracy() {
+        let mut sketch = QuantileSketch::default();
+        for i in -500..=1000 {
+            sketch.apply(i as f64, true);
+        }
+
+        for (quantile, expected) in [(0.0, -500.0), (0.5, 250.0), (0.9, 850.0), (1.0, 1000.0)] {
+            let actual = sketch.quantile(quantile).unwrap();
+            assert!(
+                (actual - expected).abs() <= expected.abs() * QUANTILE_RELATIVE_ACCURACY,
+                "quantile {quantile}: expected {expected}, got {actual}"
+            );
+        }
+    }
+
+    #[test]
+    fn quantile_sketch_zeros() {
+        let mut sketch = QuantileSketch::default();
+        for v in [-1.0, 0.0, 0.0, 0.0, 1.0] {
+            sketch.apply(v, true);
+        }
+        assert_eq!(sketch.quantile(0.5), Some(0.0));
+    }
+
+    #[test]
+    fn quantile_sketch_removals() {
+        let mut sketch = QuantileSketch::default();
+        for i in 1..=100 {
+            sketch.apply(i as f64, true);
+        }
+        for i in 51..=100 {
+            sketch.apply(i as f64, false);
+        }
+        let max = sketch.quantile(1.0).unwrap();
+        assert!((max - 50.0).abs() <= 50.0 * QUANTILE_RELATIVE_ACCURACY);
+
+        for i in 1..=50 {
+            sketch.apply(i as f64, false);
+        }
+        assert_eq!(sketch.quantile(0.5), None);
+    }
+
+    #[test]
+    fn quantile_sketch_bounded_buckets() {
+        let mut sketch = QuantileSketch::default();
+        let values = (0..5000).map(|i| 1.01f64.powi(i)).collect::<Vec<_>>();
+        for v in &values {
+            sketch.apply(*v, true);
+        }
+        assert!(sketch.positive.counts.len() <= QUANTILE_MAX_BUCKETS);
+
+        // The largest values are still accurate
+        let max = sketch.quantile(1.0).unwrap();
+        let expected = values[values.len() - 1];
+        assert!((max - expected).abs() <= expected * QUANTILE_RELATIVE_ACCURACY);
+
+        for v in &values {
+            sketch.apply(*v, false);
+        }
+        assert_eq!(sketch.quantile(0.5), None);
+    }
+}
@@ -1,7 +1,9 @@
 use std::collections::{HashMap, HashSet};
 use std::convert::{TryFrom, TryInto};
+use std::iter;
+use std::ops::Bound;
 
-use dataflow_state::PointKey;
+use dataflow_state::{PointKey, RangeKey};
 use itertools::Itertools;
 use readyset_client::KeyComparison;
 use readyset_errors::{internal_err, ReadySetResult};
@@ -14,6 +16,7 @@ use super::Side;
 use crate::prelude::*;
 use crate::processing::{
     ColumnMiss, ColumnRef, ColumnSource, IngredientLookupResult, LookupIndex, LookupMode,
+    MissLookupKey,
 };
 
 /// Kind of join
@@ -23,6 +26,18 @@ pub enum JoinType {
     Left,
     /// Inner join between two views
     Inner,
+    /// Full outer join between two views
+    Full,
+    /// Semi-join between two views, which emits each row from the left view at most once if it
+    /// has any matches in the right view.
+    ///
+    /// Semi-joins may only emit columns from the left view.
+    Semi,
+    /// Anti-join between two views, which emits each row from the left view if it has *no*
+    /// matches in the right view.
+    ///
+    /// Anti-joins may only emit columns from the left view.
+    Anti,
 }
 
 /// Where to source a join column
@@ -36,7 +51,77 @@ pub enum JoinSource {
     B(usize, usize),
 }
 
-/// Join provides a left outer join between two views.
+/// A comparison operator, other than equality, which can be used in a [`JoinCondition`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JoinComparison {
+    /// `<`
+    Less,
+    /// `<=`
+    LessOrEqual,
+    /// `>`
+    Greater,
+    /// `>=`
+    GreaterOrEqual,
+}
+
+impl JoinComparison {
+    /// Returns the comparison with its operands swapped, such that `a op b` holds if and only if
+    /// `b op.flip() a` holds
+    pub fn flip(self) -> Self {
+        match self {
+            JoinComparison::Less => JoinComparison::Greater,
+            JoinComparison::LessOrEqual => JoinComparison::GreaterOrEqual,
+            JoinComparison::Greater => JoinComparison::Less,
+            JoinComparison::GreaterOrEqual => JoinComparison::LessOrEqual,
+        }
+    }
+
+    /// Returns true if `lhs op rhs` holds. As in SQL, comparisons involving NULL never hold.
+    fn holds(self, lhs: &DfValue, rhs: &DfValue) -> bool {
+        if lhs.is_none() || rhs.is_none() {
+            return false;
+        }
+
+        match self {
+            JoinComparison::Less => lhs < rhs,
+            JoinComparison::LessOrEqual => lhs <= rhs,
+            JoinComparison::Greater => lhs > rhs,
+            JoinComparison::GreaterOrEqual => lhs >= rhs,
+        }
+    }
+}
+
+impl std::fmt::Display for JoinComparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JoinComparison::Less => write!(f, "<"),
+            JoinComparison::LessOrEqual => write!(f, "<="),
+            JoinComparison::Greater => write!(f, ">"),
+            JoinComparison::GreaterOrEqual => write!(f, ">="),
+        }
+    }
+}
+
+/// A non-equality comparison between a column in the left parent and a column in the right parent
+/// of a [`Join`], which must hold (in addition to the equality join key) for a pair of rows to be
+/// joined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JoinCondition {
+    /// Column index in the left parent
+    pub left: usize,
+    /// The comparison, with the left column on the left-hand side
+    pub op: JoinComparison,
+    /// Column index in the right parent
+    pub right: usize,
+}
+
+/// Join provides an inner, left outer, or full outer join between two views.
+///
+/// In addition to the equality join key, a join can have a list of non-equality [`JoinCondition`]s
+/// (a "theta join"). If it does, the first of those conditions is evaluated as part of a range
+/// lookup into a [`BTreeMap`](IndexType::BTreeMap) index on the other parent, keyed on the
+/// equality columns followed by that condition's column, and all of the conditions are then
+/// checked against the rows returned by that lookup.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Join {
     left: IndexPair,
@@ -45,6 +130,9 @@ pub struct Join {
     /// Key columns in the left and right parents respectively
     on: Vec<(usize, usize)>,
 
+    /// Non-equality conditions which must hold for rows to be joined
+    conditions: Vec<JoinCondition>,
+
     // Which columns to emit
     emit: Vec<(Side, usize)>,
 
@@ -70,6 +158,20 @@ impl Join {
     /// output colunm, which source and column should be used (true means left parent, and false
     /// means right parent).
     pub fn new(left: NodeIndex, right: NodeIndex, kind: JoinType, emit: Vec<JoinSource>) -> Self {
+        Self::new_with_conditions(left, right, kind, emit, vec![])
+    }
+
+    /// Create a new instance of Join with the given non-equality `conditions` in addition to the
+    /// equality join key given by `emit`.
+    ///
+    /// Non-equality conditions are only supported for inner joins.
+    pub fn new_with_conditions(
+        left: NodeIndex,
+        right: NodeIndex,
+        kind: JoinType,
+        emit: Vec<JoinSource>,
+        conditions: Vec<JoinCondition>,
+    ) -> Self {
         let mut join_columns = Vec::new();
         let emit: Vec<_> = emit
             .into_iter()
@@ -122,11 +224,13 @@ impl Join {
         };
 
         debug_assert!(!join_columns.is_empty());
+        debug_assert!(conditions.is_empty() || kind == JoinType::Inner);
 
         Self {
             left: left.into(),
             right: right.into(),
             on: join_columns,
+            conditions,
             emit,
             in_place_left_emit,
             in_place_right_emit,
@@ -143,6 +247,59 @@ impl Join {
         self.on.iter().map(|(_, r)| *r).collect()
     }
 
+    /// Returns the columns of the left and right parents, respectively, to use for lookups into
+    /// those parents. If we have any non-equality conditions, this includes the columns of the
+    /// first condition after the equality key columns.
+    fn lookup_cols(&self) -> (Vec<usize>, Vec<usize>) {
+        let (mut left, mut right): (Vec<usize>, Vec<usize>) = self.on.iter().copied().unzip();
+        if let Some(cond) = self.conditions.first() {
+            left.push(cond.left);
+            right.push(cond.right);
+        }
+        (left, right)
+    }
+
+    /// Returns true if all of our non-equality conditions hold for the given pair of left and
+    /// right rows
+    fn conditions_hold(&self, left: &[DfValue], right: &[DfValue]) -> bool {
+        self.conditions
+            .iter()
+            .all(|cond| cond.op.holds(&left[cond.left], &right[cond.right]))
+    }
+
+    /// Build the range key to use to look up all the rows in a parent whose values for the
+    /// equality key columns are equal to all but the last value of `key`, and whose value for the
+    /// column of the first non-equality condition satisfies `op` with the last value of `key` on
+    /// the right-hand side.
+    ///
+    /// The bounds of the range are always inclusive, using NULL (the minimum [`DfValue`]) and
+    /// [`DfValue::Max`] for the unbounded side, so the results of the lookup must then be filtered
+    /// with [`Self::conditions_hold`].
+    fn range_lookup_key(op: JoinComparison, key: &[DfValue]) -> ReadySetResult<KeyComparison> {
+        let (value, eq_key) = key
+            .split_last()
+            .ok_or_else(|| internal_err!("Empty join key"))?;
+        let (lower, upper) = match op {
+            JoinComparison::Less | JoinComparison::LessOrEqual => (DfValue::None, value.clone()),
+            JoinComparison::Greater | JoinComparison::GreaterOrEqual => {
+                (value.clone(), DfValue::Max)
+            }
+        };
+        let make_bound = |v: DfValue| -> ReadySetResult<Vec1<DfValue>> {
+            eq_key
+                .iter()
+                .cloned()
+                .chain(iter::once(v))
+                .collect::<Vec<_>>()
+                .try_into()
+                .map_err(|_| internal_err!("Empty join key"))
+        };
+        Ok(KeyComparison::Range((
+            Bound::Included(make_bound(lower)?),
+            Bound::Included(make_bound(upper)?),
+        )))
+    }
+
     fn generate_row(&self, left: &[DfValue], right: &[DfValue]) -> Vec<DfValue> {
         self.emit
             .iter()
@@ -169,10 +326,10 @@ impl Join {
             let (rec, positive) = rec.extract();
             invariant!(positive, "replays should only include positive records");
 
-            for other_rec in right
-                .iter()
-                .filter(|r| rec.indices(from_key.clone()) == r.indices(other_key.clone()))
-            {
+            for other_rec in right.iter().filter(|r| {
+                rec.indices(from_key.clone()) == r.indices(other_key.clone())
+                    && self.conditions_hold(&rec, r.row())
+            }) {
                 ret.push(Record::Positive(self.generate_row(&rec, other_rec.row())))
             }
         }
@@ -193,9 +350,62 @@ impl Join {
             .collect()
     }
 
+    /// Like [`Self::generate_null`], but for a row from the right parent with no matches in the
+    /// left parent (which is only possible for full outer joins).
+    ///
+    /// Join key columns emitted from the left parent will be NULL in these rows, so full outer
+    /// joins emit the right parent's join key columns separately (see [`Self::resolve_col`]).
+    fn generate_null_left(&self, right: &[DfValue]) -> Vec<DfValue> {
+        self.emit
+            .iter()
+            .map(|&(side, col)| {
+                if side == Side::Right {
+                    right[col].clone()
+                } else {
+                    DfValue::None
+                }
+            })
+            .collect()
+    }
+
+    /// Generate the row to emit for a row from the given side with no matches in the other side
+    fn generate_null_for(&self, side: Side, row: &[DfValue]) -> Vec<DfValue> {
+        match side {
+            Side::Left => self.generate_null(row),
+            Side::Right => self.generate_null_left(row),
+        }
+    }
+
+    /// Returns true if rows from the given side must be emitted (padded with NULLs) even if they
+    /// have no matches in the other side
+    fn preserves(&self, side: Side) -> bool {
+        match self.kind {
+            JoinType::Inner => false,
+            JoinType::Left | JoinType::Anti => side == Side::Left,
+            JoinType::Full => true,
+            JoinType::Semi => false,
+        }
+    }
+
+    /// Returns true if this join only ever emits rows from the left parent, based on whether or
+    /// not they have matches in the right parent
+    fn is_semi_or_anti(&self) -> bool {
+        matches!(self.kind, JoinType::Semi | JoinType::Anti)
+    }
+
     fn resolve_col(&self, col: usize) -> (Option<usize>, Option<usize>) {
         let (side, pcol) = self.emit[col];
 
+        if self.kind == JoinType::Full {
+            // Rows with no match on one side have NULL for that side's join key columns, so join
+            // key columns can only be sourced from the parent that emits them
+            return if side == Side::Left {
+                (Some(pcol), None)
+            } else {
+                (None, Some(pcol))
+            };
+        }
+
         if let Some((on_l, on_r)) = self
             .on
             .iter()
@@ -261,14 +471,27 @@ impl Ingredient for Join {
         let from_left = from == *self.left;
 
         let other = if from_left { *self.right } else { *self.left };
+        let (from_side, other_side) = if from_left {
+            (Side::Left, Side::Right)
+        } else {
+            (Side::Right, Side::Left)
+        };
 
         let (from_key, other_key): (Vec<usize>, Vec<usize>) = if from_left {
-            self.on.iter().copied().unzip()
+            self.lookup_cols()
         } else {
-            let (other_key, from_key) = self.on.iter().copied().unzip();
+            let (other_key, from_key) = self.lookup_cols();
             (from_key, other_key)
         };
 
+        // If we have any non-equality conditions, the first one is evaluated by a range lookup
+        // into the other side, for which we need the comparison with the other side's column on
+        // the left-hand side
+        let range_op =
+            self.conditions
+                .first()
+                .map(|cond| if from_left { cond.op.flip() } else { cond.op });
+
         let orkc = replay.key();
 
         let replay_key_cols: Result<Option<Vec<usize>>, ()> =
@@ -381,14 +604,18 @@ impl Ingredient for Join {
             let nulls = join_key.iter().any(|v| v.is_none());
 
             // The difference between a left join and an inner join, is that for the former we must
-            // emit rows with nulls even if we later get no match in the other side.
+            // emit rows with nulls even if we later get no match in the other side. A full join
+            // does this for both sides. Semi-joins and anti-joins similarly have to know whether
+            // left rows have gained or lost their last match when we get updates from the right.
 
-            let mut new_right_count = None;
+            let mut new_from_count = None;
 
-            if self.kind == JoinType::Left && !from_left {
+            if self.preserves(other_side) || (self.is_semi_or_anti() && !from_left) {
+                // The other side's rows are emitted with nulls when they have no matches on our
+                // side, so we have to find out if we've changed whether they do
                 let rc = self.lookup(
-                    *self.right,
-                    &self.on_right(),
+                    from,
+                    &from_key,
                     &PointKey::from(join_key.iter().cloned()),
                     nodes,
                     state,
@@ -399,8 +626,8 @@ impl Ingredient for Join {
                     IngredientLookupResult::Records(rc) => {
                         if replay_key_cols.is_some() && !nulls {
                             lookups.push(Lookup {
-                                on: *self.right,
-                                cols: self.on_right(),
+                                on: from,
+                                cols: from_key.clone(),
                                 key: join_key
                                     .clone()
                                     .try_into()
@@ -409,7 +636,7 @@ impl Ingredient for Join {
                         }
 
                         let rc = rc.count();
-                        new_right_count = Some(rc);
+                        new_from_count = Some(rc);
                     }
                     IngredientLookupResult::Miss => {
                         // we got something from right, but that row's key is not in right??
@@ -426,26 +653,41 @@ impl Ingredient for Join {
                 }
             }
 
-            let mut other_lookup = match nulls {
-                true => IngredientLookupResult::empty(),
-                false => self.lookup(
+            let other_lookup_key = match range_op {
+                Some(op) => Self::range_lookup_key(op, &join_key)?,
+                None => join_key
+                    .try_into()
+                    .map_err(|_| internal_err!("Empty join key"))?,
+            };
+
+            let mut other_lookup = match (nulls, &other_lookup_key) {
+                (true, _) => IngredientLookupResult::empty(),
+                (false, KeyComparison::Equal(key)) => self.lookup(
                     other,
                     &other_key,
-                    &PointKey::from(join_key.iter().cloned()),
+                    &PointKey::from(key.iter().cloned()),
                     nodes,
                     state,
                     lookup_mode,
                 )?,
+                (false, KeyComparison::Range(range)) => {
+                    self.lookup_range(other, &other_key, &RangeKey::from(range), state)?
+                }
             };
 
             let other_records = match other_lookup.take() {
                 IngredientLookupResult::Records(recs) => recs,
                 IngredientLookupResult::Miss => {
+                    let miss_lookup_key = if range_op.is_some() {
+                        MissLookupKey::Key(other_lookup_key)
+                    } else {
+                        MissLookupKey::RecordColumns(from_key.clone())
+                    };
                     misses.extend(group.map(|record| {
                         Miss::builder()
                             .on(other)
                             .lookup_idx(other_key.clone())
-                            .lookup_key(from_key.clone())
+                            .lookup_key(miss_lookup_key.clone())
                             .replay(replay)
                             .replay_key_cols(replay_key_cols.as_deref())
                             .record(record.into_row())
@@ -459,9 +701,7 @@ impl Ingredient for Join {
                 lookups.push(Lookup {
                     on: other,
                     cols: other_key.clone(),
-                    key: join_key
-                        .try_into()
-                        .map_err(|_| internal_err!("Empty join key"))?,
+                    key: other_lookup_key,
                 });
             }
 
@@ -474,32 +714,49 @@ impl Ingredient for Join {
                 rc_diff += if positive { 1 } else { -1 };
 
                 if other_rows.is_empty() {
-                    if self.kind == JoinType::Left && from_left {
-                        // left join, got a thing from left, no rows in right == NULL
+                    if self.preserves(from_side) {
+                        // outer join, got a thing from a preserved side, no rows in the other side
+                        // == NULL
+                        ret.push((self.generate_null_for(from_side, &row), positive).into());
+                    }
+                } else if self.is_semi_or_anti() {
+                    // A left row with matches is emitted once by a semi-join, and not at all by an
+                    // anti-join. Rows from the right are never emitted directly; instead, we
+                    // emit or retract the left rows below if the right side's count for the key
+                    // changed to or from zero.
+                    if from_left && self.kind == JoinType::Semi {
                         ret.push((self.generate_null(&row), positive).into());
                     }
                 } else {
                     for other in other_rows.iter() {
                         if from == *self.left {
-                            ret.push((self.generate_row(&row, other), positive).into());
-                        } else {
+                            if self.conditions_hold(&row, other) {
+                                ret.push((self.generate_row(&row, other), positive).into());
+                            }
+                        } else if self.conditions_hold(other, &row) {
                             ret.push((self.generate_row(other, &row), positive).into());
                         }
                     }
                 }
             }
 
-            // For a left join with updates from the right side, we also have to emit/delete NULL
-            // rows if row count changed to/from zero
-            if let Some(new_rc) = new_right_count {
+            // For an outer join with updates from the side opposite a preserved side, we also have
+            // to emit/delete NULL rows if row count changed to/from zero. Semi-joins do the
+            // opposite, emitting left rows when they gain their first match.
+            if let Some(new_rc) = new_from_count {
                 let old_rc = new_rc as isize - rc_diff;
+                let emit_unmatched = self.kind != JoinType::Semi;
                 if new_rc == 0 && old_rc != 0 {
                     for other in other_rows.iter() {
-                        ret.push((self.generate_null(other), true).into());
+                        ret.push(
+                            (self.generate_null_for(other_side, other), emit_unmatched).into(),
+                        );
                     }
                 } else if new_rc != 0 && old_rc == 0 {
                     for other in other_rows.iter() {
-                        ret.push((self.generate_null(other), false).into());
+                        ret.push(
+                            (self.generate_null_for(other_side, other), !emit_unmatched).into(),
+                        );
                     }
                 }
             }
@@ -513,6 +770,21 @@ impl Ingredient for Join {
     }
 
     fn suggest_indexes(&self, _this: NodeIndex) -> HashMap<NodeIndex, LookupIndex> {
+        if !self.conditions.is_empty() {
+            // Theta joins do range lookups, which can't be done into weak indexes
+            let (left, right) = self.lookup_cols();
+            return HashMap::from([
+                (
+                    self.left.as_global(),
+                    LookupIndex::Strict(Index::btree_map(left)),
+                ),
+                (
+                    self.right.as_global(),
+                    LookupIndex::Strict(Index::btree_map(right)),
+                ),
+            ]);
+        }
+
         // Replays might have happened through our parents into keys *other* than the join key, and
         // we need to find those rows when looking up values to perform the join as part of forward
         // processing of normal writes - so we use a weak index here to avoid dropping writes in
@@ -534,6 +806,9 @@ impl Ingredient for Join {
             return String::from(match self.kind {
                 JoinType::Left => "⋉",
                 JoinType::Inner => "⋈",
+                JoinType::Full => "⟗",
+                JoinType::Semi => "⋉∃",
+                JoinType::Anti => "▷",
             });
         }
 
@@ -553,10 +828,25 @@ impl Ingredient for Join {
         let op = match self.kind {
             JoinType::Left => "⋉",
             JoinType::Inner => "⋈",
+            JoinType::Full => "⟗",
+            JoinType::Semi => "⋉∃",
+            JoinType::Anti => "▷",
+        };
+
+        let conditions = if self.conditions.is_empty() {
+            String::new()
+        } else {
+            format!(
+                " θ[{}]",
+                self.conditions
+                    .iter()
+                    .map(|cond| format!("l{} {} r{}", cond.left, cond.op, cond.right))
+                    .join(", ")
+            )
         };
 
         format!(
-            "[{}] {}:({}) {} {}:({})",
+            "[{}] {}:({}) {} {}:({}){}",
             emit,
             self.left.as_global().index(),
             self.on_left().into_iter().map(|i| i.to_string()).join(", "),
@@ -565,7 +855,8 @@ impl Ingredient for Join {
             self.on_right()
                 .into_iter()
                 .map(|i| i.to_string())
-                .join(", ")
+                .join(", "),
+            conditions
         )
     }
 
@@ -1252,4 +1543,530 @@ mod tests {
             );
         }
     }
+
+    mod theta {
+        use super::*;
+
+        /// `left JOIN right ON left.l0 = right.r0 AND left.l1 < right.r1`
+        fn setup() -> (ops::test::MockGraph, IndexPair, IndexPair) {
+            let mut g = ops::test::MockGraph::new();
+            let l = g.add_base("left", &["l0", "l1"]);
+            let r = g.add_base("right", &["r0", "r1"]);
+
+            use self::JoinSource::*;
+            let j = Join::new_with_conditions(
+                l.as_global(),
+                r.as_global(),
+                JoinType::Inner,
+                vec![B(0, 0), L(1), R(1)],
+                vec![JoinCondition {
+                    left: 1,
+                    op: JoinComparison::Less,
+                    right: 1,
+                }],
+            );
+
+            g.set_op("join", &["j0", "j1", "j2"], j, false);
+            (g, l, r)
+        }
+
+        #[test]
+        fn it_describes() {
+            let (j, l, r) = setup();
+            assert_eq!(
+                j.node().description(true),
+                format!(
+                    "[{}:0, {}:1, {}:1] {}:(0) ⋈ {}:(0) θ[l1 < r1]",
+                    l, l, r, l, r
+                )
+            );
+        }
+
+        #[test]
+        fn it_suggests_range_indices() {
+            let (j, l, r) = setup();
+            let me = 2.into();
+            let idx = j.node().suggest_indexes(me);
+            assert_eq!(
+                idx,
+                HashMap::from([
+                    (
+                        l.as_global(),
+                        LookupIndex::Strict(Index::btree_map(vec![0, 1]))
+                    ),
+                    (
+                        r.as_global(),
+                        LookupIndex::Strict(Index::btree_map(vec![0, 1]))
+                    ),
+                ])
+            );
+        }
+
+        #[test]
+        fn forward_from_left() {
+            let (mut j, l, r) = setup();
+
+            j.seed(r, vec![1.into(), 5.into()]);
+            j.seed(r, vec![1.into(), 10.into()]);
+            j.seed(r, vec![2.into(), 7.into()]);
+            j.seed(r, vec![1.into(), DfValue::None]);
+
+            j.seed(l, vec![1.into(), 5.into()]);
+            let rs = j.one_row(l, vec![1.into(), 5.into()], false);
+            assert_eq!(rs, vec![(vec![1.into(), 5.into(), 10.into()], true)].into());
+
+            // NULLs never compare, so never join
+            j.seed(l, vec![1.into(), DfValue::None]);
+            assert!(j
+                .one_row(l, vec![1.into(), DfValue::None], false)
+                .is_empty());
+        }
+
+        #[test]
+        fn forward_from_right() {
+            let (mut j, l, r) = setup();
+
+            j.seed(l, vec![1.into(), 2.into()]);
+            j.seed(l, vec![1.into(), 8.into()]);
+            j.seed(l, vec![2.into(), 3.into()]);
+            j.seed(l, vec![1.into(), DfValue::None]);
+
+            j.seed(r, vec![1.into(), 8.into()]);
+            let rs = j.one_row(r, vec![1.into(), 8.into()], false);
+            assert_eq!(rs, vec![(vec![1.into(), 2.into(), 8.into()], true)].into());
+
+            let rs = j.one_row(r, (vec![1.into(), 8.into()], false), false);
+            assert_eq!(rs, vec![(vec![1.into(), 2.into(), 8.into()], false)].into());
+        }
+
+        #[test]
+        fn multiple_conditions() {
+            // `left JOIN right ON left.l0 = right.r0 AND left.l1 BETWEEN right.r1 AND right.r2`
+            let mut g = ops::test::MockGraph::new();
+            let l = g.add_base("left", &["l0", "l1"]);
+            let r = g.add_base("right", &["r0", "r1", "r2"]);
+
+            use self::JoinSource::*;
+            let j = Join::new_with_conditions(
+                l.as_global(),
+                r.as_global(),
+                JoinType::Inner,
+                vec![B(0, 0), L(1), R(1), R(2)],
+                vec![
+                    JoinCondition {
+                        left: 1,
+                        op: JoinComparison::GreaterOrEqual,
+                        right: 1,
+                    },
+                    JoinCondition {
+                        left: 1,
+                        op: JoinComparison::LessOrEqual,
+                        right: 2,
+                    },
+                ],
+            );
+            g.set_op("join", &["j0", "j1", "j2", "j3"], j, false);
+
+            g.seed(r, vec![1.into(), 1.into(), 5.into()]);
+            g.seed(r, vec![1.into(), 3.into(), 4.into()]);
+            g.seed(r, vec![1.into(), 6.into(), 9.into()]);
+
+            g.seed(l, vec![1.into(), 5.into()]);
+            let rs = g.one_row(l, vec![1.into(), 5.into()], false);
+            assert_eq!(
+                rs,
+                vec![(vec![1.into(), 5.into(), 1.into(), 5.into()], true)].into()
+            );
+        }
+    }
+
+    mod full {
+        use super::*;
+
+        /// `left FULL OUTER JOIN right ON left.l0 = right.r0`
+        fn setup() -> (ops::test::MockGraph, IndexPair, IndexPair) {
+            let mut g = ops::test::MockGraph::new();
+            let l = g.add_base("left", &["l0", "l1"]);
+            let r = g.add_base("right", &["r0", "r1"]);
+
+            use self::JoinSource::*;
+            let j = Join::new(
+                l.as_global(),
+                r.as_global(),
+                JoinType::Full,
+                vec![B(0, 0), L(1), R(0), R(1)],
+            );
+
+            g.set_op("join", &["j0", "j1", "j2", "j3"], j, false);
+            (g, l, r)
+        }
+
+        #[test]
+        fn it_describes() {
+            let (j, l, r) = setup();
+            assert_eq!(
+                j.node().description(true),
+                format!("[{}:0, {}:1, {}:0, {}:1] {}:(0) ⟗ {}:(0)", l, l, r, r, l, r)
+            );
+        }
+
+        #[test]
+        fn unmatched_from_left() {
+            let (mut j, l, _r) = setup();
+            let l_a1 = vec![1.into(), "a".try_into().unwrap()];
+            j.seed(l, l_a1.clone());
+            let rs = j.one_row(l, l_a1, false);
+            assert_eq!(
+                rs,
+                vec![(
+                    vec![
+                        1.into(),
+                        "a".try_into().unwrap(),
+                        DfValue::None,
+                        DfValue::None
+                    ],
+                    true
+                )]
+                .into()
+            );
+        }
+
+        #[test]
+        fn unmatched_from_right() {
+            let (mut j, _l, r) = setup();
+            let r_x1 = vec![1.into(), "x".try_into().unwrap()];
+            j.seed(r, r_x1.clone());
+            let rs = j.one_row(r, r_x1, false);
+            assert_eq!(
+                rs,
+                vec![(
+                    vec![
+                        DfValue::None,
+                        DfValue::None,
+                        1.into(),
+                        "x".try_into().unwrap()
+                    ],
+                    true
+                )]
+                .into()
+            );
+        }
+
+        #[test]
+        fn match_from_left_retracts_right_nulls() {
+            let (mut j, l, r) = setup();
+            let l_a1 = vec![1.into(), "a".try_into().unwrap()];
+            let l_b1 = vec![1.into(), "b".try_into().unwrap()];
+            let r_x1 = vec![1.into(), "x".try_into().unwrap()];
+            j.seed(r, r_x1.clone());
+            j.one_row(r, r_x1, false);
+
+            j.seed(l, l_a1.clone());
+            let rs = j.one_row(l, l_a1, false);
+            assert_eq!(
+                rs,
+                vec![
+                    (
+                        vec![
+                            1.into(),
+                            "a".try_into().unwrap(),
+                            1.into(),
+                            "x".try_into().unwrap()
+                        ],
+                        true
+                    ),
+                    (
+                        vec![
+                            DfValue::None,
+                            DfValue::None,
+                            1.into(),
+                            "x".try_into().unwrap()
+                        ],
+                        false
+                    ),
+                ]
+                .into()
+            );
+
+            // a second match shouldn't retract anything else
+            j.seed(l, l_b1.clone());
+            let rs = j.one_row(l, l_b1, false);
+            assert_eq!(
+                rs,
+                vec![(
+                    vec![
+                        1.into(),
+                        "b".try_into().unwrap(),
+                        1.into(),
+                        "x".try_into().unwrap()
+                    ],
+                    true
+                )]
+                .into()
+            );
+        }
+
+        #[test]
+        fn match_from_right_retracts_left_nulls() {
+            let (mut j, l, r) = setup();
+            let l_a1 = vec![1.into(), "a".try_into().unwrap()];
+            let r_x1 = vec![1.into(), "x".try_into().unwrap()];
+            j.seed(l, l_a1.clone());
+            j.one_row(l, l_a1, false);
+
+            j.seed(r, r_x1.clone());
+            let rs = j.one_row(r, r_x1, false);
+            assert_eq!(
+                rs,
+                vec![
+                    (
+                        vec![
+                            1.into(),
+                            "a".try_into().unwrap(),
+                            1.into(),
+                            "x".try_into().unwrap()
+                        ],
+                        true
+                    ),
+                    (
+                        vec![
+                            1.into(),
+                            "a".try_into().unwrap(),
+                            DfValue::None,
+                            DfValue::None
+                        ],
+                        false
+                    ),
+                ]
+                .into()
+            );
+        }
+
+        #[test]
+        fn key_columns_sourced_from_own_side() {
+            let (j, l, r) = setup();
+            assert_eq!(
+                j.node().column_source(&[0]),
+                ColumnSource::exact_copy(l.as_global(), vec1![0])
+            );
+            assert_eq!(
+                j.node().column_source(&[2]),
+                ColumnSource::exact_copy(r.as_global(), vec1![0])
+            );
+        }
+
+        #[test]
+        fn null_keys_never_match() {
+            let (mut j, l, r) = setup();
+            let l_null = vec![DfValue::None, "a".try_into().unwrap()];
+            let r_null = vec![DfValue::None, "x".try_into().unwrap()];
+            j.seed(l, l_null.clone());
+            j.one_row(l, l_null, false);
+
+            j.seed(r, r_null.clone());
+            let rs = j.one_row(r, r_null, false);
+            assert_eq!(
+                rs,
+                vec![(
+                    vec![
+                        DfValue::None,
+                        DfValue::None,
+                        DfValue::None,
+                        "x".try_into().unwrap()
+                    ],
+                    true
+                )]
+                .into()
+            );
+        }
+    }
+
+    mod semi {
+        use super::*;
+
+        /// `SELECT left.* FROM left WHERE EXISTS (SELECT * FROM right WHERE right.r0 = left.l0)`
+        fn setup() -> (ops::test::MockGraph, IndexPair, IndexPair) {
+            let mut g = ops::test::MockGraph::new();
+            let l = g.add_base("left", &["l0", "l1"]);
+            let r = g.add_base("right", &["r0", "r1"]);
+
+            use self::JoinSource::*;
+            let j = Join::new(
+                l.as_global(),
+                r.as_global(),
+                JoinType::Semi,
+                vec![B(0, 0), L(1)],
+            );
+
+            g.set_op("join", &["j0", "j1"], j, false);
+            (g, l, r)
+        }
+
+        #[test]
+        fn it_describes() {
+            let (j, l, r) = setup();
+            assert_eq!(
+                j.node().description(true),
+                format!("[{}:0, {}:1] {}:(0) ⋉∃ {}:(0)", l, l, l, r)
+            );
+        }
+
+        #[test]
+        fn unmatched_from_left() {
+            let (mut j, l, _r) = setup();
+            let l_a1 = vec![1.into(), "a".try_into().unwrap()];
+            j.seed(l, l_a1.clone());
+            let rs = j.one_row(l, l_a1, false);
+            assert!(rs.is_empty());
+        }
+
+        #[test]
+        fn left_row_emitted_once() {
+            let (mut j, l, r) = setup();
+            let l_a1 = vec![1.into(), "a".try_into().unwrap()];
+            let r_x1 = vec![1.into(), "x".try_into().unwrap()];
+            let r_y1 = vec![1.into(), "y".try_into().unwrap()];
+            j.seed(r, r_x1.clone());
+            j.one_row(r, r_x1, false);
+            j.seed(r, r_y1.clone());
+            j.one_row(r, r_y1, false);
+
+            j.seed(l, l_a1.clone());
+            let rs = j.one_row(l, l_a1.clone(), false);
+            assert_eq!(rs, vec![(l_a1, true)].into());
+        }
+
+        #[test]
+        fn first_match_from_right_emits_left() {
+            let (mut j, l, r) = setup();
+            let l_a1 = vec![1.into(), "a".try_into().unwrap()];
+            let r_x1 = vec![1.into(), "x".try_into().unwrap()];
+            let r_y1 = vec![1.into(), "y".try_into().unwrap()];
+            j.seed(l, l_a1.clone());
+            j.one_row(l, l_a1.clone(), false);
+
+            j.seed(r, r_x1.clone());
+            let rs = j.one_row(r, r_x1, false);
+            assert_eq!(rs, vec![(l_a1, true)].into());
+
+            // a second match shouldn't emit anything else
+            j.seed(r, r_y1.clone());
+            let rs = j.one_row(r, r_y1, false);
+            assert!(rs.is_empty());
+        }
+
+        #[test]
+        fn last_match_removed_retracts_left() {
+            let (mut j, l, r) = setup();
+            let l_a1 = vec![1.into(), "a".try_into().unwrap()];
+            let r_x1 = vec![1.into(), "x".try_into().unwrap()];
+            j.seed(l, l_a1.clone());
+            j.seed(r, r_x1.clone());
+            j.one_row(r, r_x1.clone(), false);
+
+            j.unseed(r);
+            let rs = j.one_row(r, (r_x1, false), false);
+            assert_eq!(rs, vec![(l_a1, false)].into());
+        }
+    }
+
+    mod anti {
+        use super::*;
+
+        /// `SELECT left.* FROM left WHERE NOT EXISTS (SELECT * FROM right WHERE right.r0 =
+        /// left.l0)`
+        fn setup() -> (ops::test::MockGraph, IndexPair, IndexPair) {
+            let mut g = ops::test::MockGraph::new();
+            let l = g.add_base("left", &["l0", "l1"]);
+            let r = g.add_base("right", &["r0", "r1"]);
+
+            use self::JoinSource::*;
+            let j = Join::new(
+                l.as_global(),
+                r.as_global(),
+                JoinType::Anti,
+                vec![B(0, 0), L(1)],
+            );
+
+            g.set_op("join", &["j0", "j1"], j, false);
+            (g, l, r)
+        }
+
+        #[test]
+        fn it_describes() {
+            let (j, l, r) = setup();
+            assert_eq!(
+                j.node().description(true),
+                format!("[{}:0, {}:1] {}:(0) ▷ {}:(0)", l, l, l, r)
+            );
+        }
+
+        #[test]
+        fn unmatched_from_left() {
+            let (mut j, l, _r) = setup();
+            let l_a1 = vec![1.into(), "a".try_into().unwrap()];
+            j.seed(l, l_a1.clone());
+            let rs = j.one_row(l, l_a1.clone(), false);
+            assert_eq!(rs, vec![(l_a1, true)].into());
+        }
+
+        #[test]
+        fn matched_from_left() {
+            let (mut j, l, r) = setup();
+            let l_a1 = vec![1.into(), "a".try_into().unwrap()];
+            let r_x1 = vec![1.into(), "x".try_into().unwrap()];
+            j.seed(r, r_x1.clone());
+            j.one_row(r, r_x1, false);
+
+            j.seed(l, l_a1.clone());
+            let rs = j.one_row(l, l_a1, false);
+            assert!(rs.is_empty());
+        }
+
+        #[test]
+        fn first_match_from_right_retracts_left() {
+            let (mut j, l, r) = setup();
+            let l_a1 = vec![1.into(), "a".try_into().unwrap()];
+            let r_x1 = vec![1.into(), "x".try_into().unwrap()];
+            let r_y1 = vec![1.into(), "y".try_into().unwrap()];
+            j.seed(l, l_a1.clone());
+            j.one_row(l, l_a1.clone(), false);
+
+            j.seed(r, r_x1.clone());
+            let rs = j.one_row(r, r_x1, false);
+            assert_eq!(rs, vec![(l_a1, false)].into());
+
+            // a second match shouldn't retract anything else
+            j.seed(r, r_y1.clone());
+            let rs = j.one_row(r, r_y1, false);
+            assert!(rs.is_empty());
+        }
+
+        #[test]
+        fn last_match_removed_emits_left() {
+            let (mut j, l, r) = setup();
+            let l_a1 = vec![1.into(), "a".try_into().unwrap()];
+            let r_x1 = vec![1.into(), "x".try_into().unwrap()];
+            j.seed(l, l_a1.clone());
+            j.seed(r, r_x1.clone());
+            j.one_row(r, r_x1.clone(), false);
+
+            j.unseed(r);
+            let rs = j.one_row(r, (r_x1, false), false);
+            assert_eq!(rs, vec![(l_a1, true)].into());
+        }
+
+        #[test]
+        fn null_keys_never_match() {
+            let (mut j, l, r) = setup();
+            let l_null = vec![DfValue::None, "a".try_into().unwrap()];
+            let r_null = vec![DfValue::None, "x".try_into().unwrap()];
+            j.seed(r, r_null.clone());
+            j.one_row(r, r_null, false);
+
+            j.seed(l, l_null.clone());
+            let rs = j.one_row(l, l_null.clone(), false);
+            assert_eq!(rs, vec![(l_null, true)].into());
+        }
+    }
 }
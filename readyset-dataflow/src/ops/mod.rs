@@ -17,7 +17,9 @@ pub mod project;
 pub mod topk;
 pub mod union;
 pub(crate) mod utils;
+pub mod window;
 
+use crate::ops::grouped::collect::Collect;
 use crate::ops::grouped::concat::GroupConcat;
 use crate::processing::{
     ColumnMiss, ColumnSource, IngredientLookupResult, LookupIndex, LookupMode,
@@ -47,6 +49,7 @@ pub enum NodeOperator {
     Aggregation(grouped::GroupedOperator<grouped::aggregate::Aggregator>),
     Extremum(grouped::GroupedOperator<grouped::extremum::ExtremumOperator>),
     Concat(grouped::GroupedOperator<GroupConcat>),
+    Collect(grouped::GroupedOperator<Collect>),
     Join(join::Join),
     Paginate(paginate::Paginate),
     Project(project::Project),
@@ -54,6 +57,7 @@ pub enum NodeOperator {
     Identity(identity::Identity),
     Filter(filter::Filter),
     TopK(topk::TopK),
+    Window(window::Window),
 }
 
 impl ToString for NodeOperator {
@@ -62,6 +66,7 @@ impl ToString for NodeOperator {
             NodeOperator::Aggregation(_) => "Aggregation",
             NodeOperator::Extremum(_) => "Extermum",
             NodeOperator::Concat(_) => "Concat",
+            NodeOperator::Collect(_) => "Collect",
             NodeOperator::Join(_) => "Join",
             NodeOperator::Paginate(_) => "Paginate",
             NodeOperator::Project(_) => "Project",
@@ -69,6 +74,7 @@ impl ToString for NodeOperator {
             NodeOperator::Identity(_) => "Identity",
             NodeOperator::Filter(_) => "Filter",
             NodeOperator::TopK(_) => "TopK",
+            NodeOperator::Window(_) => "Window",
         }
         .to_string()
     }
@@ -80,6 +86,7 @@ macro_rules! impl_ingredient_fn_mut {
             NodeOperator::Aggregation(ref mut i) => i.$fn($($arg),*),
             NodeOperator::Extremum(ref mut i) => i.$fn($($arg),*),
             NodeOperator::Concat(ref mut i) => i.$fn($($arg),*),
+            NodeOperator::Collect(ref mut i) => i.$fn($($arg),*),
             NodeOperator::Join(ref mut i) => i.$fn($($arg),*),
             NodeOperator::Paginate(ref mut i) => i.$fn($($arg),*),
             NodeOperator::Project(ref mut i) => i.$fn($($arg),*),
@@ -87,6 +94,7 @@ macro_rules! impl_ingredient_fn_mut {
             NodeOperator::Identity(ref mut i) => i.$fn($($arg),*),
             NodeOperator::Filter(ref mut i) => i.$fn($($arg),*),
             NodeOperator::TopK(ref mut i) => i.$fn($($arg),*),
+            NodeOperator::Window(ref mut i) => i.$fn($($arg),*),
         }
     }
 }
@@ -97,6 +105,7 @@ macro_rules! impl_ingredient_fn_ref {
             NodeOperator::Aggregation(ref i) => i.$fn($($arg),*),
             NodeOperator::Extremum(ref i) => i.$fn($($arg),*),
             NodeOperator::Concat(ref i) => i.$fn($($arg),*),
+            NodeOperator::Collect(ref i) => i.$fn($($arg),*),
             NodeOperator::Join(ref i) => i.$fn($($arg),*),
             NodeOperator::Paginate(ref i) => i.$fn($($arg),*),
             NodeOperator::Project(ref i) => i.$fn($($arg),*),
@@ -104,6 +113,7 @@ macro_rules! impl_ingredient_fn_ref {
             NodeOperator::Identity(ref i) => i.$fn($($arg),*),
             NodeOperator::Filter(ref i) => i.$fn($($arg),*),
             NodeOperator::TopK(ref i) => i.$fn($($arg),*),
+            NodeOperator::Window(ref i) => i.$fn($($arg),*),
         }
     }
 }
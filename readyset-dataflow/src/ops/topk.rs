@@ -7,7 +7,7 @@ use std::num::NonZeroUsize;
 
 use dataflow_state::PointKey;
 use itertools::Itertools;
-use nom_sql::OrderType;
+use nom_sql::{NullOrder, OrderType};
 use readyset_client::internal;
 use readyset_errors::{internal, internal_err, invariant, ReadySetResult};
 use readyset_util::Indices;
@@ -91,12 +91,13 @@ impl TopK {
     /// # Arguments
     ///
     /// * `src` - this operator's ancestor
-    /// * `order` - The list of columns to compute top k over
+    /// * `order` - The list of columns to compute top k over, with the direction and placement of
+    ///   NULLs for each
     /// * `group_by` - the columns that this operator is keyed on
     /// * `k` - the maximum number of results per group.
     pub fn new(
         src: NodeIndex,
-        order: Vec<(usize, OrderType)>,
+        order: Vec<(usize, OrderType, NullOrder)>,
         group_by: Vec<usize>,
         k: usize,
     ) -> Self {
@@ -474,12 +475,16 @@ mod tests {
     use crate::ops;
 
     fn setup(reversed: bool) -> (ops::test::MockGraph, IndexPair) {
-        let cmp_rows = if reversed {
-            vec![(2, OrderType::OrderDescending)]
+        setup_with_order(if reversed {
+            vec![(2, OrderType::OrderDescending, NullOrder::NullsLast)]
         } else {
-            vec![(2, OrderType::OrderAscending)]
-        };
+            vec![(2, OrderType::OrderAscending, NullOrder::NullsFirst)]
+        })
+    }
 
+    fn setup_with_order(
+        cmp_rows: Vec<(usize, OrderType, NullOrder)>,
+    ) -> (ops::test::MockGraph, IndexPair) {
         let mut g = ops::test::MockGraph::new();
         let s = g.add_base("source", &["x", "y", "z"]);
 
@@ -844,4 +849,41 @@ mod tests {
         let emit = g.narrow_one(vec![(ra3.clone(), false), (ra0, true)], true);
         assert_eq!(emit, vec![(ra3, false), (ra1, true)].into());
     }
+
+    #[test]
+    fn drops_nulls_when_nulls_are_smallest() {
+        let (mut g, _) = setup(false);
+        let ni = g.node().local_addr();
+
+        let r12: Vec<DfValue> = vec![1.into(), "z".try_into().unwrap(), 12.into()];
+        let r11: Vec<DfValue> = vec![2.into(), "z".try_into().unwrap(), 11.into()];
+        let r10: Vec<DfValue> = vec![3.into(), "z".try_into().unwrap(), 10.into()];
+        let rnull: Vec<DfValue> = vec![4.into(), "z".try_into().unwrap(), DfValue::None];
+
+        g.narrow_one_row(r12, true);
+        g.narrow_one_row(r11, true);
+        g.narrow_one_row(r10, true);
+        let a = g.narrow_one_row(rnull, true);
+        assert_eq!(a.len(), 0);
+        assert_eq!(g.states[ni].row_count(), 3);
+    }
+
+    #[test]
+    fn keeps_nulls_when_nulls_are_largest() {
+        let (mut g, _) =
+            setup_with_order(vec![(2, OrderType::OrderAscending, NullOrder::NullsLast)]);
+
+        let r12: Vec<DfValue> = vec![1.into(), "z".try_into().unwrap(), 12.into()];
+        let r11: Vec<DfValue> = vec![2.into(), "z".try_into().unwrap(), 11.into()];
+        let r10: Vec<DfValue> = vec![3.into(), "z".try_into().unwrap(), 10.into()];
+        let rnull: Vec<DfValue> = vec![4.into(), "z".try_into().unwrap(), DfValue::None];
+
+        g.narrow_one_row(r12, true);
+        g.narrow_one_row(r11, true);
+        g.narrow_one_row(r10.clone(), true);
+        let a = g.narrow_one_row(rnull.clone(), true);
+        assert_eq!(a.len(), 2);
+        assert!(a.iter().any(|r| r == &(r10.clone(), false).into()));
+        assert!(a.iter().any(|r| r == &(rnull.clone(), true).into()));
+    }
 }
@@ -5,7 +5,7 @@ use std::ops::Bound;
 
 use itertools::Itertools;
 use readyset_client::KeyComparison;
-use readyset_errors::{invariant, ReadySetResult};
+use readyset_errors::{invariant, invariant_eq, ReadySetResult};
 use readyset_util::hash::hash;
 use readyset_util::intervals::{cmp_endbound, cmp_startbound};
 use readyset_util::Indices;
@@ -37,6 +37,32 @@ pub enum DuplicateMode {
     /// "All" union mode - no duplicate removal is done. Each row in each parent is passed through
     /// unchanged
     UnionAll,
+
+    /// Intersection mode, which only returns rows which exist in both parents.
+    ///
+    /// If `all` is true, for each distinct row this returns a number of duplicates of that row
+    /// equal to the *minimum* number of duplicates of that row per parent (like `INTERSECT ALL`),
+    /// otherwise it returns at most one copy of each row (like `INTERSECT`).
+    ///
+    /// For example, the intersection of the bags `{1, 2, 3, 3, 3}` and `{3, 3, 4}` with `all` set
+    /// is equal to `{3, 3}`
+    ///
+    /// Intersect is only supported for Union nodes with exactly two parents
+    Intersect { all: bool },
+
+    /// Difference mode, which returns rows in the first parent which don't exist in the second
+    /// parent.
+    ///
+    /// If `all` is true, for each distinct row this returns a number of duplicates of that row
+    /// equal to the number of duplicates in the first parent minus the number of duplicates in the
+    /// second parent (like `EXCEPT ALL`), otherwise it returns one copy of each row in the first
+    /// parent which doesn't exist at all in the second parent (like `EXCEPT`).
+    ///
+    /// For example, the difference of the bags `{1, 2, 3, 3, 3}` and `{3, 4}` with `all` set is
+    /// equal to `{1, 2, 3, 3}`
+    ///
+    /// Except is only supported for Union nodes with exactly two parents
+    Except { all: bool },
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -178,15 +204,92 @@ impl PartialOrd for BufferedReplayKey {
     }
 }
 
+/// State for [`DuplicateMode::Intersect`] and [`DuplicateMode::Except`]
+///
+/// Like [`BagUnionState`], this is a map from the hash of unique rows, but to the number of copies
+/// of that row stored in each parent. Since the output only depends on those numbers, every time
+/// one of them changes we can compare the number of copies of the row we should be emitting before
+/// and after the change to know whether to emit or retract a copy of it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SetOperationState {
+    /// True for [`DuplicateMode::Intersect`], false for [`DuplicateMode::Except`]
+    intersect: bool,
+    /// Whether to return duplicate rows (the `all` field of the duplicate mode)
+    all: bool,
+    /// The parent which rows are taken from for [`DuplicateMode::Except`]
+    left: IndexPair,
+    // We skip any state when serializing, as we will deserialize
+    // when recovering.
+    #[serde(skip)]
+    counts: HashMap<u64, (usize, usize)>,
+}
+
+impl SetOperationState {
+    /// Returns the number of copies of a row we should be emitting, given the number of copies of
+    /// that row in the left and right parents
+    fn multiplicity(intersect: bool, all: bool, (left, right): (usize, usize)) -> usize {
+        match (intersect, all) {
+            (true, true) => left.min(right),
+            (true, false) => usize::from(left > 0 && right > 0),
+            (false, true) => left.saturating_sub(right),
+            (false, false) => usize::from(left > 0 && right == 0),
+        }
+    }
+
+    fn symbol(&self) -> &'static str {
+        match (self.intersect, self.all) {
+            (true, true) => "∩ALL",
+            (true, false) => "∩",
+            (false, true) => "∖ALL",
+            (false, false) => "∖",
+        }
+    }
+
+    /// Process a single record through the set operation state, and return the record to emit in
+    /// response to it, if any
+    fn process(&mut self, from_side: Side, record: Record) -> Option<Record> {
+        let (intersect, all) = (self.intersect, self.all);
+        let row_hash = hash(record.row());
+        let counts = self.counts.entry(row_hash).or_default();
+        let before = Self::multiplicity(intersect, all, *counts);
+
+        let count = match from_side {
+            Side::Left => &mut counts.0,
+            Side::Right => &mut counts.1,
+        };
+        if record.is_positive() {
+            *count += 1;
+        } else {
+            *count = count.saturating_sub(1);
+        }
+
+        let after = Self::multiplicity(intersect, all, *counts);
+        if *counts == (0, 0) {
+            self.counts.remove(&row_hash);
+        }
+
+        // Every record changes one of the counts by one, which changes the number of copies of the
+        // row we should be emitting by at most one
+        match after.cmp(&before) {
+            Ordering::Greater => Some(Record::Positive(record.into_row())),
+            Ordering::Less => Some(Record::Negative(record.into_row())),
+            Ordering::Equal => None,
+        }
+    }
+}
+
 /// A union of a set of views.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Union {
     emit: Emit,
 
-    /// State for implementing [`DuplicateMode::BagUnion`]. If this is None, then the duplicate
-    /// mode is [`DuplicateMode::UnionAll`]
+    /// State for implementing [`DuplicateMode::BagUnion`]. If this and `set_operation_state` are
+    /// both None, then the duplicate mode is [`DuplicateMode::UnionAll`]
     bag_union_state: Option<BagUnionState>,
 
+    /// State for implementing [`DuplicateMode::Intersect`] and [`DuplicateMode::Except`]
+    set_operation_state: Option<SetOperationState>,
+
     /// This is a map from (Tag, LocalNodeIndex) to ColumnList
     #[serde(with = "serde_with::rust::hashmap_as_tuple_list")]
     replay_key: HashMap<(Tag, usize), Vec<usize>>,
@@ -214,6 +317,7 @@ impl Clone for Union {
         Union {
             emit: self.emit.clone(),
             bag_union_state: self.bag_union_state.clone(),
+            set_operation_state: self.set_operation_state.clone(),
             required: self.required,
             replay_key: Default::default(),
             replay_pieces: Default::default(),
@@ -230,16 +334,20 @@ impl Union {
     /// When receiving an update from node `a`, a union will emit the columns selected in `emit[a]`.
     /// `emit` only supports omitting columns, not rearranging them.
     ///
+    /// For [`DuplicateMode::Except`], rows are taken from the first parent in `emit`, so `emit`
+    /// should be given in a consistent order.
+    ///
     /// Invariants:
     ///
     /// * `emit` argument's values must be ordered lists already. Union does not support
     /// re-arranging columns, only omitting them.
-    pub fn new(
-        emit: HashMap<NodeIndex, Vec<usize>>,
-        duplicate_mode: DuplicateMode,
-    ) -> ReadySetResult<Union> {
+    pub fn new<I>(emit: I, duplicate_mode: DuplicateMode) -> ReadySetResult<Union>
+    where
+        I: IntoIterator<Item = (NodeIndex, Vec<usize>)>,
+    {
+        let emit = emit.into_iter().collect::<Vec<_>>();
         invariant!(!emit.is_empty());
-        for emit in emit.values() {
+        for (_, emit) in &emit {
             let mut last = &emit[0];
             for i in emit {
                 invariant!(
@@ -250,6 +358,27 @@ impl Union {
                 last = i;
             }
         }
+        let set_operation_state = match duplicate_mode {
+            DuplicateMode::Intersect { .. } | DuplicateMode::Except { .. } => {
+                invariant_eq!(
+                    emit.len(),
+                    2,
+                    "Union node with DuplicateMode::{:?} must have exactly 2 parents",
+                    duplicate_mode
+                );
+                Some(SetOperationState {
+                    intersect: matches!(duplicate_mode, DuplicateMode::Intersect { .. }),
+                    all: matches!(
+                        duplicate_mode,
+                        DuplicateMode::Intersect { all: true }
+                            | DuplicateMode::Except { all: true }
+                    ),
+                    left: emit[0].0.into(),
+                    counts: Default::default(),
+                })
+            }
+            DuplicateMode::BagUnion | DuplicateMode::UnionAll => None,
+        };
         let emit: HashMap<_, _> = emit.into_iter().map(|(k, v)| (k.into(), v)).collect();
         let parents = emit.len();
         Ok(Union {
@@ -261,8 +390,9 @@ impl Union {
             },
             bag_union_state: match duplicate_mode {
                 DuplicateMode::BagUnion => Some(BagUnionState::default()),
-                DuplicateMode::UnionAll => None,
+                _ => None,
             },
+            set_operation_state,
             required: parents,
             replay_key: Default::default(),
             replay_pieces: Default::default(),
@@ -277,6 +407,7 @@ impl Union {
         Union {
             emit: Emit::AllFrom(parent.into(), sharding),
             bag_union_state: None,
+            set_operation_state: None,
             required: shards,
             replay_key: Default::default(),
             replay_pieces: Default::default(),
@@ -339,6 +470,11 @@ impl Ingredient for Union {
                 }
             }
         }
+        if let Some(sos) = &mut self.set_operation_state {
+            if sos.left.as_global() == from_idx {
+                sos.left = to_idx.into();
+            }
+        }
     }
 
     fn on_commit(&mut self, me: NodeIndex, remap: &HashMap<NodeIndex, IndexPair>) {
@@ -373,6 +509,9 @@ impl Ingredient for Union {
                 p.remap(remap);
             }
         }
+        if let Some(sos) = &mut self.set_operation_state {
+            sos.left.remap(remap);
+        }
     }
 
     fn on_input(
@@ -425,6 +564,18 @@ impl Ingredient for Union {
             }
         }
 
+        if let Some(sos) = &mut self.set_operation_state {
+            let side = if from == *sos.left {
+                Side::Left
+            } else {
+                Side::Right
+            };
+            results = results
+                .into_iter()
+                .filter_map(|rec| sos.process(side, rec))
+                .collect();
+        }
+
         Ok(ProcessingResult {
             results,
             ..Default::default()
@@ -1012,6 +1163,13 @@ impl Ingredient for Union {
     }
 
     fn column_source(&self, cols: &[usize]) -> ColumnSource {
+        if self.set_operation_state.is_some() {
+            // The counts in the set operation state are only correct if they include every row in
+            // both parents, so we can't be partially materialized
+            #[allow(clippy::unwrap_used)] // Set operations always have two parents
+            return ColumnSource::RequiresFullReplay(self.ancestors().try_into().unwrap());
+        }
+
         match self.emit {
             Emit::AllFrom(p, _) => {
                 ColumnSource::exact_copy(p.as_global(), cols.try_into().unwrap())
@@ -1040,14 +1198,23 @@ impl Ingredient for Union {
             Emit::AllFrom(..) => "⊍".to_string(),
             Emit::Project { .. } if !detailed => String::from("⋃"),
             Emit::Project { ref emit, .. } => {
-                let symbol = if self.bag_union_state.is_none() {
-                    '⋃' // DuplicateMode::UnionAll
-                } else {
-                    '⊎' // DuplicateMode::BagUnion
+                let symbol = match &self.set_operation_state {
+                    Some(sos) => sos.symbol(),
+                    None if self.bag_union_state.is_none() => "⋃", // DuplicateMode::UnionAll
+                    None => "⊎",                                   // DuplicateMode::BagUnion
                 };
                 if detailed {
                     emit.iter()
-                        .sorted()
+                        // Put the left parent of set operations first, since Except isn't
+                        // commutative
+                        .sorted_by_key(|(src, _)| {
+                            (
+                                self.set_operation_state
+                                    .as_ref()
+                                    .map_or(false, |sos| sos.left != **src),
+                                **src,
+                            )
+                        })
                         .map(|(src, emit)| {
                             let cols = emit
                                 .iter()
@@ -1396,4 +1563,137 @@ mod tests {
             assert_eq!(u.one_row(r, right_row, false), vec![left_row].into());
         }
     }
+
+    mod set_operations {
+        use super::*;
+
+        fn setup_set_operation(
+            duplicate_mode: DuplicateMode,
+        ) -> (ops::test::MockGraph, IndexPair, IndexPair) {
+            let mut g = ops::test::MockGraph::new();
+            let l = g.add_base("left", &["l0", "l1"]);
+            let r = g.add_base("right", &["r0", "r1", "r2"]);
+
+            g.set_op(
+                "union",
+                &["u0", "u1"],
+                Union::new(
+                    vec![(l.as_global(), vec![0, 1]), (r.as_global(), vec![0, 2])],
+                    duplicate_mode,
+                )
+                .unwrap(),
+                false,
+            );
+            (g, l, r)
+        }
+
+        fn rows() -> (Vec<DfValue>, Vec<DfValue>) {
+            (
+                vec![1.into(), "a".try_into().unwrap()],
+                vec![
+                    1.into(),
+                    "skipped".try_into().unwrap(),
+                    "a".try_into().unwrap(),
+                ],
+            )
+        }
+
+        #[test]
+        fn it_describes() {
+            let (u, l, r) = setup_set_operation(DuplicateMode::Except { all: true });
+            assert_eq!(
+                u.node().description(true),
+                format!("{}:[0, 1] ∖ALL {}:[0, 2]", l, r)
+            );
+        }
+
+        #[test]
+        fn intersect() {
+            let (mut u, l, r) = setup_set_operation(DuplicateMode::Intersect { all: false });
+            let (left_row, right_row) = rows();
+
+            assert_eq!(u.one_row(l, left_row.clone(), false), Default::default());
+            assert_eq!(u.one_row(l, left_row.clone(), false), Default::default());
+            assert_eq!(
+                u.one_row(r, right_row.clone(), false),
+                vec![left_row.clone()].into()
+            );
+            assert_eq!(u.one_row(r, right_row.clone(), false), Default::default());
+            assert_eq!(
+                u.one_row(r, (right_row.clone(), false), false),
+                Default::default()
+            );
+            assert_eq!(
+                u.one_row(r, (right_row, false), false),
+                vec![(left_row, false)].into()
+            );
+        }
+
+        #[test]
+        fn intersect_all() {
+            let (mut u, l, r) = setup_set_operation(DuplicateMode::Intersect { all: true });
+            let (left_row, right_row) = rows();
+
+            assert_eq!(u.one_row(l, left_row.clone(), false), Default::default());
+            assert_eq!(u.one_row(l, left_row.clone(), false), Default::default());
+            assert_eq!(
+                u.one_row(r, right_row.clone(), false),
+                vec![left_row.clone()].into()
+            );
+            assert_eq!(
+                u.one_row(r, right_row.clone(), false),
+                vec![left_row.clone()].into()
+            );
+            assert_eq!(u.one_row(r, right_row, false), Default::default());
+            assert_eq!(
+                u.one_row(l, (left_row.clone(), false), false),
+                vec![(left_row, false)].into()
+            );
+        }
+
+        #[test]
+        fn except() {
+            let (mut u, l, r) = setup_set_operation(DuplicateMode::Except { all: false });
+            let (left_row, right_row) = rows();
+
+            assert_eq!(
+                u.one_row(l, left_row.clone(), false),
+                vec![left_row.clone()].into()
+            );
+            assert_eq!(u.one_row(l, left_row.clone(), false), Default::default());
+            assert_eq!(
+                u.one_row(r, right_row.clone(), false),
+                vec![(left_row.clone(), false)].into()
+            );
+            assert_eq!(
+                u.one_row(r, (right_row, false), false),
+                vec![left_row].into()
+            );
+        }
+
+        #[test]
+        fn except_all() {
+            let (mut u, l, r) = setup_set_operation(DuplicateMode::Except { all: true });
+            let (left_row, right_row) = rows();
+
+            assert_eq!(
+                u.one_row(l, left_row.clone(), false),
+                vec![left_row.clone()].into()
+            );
+            assert_eq!(
+                u.one_row(l, left_row.clone(), false),
+                vec![left_row.clone()].into()
+            );
+            assert_eq!(
+                u.one_row(r, right_row.clone(), false),
+                vec![(left_row.clone(), false)].into()
+            );
+            assert_eq!(
+                u.one_row(r, right_row.clone(), false),
+                vec![(left_row, false)].into()
+            );
+            // Extra copies of rows in the right parent don't do anything
+            assert_eq!(u.one_row(r, right_row, false), Default::default());
+        }
+    }
 }
@@ -2,19 +2,19 @@ use std::cmp::Ordering;
 use std::fmt::Display;
 
 use itertools::Itertools;
-use nom_sql::OrderType;
+use nom_sql::{NullOrder, OrderType};
 use serde::{Deserialize, Serialize};
 
 use crate::prelude::DfValue;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-pub(crate) struct Order(Vec<(usize, OrderType)>);
+pub(crate) struct Order(Vec<(usize, OrderType, NullOrder)>);
 impl Order {
     pub(crate) fn cmp(&self, a: &[DfValue], b: &[DfValue]) -> Ordering {
-        for &(c, ref order_type) in &self.0 {
-            let result = match *order_type {
-                OrderType::OrderAscending => a[c].cmp(&b[c]),
-                OrderType::OrderDescending => b[c].cmp(&a[c]),
+        for &(c, ref order_type, ref null_order) in &self.0 {
+            let result = match null_order.apply(a[c].is_none(), b[c].is_none()) {
+                Some(result) => result,
+                None => order_type.apply(a[c].cmp(&b[c])),
             };
             if result != Ordering::Equal {
                 return result;
@@ -24,9 +24,21 @@ impl Order {
     }
 }
 
+impl From<Vec<(usize, OrderType, NullOrder)>> for Order {
+    fn from(other: Vec<(usize, OrderType, NullOrder)>) -> Self {
+        Order(other)
+    }
+}
+
+/// Orderings which don't specify where to place NULLs treat NULL as smaller than every other value
 impl From<Vec<(usize, OrderType)>> for Order {
     fn from(other: Vec<(usize, OrderType)>) -> Self {
-        Order(other)
+        Order(
+            other
+                .into_iter()
+                .map(|(c, ot)| (c, ot, NullOrder::default_for(ot)))
+                .collect(),
+        )
     }
 }
 
@@ -37,14 +49,22 @@ impl Display for Order {
             "{}",
             self.0
                 .iter()
-                .map(|(c, dir)| {
+                .map(|(c, dir, nulls)| {
                     format!(
-                        "{}{}",
+                        "{}{}{}",
                         match dir {
                             OrderType::OrderAscending => "<",
                             OrderType::OrderDescending => ">",
                         },
                         c,
+                        if *nulls == NullOrder::default_for(*dir) {
+                            ""
+                        } else {
+                            match nulls {
+                                NullOrder::NullsFirst => " nulls first",
+                                NullOrder::NullsLast => " nulls last",
+                            }
+                        },
                     )
                 })
                 .join(", "),
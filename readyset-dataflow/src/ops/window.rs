@@ -0,0 +1,685 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::convert::TryInto;
+use std::fmt;
+
+use dataflow_state::PointKey;
+use itertools::Itertools;
+use nom_sql::OrderType;
+use readyset_data::DfType;
+use readyset_util::Indices;
+use serde::{Deserialize, Serialize};
+
+use crate::ops::utils::Order;
+use crate::prelude::*;
+use crate::processing::{ColumnMiss, LookupIndex};
+
+/// The window functions which can be computed by a [`Window`] operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WindowFunction {
+    /// `ROW_NUMBER()`: the 1-based position of the row within its partition.
+    RowNumber,
+    /// `RANK()`: the 1-based position of the first of the row's peers within its partition, with
+    /// gaps after groups of peers.
+    Rank,
+    /// `DENSE_RANK()`: the 1-based index of the row's group of peers within its partition, without
+    /// gaps.
+    DenseRank,
+    /// `COUNT(col)` or `COUNT(*)`: the running count of rows (or non-null values of the `over`
+    /// column) up to and including the row's peers.
+    Count,
+    /// `SUM(col)`: the running sum of the `over` column up to and including the row's peers.
+    Sum,
+}
+
+impl fmt::Display for WindowFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WindowFunction::RowNumber => write!(f, "row_number"),
+            WindowFunction::Rank => write!(f, "rank"),
+            WindowFunction::DenseRank => write!(f, "dense_rank"),
+            WindowFunction::Count => write!(f, "count"),
+            WindowFunction::Sum => write!(f, "sum"),
+        }
+    }
+}
+
+/// Operator which computes a window function over the rows of each partition of its input,
+/// emitting its parent's rows with the result of that function appended as an extra column.
+///
+/// Like [`Paginate`](crate::ops::paginate::Paginate), this operator keeps its own output
+/// materialized, indexed on the partition columns. When records arrive for a partition, the
+/// current contents of that partition are loaded from that state, the window function is
+/// recomputed over the partition in order, and a negative and positive record are emitted for
+/// every row whose value for the window function changed.
+///
+/// Two rows are *peers* if they compare equal according to the ordering of the window. As in SQL,
+/// rows which are peers always have the same value for [`Rank`], [`DenseRank`], [`Count`] and
+/// [`Sum`], and if the window has no ordering all the rows in a partition are peers.
+///
+/// [`Rank`]: WindowFunction::Rank
+/// [`DenseRank`]: WindowFunction::DenseRank
+/// [`Count`]: WindowFunction::Count
+/// [`Sum`]: WindowFunction::Sum
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Window {
+    /// The direct Ingredient or Base ancestor of this node
+    src: IndexPair,
+    /// The index of this node. Used to look up into our own state
+    our_index: Option<IndexPair>,
+    /// The column index of the output column emitted by this node
+    ///
+    /// This is always equal to the number of columns in the parent node (there is one more output
+    /// column than input columns)
+    ///
+    /// Set during [`Ingredient::on_connected`]
+    output_col: Option<usize>,
+    /// The window function to compute
+    function: WindowFunction,
+    /// The column to compute the window function over, if the function takes an argument
+    over: Option<usize>,
+    /// The list of column indices that we're partitioning by
+    partition_by: Vec<usize>,
+    /// The ordering of records within each partition
+    order: Order,
+    /// The type of the output column
+    out_ty: DfType,
+}
+
+impl Window {
+    /// Construct a new [`Window`] operator computing `function` over partitions of the rows of
+    /// `src`.
+    ///
+    /// Unlike [`TopK`](crate::ops::topk::TopK) and [`Paginate`](crate::ops::paginate::Paginate),
+    /// `order` is given in the same direction as SQL - rows which compare lower are earlier in the
+    /// window.
+    pub fn new(
+        src: NodeIndex,
+        function: WindowFunction,
+        over: Option<(usize, &DfType)>,
+        partition_by: Vec<usize>,
+        order: Vec<(usize, OrderType)>,
+    ) -> ReadySetResult<Self> {
+        let out_ty = match function {
+            WindowFunction::RowNumber
+            | WindowFunction::Rank
+            | WindowFunction::DenseRank
+            | WindowFunction::Count => DfType::BigInt,
+            WindowFunction::Sum => match over {
+                // Matches the return type of the SUM() aggregate
+                Some((_, over_ty)) if over_ty.is_any_float() => DfType::Double,
+                Some(_) => DfType::DEFAULT_NUMERIC,
+                None => internal!("SUM window function must have a column to compute over"),
+            },
+        };
+
+        Ok(Window {
+            src: src.into(),
+            our_index: None,
+            output_col: None,
+            function,
+            over: over.map(|(col, _)| col),
+            partition_by,
+            order: order.into(),
+            out_ty,
+        })
+    }
+
+    /// Returns the type of the column emitted by this operator for the result of the window
+    /// function
+    pub fn output_col_type(&self) -> DfType {
+        self.out_ty.clone()
+    }
+
+    /// Project the columns we are partitioning by out of the given record
+    fn project_partition<'rec, R>(&self, rec: &'rec R) -> ReadySetResult<Vec<&'rec DfValue>>
+    where
+        R: Indices<'static, usize, Output = DfValue> + ?Sized,
+    {
+        rec.indices(self.partition_by.clone())
+            .map_err(|_| ReadySetError::InvalidRecordLength)
+    }
+
+    /// Return the column index of the output column of this node (which will always be the last
+    /// column)
+    ///
+    /// # Panics
+    ///
+    /// Panics if called before [`Ingredient::on_connected`]
+    fn output_column(&self) -> usize {
+        self.output_col
+            .expect("output_column called before Ingredient::on_connected")
+    }
+
+    fn zero(&self) -> DfValue {
+        match self.out_ty {
+            DfType::Double => DfValue::Double(0.0),
+            DfType::Numeric { .. } => DfValue::Numeric(Default::default()),
+            _ => DfValue::Int(0),
+        }
+    }
+
+    /// Compute the value of the window function for each of the given rows, which must all be in
+    /// the same partition and sorted according to the order of the window
+    fn compute(&self, rows: &[Cow<'_, [DfValue]>]) -> ReadySetResult<Vec<DfValue>> {
+        let mut values = Vec::with_capacity(rows.len());
+        let mut peers_start = 0;
+        let mut dense_rank = 0i64;
+        // The running count or sum, which is None for a SUM until we've seen a non-null value
+        let mut running = match self.function {
+            WindowFunction::Sum => DfValue::None,
+            _ => self.zero(),
+        };
+
+        while peers_start < rows.len() {
+            let peers_end = rows[peers_start..]
+                .iter()
+                .position(|row| self.order.cmp(row, &rows[peers_start]) != Ordering::Equal)
+                .map_or(rows.len(), |len| peers_start + len);
+            let peers = &rows[peers_start..peers_end];
+            dense_rank += 1;
+
+            match self.function {
+                WindowFunction::RowNumber => {
+                    values.extend((peers_start..peers_end).map(|i| DfValue::from(i as i64 + 1)))
+                }
+                WindowFunction::Rank => {
+                    values.extend(peers.iter().map(|_| DfValue::from(peers_start as i64 + 1)))
+                }
+                WindowFunction::DenseRank => {
+                    values.extend(peers.iter().map(|_| DfValue::from(dense_rank)))
+                }
+                WindowFunction::Count => {
+                    for row in peers {
+                        let counted = match self.over {
+                            Some(over) => !row[over].is_none(),
+                            None => true,
+                        };
+                        if counted {
+                            running = (&running + &DfValue::Int(1))?;
+                        }
+                    }
+                    values.extend(peers.iter().map(|_| running.clone()));
+                }
+                WindowFunction::Sum => {
+                    let over = self
+                        .over
+                        .ok_or_else(|| internal_err!("SUM window function without a column"))?;
+                    for row in peers {
+                        let value = &row[over];
+                        if value.is_none() {
+                            continue;
+                        }
+                        running = if running.is_none() {
+                            (&self.zero() + value)?
+                        } else {
+                            (&running + value)?
+                        };
+                    }
+                    values.extend(peers.iter().map(|_| running.clone()));
+                }
+            }
+
+            peers_start = peers_end;
+        }
+
+        Ok(values)
+    }
+
+    /// Recompute the window function over all the rows of a partition, emitting records for every
+    /// row whose value changed (or that is new to the partition) into `out`
+    fn post_partition(
+        &self,
+        out: &mut Vec<Record>,
+        mut rows: Vec<Cow<'_, [DfValue]>>,
+    ) -> ReadySetResult<()> {
+        let output_col = self.output_column();
+        // Break ties between peers by comparing the rest of the row, so that functions which
+        // differ between peers (ROW_NUMBER) are deterministic
+        rows.sort_by(|a, b| {
+            self.order
+                .cmp(a, b)
+                .then_with(|| a[..output_col].cmp(&b[..output_col]))
+        });
+        let values = self.compute(&rows)?;
+
+        for (mut row, value) in rows.into_iter().zip(values) {
+            match row.get(output_col) {
+                Some(current) if *current == value => {}
+                Some(_) => {
+                    out.push(Record::Negative(row.clone().into_owned()));
+                    row.to_mut()[output_col] = value;
+                    out.push(Record::Positive(row.into_owned()));
+                }
+                None => {
+                    row.to_mut().push(value);
+                    out.push(Record::Positive(row.into_owned()));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Ingredient for Window {
+    fn take(&mut self) -> NodeOperator {
+        self.clone().into()
+    }
+
+    fn ancestors(&self) -> Vec<NodeIndex> {
+        vec![self.src.as_global()]
+    }
+
+    impl_replace_sibling!(src);
+
+    fn on_connected(&mut self, graph: &Graph) {
+        self.output_col = Some(graph[self.src.as_global()].columns().len());
+    }
+
+    fn on_commit(&mut self, us: NodeIndex, remap: &HashMap<NodeIndex, IndexPair>) {
+        self.src.remap(remap);
+        self.our_index = Some(remap[&us]);
+    }
+
+    fn on_input(
+        &mut self,
+        from: LocalNodeIndex,
+        rs: Records,
+        replay: &ReplayContext,
+        _nodes: &DomainNodes,
+        state: &StateMap,
+    ) -> ReadySetResult<ProcessingResult> {
+        debug_assert_eq!(from, *self.src);
+
+        if rs.is_empty() {
+            return Ok(ProcessingResult {
+                results: rs,
+                ..Default::default()
+            });
+        }
+
+        let mut rs = Vec::from(rs);
+        rs.sort_by(|a: &Record, b: &Record| {
+            self.project_partition(&***a)
+                .unwrap_or_default()
+                .cmp(&self.project_partition(&***b).unwrap_or_default())
+        });
+
+        let us = self.our_index.unwrap();
+        let db = state
+            .get(*us)
+            .ok_or_else(|| internal_err!("window must have its own state materialized"))?;
+        let output_col = self.output_column();
+
+        let mut current_partition_key: Option<Vec<DfValue>> = None;
+        // Rows currently in the partition. Rows loaded from the state will contain the output
+        // column, but new rows received in the batch will be one column shorter.
+        let mut current_partition: Vec<Cow<[DfValue]>> = vec![];
+        let mut partition_missed = false;
+
+        let mut out = vec![];
+        let mut lookups = vec![];
+        let mut misses = vec![];
+
+        for r in rs {
+            let record_partition = self.project_partition(r.rec())?;
+            if current_partition_key.as_ref().map_or(true, |key| {
+                key.iter().cmp(record_partition.iter().copied()) != Ordering::Equal
+            }) {
+                // New partition!
+                if current_partition_key.is_some() && !partition_missed {
+                    self.post_partition(&mut out, std::mem::take(&mut current_partition))?;
+                }
+                current_partition.clear();
+
+                let key = record_partition.into_iter().cloned().collect::<Vec<_>>();
+
+                // Load the full partition into memory
+                match db.lookup(&self.partition_by, &PointKey::from(key.clone())) {
+                    LookupResult::Some(local_records) => {
+                        if replay.is_partial() {
+                            lookups.push(Lookup {
+                                on: *us,
+                                cols: self.partition_by.clone(),
+                                key: key.clone().try_into().expect("Empty partition"),
+                            });
+                        }
+
+                        partition_missed = false;
+                        current_partition.extend(local_records);
+                    }
+                    LookupResult::Missing => {
+                        partition_missed = true;
+                    }
+                }
+
+                current_partition_key = Some(key);
+            }
+
+            if partition_missed {
+                misses.push(
+                    Miss::builder()
+                        .on(*us)
+                        .lookup_idx(self.partition_by.clone())
+                        .lookup_key(self.partition_by.clone())
+                        .replay(replay)
+                        .record(r.into_row())
+                        .build(),
+                );
+                continue;
+            }
+
+            match r {
+                Record::Positive(r) => current_partition.push(Cow::Owned(r)),
+                Record::Negative(r) => {
+                    if let Some(pos) = current_partition
+                        .iter()
+                        .position(|row| row[..output_col] == *r)
+                    {
+                        let row = current_partition.swap_remove(pos);
+                        // Only retract rows that we've previously emitted
+                        if row.len() > output_col {
+                            out.push(Record::Negative(row.into_owned()));
+                        }
+                    }
+                }
+            }
+        }
+
+        if current_partition_key.is_some() && !partition_missed {
+            self.post_partition(&mut out, current_partition)?;
+        }
+
+        Ok(ProcessingResult {
+            results: out.into(),
+            lookups,
+            misses,
+        })
+    }
+
+    fn suggest_indexes(&self, this: NodeIndex) -> HashMap<NodeIndex, LookupIndex> {
+        HashMap::from([(
+            this,
+            LookupIndex::Strict(Index::hash_map(self.partition_by.clone())),
+        )])
+    }
+
+    fn column_source(&self, cols: &[usize]) -> ColumnSource {
+        if cols.contains(&self.output_column()) {
+            if cols.len() == 1 {
+                // Lookups on only the result of the window function require a full replay
+                return ColumnSource::RequiresFullReplay(vec1![self.src.as_global()]);
+            }
+
+            #[allow(clippy::unwrap_used)]
+            // Once we remove the output column, we have to have at least one column left
+            // (because we just checked len > 1)
+            let columns = cols
+                .iter()
+                .copied()
+                .filter(|c| *c != self.output_column())
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap();
+            ColumnSource::GeneratedFromColumns(vec1![ColumnRef {
+                node: self.our_index.unwrap().as_global(),
+                columns,
+            }])
+        } else {
+            ColumnSource::ExactCopy(ColumnRef {
+                node: self.src.as_global(),
+                columns: cols.to_vec().try_into().unwrap(),
+            })
+        }
+    }
+
+    fn handle_upquery(&mut self, miss: ColumnMiss) -> ReadySetResult<Vec<ColumnMiss>> {
+        let output_column = miss
+            .column_indices
+            .iter()
+            .position(|ci| *ci == self.output_column())
+            .expect("handle_upquery invariant");
+
+        Ok(vec![ColumnMiss {
+            node: *self.our_index.unwrap(),
+            column_indices: miss
+                .column_indices
+                .iter()
+                .copied()
+                .filter(|ci| *ci != self.output_column())
+                .collect::<Vec<_>>()
+                .try_into()
+                .expect("handle_upquery invariant"),
+            missed_keys: miss.missed_keys.mapped(|k| {
+                k.map_endpoints(|mut r| {
+                    r.remove(output_column).expect("handle_upquery invariant");
+                    r
+                })
+            }),
+        }])
+    }
+
+    fn description(&self, detailed: bool) -> String {
+        if !detailed {
+            return "Window".into();
+        }
+
+        format!(
+            "{}({}) OVER (π[{}] o[{}])",
+            self.function,
+            self.over.iter().join(", "),
+            self.partition_by.iter().join(", "),
+            self.order
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::test::MockGraph;
+
+    fn setup(function: WindowFunction, over: Option<usize>) -> MockGraph {
+        let mut g = MockGraph::new();
+        let s = g.add_base("source", &["x", "y", "z"]);
+
+        // function(z) OVER (PARTITION BY y ORDER BY x)
+        g.set_op(
+            "window",
+            &["x", "y", "z", "w"],
+            Window::new(
+                s.as_global(),
+                function,
+                over.map(|c| (c, &DfType::Double)),
+                vec![1],
+                vec![(0, OrderType::OrderAscending)],
+            )
+            .unwrap(),
+            true,
+        );
+        g
+    }
+
+    fn with_value(row: &[DfValue], value: DfValue) -> Vec<DfValue> {
+        let mut res = row.to_vec();
+        res.push(value);
+        res
+    }
+
+    #[test]
+    fn suggest_indexes() {
+        let g = setup(WindowFunction::RowNumber, None);
+        let res = g.node().suggest_indexes(g.node_index().as_global());
+        assert_eq!(res.len(), 1);
+        assert_eq!(
+            res[&g.node_index().as_global()],
+            LookupIndex::Strict(Index::hash_map(vec![1]))
+        );
+    }
+
+    #[test]
+    fn column_source_for_output_column() {
+        let g = setup(WindowFunction::RowNumber, None);
+        let src = g.node().column_source(&[1, 3]);
+        assert_eq!(
+            src,
+            ColumnSource::GeneratedFromColumns(vec1![ColumnRef {
+                node: g.node_index().as_global(),
+                columns: vec1![1],
+            }])
+        );
+    }
+
+    #[test]
+    fn row_number_multiple_partitions() {
+        let mut g = setup(WindowFunction::RowNumber, None);
+
+        let r1a: Vec<DfValue> = vec![1.into(), "a".into(), 1.into()];
+        let r2a: Vec<DfValue> = vec![2.into(), "a".into(), 1.into()];
+        let r1b: Vec<DfValue> = vec![1.into(), "b".into(), 1.into()];
+
+        let res = g.narrow_one(vec![r2a.clone(), r1b.clone(), r1a.clone()], true);
+        assert_eq!(
+            res,
+            vec![
+                with_value(&r1a, 1.into()),
+                with_value(&r2a, 2.into()),
+                with_value(&r1b, 1.into()),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn row_number_insert_shifts_later_rows() {
+        let mut g = setup(WindowFunction::RowNumber, None);
+
+        let r1a: Vec<DfValue> = vec![1.into(), "a".into(), 1.into()];
+        let r2a: Vec<DfValue> = vec![2.into(), "a".into(), 1.into()];
+        let r3a: Vec<DfValue> = vec![3.into(), "a".into(), 1.into()];
+
+        g.narrow_one(vec![r1a, r3a.clone()], true);
+
+        let res = g.narrow_one_row(r2a.clone(), true);
+        assert_eq!(
+            res,
+            vec![
+                (with_value(&r2a, 2.into()), true),
+                (with_value(&r3a, 2.into()), false),
+                (with_value(&r3a, 3.into()), true),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn row_number_delete_shifts_later_rows() {
+        let mut g = setup(WindowFunction::RowNumber, None);
+
+        let r1a: Vec<DfValue> = vec![1.into(), "a".into(), 1.into()];
+        let r2a: Vec<DfValue> = vec![2.into(), "a".into(), 1.into()];
+        let r3a: Vec<DfValue> = vec![3.into(), "a".into(), 1.into()];
+
+        g.narrow_one(vec![r1a.clone(), r2a, r3a.clone()], true);
+
+        let res = g.narrow_one_row((r1a.clone(), false), true);
+        assert_eq!(
+            res,
+            vec![
+                (with_value(&r1a, 1.into()), false),
+                (with_value(&r3a, 3.into()), false),
+                (with_value(&r3a, 2.into()), true),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn rank_and_dense_rank_with_peers() {
+        let r1: Vec<DfValue> = vec![1.into(), "a".into(), 1.into()];
+        let r1_dup: Vec<DfValue> = vec![1.into(), "a".into(), 2.into()];
+        let r2: Vec<DfValue> = vec![2.into(), "a".into(), 1.into()];
+
+        let mut g = setup(WindowFunction::Rank, None);
+        let res = g.narrow_one(vec![r1.clone(), r1_dup.clone(), r2.clone()], true);
+        assert_eq!(
+            res,
+            vec![
+                with_value(&r1, 1.into()),
+                with_value(&r1_dup, 1.into()),
+                with_value(&r2, 3.into()),
+            ]
+            .into()
+        );
+
+        let mut g = setup(WindowFunction::DenseRank, None);
+        let res = g.narrow_one(vec![r1.clone(), r1_dup.clone(), r2.clone()], true);
+        assert_eq!(
+            res,
+            vec![
+                with_value(&r1, 1.into()),
+                with_value(&r1_dup, 1.into()),
+                with_value(&r2, 2.into()),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn running_sum() {
+        let mut g = setup(WindowFunction::Sum, Some(2));
+
+        let r1: Vec<DfValue> = vec![1.into(), "a".into(), 5.into()];
+        let r2: Vec<DfValue> = vec![2.into(), "a".into(), DfValue::None];
+        let r3: Vec<DfValue> = vec![3.into(), "a".into(), 7.into()];
+
+        let res = g.narrow_one(vec![r1.clone(), r2.clone(), r3.clone()], true);
+        assert_eq!(
+            res,
+            vec![
+                with_value(&r1, DfValue::Double(5.0)),
+                with_value(&r2, DfValue::Double(5.0)),
+                with_value(&r3, DfValue::Double(12.0)),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn running_count_ignores_nulls() {
+        let mut g = setup(WindowFunction::Count, Some(2));
+
+        let r1: Vec<DfValue> = vec![1.into(), "a".into(), 5.into()];
+        let r2: Vec<DfValue> = vec![2.into(), "a".into(), DfValue::None];
+        let r3: Vec<DfValue> = vec![3.into(), "a".into(), 7.into()];
+
+        let res = g.narrow_one(vec![r1.clone(), r2.clone(), r3.clone()], true);
+        assert_eq!(
+            res,
+            vec![
+                with_value(&r1, 1.into()),
+                with_value(&r2, 1.into()),
+                with_value(&r3, 2.into()),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn insert_and_delete_in_same_batch() {
+        let mut g = setup(WindowFunction::RowNumber, None);
+
+        let r1: Vec<DfValue> = vec![1.into(), "a".into(), 1.into()];
+        let r2: Vec<DfValue> = vec![2.into(), "a".into(), 1.into()];
+
+        g.narrow_one_row(r2.clone(), true);
+
+        let res = g.narrow_one(
+            vec![Record::Positive(r1.clone()), Record::Negative(r1)],
+            true,
+        );
+        assert!(res.is_empty());
+    }
+}
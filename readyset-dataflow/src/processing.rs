@@ -4,7 +4,7 @@ use std::convert::TryInto;
 use std::ops::{Bound, RangeBounds};
 use std::{iter, mem};
 
-use dataflow_state::PointKey;
+use dataflow_state::{PointKey, RangeKey, RangeLookupResult};
 use derive_more::From;
 use readyset_client::KeyComparison;
 use readyset_errors::ReadySetResult;
@@ -492,6 +492,15 @@ impl<'a> From<LookupResult<'a>> for IngredientLookupResult<'a> {
     }
 }
 
+impl<'a> From<RangeLookupResult<'a>> for IngredientLookupResult<'a> {
+    fn from(lookup_res: RangeLookupResult<'a>) -> Self {
+        match lookup_res {
+            RangeLookupResult::Some(rs) => Self::records(rs),
+            RangeLookupResult::Missing(_) => Self::Miss,
+        }
+    }
+}
+
 impl<'a, I> From<Box<I>> for IngredientLookupResult<'a>
 where
     I: Iterator<Item = ReadySetResult<Cow<'a, [DfValue]>>> + 'a,
@@ -812,6 +821,31 @@ where
         }
     }
 
+    /// Look up the given range `key` in the given parent's state.
+    ///
+    /// Unlike [`lookup`](Ingredient::lookup), range lookups can't be performed through
+    /// query-through operators, so this will return [`ReadySetError::IndexNotFound`] unless
+    /// `parent` is materialized with a [`BTreeMap`](IndexType::BTreeMap) index on `columns`.
+    ///
+    /// # Invariants
+    ///
+    /// * `columns` and `key` must have the same length
+    fn lookup_range<'a>(
+        &self,
+        parent_index: LocalNodeIndex,
+        columns: &[usize],
+        key: &RangeKey,
+        states: &'a StateMap,
+    ) -> ReadySetResult<IngredientLookupResult<'a>> {
+        match states.get(parent_index) {
+            Some(state) => Ok(state.lookup_range(columns, key).into()),
+            None => Err(ReadySetError::IndexNotFound {
+                node: parent_index.id(),
+                columns: columns.to_vec(),
+            }),
+        }
+    }
+
     /// Performance hint: should return true if this operator reduces the size of its input
     fn is_selective(&self) -> bool {
         false
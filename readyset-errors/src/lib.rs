@@ -303,6 +303,10 @@ pub enum ReadySetError {
     #[error("the queries lookup key is not found at the reader")]
     ReaderMissingKey,
 
+    /// A scalar subquery returned more than one row for a single row of the outer query.
+    #[error("Subquery returns more than 1 row")]
+    SubqueryReturnedMultipleRows,
+
     /// A prepared statement is missing.
     #[error("Prepared statement with ID {statement_id} not found")]
     PreparedStatementMissing {
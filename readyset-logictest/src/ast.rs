@@ -427,6 +427,7 @@ impl TryFrom<DfValue> for Value {
             DfValue::ByteArray(t) => Ok(Value::ByteArray(t.as_ref().clone())),
             DfValue::Numeric(ref d) => Ok(Value::Numeric(*d.as_ref())),
             DfValue::BitVector(ref b) => Ok(Value::BitVector(b.as_ref().clone())),
+            DfValue::Interval(ref i) => Ok(Value::Text(i.to_string())),
             DfValue::Array(_) => bail!("Arrays not supported"),
             DfValue::PassThrough(_) => unimplemented!(),
         }
@@ -7,6 +7,7 @@
 use std::iter;
 use std::ops::{Deref, DerefMut, Index, IndexMut};
 
+use dataflow::PostLookupAggregateFunction;
 use itertools::Itertools;
 use nom_sql::analysis::ReferredColumns;
 use petgraph::stable_graph::StableGraph;
@@ -155,6 +156,25 @@ impl MirGraph {
                 }
                 columns
             }
+            MirNodeInner::Collect {
+                on,
+                key,
+                order_by,
+                group_by,
+                ..
+            } => {
+                // Collecting aggregates additionally need their key and order_by columns
+                let mut columns = group_by.clone();
+                for c in iter::once(on)
+                    .chain(key)
+                    .chain(order_by.iter().map(|(c, _)| c))
+                {
+                    if !columns.contains(c) {
+                        columns.push(c.clone());
+                    }
+                }
+                columns
+            }
             MirNodeInner::Project {
                 emit, expressions, ..
             } => {
@@ -178,23 +198,81 @@ impl MirGraph {
                 order_by,
                 returned_cols,
                 aggregates,
+                subquery_row_counts,
                 ..
             } => {
                 let mut columns = self.columns(node);
                 columns.extend(
                     keys.iter()
                         .map(|(c, _)| c.clone())
-                        .chain(order_by.iter().flatten().map(|(c, _)| c.clone()))
+                        .chain(order_by.iter().flatten().map(|(c, _, _)| c.clone()))
                         .chain(returned_cols.iter().flatten().cloned())
                         .chain(aggregates.iter().flat_map(|aggs| {
-                            aggs.group_by
-                                .clone()
-                                .into_iter()
-                                .chain(aggs.aggregates.iter().map(|agg| agg.column.clone()))
-                        })),
+                            aggs.group_by.clone().into_iter().chain(
+                                aggs.aggregates.iter().flat_map(|agg| {
+                                    iter::once(agg.column.clone()).chain(match &agg.function {
+                                        PostLookupAggregateFunction::Avg { sum, count } => {
+                                            vec![sum.clone(), count.clone()]
+                                        }
+                                        _ => vec![],
+                                    })
+                                }),
+                            )
+                        }))
+                        .chain(subquery_row_counts.iter().cloned()),
                 );
                 columns
             }
+            MirNodeInner::Window {
+                on,
+                partition_by,
+                order_by,
+                ..
+            } => {
+                // Window nodes pass through all their parent's columns, and additionally need the
+                // partition, order, and "over" columns
+                let mut columns = self.columns(node);
+                columns.pop();
+                for c in partition_by
+                    .iter()
+                    .chain(order_by.iter().map(|(c, _)| c))
+                    .chain(on)
+                {
+                    if !columns.contains(c) {
+                        columns.push(c.clone());
+                    }
+                }
+                columns
+            }
+            MirNodeInner::Join { conditions, .. } => {
+                // Joins need the columns of their non-equality conditions, even if they don't
+                // project them
+                let mut columns = self.columns(node);
+                for (l, _, r) in conditions {
+                    for c in [l, r] {
+                        if !columns.contains(c) {
+                            columns.push(c.clone());
+                        }
+                    }
+                }
+                columns
+            }
+            MirNodeInner::SemiJoin { on, .. }
+            | MirNodeInner::AntiJoin { on, .. }
+            | MirNodeInner::DependentSemiJoin { on, .. }
+            | MirNodeInner::DependentAntiJoin { on, .. } => {
+                // Semi-joins and anti-joins don't project any columns from the right, but still
+                // need the join keys from both sides
+                let mut columns = self.columns(node);
+                for (l, r) in on {
+                    for c in [l, r] {
+                        if !columns.contains(c) {
+                            columns.push(c.clone());
+                        }
+                    }
+                }
+                columns
+            }
             MirNodeInner::Filter { conditions } => {
                 let mut columns = self.columns(node);
                 for c in conditions.referred_columns() {
@@ -242,6 +320,11 @@ impl MirGraph {
                 group_by,
                 output_column,
                 ..
+            }
+            | MirNodeInner::Collect {
+                group_by,
+                output_column,
+                ..
             } => group_by
                 .iter()
                 .cloned()
@@ -249,7 +332,13 @@ impl MirGraph {
                 .collect(),
             MirNodeInner::Join { project, .. }
             | MirNodeInner::LeftJoin { project, .. }
-            | MirNodeInner::DependentJoin { project, .. } => project.clone(),
+            | MirNodeInner::FullJoin { project, .. }
+            | MirNodeInner::SemiJoin { project, .. }
+            | MirNodeInner::AntiJoin { project, .. }
+            | MirNodeInner::DependentJoin { project, .. }
+            | MirNodeInner::DependentSemiJoin { project, .. }
+            | MirNodeInner::DependentAntiJoin { project, .. }
+            | MirNodeInner::DependentLeftJoin { project, .. } => project.clone(),
             MirNodeInner::JoinAggregates => {
                 let cols = self
                     // see note [edge-ordering]
@@ -288,6 +377,10 @@ impl MirGraph {
                 .into_iter()
                 .chain(iter::once(MirColumn::named(&*PAGE_NUMBER_COL)))
                 .collect(),
+            MirNodeInner::Window { output_column, .. } => parent_columns()
+                .into_iter()
+                .chain(iter::once(output_column.clone()))
+                .collect(),
             MirNodeInner::Distinct { group_by } => group_by
                 .iter()
                 .cloned()
@@ -310,6 +403,16 @@ impl MirGraph {
             }
         }
 
+        if self.graph[node].inner.is_semi_or_anti_join() {
+            // Semi-joins and anti-joins can only ever project columns from their left parent
+            return self.columns(node).contains(column)
+                || self
+                    // see note [edge-ordering]
+                    .sorted_ancestors(node)
+                    .next()
+                    .map_or(false, |left| self.provides_column(left, column));
+        }
+
         self.columns(node).contains(column)
             || self
                 .graph
@@ -417,7 +520,7 @@ impl MirGraph {
         None
     }
 
-    fn sorted_ancestors(&self, node: NodeIndex) -> impl Iterator<Item = NodeIndex> + '_ {
+    pub(crate) fn sorted_ancestors(&self, node: NodeIndex) -> impl Iterator<Item = NodeIndex> + '_ {
         self.graph
             .edges_directed(node, Direction::Incoming)
             .sorted_by_key(|e| e.weight())
@@ -490,6 +593,7 @@ mod tests {
             "join".into(),
             MirNodeInner::Join {
                 on: vec![],
+                conditions: vec![],
                 project: vec![],
             },
         ));
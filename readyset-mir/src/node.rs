@@ -120,9 +120,10 @@ mod tests {
     mod columns {
         use common::IndexType;
         use dataflow::ops::grouped::aggregate::Aggregation;
+        use dataflow::ops::grouped::collect::CollectKind;
         use dataflow::ops::grouped::extremum::Extremum;
         use dataflow::ops::union::DuplicateMode;
-        use nom_sql::{BinaryOperator, ColumnSpecification, Expr, OrderType, SqlType};
+        use nom_sql::{BinaryOperator, ColumnSpecification, Expr, NullOrder, OrderType, SqlType};
         use readyset_client::ViewPlaceholder;
 
         use super::*;
@@ -230,6 +231,21 @@ mod tests {
             );
         }
 
+        #[test]
+        fn collect() {
+            has_columns_single_parent(
+                MirNodeInner::Collect {
+                    on: Column::new(Some("base"), "a"),
+                    key: None,
+                    order_by: vec![(Column::new(Some("base"), "a"), OrderType::OrderDescending)],
+                    group_by: vec![Column::new(Some("base"), "b")],
+                    output_column: Column::named("agg"),
+                    kind: CollectKind::Array,
+                },
+                vec![Column::new(Some("base"), "b"), Column::named("agg")],
+            );
+        }
+
         #[test]
         fn project() {
             has_columns_single_parent(
@@ -286,6 +302,7 @@ mod tests {
                         Column::new(Some("base"), "a"),
                         Column::new(Some("base2"), "a"),
                     )],
+                    conditions: vec![],
                     project: project.clone(),
                 },
             ));
@@ -353,6 +370,7 @@ mod tests {
                 returned_cols: None,
                 default_row: None,
                 aggregates: None,
+                subquery_row_counts: vec![],
             })
         }
 
@@ -362,6 +380,7 @@ mod tests {
                 order: Some(vec![(
                     Column::new(Some("base"), "a"),
                     OrderType::OrderAscending,
+                    NullOrder::NullsFirst,
                 )]),
                 group_by: vec![Column::new(Some("base"), "b")],
                 limit: 3,
@@ -375,6 +394,7 @@ mod tests {
                     order: Some(vec![(
                         Column::new(Some("base"), "a"),
                         OrderType::OrderAscending,
+                        NullOrder::NullsFirst,
                     )]),
                     group_by: vec![Column::new(Some("base"), "b")],
                     limit: 3,
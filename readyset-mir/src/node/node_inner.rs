@@ -1,12 +1,16 @@
 use std::fmt::Debug;
+use std::iter;
 
 use common::{DfValue, IndexType};
 use dataflow::ops::grouped::aggregate::Aggregation;
+use dataflow::ops::grouped::collect::CollectKind;
 use dataflow::ops::grouped::extremum::Extremum;
+use dataflow::ops::join::JoinComparison;
 use dataflow::ops::union;
+use dataflow::ops::window::WindowFunction;
 use dataflow::PostLookupAggregates;
 use itertools::Itertools;
-use nom_sql::{ColumnSpecification, Expr, OrderType, Relation, SqlIdentifier};
+use nom_sql::{ColumnSpecification, Expr, NullOrder, OrderType, Relation, SqlIdentifier};
 use readyset_client::ViewPlaceholder;
 use readyset_errors::{internal, ReadySetResult};
 use serde::{Deserialize, Serialize};
@@ -59,6 +63,26 @@ pub enum MirNodeInner {
         /// Which kind of extreme value to compute (minimum or maximum).
         kind: Extremum,
     },
+    /// Node that collects all the values of a column grouped by another set of columns into a
+    /// single array or JSON value, outputting its result as an additional column.
+    ///
+    /// Converted to [`Collect`] when lowering to dataflow
+    ///
+    /// [`Collect`]: dataflow::ops::grouped::collect::Collect
+    Collect {
+        /// Column to collect the values of
+        on: Column,
+        /// For [`CollectKind::JsonObject`], the column containing the keys of the object
+        key: Option<Column>,
+        /// Columns to order the collected values by within each group
+        order_by: Vec<(Column, OrderType)>,
+        /// List of columns to group by
+        group_by: Vec<Column>,
+        /// The column name to use for the collected value, which will always be the last column
+        output_column: Column,
+        /// Which kind of value to collect the values into
+        kind: CollectKind,
+    },
     /// Node that filters its input to only rows where a particular expression evaluates to a
     /// truthy value.
     ///
@@ -79,7 +103,8 @@ pub enum MirNodeInner {
     /// [`Identity`]: dataflow::ops::identity::Identity
     Identity,
     /// Node which computes a join on its two parents by finding all rows in the left where the
-    /// values in `on_left` are equal to the values of `on_right` on the right
+    /// values in `on_left` are equal to the values of `on_right` on the right, and for which all
+    /// of the non-equality `conditions` hold
     ///
     /// Converted to [`Join`] with [`JoinType::Inner`] when lowering to dataflow.
     ///
@@ -89,6 +114,9 @@ pub enum MirNodeInner {
         /// Columns to use as the join keys. Each tuple corresponds to a column in the left parent
         /// and column in the right parent.
         on: Vec<(Column, Column)>,
+        /// Non-equality comparisons between a column in the left parent and a column in the right
+        /// parent, which must also hold for a pair of rows to be joined.
+        conditions: Vec<(Column, JoinComparison, Column)>,
         /// Columns (from both parents) to project in the output.
         project: Vec<Column>,
     },
@@ -118,6 +146,51 @@ pub enum MirNodeInner {
         /// Columns (from both parents) to project in the output.
         project: Vec<Column>,
     },
+    /// Node which computes a *full outer* join on its two parents by finding all rows in the right
+    /// where the values in `on_right` are equal to the values of `on_left` on the left, and
+    /// emitting rows from either side with no matches padded with NULLs.
+    ///
+    /// Converted to [`Join`] with [`JoinType::Full`] when lowering to dataflow.
+    ///
+    /// [`Join`]: dataflow::ops::join::Join
+    /// [`JoinType::Full`]: dataflow::ops::join::JoinType::Full
+    FullJoin {
+        /// Columns to use as the join keys. Each tuple corresponds to a column in the left parent
+        /// and column in the right parent.
+        on: Vec<(Column, Column)>,
+        /// Columns (from both parents) to project in the output.
+        project: Vec<Column>,
+    },
+    /// Node which computes a *semi*-join on its two parents, emitting each row in the left once if
+    /// there are any rows in the right where the values in `on_right` are equal to the values of
+    /// `on_left` on the left. Used to implement `EXISTS`.
+    ///
+    /// Converted to [`Join`] with [`JoinType::Semi`] when lowering to dataflow.
+    ///
+    /// [`Join`]: dataflow::ops::join::Join
+    /// [`JoinType::Semi`]: dataflow::ops::join::JoinType::Semi
+    SemiJoin {
+        /// Columns to use as the join keys. Each tuple corresponds to a column in the left parent
+        /// and column in the right parent.
+        on: Vec<(Column, Column)>,
+        /// Columns (from the left parent only) to project in the output.
+        project: Vec<Column>,
+    },
+    /// Node which computes an *anti*-join on its two parents, emitting each row in the left for
+    /// which there are *no* rows in the right where the values in `on_right` are equal to the
+    /// values of `on_left` on the left. Used to implement `NOT EXISTS`.
+    ///
+    /// Converted to [`Join`] with [`JoinType::Anti`] when lowering to dataflow.
+    ///
+    /// [`Join`]: dataflow::ops::join::Join
+    /// [`JoinType::Anti`]: dataflow::ops::join::JoinType::Anti
+    AntiJoin {
+        /// Columns to use as the join keys. Each tuple corresponds to a column in the left parent
+        /// and column in the right parent.
+        on: Vec<(Column, Column)>,
+        /// Columns (from the left parent only) to project in the output.
+        project: Vec<Column>,
+    },
     /// Join where nodes in the right-hand side depend on columns in the left-hand side
     /// (referencing tables in `dependent_tables`). These are created during compilation for
     /// correlated subqueries, and must be removed entirely by rewrite passes before lowering
@@ -135,6 +208,42 @@ pub enum MirNodeInner {
         /// Columns (from both parents) to project in the output.
         project: Vec<Column>,
     },
+    /// Like a [`DependentJoin`], but which becomes a [`SemiJoin`] once decorrelated. These are
+    /// created during compilation for correlated `EXISTS` subqueries.
+    ///
+    /// [`DependentJoin`]: MirNodeInner::DependentJoin
+    /// [`SemiJoin`]: MirNodeInner::SemiJoin
+    DependentSemiJoin {
+        /// Columns to use as the join keys. Each tuple corresponds to a column in the left parent
+        /// and column in the right parent.
+        on: Vec<(Column, Column)>,
+        /// Columns (from the left parent only) to project in the output.
+        project: Vec<Column>,
+    },
+    /// Like a [`DependentJoin`], but which becomes an [`AntiJoin`] once decorrelated. These are
+    /// created during compilation for correlated `NOT EXISTS` subqueries.
+    ///
+    /// [`DependentJoin`]: MirNodeInner::DependentJoin
+    /// [`AntiJoin`]: MirNodeInner::AntiJoin
+    DependentAntiJoin {
+        /// Columns to use as the join keys. Each tuple corresponds to a column in the left parent
+        /// and column in the right parent.
+        on: Vec<(Column, Column)>,
+        /// Columns (from the left parent only) to project in the output.
+        project: Vec<Column>,
+    },
+    /// Like a [`DependentJoin`], but which becomes a [`LeftJoin`] once decorrelated. These are
+    /// created during compilation for correlated scalar subqueries.
+    ///
+    /// [`DependentJoin`]: MirNodeInner::DependentJoin
+    /// [`LeftJoin`]: MirNodeInner::LeftJoin
+    DependentLeftJoin {
+        /// Columns to use as the join keys. Each tuple corresponds to a column in the left parent
+        /// and column in the right parent.
+        on: Vec<(Column, Column)>,
+        /// Columns (from both parents) to project in the output.
+        project: Vec<Column>,
+    },
     /// Node which outputs a subset of columns from its parent in any order, and can evaluate
     /// expressions.
     ///
@@ -180,8 +289,9 @@ pub enum MirNodeInner {
     /// [`PAGE_NUMBER_COL`]: crate::PAGE_NUMBER_COL
     /// [`Paginate`]: dataflow::ops::paginate::Paginate
     Paginate {
-        /// Set of columns used for ordering the results
-        order: Option<Vec<(Column, OrderType)>>,
+        /// Set of columns used for ordering the results, along with the direction and the
+        /// placement of NULLs for each
+        order: Option<Vec<(Column, OrderType, NullOrder)>>,
         /// Set of columns that are indexed to form a unique grouping of results
         group_by: Vec<Column>,
         /// How many rows per page
@@ -193,14 +303,35 @@ pub enum MirNodeInner {
     ///
     /// [`TopK`]: dataflow::ops::topk::TopK
     TopK {
-        /// Set of columns used for ordering the results
-        order: Option<Vec<(Column, OrderType)>>,
+        /// Set of columns used for ordering the results, along with the direction and the
+        /// placement of NULLs for each
+        order: Option<Vec<(Column, OrderType, NullOrder)>>,
         /// Set of columns that are indexed to form a unique grouping of results
         group_by: Vec<Column>,
         /// Numeric literal that determines the number of results stored per group. Taken from the
         /// LIMIT clause
         limit: usize,
     },
+    /// Node which computes a window function over the rows of each partition of its input,
+    /// emitting all of its parent's columns followed by the result of the function as an
+    /// additional column.
+    ///
+    /// Converted to [`Window`] when lowering to dataflow.
+    ///
+    /// [`Window`]: dataflow::ops::window::Window
+    Window {
+        /// Which window function we are computing
+        function: WindowFunction,
+        /// Column to compute the window function over, if the function takes an argument
+        on: Option<Column>,
+        /// List of columns to partition by
+        partition_by: Vec<Column>,
+        /// Set of columns used for ordering rows within each partition
+        order_by: Vec<(Column, OrderType)>,
+        /// The column name to use for the result of the window function, which will always be
+        /// the last column
+        output_column: Column,
+    },
     /// Node which emits only distinct rows per some group.
     ///
     /// Converted to [`Aggregator`] with [`Aggregation::Count`] when lowering to dataflow.
@@ -227,8 +358,9 @@ pub enum MirNodeInner {
         /// Whether or not this leaf node was already lowered to dataflow or not.
         lowered_to_df: bool,
 
-        /// Optional set of columns and direction to order the results of lookups to this leaf
-        order_by: Option<Vec<(Column, OrderType)>>,
+        /// Optional set of columns, directions, and placements of NULLs to order the results of
+        /// lookups to this leaf
+        order_by: Option<Vec<(Column, OrderType, NullOrder)>>,
         /// Optional limit for the set of results to lookups to this leaf
         limit: Option<usize>,
         /// Optional set of expression columns requested in the original query
@@ -238,6 +370,9 @@ pub enum MirNodeInner {
         default_row: Option<Vec<DfValue>>,
         /// Aggregates to perform in the reader on result sets for keys after performing the lookup
         aggregates: Option<PostLookupAggregates<Column>>,
+        /// Columns containing the number of rows returned by scalar subqueries in the query, which
+        /// the reader checks to return an error if any of them returned more than one row
+        subquery_row_counts: Vec<Column>,
     },
 }
 
@@ -254,6 +389,7 @@ impl MirNodeInner {
             returned_cols: None,
             default_row: None,
             aggregates: None,
+            subquery_row_counts: vec![],
         }
     }
 
@@ -273,13 +409,19 @@ impl MirNodeInner {
                 }
                 Ok(true)
             }
-            MirNodeInner::Extremum { group_by, .. } => {
+            MirNodeInner::Extremum { group_by, .. } | MirNodeInner::Collect { group_by, .. } => {
                 group_by.push(c);
                 Ok(true)
             }
             MirNodeInner::Join { project, .. }
             | MirNodeInner::LeftJoin { project, .. }
-            | MirNodeInner::DependentJoin { project, .. } => {
+            | MirNodeInner::FullJoin { project, .. }
+            | MirNodeInner::SemiJoin { project, .. }
+            | MirNodeInner::AntiJoin { project, .. }
+            | MirNodeInner::DependentJoin { project, .. }
+            | MirNodeInner::DependentSemiJoin { project, .. }
+            | MirNodeInner::DependentAntiJoin { project, .. }
+            | MirNodeInner::DependentLeftJoin { project, .. } => {
                 if !project.contains(&c) {
                     project.push(c);
                 }
@@ -311,11 +453,32 @@ impl MirNodeInner {
         }
     }
 
-    /// Returns `true` if self is a [`DependentJoin`].
+    /// Returns `true` if self is a [`DependentJoin`], [`DependentSemiJoin`],
+    /// [`DependentAntiJoin`], or [`DependentLeftJoin`].
     ///
     /// [`DependentJoin`]: MirNodeInner::DependentJoin
+    /// [`DependentSemiJoin`]: MirNodeInner::DependentSemiJoin
+    /// [`DependentAntiJoin`]: MirNodeInner::DependentAntiJoin
+    /// [`DependentLeftJoin`]: MirNodeInner::DependentLeftJoin
     pub fn is_dependent_join(&self) -> bool {
-        matches!(self, Self::DependentJoin { .. })
+        matches!(
+            self,
+            Self::DependentJoin { .. }
+                | Self::DependentSemiJoin { .. }
+                | Self::DependentAntiJoin { .. }
+                | Self::DependentLeftJoin { .. }
+        )
+    }
+
+    /// Returns `true` if self is a join which only projects columns from its left parent
+    pub fn is_semi_or_anti_join(&self) -> bool {
+        matches!(
+            self,
+            Self::SemiJoin { .. }
+                | Self::AntiJoin { .. }
+                | Self::DependentSemiJoin { .. }
+                | Self::DependentAntiJoin { .. }
+        )
     }
 
     pub(crate) fn description(&self) -> String {
@@ -330,6 +493,31 @@ impl MirNodeInner {
                     Aggregation::Count { .. } => format!("|*|({})", on.name.as_str()),
                     Aggregation::Sum => format!("𝛴({})", on.name.as_str()),
                     Aggregation::Avg => format!("AVG({})", on.name.as_str()),
+                    Aggregation::StdDev { sample: true } => {
+                        format!("STDDEV_SAMP({})", on.name.as_str())
+                    }
+                    Aggregation::StdDev { sample: false } => {
+                        format!("STDDEV_POP({})", on.name.as_str())
+                    }
+                    Aggregation::Variance { sample: true } => {
+                        format!("VAR_SAMP({})", on.name.as_str())
+                    }
+                    Aggregation::Variance { sample: false } => {
+                        format!("VAR_POP({})", on.name.as_str())
+                    }
+                    Aggregation::BoolAnd => format!("BOOL_AND({})", on.name.as_str()),
+                    Aggregation::BoolOr => format!("BOOL_OR({})", on.name.as_str()),
+                    Aggregation::BitAnd => format!("BIT_AND({})", on.name.as_str()),
+                    Aggregation::BitOr => format!("BIT_OR({})", on.name.as_str()),
+                    Aggregation::BitXor => format!("BIT_XOR({})", on.name.as_str()),
+                    Aggregation::ApproxCountDistinct => {
+                        format!("APPROX_COUNT_DISTINCT({})", on.name.as_str())
+                    }
+                    Aggregation::ApproxPercentile { ref percentile } => format!(
+                        "APPROX_PERCENTILE({}, {})",
+                        on.name.as_str(),
+                        percentile.value
+                    ),
                     Aggregation::GroupConcat { separator: ref s } => {
                         format!("||([{}], \"{}\")", on.name.as_str(), s.as_str())
                     }
@@ -378,18 +566,59 @@ impl MirNodeInner {
                     .join(", ");
                 format!("{} γ[{}]", op_string, group_cols)
             }
+            MirNodeInner::Collect {
+                ref on,
+                ref key,
+                ref order_by,
+                ref group_by,
+                ref kind,
+                ..
+            } => {
+                let function = match kind {
+                    CollectKind::Array => "ARRAY_AGG",
+                    CollectKind::JsonArray => "JSON_AGG",
+                    CollectKind::JsonObject => "JSON_OBJECT_AGG",
+                };
+                let args = key
+                    .iter()
+                    .chain(iter::once(on))
+                    .map(|c| c.name.as_str())
+                    .join(", ");
+                let order = if order_by.is_empty() {
+                    "".to_owned()
+                } else {
+                    format!(
+                        " ORDER BY {}",
+                        order_by
+                            .iter()
+                            .map(|(c, o)| format!("{} {}", c.name.as_str(), o))
+                            .join(", ")
+                    )
+                };
+                let group_cols = group_by
+                    .iter()
+                    .map(|c| c.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{}({}{}) γ[{}]", function, args, order, group_cols)
+            }
             MirNodeInner::Filter { ref conditions, .. } => {
                 format!("σ[{}]", conditions.display(nom_sql::Dialect::MySQL))
             }
             MirNodeInner::Identity => "≡".to_string(),
             MirNodeInner::Join {
                 ref on,
+                ref conditions,
                 ref project,
-                ..
             } => {
                 let jc = on
                     .iter()
                     .map(|(l, r)| format!("{}:{}", l.name, r.name))
+                    .chain(
+                        conditions
+                            .iter()
+                            .map(|(l, op, r)| format!("{} {} {}", l.name, op, r.name)),
+                    )
                     .collect::<Vec<_>>()
                     .join(", ");
                 format!(
@@ -431,13 +660,61 @@ impl MirNodeInner {
                     jc
                 )
             }
+            MirNodeInner::FullJoin {
+                ref on,
+                ref project,
+                ..
+            } => {
+                let jc = on
+                    .iter()
+                    .map(|(l, r)| format!("{}:{}", l.name, r.name))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!(
+                    "⟗ [{} on {}]",
+                    project
+                        .iter()
+                        .map(|c| c.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                    jc
+                )
+            }
             MirNodeInner::DependentJoin {
                 ref on,
                 ref project,
                 ..
+            }
+            | MirNodeInner::DependentLeftJoin {
+                ref on,
+                ref project,
             } => {
                 format!(
-                    "⧑ | {} on: {}",
+                    "{} | {} on: {}",
+                    if matches!(self, MirNodeInner::DependentLeftJoin { .. }) {
+                        "⧑⋉"
+                    } else {
+                        "⧑"
+                    },
+                    project.iter().map(|c| &c.name).join(", "),
+                    on.iter()
+                        .map(|(l, r)| format!("{}:{}", l.name, r.name))
+                        .join(", ")
+                )
+            }
+            MirNodeInner::SemiJoin { on, project }
+            | MirNodeInner::AntiJoin { on, project }
+            | MirNodeInner::DependentSemiJoin { on, project }
+            | MirNodeInner::DependentAntiJoin { on, project } => {
+                let op = match self {
+                    MirNodeInner::SemiJoin { .. } => "⋉∃",
+                    MirNodeInner::AntiJoin { .. } => "▷",
+                    MirNodeInner::DependentSemiJoin { .. } => "⧑∃",
+                    _ => "⧑▷",
+                };
+                format!(
+                    "{} [{} on {}]",
+                    op,
                     project.iter().map(|c| &c.name).join(", "),
                     on.iter()
                         .map(|(l, r)| format!("{}:{}", l.name, r.name))
@@ -481,13 +758,32 @@ impl MirNodeInner {
             } => {
                 format!("TopK [k: {}, {:?}]", limit, order)
             }
+            MirNodeInner::Window {
+                ref function,
+                ref on,
+                ref partition_by,
+                ref order_by,
+                ..
+            } => {
+                format!(
+                    "{}({}) OVER [π: {}; {:?}]",
+                    function,
+                    on.iter().map(|c| c.name.as_str()).join(", "),
+                    partition_by.iter().map(|c| c.name.as_str()).join(", "),
+                    order_by
+                )
+            }
             MirNodeInner::Union {
                 ref emit,
                 ref duplicate_mode,
             } => {
                 let symbol = match duplicate_mode {
-                    union::DuplicateMode::BagUnion => '⊎',
-                    union::DuplicateMode::UnionAll => '⋃',
+                    union::DuplicateMode::BagUnion => "⊎",
+                    union::DuplicateMode::UnionAll => "⋃",
+                    union::DuplicateMode::Intersect { all: false } => "∩",
+                    union::DuplicateMode::Intersect { all: true } => "∩ALL",
+                    union::DuplicateMode::Except { all: false } => "∖",
+                    union::DuplicateMode::Except { all: true } => "∖ALL",
                 };
                 emit.iter()
                     .map(|c| {
@@ -11,9 +11,9 @@ use crate::Column;
 /// node to the query that projects out a constant literal value (a "bogokey", from "bogus key") and
 /// making that the key for the query.
 ///
-/// This pass will also handle ensuring that any topk or paginate nodes in leaf position in such
-/// queries have `group_by` columns, by lifting the bogokey project node over those nodes and adding
-/// the bogokey to their `group_by`
+/// This pass will also handle ensuring that any topk, paginate, or window nodes in leaf position in
+/// such queries have `group_by` (or `partition_by`) columns, by lifting the bogokey project node
+/// over those nodes and adding the bogokey to their `group_by` (or `partition_by`)
 pub(crate) fn add_bogokey_if_necessary(query: &mut MirQuery<'_>) -> ReadySetResult<()> {
     match &query.leaf_node().inner {
         MirNodeInner::Leaf { keys, .. } if keys.is_empty() => {}
@@ -28,8 +28,10 @@ pub(crate) fn add_bogokey_if_necessary(query: &mut MirQuery<'_>) -> ReadySetResu
     // Find the node we're going to insert the bogokey project node above
     //
     // Usually this'll be the first leaf project node, but in the case of topk or paginate with an
-    // empty group_by we insert above those instead, since those both happen to need a group_by.
+    // empty group_by (or window with an empty partition_by) we insert above those instead, since
+    // those all happen to need a group_by.
     let mut node_to_insert_above = query.leaf();
+    let mut nodes_needing_bogokey = vec![];
     while let Some(parent) = query
         .ancestors(node_to_insert_above)?
         .first()
@@ -40,11 +42,18 @@ pub(crate) fn add_bogokey_if_necessary(query: &mut MirQuery<'_>) -> ReadySetResu
                     inner,
                     MirNodeInner::TopK { group_by, .. }
                     | MirNodeInner::Paginate { group_by, .. }
+                    | MirNodeInner::Window { partition_by: group_by, .. }
                     if group_by.is_empty()
                 )
         })
     {
         node_to_insert_above = *parent;
+        if matches!(
+            query.get_node(node_to_insert_above).unwrap().inner,
+            MirNodeInner::TopK { .. } | MirNodeInner::Window { .. }
+        ) {
+            nodes_needing_bogokey.push(node_to_insert_above);
+        }
         invariant_eq!(query.ancestors(node_to_insert_above)?.len(), 1);
     }
     trace!(
@@ -73,10 +82,15 @@ pub(crate) fn add_bogokey_if_necessary(query: &mut MirQuery<'_>) -> ReadySetResu
         keys.push((Column::named("bogokey"), ViewPlaceholder::Generated))
     }
 
-    if let MirNodeInner::TopK { group_by, .. } =
-        &mut query.get_node_mut(node_to_insert_above).unwrap().inner
-    {
-        group_by.push(Column::named("bogokey"))
+    for node in nodes_needing_bogokey {
+        if let MirNodeInner::TopK { group_by, .. }
+        | MirNodeInner::Window {
+            partition_by: group_by,
+            ..
+        } = &mut query.get_node_mut(node).unwrap().inner
+        {
+            group_by.push(Column::named("bogokey"))
+        }
     }
 
     Ok(())
@@ -19,6 +19,12 @@ use crate::{Column, NodeIndex};
 /// - [`Project`], [`Join`], [`LeftJoin`], and dependent joins *other* than the one this filter
 ///   depends on are all totally commutative with filters, so can be swapped in position with those
 ///   filters with impunity
+/// - Semi-joins and anti-joins are commutative with filters on their left-hand side, but since they
+///   don't project any columns from their right-hand side, filters can't be pushed through them
+///   from the right
+/// - The dependent semi-join, anti-join, or left join this filter depends on can absorb equality
+///   filters as join keys, but not any other kind of filter
+/// - Other dependent left joins are only commutative with filters on their left-hand side
 /// - Grouped nodes ([`Aggregation`] and [`Extremum`]) require adding any *non* dependent columns
 ///   mentioned in the filter to the group-by of the node.
 /// - All other nodes currently return an [unsupported error][] - it *is* theoretically possible to
@@ -94,13 +100,78 @@ fn push_dependent_filter(
                 DependentCondition::FullyDependent { .. } => true,
             }
         }
+        MirNodeInner::DependentSemiJoin { on, .. } | MirNodeInner::DependentAntiJoin { on, .. }
+            if child_idx == dependent_join_idx =>
+        {
+            match dependency {
+                DependentCondition::JoinKey { lhs, rhs } => {
+                    on.push((lhs.clone(), rhs));
+                    // Semi-joins and anti-joins only project columns from the left
+                    query.graph.add_column(child_idx, lhs)?;
+                    false
+                }
+                DependentCondition::FullyDependent { .. } => {
+                    // We can't lift the filter above the join, since the join doesn't project any
+                    // of the columns from the right-hand side that the filter refers to
+                    unsupported!(
+                        "Correlated EXISTS subqueries may only contain equality comparisons \
+                         against columns in the outer query"
+                    )
+                }
+            }
+        }
+        MirNodeInner::DependentLeftJoin { on, .. } if child_idx == dependent_join_idx => {
+            match dependency {
+                DependentCondition::JoinKey { lhs, rhs } => {
+                    on.push((lhs.clone(), rhs.clone()));
+                    query.graph.add_column(child_idx, lhs)?;
+                    query.graph.add_column(child_idx, rhs)?;
+                    false
+                }
+                DependentCondition::FullyDependent { .. } => {
+                    // Lifting the filter above a left join would filter out rows on the left,
+                    // rather than just the rows of the subquery
+                    unsupported!(
+                        "Correlated scalar subqueries may only contain equality comparisons \
+                         against columns in the outer query"
+                    )
+                }
+            }
+        }
         MirNodeInner::Project { .. }
         | MirNodeInner::Filter { .. }
         | MirNodeInner::Join { .. }
         | MirNodeInner::LeftJoin { .. }
         | MirNodeInner::DependentJoin { .. }
         | MirNodeInner::AliasTable { .. } => true,
-        MirNodeInner::Aggregation { .. } | MirNodeInner::Extremum { .. } => {
+        MirNodeInner::SemiJoin { .. }
+        | MirNodeInner::AntiJoin { .. }
+        | MirNodeInner::DependentSemiJoin { .. }
+        | MirNodeInner::DependentAntiJoin { .. } => {
+            // Semi-joins and anti-joins don't project any columns from their right parent, so we
+            // can only push filters through them from the left
+            if query.graph.sorted_ancestors(child_idx).next() != Some(node_idx) {
+                unsupported!(
+                    "Can't push dependent filter through the right-hand side of a semi-join or \
+                     anti-join"
+                )
+            }
+            true
+        }
+        MirNodeInner::DependentLeftJoin { .. } => {
+            // Filtering the right-hand side of a left join isn't the same as filtering its output,
+            // since rows on the left are still emitted (padded with NULLs) if they have no matches
+            if query.graph.sorted_ancestors(child_idx).next() != Some(node_idx) {
+                unsupported!(
+                    "Can't push dependent filter through the right-hand side of a dependent left \
+                     join"
+                )
+            }
+            true
+        }
+        MirNodeInner::Aggregation { .. }
+        | MirNodeInner::Extremum { .. }
+        | MirNodeInner::Collect { .. } => {
             for col in dependency.non_dependent_columns() {
                 query.graph.add_column(child_idx, col.clone())?;
             }
@@ -138,7 +209,8 @@ fn push_dependent_filter(
 /// 2. Find a filter in the ancestors of the right hand side of that join that references columns
 ///    on the left hand side of that join
 ///    - If we don't find one, then the join no longer needs to be dependent, so convert it to a
-///      regular inner join
+///      regular inner join (or semi-join, anti-join, or left join, for dependent semi-joins,
+///      anti-joins, and left joins)
 /// 3. Attempt to push that filter down the graph, using an algebraic rewrite rule (this is done
 ///    in [`push_dependent_filter`])
 ///
@@ -269,6 +341,19 @@ pub(crate) fn eliminate_dependent_joins(query: &mut MirQuery<'_>) -> ReadySetRes
             // it into a non-dependent join
             let new_inner = match &query.get_node(join).unwrap().inner {
                 MirNodeInner::DependentJoin { on, project } => MirNodeInner::Join {
+                    on: on.clone(),
+                    conditions: vec![],
+                    project: project.clone(),
+                },
+                MirNodeInner::DependentSemiJoin { on, project } => MirNodeInner::SemiJoin {
+                    on: on.clone(),
+                    project: project.clone(),
+                },
+                MirNodeInner::DependentAntiJoin { on, project } => MirNodeInner::AntiJoin {
+                    on: on.clone(),
+                    project: project.clone(),
+                },
+                MirNodeInner::DependentLeftJoin { on, project } => MirNodeInner::LeftJoin {
                     on: on.clone(),
                     project: project.clone(),
                 },
@@ -315,6 +400,7 @@ impl DependentCondition {
 mod tests {
     use common::{DfValue, IndexType};
     use dataflow::ops::grouped::aggregate::Aggregation;
+    use dataflow::ops::grouped::extremum::Extremum;
     use nom_sql::{BinaryOperator, ColumnSpecification, Expr, Literal, Relation, SqlType};
     use petgraph::Direction;
 
@@ -518,6 +604,250 @@ mod tests {
         assert!(pull_result.is_ok(), "{}", pull_result.err().unwrap());
     }
 
+    #[test]
+    fn not_exists() {
+        readyset_tracing::init_test_logging();
+        // query looks something like:
+        //     SELECT t1.a FROM t1 WHERE NOT EXISTS (SELECT * FROM t2 WHERE t2.a = t1.a)
+        let mut graph = MirGraph::new();
+
+        let query_name = Relation::from("q");
+
+        let t2 = graph.add_node(MirNode::new(
+            "t2".into(),
+            MirNodeInner::Base {
+                column_specs: vec![ColumnSpecification {
+                    column: nom_sql::Column::from("t2.a"),
+                    sql_type: SqlType::Int(None),
+                    constraints: vec![],
+                    comment: None,
+                }],
+                primary_key: Some([Column::new(Some("t2"), "a")].into()),
+                unique_keys: Default::default(),
+            },
+        ));
+        graph[t2].add_owner(query_name.clone());
+        // t2 -> ...
+
+        // -> σ[t2.a = t1.a]
+        let t2_filter = graph.add_node(MirNode::new(
+            "t2_filter".into(),
+            MirNodeInner::Filter {
+                conditions: Expr::BinaryOp {
+                    lhs: Box::new(Expr::Column("t2.a".into())),
+                    op: BinaryOperator::Equal,
+                    rhs: Box::new(Expr::Column("t1.a".into())),
+                },
+            },
+        ));
+        graph[t2_filter].add_owner(query_name.clone());
+        graph.add_edge(t2, t2_filter, 0);
+
+        // -> AliasTable
+        let t2_alias_table = graph.add_node(MirNode::new(
+            "alias_table".into(),
+            MirNodeInner::AliasTable {
+                table: "rhs".into(),
+            },
+        ));
+        graph[t2_alias_table].add_owner(query_name.clone());
+        graph.add_edge(t2_filter, t2_alias_table, 0);
+
+        let t1 = graph.add_node(MirNode::new(
+            "t1".into(),
+            MirNodeInner::Base {
+                column_specs: vec![ColumnSpecification {
+                    column: nom_sql::Column::from("t1.a"),
+                    sql_type: SqlType::Int(None),
+                    constraints: vec![],
+                    comment: None,
+                }],
+                primary_key: Some([Column::from("a")].into()),
+                unique_keys: Default::default(),
+            },
+        ));
+        graph[t1].add_owner(query_name.clone());
+
+        // t1 -> ⧑▷ on: []
+        let anti_join = graph.add_node(MirNode::new(
+            "anti_join".into(),
+            MirNodeInner::DependentAntiJoin {
+                on: vec![],
+                project: vec![Column::new(Some("t1"), "a")],
+            },
+        ));
+        graph[anti_join].add_owner(query_name.clone());
+        graph.add_edge(t1, anti_join, 0);
+        graph.add_edge(t2_alias_table, anti_join, 1);
+
+        let leaf = graph.add_node(MirNode::new(
+            "q".into(),
+            MirNodeInner::leaf(vec![], IndexType::HashMap),
+        ));
+        graph[leaf].add_owner(query_name.clone());
+        graph.add_edge(anti_join, leaf, 0);
+
+        let mut query = MirQuery::new(query_name, leaf, &mut graph);
+
+        eliminate_dependent_joins(&mut query).unwrap();
+
+        match &query.graph[anti_join].inner {
+            MirNodeInner::AntiJoin { on, project } => {
+                assert_eq!(
+                    *on,
+                    vec![(Column::new(Some("t1"), "a"), Column::new(Some("rhs"), "a"))]
+                );
+                // The anti join shouldn't project any columns from the right
+                assert_eq!(*project, vec![Column::new(Some("t1"), "a")]);
+            }
+            _ => panic!(
+                "should have rewritten dependent anti join to non-dependent anti join (got: {})",
+                query.graph[anti_join].inner.description()
+            ),
+        };
+
+        assert_eq!(
+            query.graph[query
+                .graph
+                .neighbors_directed(t2_alias_table, Direction::Incoming)
+                .next()
+                .unwrap()]
+            .name(),
+            &Relation::from("t2"),
+            "t2_filter should be removed"
+        );
+
+        let pull_result = pull_all_required_columns(&mut query);
+        assert!(pull_result.is_ok(), "{}", pull_result.err().unwrap());
+        assert_eq!(
+            query.graph.columns(anti_join),
+            vec![Column::new(Some("t1"), "a")]
+        );
+    }
+
+    #[test]
+    fn scalar_subquery() {
+        readyset_tracing::init_test_logging();
+        // query looks something like:
+        //     SELECT t1.a, (SELECT max(t2.b) FROM t2 WHERE t2.a = t1.a) FROM t1
+        let mut graph = MirGraph::new();
+
+        let query_name = Relation::from("q");
+
+        let t2 = graph.add_node(MirNode::new(
+            "t2".into(),
+            MirNodeInner::Base {
+                column_specs: vec![
+                    ColumnSpecification {
+                        column: nom_sql::Column::from("t2.a"),
+                        sql_type: SqlType::Int(None),
+                        constraints: vec![],
+                        comment: None,
+                    },
+                    ColumnSpecification {
+                        column: nom_sql::Column::from("t2.b"),
+                        sql_type: SqlType::Int(None),
+                        constraints: vec![],
+                        comment: None,
+                    },
+                ],
+                primary_key: Some([Column::new(Some("t2"), "a")].into()),
+                unique_keys: Default::default(),
+            },
+        ));
+        graph[t2].add_owner(query_name.clone());
+        // t2 -> ...
+
+        // -> σ[t2.a = t1.a]
+        let t2_filter = graph.add_node(MirNode::new(
+            "t2_filter".into(),
+            MirNodeInner::Filter {
+                conditions: Expr::BinaryOp {
+                    lhs: Box::new(Expr::Column("t2.a".into())),
+                    op: BinaryOperator::Equal,
+                    rhs: Box::new(Expr::Column("t1.a".into())),
+                },
+            },
+        ));
+        graph[t2_filter].add_owner(query_name.clone());
+        graph.add_edge(t2, t2_filter, 0);
+
+        // -> max(t2.b) γ[]
+        let t2_max = graph.add_node(MirNode::new(
+            "q_t2_max".into(),
+            MirNodeInner::Extremum {
+                on: Column::new(Some("t2"), "b"),
+                group_by: vec![],
+                output_column: Column::named("max(t2.b)"),
+                kind: Extremum::Max,
+            },
+        ));
+        graph[t2_max].add_owner(query_name.clone());
+        graph.add_edge(t2_filter, t2_max, 0);
+
+        let t1 = graph.add_node(MirNode::new(
+            "t1".into(),
+            MirNodeInner::Base {
+                column_specs: vec![ColumnSpecification {
+                    column: nom_sql::Column::from("t1.a"),
+                    sql_type: SqlType::Int(None),
+                    constraints: vec![],
+                    comment: None,
+                }],
+                primary_key: Some([Column::from("a")].into()),
+                unique_keys: Default::default(),
+            },
+        ));
+        graph[t1].add_owner(query_name.clone());
+
+        // t1 -> ⧑⋉ on: []
+        let left_join = graph.add_node(MirNode::new(
+            "left_join".into(),
+            MirNodeInner::DependentLeftJoin {
+                on: vec![],
+                project: vec![Column::new(Some("t1"), "a"), Column::named("max(t2.b)")],
+            },
+        ));
+        graph[left_join].add_owner(query_name.clone());
+        graph.add_edge(t1, left_join, 0);
+        graph.add_edge(t2_max, left_join, 1);
+
+        let leaf = graph.add_node(MirNode::new(
+            "q".into(),
+            MirNodeInner::leaf(vec![], IndexType::HashMap),
+        ));
+        graph[leaf].add_owner(query_name.clone());
+        graph.add_edge(left_join, leaf, 0);
+
+        let mut query = MirQuery::new(query_name, leaf, &mut graph);
+
+        eliminate_dependent_joins(&mut query).unwrap();
+
+        match &query.graph[left_join].inner {
+            MirNodeInner::LeftJoin { on, project } => {
+                assert_eq!(
+                    *on,
+                    vec![(Column::new(Some("t1"), "a"), Column::new(Some("t2"), "a"))]
+                );
+                assert!(project.contains(&Column::named("max(t2.b)")));
+            }
+            _ => panic!(
+                "should have rewritten dependent left join to non-dependent left join (got: {})",
+                query.graph[left_join].inner.description()
+            ),
+        };
+
+        match &query.graph[t2_max].inner {
+            MirNodeInner::Extremum { group_by, .. } => {
+                assert_eq!(*group_by, vec![Column::new(Some("t2"), "a")]);
+            }
+            _ => panic!("should not have replaced the extremum node"),
+        }
+
+        let pull_result = pull_all_required_columns(&mut query);
+        assert!(pull_result.is_ok(), "{}", pull_result.err().unwrap());
+    }
+
     #[test]
     fn multiple_filters_after_agg() {
         readyset_tracing::init_test_logging();
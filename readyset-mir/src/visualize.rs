@@ -1,6 +1,8 @@
 use std::fmt::{self, Display, Formatter};
+use std::iter;
 
 use dataflow::ops::grouped::aggregate::Aggregation as AggregationKind;
+use dataflow::ops::grouped::collect::CollectKind;
 use dataflow::ops::grouped::extremum::Extremum as ExtremumKind;
 use dataflow::ops::union;
 use dataflow::PostLookupAggregateFunction;
@@ -159,6 +161,21 @@ impl GraphViz for MirNodeInner {
                     AggregationKind::Count { .. } => format!("\\|*\\|({})", on),
                     AggregationKind::Sum => format!("𝛴({})", on),
                     AggregationKind::Avg => format!("AVG({})", on),
+                    AggregationKind::StdDev { sample: true } => format!("STDDEV_SAMP({})", on),
+                    AggregationKind::StdDev { sample: false } => format!("STDDEV_POP({})", on),
+                    AggregationKind::Variance { sample: true } => format!("VAR_SAMP({})", on),
+                    AggregationKind::Variance { sample: false } => format!("VAR_POP({})", on),
+                    AggregationKind::BoolAnd => format!("BOOL_AND({})", on),
+                    AggregationKind::BoolOr => format!("BOOL_OR({})", on),
+                    AggregationKind::BitAnd => format!("BIT_AND({})", on),
+                    AggregationKind::BitOr => format!("BIT_OR({})", on),
+                    AggregationKind::BitXor => format!("BIT_XOR({})", on),
+                    AggregationKind::ApproxCountDistinct => {
+                        format!("APPROX_COUNT_DISTINCT({})", on)
+                    }
+                    AggregationKind::ApproxPercentile { percentile } => {
+                        format!("APPROX_PERCENTILE({}, {})", on, percentile.value)
+                    }
                     AggregationKind::GroupConcat { separator: s } => {
                         format!("||({}, \"{}\")", on, s)
                     }
@@ -201,14 +218,54 @@ impl GraphViz for MirNodeInner {
                 let group_cols = group_by.iter().join(", ");
                 write!(f, "{} | γ: {}", op_string, group_cols)
             }
+            MirNodeInner::Collect {
+                ref on,
+                ref key,
+                ref order_by,
+                ref group_by,
+                ref kind,
+                ..
+            } => {
+                let function = match kind {
+                    CollectKind::Array => "array_agg",
+                    CollectKind::JsonArray => "json_agg",
+                    CollectKind::JsonObject => "json_object_agg",
+                };
+                let args = key.iter().chain(iter::once(on)).join(", ");
+                let order = if order_by.is_empty() {
+                    "".to_owned()
+                } else {
+                    format!(
+                        " ORDER BY {}",
+                        order_by
+                            .iter()
+                            .map(|(c, o)| format!("{} {}", c, o))
+                            .join(", ")
+                    )
+                };
+                let group_cols = group_by.iter().join(", ");
+                write!(f, "{}({}{}) | γ: {}", function, args, order, group_cols)
+            }
             MirNodeInner::Filter { ref conditions, .. } => {
                 // FIXME(ENG-2502): Use correct dialect.
                 write!(f, "σ: {}", conditions.display(nom_sql::Dialect::MySQL))
             }
 
             MirNodeInner::Identity => write!(f, "≡"),
-            MirNodeInner::Join { ref on, .. } => {
-                let jc = on.iter().map(|(l, r)| format!("{}:{}", l, r)).join(", ");
+            MirNodeInner::Join {
+                ref on,
+                ref conditions,
+                ..
+            } => {
+                let jc = on
+                    .iter()
+                    .map(|(l, r)| format!("{}:{}", l, r))
+                    .chain(
+                        conditions
+                            .iter()
+                            .map(|(l, op, r)| format!("{} {} {}", l, op, r)),
+                    )
+                    .join(", ");
                 write!(f, "⋈  | on: {}", jc)
             }
             MirNodeInner::JoinAggregates => write!(f, "AGG ⋈"),
@@ -230,7 +287,7 @@ impl GraphViz for MirNodeInner {
                         "\\norder_by: {}",
                         order_by
                             .iter()
-                            .map(|(col, ot)| format!("{} {}", col, ot))
+                            .map(|(col, ot, no)| format!("{} {} {}", col, ot, no))
                             .join(", ")
                     )?;
                 }
@@ -258,6 +315,7 @@ impl GraphViz for MirNodeInner {
                                     PostLookupAggregateFunction::GroupConcat { .. } => "GC",
                                     PostLookupAggregateFunction::Max => "Max",
                                     PostLookupAggregateFunction::Min => "Min",
+                                    PostLookupAggregateFunction::Avg { .. } => "Avg",
                                 },
                                 &aggregate.column
                             ))
@@ -272,6 +330,18 @@ impl GraphViz for MirNodeInner {
                 let jc = on.iter().map(|(l, r)| format!("{}:{}", l, r)).join(", ");
                 write!(f, "⋉  | on: {}", jc)
             }
+            MirNodeInner::FullJoin { ref on, .. } => {
+                let jc = on.iter().map(|(l, r)| format!("{}:{}", l, r)).join(", ");
+                write!(f, "⟗  | on: {}", jc)
+            }
+            MirNodeInner::SemiJoin { ref on, .. } => {
+                let jc = on.iter().map(|(l, r)| format!("{}:{}", l, r)).join(", ");
+                write!(f, "⋉∃  | on: {}", jc)
+            }
+            MirNodeInner::AntiJoin { ref on, .. } => {
+                let jc = on.iter().map(|(l, r)| format!("{}:{}", l, r)).join(", ");
+                write!(f, "▷  | on: {}", jc)
+            }
             MirNodeInner::DependentJoin { ref on, .. } => {
                 write!(
                     f,
@@ -279,6 +349,27 @@ impl GraphViz for MirNodeInner {
                     on.iter().map(|(l, r)| format!("{}:{}", l, r)).join(", ")
                 )
             }
+            MirNodeInner::DependentSemiJoin { ref on, .. } => {
+                write!(
+                    f,
+                    "⧑∃ | on: {}",
+                    on.iter().map(|(l, r)| format!("{}:{}", l, r)).join(", ")
+                )
+            }
+            MirNodeInner::DependentAntiJoin { ref on, .. } => {
+                write!(
+                    f,
+                    "⧑▷ | on: {}",
+                    on.iter().map(|(l, r)| format!("{}:{}", l, r)).join(", ")
+                )
+            }
+            MirNodeInner::DependentLeftJoin { ref on, .. } => {
+                write!(
+                    f,
+                    "⧑⋉ | on: {}",
+                    on.iter().map(|(l, r)| format!("{}:{}", l, r)).join(", ")
+                )
+            }
             MirNodeInner::Project {
                 ref emit,
                 ref literals,
@@ -312,7 +403,7 @@ impl GraphViz for MirNodeInner {
                     .as_ref()
                     .map(|v| {
                         v.iter()
-                            .map(|(c, o)| format!("{}: {}", c.name.as_str(), o))
+                            .map(|(c, o, n)| format!("{}: {} {}", c.name.as_str(), o, n))
                             .collect::<Vec<_>>()
                             .join(", ")
                     })
@@ -328,20 +419,43 @@ impl GraphViz for MirNodeInner {
                     .as_ref()
                     .map(|v| {
                         v.iter()
-                            .map(|(c, o)| format!("{}: {}", c.name.as_str(), o))
+                            .map(|(c, o, n)| format!("{}: {} {}", c.name.as_str(), o, n))
                             .collect::<Vec<_>>()
                             .join(", ")
                     })
                     .unwrap_or_else(|| "".into());
                 write!(f, "TopK [k: {}; {}]", limit, order)
             }
+            MirNodeInner::Window {
+                ref function,
+                ref on,
+                ref partition_by,
+                ref order_by,
+                ..
+            } => {
+                write!(
+                    f,
+                    "{}({}) OVER | π: {} | o: {}",
+                    function,
+                    on.iter().map(|c| c.name.as_str()).join(", "),
+                    partition_by.iter().map(|c| c.name.as_str()).join(", "),
+                    order_by
+                        .iter()
+                        .map(|(c, o)| format!("{}: {}", c.name.as_str(), o))
+                        .join(", ")
+                )
+            }
             MirNodeInner::Union {
                 ref emit,
                 ref duplicate_mode,
             } => {
                 let symbol = match duplicate_mode {
-                    union::DuplicateMode::BagUnion => '⊎',
-                    union::DuplicateMode::UnionAll => '⋃',
+                    union::DuplicateMode::BagUnion => "⊎",
+                    union::DuplicateMode::UnionAll => "⋃",
+                    union::DuplicateMode::Intersect { all: false } => "∩",
+                    union::DuplicateMode::Intersect { all: true } => "∩ALL",
+                    union::DuplicateMode::Except { all: false } => "∖",
+                    union::DuplicateMode::Except { all: true } => "∖ALL",
                 };
                 let cols = emit
                     .iter()
@@ -153,6 +153,9 @@ async fn write_column<W: AsyncWrite + Unpin>(
         DfValue::BitVector(_) => {
             internal!("Cannot write MySQL column: MySQL does not support bit vectors")
         }
+        DfValue::Interval(_) => {
+            internal!("Cannot write MySQL column: MySQL does not support intervals")
+        }
         DfValue::PassThrough(_) => {
             internal!("Cannot write MySQL column: PassThrough types aren't supported for MySQL")
         }
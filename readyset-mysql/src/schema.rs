@@ -95,6 +95,7 @@ pub(crate) fn convert_column(col: &ColumnSchema) -> ReadySetResult<mysql_srv::Co
         DfType::MacAddr => unsupported!("MySQL does not support the MACADDR type"),
         DfType::Inet => unsupported!("MySQL does not support the INET type"),
         DfType::Uuid => unsupported!("MySQL does not support the UUID type"),
+        DfType::Interval => unsupported!("MySQL does not support the INTERVAL type"),
         DfType::Jsonb => unsupported!("MySQL does not support the JSONB type"),
         DfType::Bit(size) => {
             if size < 64 {
@@ -170,7 +170,6 @@ async fn range_query() {
     shutdown_tx.shutdown().await;
 }
 
-// TODO: remove this once we support aggregates on parametrized IN
 #[tokio::test(flavor = "multi_thread")]
 #[serial]
 async fn aggregate_in() {
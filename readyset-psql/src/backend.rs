@@ -173,6 +173,7 @@ impl TryFrom<ParamRef<'_>> for DfValue {
             ps::Value::TimestampTz(v) => Ok(DfValue::from(*v)),
             ps::Value::Date(v) => Ok((*v).into()),
             ps::Value::Time(v) => Ok((*v).into()),
+            ps::Value::Interval(v) => Ok(DfValue::from(*v)),
             ps::Value::ByteArray(b) => Ok(DfValue::ByteArray(Arc::new(b.clone()))),
             ps::Value::MacAddress(m) => Ok(DfValue::from(m.to_string(MacAddressFormat::HexString))),
             ps::Value::Inet(ip) => Ok(DfValue::from(ip.to_string())),
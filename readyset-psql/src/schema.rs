@@ -87,6 +87,7 @@ pub fn type_to_pgsql(col_type: &DfType) -> Result<pgsql::types::Type, Error> {
         DfType::Jsonb => Ok(Type::JSONB),
         DfType::Date => Ok(Type::DATE),
         DfType::Time { .. } => Ok(Type::TIME),
+        DfType::Interval => Ok(Type::INTERVAL),
         DfType::UnsignedInt => Ok(Type::INT4),
         DfType::UnsignedBigInt => Ok(Type::INT8),
         DfType::TinyInt => Ok(Type::CHAR),
@@ -142,6 +143,7 @@ pub fn type_to_pgsql(col_type: &DfType) -> Result<pgsql::types::Type, Error> {
         DfType::Array(box DfType::Jsonb) => Ok(Type::JSONB_ARRAY),
         DfType::Array(box DfType::Date) => Ok(Type::DATE_ARRAY),
         DfType::Array(box DfType::Time { .. }) => Ok(Type::TIME_ARRAY),
+        DfType::Array(box DfType::Interval) => Ok(Type::INTERVAL_ARRAY),
         DfType::Array(box DfType::UnsignedInt) => unsupported_type!(),
         DfType::Array(box DfType::UnsignedBigInt) => unsupported_type!(),
         DfType::Array(box DfType::TinyInt) => Ok(Type::CHAR_ARRAY),
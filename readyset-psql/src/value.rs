@@ -83,6 +83,7 @@ impl TryFrom<Value> for ps::Value {
                 Ok(ps::Value::Date(v.to_chrono().naive_local().date()))
             }
             (Type::TIME, DfValue::Time(t)) => Ok(ps::Value::Time((t).into())),
+            (Type::INTERVAL, DfValue::Interval(ref i)) => Ok(ps::Value::Interval(**i)),
             (Type::BOOL, DfValue::UnsignedInt(v)) => Ok(ps::Value::Bool(v != 0)),
             (Type::BOOL, DfValue::Int(v)) => Ok(ps::Value::Bool(v != 0)),
             (Type::BYTEA, DfValue::ByteArray(b)) => Ok(ps::Value::ByteArray(
@@ -84,6 +84,9 @@ impl Builder {
         builder.set_allow_topk(opts.enable_experimental_topk_support);
         builder.set_allow_paginate(opts.enable_experimental_paginate_support);
         builder.set_allow_mixed_comparisons(opts.enable_experimental_mixed_comparisons);
+        builder.set_allow_recursive_ctes(opts.enable_experimental_recursive_ctes);
+        builder.set_max_recursive_cte_depth(opts.max_recursive_cte_depth);
+        builder.set_max_parametrized_limit(opts.max_parametrized_limit);
         builder.set_worker_timeout(std::time::Duration::from_secs(
             opts.worker_request_timeout_seconds,
         ));
@@ -197,6 +200,21 @@ impl Builder {
         self.config.mir_config.allow_mixed_comparisons = allow_mixed_comparisons;
     }
 
+    /// Set the value of [`controller::sql::Config::allow_recursive_ctes`]
+    pub fn set_allow_recursive_ctes(&mut self, allow_recursive_ctes: bool) {
+        self.config.mir_config.allow_recursive_ctes = allow_recursive_ctes;
+    }
+
+    /// Set the value of [`controller::sql::Config::max_recursive_cte_depth`]
+    pub fn set_max_recursive_cte_depth(&mut self, max_recursive_cte_depth: usize) {
+        self.config.mir_config.max_recursive_cte_depth = max_recursive_cte_depth;
+    }
+
+    /// Set the value of [`controller::sql::Config::max_parametrized_limit`]
+    pub fn set_max_parametrized_limit(&mut self, max_parametrized_limit: usize) {
+        self.config.mir_config.max_parametrized_limit = max_parametrized_limit;
+    }
+
     /// Set the value of [`controller::sql::Config::worker_request_timeout`]
     pub fn set_worker_timeout(&mut self, worker_request_timeout: Duration) {
         self.config.worker_request_timeout = worker_request_timeout;
@@ -594,7 +594,7 @@ fn trace(
 mod tests {
     use dataflow::utils::make_columns;
     use dataflow::{node, ops};
-    use nom_sql::OrderType;
+    use nom_sql::{NullOrder, OrderType};
 
     use super::*;
 
@@ -850,7 +850,7 @@ mod tests {
             make_columns(&["x1", "x2"]),
             ops::NodeOperator::Union(
                 ops::union::Union::new(
-                    vec![(a, vec![0, 1]), (b, vec![0, 1])].into_iter().collect(),
+                    vec![(a, vec![0, 1]), (b, vec![0, 1])],
                     ops::union::DuplicateMode::UnionAll,
                 )
                 .unwrap(),
@@ -1127,7 +1127,7 @@ mod tests {
             make_columns(&["a1", "a2", "__page_number"]),
             ops::NodeOperator::Paginate(ops::paginate::Paginate::new(
                 a,
-                vec![(0, OrderType::OrderAscending)],
+                vec![(0, OrderType::OrderAscending, NullOrder::NullsFirst)],
                 vec![1],
                 3,
             )),
@@ -370,6 +370,13 @@ impl Materializations {
                 if !m.is_internal() || !m.can_query_through() {
                     break;
                 }
+                // range lookups can't be performed through query-through operators
+                if indices
+                    .iter()
+                    .any(|index| index.index().index_type == IndexType::BTreeMap)
+                {
+                    break;
+                }
 
                 let mut parents = graph.neighbors_directed(mi, petgraph::EdgeDirection::Incoming);
                 #[allow(clippy::unwrap_used)] // parent must exist because node is internal
@@ -11,9 +11,11 @@ use std::convert::TryInto;
 
 use common::DfValue;
 use dataflow::node::Column as DfColumn;
+use dataflow::ops::grouped::collect::{Collect, CollectKind};
 use dataflow::ops::grouped::concat::GroupConcat;
-use dataflow::ops::join::{Join, JoinType};
+use dataflow::ops::join::{Join, JoinComparison, JoinCondition, JoinType};
 use dataflow::ops::project::Project;
+use dataflow::ops::window::WindowFunction;
 use dataflow::{node, ops, Expr as DfExpr, PostLookupAggregates, ReaderProcessing};
 use itertools::Itertools;
 use mir::graph::MirGraph;
@@ -21,7 +23,9 @@ use mir::node::node_inner::MirNodeInner;
 use mir::node::GroupedNodeType;
 use mir::query::MirQuery;
 use mir::{Column, DfNodeIndex, NodeIndex as MirNodeIndex};
-use nom_sql::{ColumnConstraint, ColumnSpecification, Expr, OrderType, Relation, SqlIdentifier};
+use nom_sql::{
+    ColumnConstraint, ColumnSpecification, Expr, NullOrder, OrderType, Relation, SqlIdentifier,
+};
 use petgraph::graph::NodeIndex;
 use petgraph::Direction;
 use readyset_client::internal::{Index, IndexType};
@@ -134,6 +138,29 @@ pub(super) fn mir_node_to_flow_parts(
                         mig,
                     )?)
                 }
+                MirNodeInner::Collect {
+                    ref on,
+                    ref key,
+                    ref order_by,
+                    ref group_by,
+                    kind,
+                    ..
+                } => {
+                    invariant_eq!(ancestors.len(), 1);
+                    let parent = ancestors[0];
+                    Some(make_collect_node(
+                        graph,
+                        name,
+                        parent,
+                        &graph.columns(mir_node),
+                        on,
+                        key.as_ref(),
+                        order_by,
+                        group_by,
+                        kind,
+                        mig,
+                    )?)
+                }
                 MirNodeInner::Filter { ref conditions } => {
                     invariant_eq!(ancestors.len(), 1);
                     let parent = ancestors[0];
@@ -160,8 +187,8 @@ pub(super) fn mir_node_to_flow_parts(
                 }
                 MirNodeInner::Join {
                     ref on,
+                    ref conditions,
                     ref project,
-                    ..
                 } => {
                     invariant_eq!(ancestors.len(), 2);
                     let left = ancestors[0];
@@ -171,8 +198,9 @@ pub(super) fn mir_node_to_flow_parts(
                         name,
                         left,
                         right,
-                        &graph.referenced_columns(mir_node),
+                        &graph.columns(mir_node),
                         on,
+                        conditions,
                         project,
                         JoinType::Inner,
                         custom_types,
@@ -192,7 +220,10 @@ pub(super) fn mir_node_to_flow_parts(
                         mig,
                     )?)
                 }
-                MirNodeInner::DependentJoin { .. } => {
+                MirNodeInner::DependentJoin { .. }
+                | MirNodeInner::DependentSemiJoin { .. }
+                | MirNodeInner::DependentAntiJoin { .. }
+                | MirNodeInner::DependentLeftJoin { .. } => {
                     // See the docstring for MirNodeInner::DependentJoin
                     internal!("Encountered dependent join when lowering to dataflow")
                 }
@@ -205,6 +236,7 @@ pub(super) fn mir_node_to_flow_parts(
                     ref returned_cols,
                     ref default_row,
                     ref aggregates,
+                    ref subquery_row_counts,
                     ..
                 } => {
                     if !lowered_to_df {
@@ -218,6 +250,7 @@ pub(super) fn mir_node_to_flow_parts(
                             returned_cols,
                             default_row.clone(),
                             aggregates,
+                            subquery_row_counts,
                         )?;
                         materialize_leaf_node(
                             graph,
@@ -246,12 +279,64 @@ pub(super) fn mir_node_to_flow_parts(
                         right,
                         &graph.columns(mir_node),
                         on,
+                        &[],
                         project,
                         JoinType::Left,
                         custom_types,
                         mig,
                     )?)
                 }
+                MirNodeInner::FullJoin {
+                    ref on,
+                    ref project,
+                    ..
+                } => {
+                    invariant_eq!(ancestors.len(), 2);
+                    let left = ancestors[0];
+                    let right = ancestors[1];
+                    Some(make_join_node(
+                        graph,
+                        name,
+                        left,
+                        right,
+                        &graph.columns(mir_node),
+                        on,
+                        &[],
+                        project,
+                        JoinType::Full,
+                        custom_types,
+                        mig,
+                    )?)
+                }
+                MirNodeInner::SemiJoin {
+                    ref on,
+                    ref project,
+                }
+                | MirNodeInner::AntiJoin {
+                    ref on,
+                    ref project,
+                } => {
+                    invariant_eq!(ancestors.len(), 2);
+                    let left = ancestors[0];
+                    let right = ancestors[1];
+                    Some(make_join_node(
+                        graph,
+                        name,
+                        left,
+                        right,
+                        &graph.columns(mir_node),
+                        on,
+                        &[],
+                        project,
+                        if matches!(graph[mir_node].inner, MirNodeInner::SemiJoin { .. }) {
+                            JoinType::Semi
+                        } else {
+                            JoinType::Anti
+                        },
+                        custom_types,
+                        mig,
+                    )?)
+                }
                 MirNodeInner::Project {
                     ref emit,
                     ref literals,
@@ -326,6 +411,27 @@ pub(super) fn mir_node_to_flow_parts(
                         mig,
                     )?)
                 }
+                MirNodeInner::Window {
+                    function,
+                    ref on,
+                    ref partition_by,
+                    ref order_by,
+                    ..
+                } => {
+                    invariant_eq!(ancestors.len(), 1);
+                    let parent = ancestors[0];
+                    Some(make_window_node(
+                        graph,
+                        name,
+                        parent,
+                        &graph.columns(mir_node),
+                        function,
+                        on.as_ref(),
+                        partition_by,
+                        order_by,
+                        mig,
+                    )?)
+                }
                 MirNodeInner::AliasTable { .. } => None,
             };
 
@@ -422,7 +528,9 @@ fn make_union_node(
     duplicate_mode: ops::union::DuplicateMode,
     mig: &mut Migration<'_>,
 ) -> ReadySetResult<DfNodeIndex> {
-    let mut emit_column_id: HashMap<NodeIndex, Vec<usize>> = HashMap::new();
+    // Kept in the same order as the ancestors, since for set differences rows are taken from the
+    // first ancestor
+    let mut emit_column_id: Vec<(NodeIndex, Vec<usize>)> = Vec::with_capacity(ancestors.len());
 
     let mut cols = Vec::with_capacity(
         emit.get(0)
@@ -461,7 +569,7 @@ fn make_union_node(
                 .collect::<ReadySetResult<Vec<_>>>()?;
         }
 
-        emit_column_id.insert(ni.address(), emit_cols);
+        emit_column_id.push((ni.address(), emit_cols));
     }
     set_names(&column_names(columns), &mut cols)?;
 
@@ -577,6 +685,7 @@ fn make_grouped_node(
                 over_col_indx,
                 group_col_indx.as_slice(),
                 over_col_ty,
+                mig.dialect,
             )?;
             let agg_col = make_agg_col(grouped.output_col_type().or_ref(over_col_ty).clone());
             cols.push(agg_col);
@@ -598,6 +707,77 @@ fn make_grouped_node(
     Ok(DfNodeIndex::new(na))
 }
 
+fn make_collect_node(
+    graph: &MirGraph,
+    name: Relation,
+    parent: MirNodeIndex,
+    columns: &[Column],
+    on: &Column,
+    key: Option<&Column>,
+    order_by: &[(Column, OrderType)],
+    group_by: &[Column],
+    kind: CollectKind,
+    mig: &mut Migration<'_>,
+) -> ReadySetResult<DfNodeIndex> {
+    invariant!(!group_by.is_empty());
+    let parent_na = graph.resolve_dataflow_node(parent).ok_or_else(|| {
+        ReadySetError::MirNodeMustHaveDfNodeAssigned {
+            mir_node_index: parent.index(),
+        }
+    })?;
+    let over_col_indx = graph.column_id_for_column(parent, on)?;
+    let key_col_indx = key
+        .map(|c| graph.column_id_for_column(parent, c))
+        .transpose()?;
+    let order_by_indx = order_by
+        .iter()
+        .map(|(c, ot)| Ok((graph.column_id_for_column(parent, c)?, *ot)))
+        .collect::<ReadySetResult<Vec<_>>>()?;
+    let group_col_indx = group_by
+        .iter()
+        .map(|c| graph.column_id_for_column(parent, c))
+        .collect::<ReadySetResult<Vec<_>>>()?;
+
+    // Like other grouped nodes, we project the group_by columns followed by the collected column
+    let parent_cols = mig.dataflow_state.ingredients[parent_na.address()].columns();
+    let mut cols = group_col_indx
+        .iter()
+        .map(|i| {
+            parent_cols
+                .get(*i)
+                .cloned()
+                .ok_or_else(|| internal_err!("Invalid index"))
+        })
+        .collect::<ReadySetResult<Vec<_>>>()?;
+    let over_col_ty = parent_cols
+        .get(over_col_indx)
+        .ok_or_else(|| internal_err!("Invalid index"))?
+        .ty()
+        .clone();
+    let over_col_name = &columns
+        .last()
+        .ok_or_else(|| internal_err!("Grouped has no projections"))?
+        .name;
+
+    let grouped = Collect::new(
+        parent_na.address(),
+        kind,
+        over_col_indx,
+        key_col_indx,
+        order_by_indx,
+        group_col_indx,
+        over_col_ty,
+    )?;
+    cols.push(DfColumn::new(
+        over_col_name.clone(),
+        grouped.output_col_type(),
+        Some(name.clone()),
+    ));
+    set_names(&column_names(columns), &mut cols)?;
+    let na = mig.add_ingredient(name, cols, grouped);
+    Ok(DfNodeIndex::new(na))
+}
+
 fn make_identity_node(
     graph: &MirGraph,
     name: Relation,
@@ -626,8 +806,8 @@ fn make_identity_node(
 
 /// Lower a join MIR node to dataflow
 ///
-/// See [`MirNodeInner::Join`] for documentation on what `on_left`, `on_right`, and `project` mean
-/// here
+/// See [`MirNodeInner::Join`] for documentation on what `on_left`, `on_right`, `conditions`, and
+/// `project` mean here
 fn make_join_node(
     graph: &MirGraph,
     name: Relation,
@@ -635,6 +815,7 @@ fn make_join_node(
     right: MirNodeIndex,
     columns: &[Column],
     on: &[(Column, Column)],
+    conditions: &[(Column, JoinComparison, Column)],
     proj_cols: &[Column],
     kind: JoinType,
     custom_types: &HashMap<Relation, DfType>,
@@ -701,7 +882,19 @@ fn make_join_node(
 
     set_names(&column_names(columns), &mut cols)?;
 
-    // If we don't have any join condition, we're making a cross join.
+    let conditions = conditions
+        .iter()
+        .map(|(l, op, r)| -> ReadySetResult<_> {
+            Ok(JoinCondition {
+                left: graph.column_id_for_column(left, l)?,
+                op: *op,
+                right: graph.column_id_for_column(right, r)?,
+            })
+        })
+        .collect::<ReadySetResult<Vec<_>>>()?;
+
+    // If we don't have any equality join condition, we're making a cross join (possibly filtered
+    // by non-equality conditions).
     // Dataflow needs a non-empty join condition, so project out a constant value on both sides to
     // use as our join key
     if on.is_empty() {
@@ -740,7 +933,13 @@ fn make_join_node(
         ));
     }
 
-    let j = Join::new(left_na.address(), right_na.address(), kind, emit);
+    let j = Join::new_with_conditions(
+        left_na.address(),
+        right_na.address(),
+        kind,
+        emit,
+        conditions,
+    );
     let n = mig.add_ingredient(name, cols, j);
 
     Ok(DfNodeIndex::new(n))
@@ -1060,7 +1259,13 @@ fn make_distinct_node(
         // remaining occurances of the set.
         //
         // We use 0 as a placeholder value
-        Aggregation::Count.over(parent_na.address(), 0, &group_by_indx, &DfType::Unknown)?,
+        Aggregation::Count.over(
+            parent_na.address(),
+            0,
+            &group_by_indx,
+            &DfType::Unknown,
+            mig.dialect,
+        )?,
     );
     Ok(DfNodeIndex::new(na))
 }
@@ -1070,7 +1275,7 @@ fn make_paginate_or_topk_node(
     name: Relation,
     parent: MirNodeIndex,
     columns: &[Column],
-    order: &Option<Vec<(Column, OrderType)>>,
+    order: &Option<Vec<(Column, OrderType, NullOrder)>>,
     group_by: &[Column],
     limit: usize,
     is_topk: bool,
@@ -1111,16 +1316,20 @@ fn make_paginate_or_topk_node(
     let cmp_rows = match *order {
         Some(ref o) => {
             o.iter()
-                .map(|(c, order_type)| {
+                .map(|(c, order_type, null_order)| {
                     // SQL and Soup disagree on what ascending and descending order means, so do the
-                    // conversion here.
+                    // conversion here. Reversing the order also reverses where NULLs are placed.
                     let reversed_order_type = match *order_type {
                         OrderType::OrderAscending => OrderType::OrderDescending,
                         OrderType::OrderDescending => OrderType::OrderAscending,
                     };
+                    let reversed_null_order = match *null_order {
+                        NullOrder::NullsFirst => NullOrder::NullsLast,
+                        NullOrder::NullsLast => NullOrder::NullsFirst,
+                    };
                     graph
                         .column_id_for_column(parent, c)
-                        .map(|id| (id, reversed_order_type))
+                        .map(|id| (id, reversed_order_type, reversed_null_order))
                 })
                 .collect::<ReadySetResult<Vec<_>>>()?
         }
@@ -1144,21 +1353,99 @@ fn make_paginate_or_topk_node(
     Ok(DfNodeIndex::new(na))
 }
 
+fn make_window_node(
+    graph: &MirGraph,
+    name: Relation,
+    parent: MirNodeIndex,
+    columns: &[Column],
+    function: WindowFunction,
+    on: Option<&Column>,
+    partition_by: &[Column],
+    order_by: &[(Column, OrderType)],
+    mig: &mut Migration<'_>,
+) -> ReadySetResult<DfNodeIndex> {
+    invariant!(
+        !partition_by.is_empty(),
+        "need bogokey for Window without partition columns"
+    );
+
+    let parent_na = graph.resolve_dataflow_node(parent).ok_or_else(|| {
+        ReadySetError::MirNodeMustHaveDfNodeAssigned {
+            mir_node_index: parent.index(),
+        }
+    })?;
+    let mut cols = mig.dataflow_state.ingredients[parent_na.address()]
+        .columns()
+        .to_vec();
+
+    let over = on
+        .map(|c| -> ReadySetResult<_> {
+            let idx = graph.column_id_for_column(parent, c)?;
+            let ty = cols
+                .get(idx)
+                .ok_or_else(|| internal_err!("Invalid index"))?
+                .ty()
+                .clone();
+            Ok((idx, ty))
+        })
+        .transpose()?;
+    let partition_by = partition_by
+        .iter()
+        .map(|c| graph.column_id_for_column(parent, c))
+        .collect::<ReadySetResult<Vec<_>>>()?;
+    // Unlike TopK and Paginate, the Window operator takes its order in the same direction as SQL
+    let order = order_by
+        .iter()
+        .map(|(c, order_type)| {
+            graph
+                .column_id_for_column(parent, c)
+                .map(|id| (id, *order_type))
+        })
+        .collect::<ReadySetResult<Vec<_>>>()?;
+
+    let window = ops::window::Window::new(
+        parent_na.address(),
+        function,
+        over.as_ref().map(|(idx, ty)| (*idx, ty)),
+        partition_by,
+        order,
+    )?;
+
+    let column_names = column_names(columns);
+    let output_col_name = column_names
+        .last()
+        .ok_or_else(|| internal_err!("Window has no projections"))?;
+    cols.push(DfColumn::new(
+        (*output_col_name).into(),
+        window.output_col_type(),
+        Some(name.clone()),
+    ));
+    set_names(&column_names, &mut cols)?;
+
+    let na = mig.add_ingredient(name, cols, window);
+    Ok(DfNodeIndex::new(na))
+}
+
 fn make_reader_processing(
     graph: &MirGraph,
     parent: &MirNodeIndex,
-    order_by: &Option<Vec<(Column, OrderType)>>,
+    order_by: &Option<Vec<(Column, OrderType, NullOrder)>>,
     limit: Option<usize>,
     returned_cols: &Option<Vec<Column>>,
     default_row: Option<Vec<DfValue>>,
     aggregates: &Option<PostLookupAggregates<Column>>,
+    subquery_row_counts: &[Column],
 ) -> ReadySetResult<ReaderProcessing> {
     let order_by = if let Some(order) = order_by.as_ref() {
         Some(
             order
                 .iter()
-                .map(|(col, ot)| graph.column_id_for_column(*parent, col).map(|id| (id, *ot)))
-                .collect::<ReadySetResult<Vec<(usize, OrderType)>>>()?,
+                .map(|(col, ot, no)| {
+                    graph
+                        .column_id_for_column(*parent, col)
+                        .map(|id| (id, *ot, *no))
+                })
+                .collect::<ReadySetResult<Vec<(usize, OrderType, NullOrder)>>>()?,
         )
     } else {
         None
@@ -1183,7 +1470,19 @@ fn make_reader_processing(
         .map(|aggs| aggs.map_columns(|col| graph.column_id_for_column(*parent, &col)))
         .transpose()?;
 
-    ReaderProcessing::new(order_by, limit, returned_cols, default_row, aggregates)
+    let subquery_row_counts = subquery_row_counts
+        .iter()
+        .map(|col| graph.column_id_for_column(*parent, col))
+        .collect::<ReadySetResult<Vec<_>>>()?;
+
+    ReaderProcessing::new(
+        order_by,
+        limit,
+        returned_cols,
+        default_row,
+        aggregates,
+        subquery_row_counts,
+    )
 }
 
 fn materialize_leaf_node(
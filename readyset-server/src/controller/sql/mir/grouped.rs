@@ -6,7 +6,7 @@ use mir::{Column, NodeIndex};
 use nom_sql::analysis::ReferredColumns;
 use nom_sql::FunctionExpr::*;
 use nom_sql::{self, Expr, FieldDefinitionExpr, Relation, SqlIdentifier};
-use readyset_errors::{unsupported, ReadySetError, ReadySetResult};
+use readyset_errors::{internal_err, unsupported, ReadySetError, ReadySetResult};
 use readyset_sql_passes::is_aggregate;
 
 use crate::controller::sql::mir::join::make_joins_for_aggregates;
@@ -49,8 +49,8 @@ pub(super) fn make_predicates_above_grouped<'a>(
 }
 
 /// Normally, projection happens after grouped nodes - however, if aggregates used in grouped
-/// expressions reference expressions rather than columns directly, we need to project them out
-/// before the grouped nodes.
+/// expressions reference expressions rather than columns directly, or if the query groups by
+/// expressions rather than columns, we need to project them out before the grouped nodes.
 ///
 /// This does that projection, and returns a mapping from the expressions themselves to the names of
 /// the columns they have been projected to
@@ -61,7 +61,7 @@ pub(super) fn make_expressions_above_grouped(
     qg: &QueryGraph,
     prev_node: &mut NodeIndex,
 ) -> HashMap<Expr, SqlIdentifier> {
-    let exprs: Vec<_> = qg
+    let mut exprs: Vec<_> = qg
         .aggregates
         .keys()
         .filter(|&f| is_aggregate(f))
@@ -76,6 +76,11 @@ pub(super) fn make_expressions_above_grouped(
             )
         })
         .collect();
+    for ec in &qg.group_by_exprs {
+        if !exprs.iter().any(|(name, _)| *name == ec.name) {
+            exprs.push((ec.name.clone(), ec.expression.clone()));
+        }
+    }
 
     if !exprs.is_empty() {
         let cols = mir_converter.columns(*prev_node).to_vec();
@@ -247,6 +252,7 @@ fn joinable_aggregate_nodes(
         .filter_map(|&node| match mir_converter.get_node(node).unwrap().inner {
             MirNodeInner::Aggregation { .. } => Some(node),
             MirNodeInner::Extremum { .. } => Some(node),
+            MirNodeInner::Collect { .. } => Some(node),
             _ => None,
         })
         .collect()
@@ -298,18 +304,67 @@ pub(super) fn post_lookup_aggregates(
         aggregates.push(PostLookupAggregate {
             column: Column::named(alias.clone()).aliased_as_table(query_name.clone()),
             function: match function {
-                Avg { .. } => {
-                    unsupported!("Average is not supported as a post-lookup aggregate")
+                // The same value may be counted for more than one key, so the per-key sum and
+                // count of distinct values can't be combined
+                Avg { distinct: true, .. } => {
+                    unsupported!("DISTINCT averages are not supported as post-lookup aggregates")
                 }
+                // Averages are recomputed from the sums and counts of their arguments, which are
+                // added to the query graph for queries that need post-lookup aggregation
+                Avg { expr, .. } => {
+                    let carried_column = |function: nom_sql::FunctionExpr| {
+                        query_graph
+                            .aggregates
+                            .get(&function)
+                            .map(|alias| {
+                                Column::named(alias.clone()).aliased_as_table(query_name.clone())
+                            })
+                            .ok_or_else(|| {
+                                internal_err!(
+                                    "Average is missing its {} column",
+                                    function.display(nom_sql::Dialect::MySQL)
+                                )
+                            })
+                    };
+                    PostLookupAggregateFunction::Avg {
+                        sum: carried_column(Sum {
+                            expr: expr.clone(),
+                            distinct: false,
+                        })?,
+                        count: carried_column(Count {
+                            expr: expr.clone(),
+                            distinct: false,
+                        })?,
+                    }
+                }
+                StdDev { .. } | Variance { .. } => unsupported!(
+                    "Standard deviation and variance are not supported as post-lookup aggregates"
+                ),
+                Count { distinct: true, .. } | Sum { distinct: true, .. } => unsupported!(
+                    "DISTINCT counts and sums are not supported as post-lookup aggregates"
+                ),
                 // Count and sum are handled the same way, as re-aggregating counts is
                 // done by just summing the numbers together
                 Count { .. } | CountStar | Sum { .. } => PostLookupAggregateFunction::Sum,
                 Max(_) => PostLookupAggregateFunction::Max,
                 Min(_) => PostLookupAggregateFunction::Min,
+                // Booleans are represented as 0 and 1, so re-aggregating them is the same as taking
+                // the minimum or maximum
+                BoolAnd(_) => PostLookupAggregateFunction::Min,
+                BoolOr(_) => PostLookupAggregateFunction::Max,
+                BitAnd(_) | BitOr(_) | BitXor(_) => {
+                    unsupported!("Bitwise aggregates are not supported as post-lookup aggregates")
+                }
+                ArrayAgg { .. } | JsonAgg { .. } | JsonObjectAgg { .. } => unsupported!(
+                    "Array and JSON aggregates are not supported as post-lookup aggregates"
+                ),
+                ApproxCountDistinct(_) | ApproxPercentile { .. } => unsupported!(
+                    "Approximate aggregates are not supported as post-lookup aggregates"
+                ),
                 GroupConcat { separator, .. } => PostLookupAggregateFunction::GroupConcat {
                     separator: separator.clone(),
                 },
-                Call { .. } | Substring { .. } => continue,
+                Call { .. } | Substring { .. } | Extract { .. } | DateAdd { .. } => continue,
             },
         });
     }
@@ -2,7 +2,7 @@ use std::collections::{HashMap, HashSet};
 
 use mir::NodeIndex;
 use nom_sql::Relation;
-use readyset_errors::{internal, internal_err, invariant, ReadySetResult};
+use readyset_errors::{internal, internal_err, invariant, unsupported, ReadySetResult};
 
 use super::JoinKind;
 use crate::controller::sql::mir::SqlToMirConverter;
@@ -45,13 +45,35 @@ pub(super) fn make_joins(
     let mut join_chains = Vec::new();
 
     for jref in qg.join_order.iter() {
+        let (left_chain, right_chain) =
+            pick_join_chains(&jref.src, &jref.dst, &mut join_chains, node_for_rel)?;
+
         let (mut join_kind, jps) = match &qg.edges[&(jref.src.clone(), jref.dst.clone())] {
             QueryGraphEdge::Join { on } => (JoinKind::Inner, on),
             QueryGraphEdge::LeftJoin { on } => (JoinKind::Left, on),
-        };
+            QueryGraphEdge::FullJoin { on } => (JoinKind::Full, on),
+            QueryGraphEdge::DisjunctiveJoin { branches } => {
+                if correlated_nodes.contains(&right_chain.last_node) {
+                    unsupported!(
+                        "Dependent join with OR in join condition not yet supported (when \
+                         joining to {})",
+                        jref.dst.display_unquoted()
+                    )
+                }
 
-        let (left_chain, right_chain) =
-            pick_join_chains(&jref.src, &jref.dst, &mut join_chains, node_for_rel)?;
+                let jn = mir_converter.make_disjunctive_join_node(
+                    query_name,
+                    mir_converter.generate_label(&name),
+                    branches,
+                    left_chain.last_node,
+                    right_chain.last_node,
+                )?;
+
+                join_chains.push(left_chain.merge_chain(right_chain, jn));
+                join_nodes.push(jn);
+                continue;
+            }
+        };
 
         // TODO(fran): Use NodeIndex instead of name.
         if correlated_nodes.contains(&right_chain.last_node) {
@@ -60,10 +82,24 @@ pub(super) fn make_joins(
                     "Dependent left join not yet supported (when joining to {})",
                     jref.dst.display_unquoted()
                 ),
+                JoinKind::Full => internal!(
+                    "Dependent full join not yet supported (when joining to {})",
+                    jref.dst.display_unquoted()
+                ),
                 JoinKind::Inner => {
                     join_kind = JoinKind::Dependent;
                 }
                 JoinKind::Dependent => {}
+                JoinKind::Semi
+                | JoinKind::Anti
+                | JoinKind::DependentSemi
+                | JoinKind::DependentAnti
+                | JoinKind::DependentLeft => {
+                    internal!(
+                        "Semi-joins, anti-joins, and dependent left joins are only created for \
+                         subqueries in expressions"
+                    )
+                }
             }
         }
 
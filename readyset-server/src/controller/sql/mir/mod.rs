@@ -7,6 +7,8 @@ use ::serde::{Deserialize, Serialize};
 use catalog_tables::is_catalog_table;
 use common::{DfValue, IndexType};
 use dataflow::ops::grouped::aggregate::Aggregation;
+use dataflow::ops::grouped::collect::CollectKind;
+use dataflow::ops::join::JoinComparison;
 use dataflow::ops::union;
 use lazy_static::lazy_static;
 use mir::graph::MirGraph;
@@ -17,9 +19,9 @@ use mir::DfNodeIndex;
 pub use mir::{Column, NodeIndex};
 use nom_sql::analysis::ReferredColumns;
 use nom_sql::{
-    BinaryOperator, ColumnSpecification, CompoundSelectOperator, CreateTableBody, Expr,
-    FieldDefinitionExpr, FieldReference, FunctionExpr, LimitClause, Literal, OrderClause,
-    OrderType, Relation, SqlIdentifier, TableKey,
+    BinaryOperator, ColumnSpecification, CompoundSelectOperator, CreateTableBody, Double, Expr,
+    FieldDefinitionExpr, FieldReference, FunctionExpr, InValue, LimitClause, Literal, NullOrder,
+    OrderClause, OrderType, Relation, SelectStatement, SqlIdentifier, TableKey, UnaryOperator,
 };
 use petgraph::visit::Reversed;
 use petgraph::Direction;
@@ -31,13 +33,15 @@ use readyset_sql_passes::is_correlated;
 use readyset_util::redacted::Sensitive;
 use tracing::{debug, trace};
 
-use super::query_graph::{extract_limit_offset, JoinPredicate};
+use super::query_graph::{extract_limit_offset, JoinBranch, JoinPredicate, Limit};
 use crate::controller::sql::mir::grouped::{
     make_expressions_above_grouped, make_grouped, make_predicates_above_grouped,
     post_lookup_aggregates,
 };
 use crate::controller::sql::mir::join::{make_cross_joins, make_joins};
-use crate::controller::sql::query_graph::{to_query_graph, OutputColumn, Pagination, QueryGraph};
+use crate::controller::sql::query_graph::{
+    to_query_graph, DistinctOn, OutputColumn, Pagination, QueryGraph, ScalarSubquery,
+};
 use crate::controller::sql::query_signature::Signature;
 
 mod grouped;
@@ -100,8 +104,20 @@ pub enum JoinKind {
     Inner,
     /// Left joins - see [`MirNodeInner::LeftJoin`]
     Left,
+    /// Full outer joins - see [`MirNodeInner::FullJoin`]
+    Full,
     /// Dependent joins - see [`MirNodeInner::DependentJoin`]
     Dependent,
+    /// Semi-joins - see [`MirNodeInner::SemiJoin`]
+    Semi,
+    /// Anti-joins - see [`MirNodeInner::AntiJoin`]
+    Anti,
+    /// Dependent semi-joins - see [`MirNodeInner::DependentSemiJoin`]
+    DependentSemi,
+    /// Dependent anti-joins - see [`MirNodeInner::DependentAntiJoin`]
+    DependentAnti,
+    /// Dependent left joins - see [`MirNodeInner::DependentLeftJoin`]
+    DependentLeft,
 }
 
 /// Specification for how to treat the leaf node of a query when converting it to MIR
@@ -132,8 +148,14 @@ impl LeafBehavior {
     }
 }
 
+/// Default value for [`Config::max_recursive_cte_depth`]
+pub(crate) const DEFAULT_MAX_RECURSIVE_CTE_DEPTH: usize = 8;
+
+/// Default value for [`Config::max_parametrized_limit`]
+pub(crate) const DEFAULT_MAX_PARAMETRIZED_LIMIT: usize = 1000;
+
 /// Configuration for how SQL is converted to MIR
-#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub(crate) struct Config {
     /// If set to `true`, a SQL `ORDER BY` with `LIMIT` will emit a [`TopK`][] node. If set to
     /// `false`, the SQL conversion process returns a [`ReadySetError::Unsupported`], causing the
@@ -153,6 +175,36 @@ pub(crate) struct Config {
     /// Enable support for mixing equality and range comparisons in a query. Support for mixed
     /// comparisons is currently unfinished, so these queries may return incorrect results.
     pub(crate) allow_mixed_comparisons: bool,
+
+    /// If set to `true`, recursive common table expressions (`WITH RECURSIVE`) are supported by
+    /// unrolling them to [`max_recursive_cte_depth`][Self::max_recursive_cte_depth] levels. If
+    /// set to `false`, the SQL conversion process returns a [`ReadySetError::Unsupported`].
+    /// Defaults to `false`.
+    pub(crate) allow_recursive_ctes: bool,
+
+    /// The maximum number of times the recursive term of a recursive common table expression
+    /// (`WITH RECURSIVE`) is applied. Recursive CTEs are unrolled to this depth, so any rows which
+    /// can only be reached via deeper recursion are not returned. Defaults to
+    /// [`DEFAULT_MAX_RECURSIVE_CTE_DEPTH`].
+    pub(crate) max_recursive_cte_depth: usize,
+
+    /// The number of rows kept per key for queries with a parametrized `LIMIT` (eg `LIMIT ?`).
+    /// The concrete limit and offset are applied post-lookup, and lookups whose limit and offset
+    /// add up to more than this return an error. Defaults to [`DEFAULT_MAX_PARAMETRIZED_LIMIT`].
+    pub(crate) max_parametrized_limit: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            allow_topk: false,
+            allow_paginate: false,
+            allow_mixed_comparisons: false,
+            allow_recursive_ctes: false,
+            max_recursive_cte_depth: DEFAULT_MAX_RECURSIVE_CTE_DEPTH,
+            max_parametrized_limit: DEFAULT_MAX_PARAMETRIZED_LIMIT,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -225,11 +277,12 @@ impl SqlToMirConverter {
         }
     }
 
+    /// Make the nodes for a compound select statement, given the leaves of the queries for each of
+    /// its selects along with the operator preceding each select
     pub(super) fn compound_query_to_mir(
         &mut self,
         query_name: &Relation,
-        subquery_leaves: Vec<NodeIndex>,
-        op: CompoundSelectOperator,
+        subquery_leaves: Vec<(Option<CompoundSelectOperator>, NodeIndex)>,
         order: &Option<OrderClause>,
         limit_clause: &LimitClause,
         leaf_behavior: LeafBehavior,
@@ -243,17 +296,15 @@ impl SqlToMirConverter {
         } else {
             format!("{}_union", query_name.display_unquoted()).into()
         };
-        let mut final_node = match op {
-            CompoundSelectOperator::Union => self.make_union_node(
-                query_name,
-                name,
-                subquery_leaves.as_slice(),
-                union::DuplicateMode::UnionAll,
-            )?,
-            _ => internal!(),
-        };
+        let mut final_node = self.make_compound_select_nodes(query_name, name, subquery_leaves)?;
 
         if let Some((limit, offset)) = extract_limit_offset(limit_clause)? {
+            let limit = match limit {
+                Limit::Constant(limit) => limit,
+                Limit::Parametrized => {
+                    unsupported!("Parametrized LIMIT is not supported for compound queries")
+                }
+            };
             let make_topk = offset.is_none();
             let paginate_name = if leaf_behavior.should_register() {
                 if make_topk {
@@ -279,7 +330,8 @@ impl SqlToMirConverter {
                         .map(|o| {
                             o.order_by
                                 .iter()
-                                .map(|(e, ot)| {
+                                .map(|(e, ot, no)| {
+                                    let ot = ot.unwrap_or(OrderType::OrderAscending);
                                     Ok((
                                         match e {
                                             FieldReference::Numeric(_) => internal!(
@@ -287,7 +339,8 @@ impl SqlToMirConverter {
                                             ),
                                             FieldReference::Expr(e) => e.clone(),
                                         },
-                                        ot.unwrap_or(OrderType::OrderAscending),
+                                        ot,
+                                        no.unwrap_or_else(|| NullOrder::default_for(ot)),
                                     ))
                                 })
                                 .collect::<ReadySetResult<_>>()
@@ -605,6 +658,110 @@ impl SqlToMirConverter {
         Ok(ni)
     }
 
+    /// Make the set operation nodes combining the leaves of the queries for each of the selects in
+    /// a compound select statement (given along with the operator preceding each select), and
+    /// return the last of those nodes, which will be named `name`.
+    ///
+    /// As in SQL, INTERSECT binds more tightly than UNION and EXCEPT, which are evaluated from left
+    /// to right. Consecutive UNIONs are combined into a single union node.
+    fn make_compound_select_nodes(
+        &mut self,
+        query_name: &Relation,
+        name: Relation,
+        subquery_leaves: Vec<(Option<CompoundSelectOperator>, NodeIndex)>,
+    ) -> ReadySetResult<NodeIndex> {
+        // First, group the selects into terms separated by UNION or EXCEPT, each of which is a
+        // chain of INTERSECTs
+        #[allow(clippy::type_complexity)]
+        let mut terms: Vec<(
+            Option<CompoundSelectOperator>,
+            NodeIndex,
+            Vec<(CompoundSelectOperator, NodeIndex)>,
+        )> = vec![];
+        for (op, leaf) in subquery_leaves {
+            match op {
+                Some(
+                    op @ (CompoundSelectOperator::Intersect | CompoundSelectOperator::IntersectAll),
+                ) => terms
+                    .last_mut()
+                    .ok_or_else(|| internal_err!("First select in compound select has operator"))?
+                    .2
+                    .push((op, leaf)),
+                op => terms.push((op, leaf, vec![])),
+            }
+        }
+        let num_terms = terms.len();
+
+        let mut union_parents = vec![];
+        for (i, (term_op, mut term, intersects)) in terms.into_iter().enumerate() {
+            let is_last_term = i + 1 == num_terms;
+
+            let num_intersects = intersects.len();
+            for (j, (op, leaf)) in intersects.into_iter().enumerate() {
+                let node_name = if num_terms == 1 && j + 1 == num_intersects {
+                    name.clone()
+                } else {
+                    self.generate_label(&name)
+                };
+                term = self.make_union_node(
+                    query_name,
+                    node_name,
+                    &[term, leaf],
+                    union::DuplicateMode::Intersect {
+                        all: op == CompoundSelectOperator::IntersectAll,
+                    },
+                )?;
+            }
+
+            match term_op {
+                None
+                | Some(CompoundSelectOperator::Union | CompoundSelectOperator::DistinctUnion) => {
+                    union_parents.push(term)
+                }
+                Some(op @ (CompoundSelectOperator::Except | CompoundSelectOperator::ExceptAll)) => {
+                    let lhs = match union_parents.as_slice() {
+                        [lhs] => *lhs,
+                        _ => {
+                            let union_name = self.generate_label(&name);
+                            self.make_union_node(
+                                query_name,
+                                union_name,
+                                &union_parents,
+                                union::DuplicateMode::UnionAll,
+                            )?
+                        }
+                    };
+                    let node_name = if is_last_term {
+                        name.clone()
+                    } else {
+                        self.generate_label(&name)
+                    };
+                    union_parents = vec![self.make_union_node(
+                        query_name,
+                        node_name,
+                        &[lhs, term],
+                        union::DuplicateMode::Except {
+                            all: op == CompoundSelectOperator::ExceptAll,
+                        },
+                    )?];
+                }
+                Some(CompoundSelectOperator::Intersect | CompoundSelectOperator::IntersectAll) => {
+                    internal!("INTERSECTs should have been grouped into terms")
+                }
+            }
+        }
+
+        match union_parents.as_slice() {
+            [node] => Ok(*node),
+            _ => self.make_union_node(
+                query_name,
+                name,
+                &union_parents,
+                union::DuplicateMode::UnionAll,
+            ),
+        }
+    }
+
     fn make_union_node(
         &mut self,
         query_name: &Relation,
@@ -743,6 +900,71 @@ impl SqlToMirConverter {
             };
         }
 
+        let approx_percentile = |percentile: &Literal| -> ReadySetResult<Double> {
+            let value = f64::try_from(&DfValue::try_from(percentile)?)?;
+            if !(0.0..=1.0).contains(&value) {
+                return Err(invalid_err!(
+                    "Percentile must be between 0 and 1, got {}",
+                    percentile
+                ));
+            }
+            Ok(Double {
+                value,
+                precision: u8::MAX,
+            })
+        };
+
+        // Collecting aggregates can refer to more than one column of their parent, so they get
+        // their own node type rather than going through `make_grouped_node`
+        let collect = match function {
+            ArrayAgg {
+                ref expr,
+                ref order_by,
+            } => Some((CollectKind::Array, None, &**expr, order_by)),
+            JsonAgg {
+                ref expr,
+                ref order_by,
+            } => Some((CollectKind::JsonArray, None, &**expr, order_by)),
+            JsonObjectAgg {
+                ref key,
+                ref value,
+                ref order_by,
+            } => Some((CollectKind::JsonObject, Some(&**key), &**value, order_by)),
+            _ => None,
+        };
+        if let Some((kind, key, on, order_by)) = collect {
+            let column_for = |expr: &Expr| -> ReadySetResult<Column> {
+                Ok(match expr {
+                    Expr::Column(col) => Column::from(col),
+                    expr => Column::named(
+                        projected_exprs
+                            .get(expr)
+                            .cloned()
+                            .ok_or_else(|| mk_error!(expr))?,
+                    ),
+                })
+            };
+            let node = self.add_query_node(
+                query_name.clone(),
+                MirNode::new(
+                    name,
+                    MirNodeInner::Collect {
+                        on: column_for(on)?,
+                        key: key.map(column_for).transpose()?,
+                        order_by: order_by
+                            .iter()
+                            .map(|(expr, order_type)| Ok((column_for(expr)?, *order_type)))
+                            .collect::<ReadySetResult<_>>()?,
+                        group_by: group_cols,
+                        output_column: func_col,
+                        kind,
+                    },
+                ),
+                &[parent],
+            );
+            return Ok(vec![node]);
+        }
+
         let mut out_nodes = Vec::new();
 
         let mknode = |over: Column, t: GroupedNodeType, distinct: bool| {
@@ -834,6 +1056,157 @@ impl SqlToMirConverter {
                 GroupedNodeType::Aggregation(Aggregation::Avg),
                 distinct,
             ),
+            StdDev {
+                expr: box Expr::Column(col),
+                sample,
+            } => mknode(
+                Column::from(col),
+                GroupedNodeType::Aggregation(Aggregation::StdDev { sample }),
+                false,
+            ),
+            StdDev { ref expr, sample } => mknode(
+                Column::named(
+                    projected_exprs
+                        .get(expr)
+                        .cloned()
+                        .ok_or_else(|| mk_error!(expr))?,
+                ),
+                GroupedNodeType::Aggregation(Aggregation::StdDev { sample }),
+                false,
+            ),
+            Variance {
+                expr: box Expr::Column(col),
+                sample,
+            } => mknode(
+                Column::from(col),
+                GroupedNodeType::Aggregation(Aggregation::Variance { sample }),
+                false,
+            ),
+            Variance { ref expr, sample } => mknode(
+                Column::named(
+                    projected_exprs
+                        .get(expr)
+                        .cloned()
+                        .ok_or_else(|| mk_error!(expr))?,
+                ),
+                GroupedNodeType::Aggregation(Aggregation::Variance { sample }),
+                false,
+            ),
+            BoolAnd(box Expr::Column(col)) => mknode(
+                Column::from(col),
+                GroupedNodeType::Aggregation(Aggregation::BoolAnd),
+                false,
+            ),
+            BoolAnd(ref expr) => mknode(
+                Column::named(
+                    projected_exprs
+                        .get(expr)
+                        .cloned()
+                        .ok_or_else(|| mk_error!(expr))?,
+                ),
+                GroupedNodeType::Aggregation(Aggregation::BoolAnd),
+                false,
+            ),
+            BoolOr(box Expr::Column(col)) => mknode(
+                Column::from(col),
+                GroupedNodeType::Aggregation(Aggregation::BoolOr),
+                false,
+            ),
+            BoolOr(ref expr) => mknode(
+                Column::named(
+                    projected_exprs
+                        .get(expr)
+                        .cloned()
+                        .ok_or_else(|| mk_error!(expr))?,
+                ),
+                GroupedNodeType::Aggregation(Aggregation::BoolOr),
+                false,
+            ),
+            BitAnd(box Expr::Column(col)) => mknode(
+                Column::from(col),
+                GroupedNodeType::Aggregation(Aggregation::BitAnd),
+                false,
+            ),
+            BitAnd(ref expr) => mknode(
+                Column::named(
+                    projected_exprs
+                        .get(expr)
+                        .cloned()
+                        .ok_or_else(|| mk_error!(expr))?,
+                ),
+                GroupedNodeType::Aggregation(Aggregation::BitAnd),
+                false,
+            ),
+            BitOr(box Expr::Column(col)) => mknode(
+                Column::from(col),
+                GroupedNodeType::Aggregation(Aggregation::BitOr),
+                false,
+            ),
+            BitOr(ref expr) => mknode(
+                Column::named(
+                    projected_exprs
+                        .get(expr)
+                        .cloned()
+                        .ok_or_else(|| mk_error!(expr))?,
+                ),
+                GroupedNodeType::Aggregation(Aggregation::BitOr),
+                false,
+            ),
+            BitXor(box Expr::Column(col)) => mknode(
+                Column::from(col),
+                GroupedNodeType::Aggregation(Aggregation::BitXor),
+                false,
+            ),
+            BitXor(ref expr) => mknode(
+                Column::named(
+                    projected_exprs
+                        .get(expr)
+                        .cloned()
+                        .ok_or_else(|| mk_error!(expr))?,
+                ),
+                GroupedNodeType::Aggregation(Aggregation::BitXor),
+                false,
+            ),
+            ApproxCountDistinct(box Expr::Column(col)) => mknode(
+                Column::from(col),
+                GroupedNodeType::Aggregation(Aggregation::ApproxCountDistinct),
+                false,
+            ),
+            ApproxCountDistinct(ref expr) => mknode(
+                Column::named(
+                    projected_exprs
+                        .get(expr)
+                        .cloned()
+                        .ok_or_else(|| mk_error!(expr))?,
+                ),
+                GroupedNodeType::Aggregation(Aggregation::ApproxCountDistinct),
+                false,
+            ),
+            ApproxPercentile {
+                expr: box Expr::Column(col),
+                ref percentile,
+            } => mknode(
+                Column::from(col),
+                GroupedNodeType::Aggregation(Aggregation::ApproxPercentile {
+                    percentile: approx_percentile(percentile)?,
+                }),
+                false,
+            ),
+            ApproxPercentile {
+                ref expr,
+                ref percentile,
+            } => mknode(
+                Column::named(
+                    projected_exprs
+                        .get(expr)
+                        .cloned()
+                        .ok_or_else(|| mk_error!(expr))?,
+                ),
+                GroupedNodeType::Aggregation(Aggregation::ApproxPercentile {
+                    percentile: approx_percentile(percentile)?,
+                }),
+                false,
+            ),
             // TODO(atsakiris): Support Filters for Extremum/GroupConcat
             // CH: https://app.clubhouse.io/readysettech/story/198
             Max(box Expr::Column(col)) => mknode(
@@ -917,6 +1290,42 @@ impl SqlToMirConverter {
         )
     }
 
+    /// Split the given join predicates into the equality join keys and non-equality conditions
+    /// of a join node
+    #[allow(clippy::type_complexity)]
+    fn join_columns(
+        join_predicates: &[JoinPredicate],
+    ) -> ReadySetResult<(Vec<(Column, Column)>, Vec<(Column, JoinComparison, Column)>)> {
+        let mut on = Vec::new();
+        let mut conditions = Vec::new();
+
+        for jp in join_predicates {
+            let l_col = match jp.left {
+                Expr::Column(ref f) => Column::from(f),
+                _ => unsupported!("no multi-level joins yet"),
+            };
+            let r_col = match jp.right {
+                Expr::Column(ref f) => Column::from(f),
+                _ => unsupported!("no multi-level joins yet"),
+            };
+
+            if jp.op == BinaryOperator::Equal {
+                on.push((l_col, r_col));
+            } else {
+                let op = match jp.op {
+                    BinaryOperator::Less => JoinComparison::Less,
+                    BinaryOperator::LessOrEqual => JoinComparison::LessOrEqual,
+                    BinaryOperator::Greater => JoinComparison::Greater,
+                    BinaryOperator::GreaterOrEqual => JoinComparison::GreaterOrEqual,
+                    op => unsupported!("Unsupported join condition operator {}", op),
+                };
+                conditions.push((l_col, op, r_col));
+            }
+        }
+
+        Ok((on, conditions))
+    }
+
     fn make_join_node(
         &mut self,
         query_name: &Relation,
@@ -931,44 +1340,49 @@ impl SqlToMirConverter {
         // actually needs; at a minimum, we could start with just the join colums, relying on the
         // automatic column pull-down to retrieve the remaining columns required.
         let projected_cols_left = self.mir_graph.columns(left_node);
-        let projected_cols_right = self.mir_graph.columns(right_node);
-        let mut project = projected_cols_left
-            .into_iter()
-            .chain(projected_cols_right.into_iter())
-            .collect::<Vec<Column>>();
+        let mut project = if matches!(
+            kind,
+            JoinKind::Semi | JoinKind::Anti | JoinKind::DependentSemi | JoinKind::DependentAnti
+        ) {
+            // semi-joins and anti-joins never project any columns from the right
+            projected_cols_left
+        } else {
+            let projected_cols_right = self.mir_graph.columns(right_node);
+            projected_cols_left
+                .into_iter()
+                .chain(projected_cols_right.into_iter())
+                .collect::<Vec<Column>>()
+        };
 
         // join columns need us to generate join group configs for the operator
-        let mut on = Vec::new();
-
-        for jp in join_predicates {
-            let mut l_col = match jp.left {
-                Expr::Column(ref f) => Column::from(f),
-                _ => unsupported!("no multi-level joins yet"),
-            };
-            let r_col = match jp.right {
-                Expr::Column(ref f) => Column::from(f),
-                _ => unsupported!("no multi-level joins yet"),
-            };
+        let (mut on, conditions) = Self::join_columns(join_predicates)?;
+        if !conditions.is_empty() && kind != JoinKind::Inner {
+            unsupported!("Non-equality join conditions are only supported for inner joins");
+        }
 
-            if kind == JoinKind::Inner {
+        // Outer joins keep the right-side join columns in the output, since they're NULL (or, for
+        // full outer joins, the only non-NULL copy of the join key) in rows with no match on the
+        // other side
+        if kind == JoinKind::Inner {
+            for (l_col, r_col) in on.iter_mut() {
                 // for inner joins, don't duplicate the join column in the output, but instead add
                 // aliases to the columns that represent it going forward (viz., the left-side join
                 // column)
-                l_col.add_alias(&r_col);
+                l_col.add_alias(r_col);
                 // add the alias to all instances of `l_col` in `fields` (there might be more than
                 // one if `l_col` is explicitly projected multiple times)
                 project = project
                     .into_iter()
                     .filter_map(|mut f| {
-                        if f == r_col {
+                        if f == *r_col {
                             // drop instances of right-side column
                             None
-                        } else if f == l_col {
+                        } else if f == *l_col {
                             // add alias for right-side column to any left-side column
                             // N.B.: since `l_col` is already aliased, need to check this *after*
                             // checking for equivalence with `r_col` (by now, `l_col` == `r_col` via
                             // alias), so `f == l_col` also triggers if `f` is in `l_col.aliases`.
-                            f.add_alias(&r_col);
+                            f.add_alias(r_col);
                             Some(f)
                         } else {
                             // keep unaffected columns
@@ -977,14 +1391,22 @@ impl SqlToMirConverter {
                     })
                     .collect();
             }
-
-            on.push((l_col, r_col));
         }
 
         let inner = match kind {
-            JoinKind::Inner => MirNodeInner::Join { on, project },
+            JoinKind::Inner => MirNodeInner::Join {
+                on,
+                conditions,
+                project,
+            },
             JoinKind::Left => MirNodeInner::LeftJoin { on, project },
+            JoinKind::Full => MirNodeInner::FullJoin { on, project },
             JoinKind::Dependent => MirNodeInner::DependentJoin { on, project },
+            JoinKind::Semi => MirNodeInner::SemiJoin { on, project },
+            JoinKind::Anti => MirNodeInner::AntiJoin { on, project },
+            JoinKind::DependentSemi => MirNodeInner::DependentSemiJoin { on, project },
+            JoinKind::DependentAnti => MirNodeInner::DependentAntiJoin { on, project },
+            JoinKind::DependentLeft => MirNodeInner::DependentLeftJoin { on, project },
         };
         trace!(?inner, "Added join node");
         Ok(self.add_query_node(
@@ -994,6 +1416,74 @@ impl SqlToMirConverter {
         ))
     }
 
+    /// Make an inner join between `left_node` and `right_node` whose condition is the disjunction
+    /// of the conditions of `branches`.
+    ///
+    /// This makes one join (followed by filters for any non-join predicates) per branch, and takes
+    /// the [`BagUnion`] of all of them. Since each branch projects every column of both parents,
+    /// identical rows from different branches always come from the same pair of input rows, so
+    /// the bag union removes exactly the duplicates for pairs of rows that match several branches.
+    ///
+    /// [`BagUnion`]: union::DuplicateMode::BagUnion
+    fn make_disjunctive_join_node(
+        &mut self,
+        query_name: &Relation,
+        name: Relation,
+        branches: &[JoinBranch],
+        left_node: NodeIndex,
+        right_node: NodeIndex,
+    ) -> ReadySetResult<NodeIndex> {
+        let project = self
+            .mir_graph
+            .columns(left_node)
+            .into_iter()
+            .chain(self.mir_graph.columns(right_node))
+            .collect::<Vec<Column>>();
+
+        let mut branch_nodes = Vec::with_capacity(branches.len());
+        for (i, branch) in branches.iter().enumerate() {
+            // Unlike in `make_join_node`, we don't alias the join columns of the right parent
+            // away, so that all the branches have the same columns
+            let (on, conditions) = Self::join_columns(&branch.on)?;
+            let mut node = self.add_query_node(
+                query_name.clone(),
+                MirNode::new(
+                    format!("{}_b{}", name.display_unquoted(), i).into(),
+                    MirNodeInner::Join {
+                        on,
+                        conditions,
+                        project: project.clone(),
+                    },
+                ),
+                &[left_node, right_node],
+            );
+            for (j, pred) in branch.predicates.iter().enumerate() {
+                node = self.make_predicate_nodes(
+                    query_name,
+                    format!("{}_b{}_p{}", name.display_unquoted(), i, j).into(),
+                    node,
+                    pred,
+                )?;
+            }
+            branch_nodes.push(node);
+        }
+
+        // Bag unions can only have two parents, so make a chain of them
+        let mut branch_nodes = branch_nodes.into_iter();
+        let first = branch_nodes
+            .next()
+            .ok_or_else(|| internal_err!("Disjunctive join must have at least one branch"))?;
+        branch_nodes.enumerate().try_fold(first, |acc, (i, node)| {
+            self.make_union_from_same_base(
+                query_name,
+                format!("{}_un{}", name.display_unquoted(), i).into(),
+                vec![acc, node],
+                project.clone(),
+                union::DuplicateMode::BagUnion,
+            )
+        })
+    }
+
     fn make_join_aggregates_node(
         &mut self,
         query_name: &Relation,
@@ -1069,7 +1559,7 @@ impl SqlToMirConverter {
         name: SqlIdentifier,
         mut parent: NodeIndex,
         group_by: Vec<Column>,
-        order: &Option<Vec<(Expr, OrderType)>>,
+        order: &Option<Vec<(Expr, OrderType, NullOrder)>>,
         limit: usize,
         is_topk: bool,
     ) -> ReadySetResult<Vec<NodeIndex>> {
@@ -1083,7 +1573,7 @@ impl SqlToMirConverter {
         let mut exprs_to_project = vec![];
         let order = order.as_ref().map(|oc| {
             oc.iter()
-                .map(|(expr, ot)| {
+                .map(|(expr, ot, no)| {
                     (
                         match expr {
                             Expr::Column(col) => Column::from(col),
@@ -1108,6 +1598,7 @@ impl SqlToMirConverter {
                             }
                         },
                         *ot,
+                        *no,
                     )
                 })
                 .collect()
@@ -1228,6 +1719,18 @@ impl SqlToMirConverter {
                 },
             ),
             Expr::Between { .. } => internal!("BETWEEN should have been removed earlier"),
+            Expr::Exists(subquery) if is_correlated(subquery) => {
+                self.make_exists_join_node(query_name, name, parent, subquery, false)?
+            }
+            Expr::UnaryOp {
+                op: UnaryOperator::Not,
+                rhs: box Expr::Exists(subquery),
+            } => self.make_exists_join_node(query_name, name, parent, subquery, true)?,
+            Expr::In {
+                lhs,
+                rhs: InValue::Subquery(subquery),
+                negated,
+            } => self.make_in_subquery_nodes(query_name, name, parent, lhs, subquery, *negated)?,
             Expr::Exists(subquery) => {
                 let query_graph = to_query_graph((**subquery).clone())?;
                 let subquery_leaf = self.named_query_to_mir(
@@ -1292,21 +1795,22 @@ impl SqlToMirConverter {
                     format!("{}_join", name.display_unquoted()).into(),
                     &[JoinPredicate {
                         left: Expr::Column("__exists_join_key".into()),
+                        op: BinaryOperator::Equal,
                         right: Expr::Column("__count_grp".into()),
                     }],
                     left_literal_join_key_proj,
                     gt_0_filter,
-                    if is_correlated(subquery) {
-                        JoinKind::Dependent
-                    } else {
-                        JoinKind::Inner
-                    },
+                    JoinKind::Inner,
                 )?
             }
             Expr::Call(_) => {
                 internal!("Function calls should have been handled by projection earlier")
             }
-            Expr::NestedSelect(_) => unsupported!("Nested selects not supported in filters"),
+            Expr::NestedSelect(_) => {
+                unsupported!(
+                    "Scalar subqueries are only supported in the SELECT list and WHERE clause"
+                )
+            }
             _ => self.make_filter_node(
                 query_name,
                 format!(
@@ -1323,6 +1827,310 @@ impl SqlToMirConverter {
         Ok(leaf)
     }
 
+    /// Make a node which filters `parent` to the rows for which `subquery` returns any rows (or,
+    /// if `negated` is true, for which it returns no rows), via a [semi-join][] (or
+    /// [anti-join][]) against the results of the subquery.
+    ///
+    /// If the subquery is correlated, the join is made dependent, to be turned into a regular
+    /// semi-join or anti-join on the correlated columns by the decorrelation rewrite pass.
+    ///
+    /// [semi-join]: MirNodeInner::SemiJoin
+    /// [anti-join]: MirNodeInner::AntiJoin
+    fn make_exists_join_node(
+        &mut self,
+        query_name: &Relation,
+        name: Relation,
+        parent: NodeIndex,
+        subquery: &SelectStatement,
+        negated: bool,
+    ) -> ReadySetResult<NodeIndex> {
+        let query_graph = to_query_graph(subquery.clone())?;
+        let subquery_leaf = self.named_query_to_mir(
+            query_name,
+            &query_graph,
+            &HashMap::new(),
+            LeafBehavior::Anonymous,
+        )?;
+
+        let kind = match (is_correlated(subquery), negated) {
+            (true, false) => JoinKind::DependentSemi,
+            (true, true) => JoinKind::DependentAnti,
+            (false, false) => JoinKind::Semi,
+            (false, true) => JoinKind::Anti,
+        };
+
+        // -> ⋉∃ (or ▷) on: []
+        //
+        // Any join keys get added to the join by decorrelation; if there aren't any, this becomes
+        // a cross join during lowering to dataflow
+        self.make_join_node(
+            query_name,
+            format!(
+                "{}_{}",
+                name.display_unquoted(),
+                if negated { "anti" } else { "semi" }
+            )
+            .into(),
+            &[],
+            parent,
+            subquery_leaf,
+            kind,
+        )
+    }
+
+    /// Compile the given subquery, which must project exactly one column (such as an `IN` subquery
+    /// or a scalar subquery) to MIR, returning the leaf of the subquery along with that column
+    fn single_column_subquery_to_mir(
+        &mut self,
+        query_name: &Relation,
+        query_graph: &QueryGraph,
+    ) -> ReadySetResult<(NodeIndex, Column)> {
+        let subquery_leaf = self.named_query_to_mir(
+            query_name,
+            query_graph,
+            &HashMap::new(),
+            LeafBehavior::Anonymous,
+        )?;
+        let mut columns = self.mir_graph.columns(subquery_leaf);
+        if columns.len() != 1 {
+            unsupported!("Subqueries used as values must return exactly one column");
+        }
+        Ok((subquery_leaf, columns.pop().unwrap()))
+    }
+
+    /// Make nodes which filter `parent` to the rows for which `lhs IN (subquery)` (or, if
+    /// `negated` is true, `lhs NOT IN (subquery)`) is true.
+    ///
+    /// `IN` is a [semi-join][] against the results of the subquery. `NOT IN` is an [anti-join][],
+    /// but since `lhs NOT IN (subquery)` is NULL (rather than true) if the subquery returns any
+    /// NULLs, or if `lhs` is NULL and the subquery returns any rows, it additionally requires:
+    ///
+    /// - An anti-join against the NULL rows returned by the subquery
+    /// - The bag union of a filter for `lhs IS NOT NULL` with an anti-join against *all* rows
+    ///   returned by the subquery
+    ///
+    /// Since decorrelation can't push dependent filters through nodes with more than one child,
+    /// each of these joins gets its own copy of the subquery.
+    ///
+    /// [semi-join]: MirNodeInner::SemiJoin
+    /// [anti-join]: MirNodeInner::AntiJoin
+    fn make_in_subquery_nodes(
+        &mut self,
+        query_name: &Relation,
+        name: Relation,
+        parent: NodeIndex,
+        lhs: &Expr,
+        subquery: &SelectStatement,
+        negated: bool,
+    ) -> ReadySetResult<NodeIndex> {
+        let lhs = match lhs {
+            Expr::Column(c) => c.clone(),
+            _ => unsupported!("Only columns are supported on the left-hand side of IN (subquery)"),
+        };
+        let correlated = is_correlated(subquery);
+        let query_graph = to_query_graph(subquery.clone())?;
+
+        let (subquery_leaf, subquery_col) =
+            self.single_column_subquery_to_mir(query_name, &query_graph)?;
+        let on = [JoinPredicate {
+            left: Expr::Column(lhs.clone()),
+            op: BinaryOperator::Equal,
+            right: Expr::Column(nom_sql::Column {
+                name: subquery_col.name.clone(),
+                table: subquery_col.table.clone(),
+            }),
+        }];
+
+        if !negated {
+            // -> ⋉∃ on: lhs ≡ subquery_col
+            return self.make_join_node(
+                query_name,
+                format!("{}_in", name.display_unquoted()).into(),
+                &on,
+                parent,
+                subquery_leaf,
+                if correlated {
+                    JoinKind::DependentSemi
+                } else {
+                    JoinKind::Semi
+                },
+            );
+        }
+
+        let anti_join_kind = if correlated {
+            JoinKind::DependentAnti
+        } else {
+            JoinKind::Anti
+        };
+
+        // -> ▷ on: lhs ≡ subquery_col
+        let no_match = self.make_join_node(
+            query_name,
+            format!("{}_not_in", name.display_unquoted()).into(),
+            &on,
+            parent,
+            subquery_leaf,
+            anti_join_kind,
+        )?;
+
+        // -> ▷ σ[subquery_col IS NULL](subquery)
+        let (subquery_leaf, subquery_col) =
+            self.single_column_subquery_to_mir(query_name, &query_graph)?;
+        let subquery_nulls = self.make_filter_node(
+            query_name,
+            format!("{}_not_in_nulls", name.display_unquoted()).into(),
+            subquery_leaf,
+            Expr::BinaryOp {
+                lhs: Box::new(Expr::Column(nom_sql::Column {
+                    name: subquery_col.name,
+                    table: subquery_col.table,
+                })),
+                op: BinaryOperator::Is,
+                rhs: Box::new(Expr::Literal(Literal::Null)),
+            },
+        );
+        let no_nulls = self.make_join_node(
+            query_name,
+            format!("{}_not_in_no_nulls", name.display_unquoted()).into(),
+            &[],
+            no_match,
+            subquery_nulls,
+            anti_join_kind,
+        )?;
+
+        // -> σ[lhs IS NOT NULL] ∪ (▷ subquery)
+        let lhs_not_null = self.make_filter_node(
+            query_name,
+            format!("{}_not_in_lhs_not_null", name.display_unquoted()).into(),
+            no_nulls,
+            Expr::BinaryOp {
+                lhs: Box::new(Expr::Column(lhs)),
+                op: BinaryOperator::IsNot,
+                rhs: Box::new(Expr::Literal(Literal::Null)),
+            },
+        );
+        let (subquery_leaf, _) = self.single_column_subquery_to_mir(query_name, &query_graph)?;
+        let subquery_empty = self.make_join_node(
+            query_name,
+            format!("{}_not_in_empty", name.display_unquoted()).into(),
+            &[],
+            no_nulls,
+            subquery_leaf,
+            anti_join_kind,
+        )?;
+
+        let columns = self.mir_graph.columns(no_nulls);
+        self.make_union_from_same_base(
+            query_name,
+            format!("{}_not_in_un", name.display_unquoted()).into(),
+            vec![lhs_not_null, subquery_empty],
+            columns,
+            // rows with a non-NULL lhs will be in both sides if the subquery is empty
+            union::DuplicateMode::BagUnion,
+        )
+    }
+
+    /// Make nodes which left-join the result of the given scalar subquery onto `parent`, as the
+    /// column named by [`ScalarSubquery::name`].
+    ///
+    /// If the subquery might return more than one row, the number of rows it returns is
+    /// additionally left-joined onto `parent` as the column named by
+    /// [`ScalarSubquery::row_count_column`], so that the reader can return an error if it's greater
+    /// than one. This gets its own copy of the subquery, since decorrelation can't push dependent
+    /// filters through nodes with more than one child.
+    ///
+    /// If the subquery is correlated, the joins are made dependent, to be turned into regular left
+    /// joins on the correlated columns by the decorrelation rewrite pass.
+    fn make_scalar_subquery_nodes(
+        &mut self,
+        query_name: &Relation,
+        name: Relation,
+        parent: NodeIndex,
+        subquery: &ScalarSubquery,
+    ) -> ReadySetResult<NodeIndex> {
+        let kind = if is_correlated(&subquery.statement) {
+            JoinKind::DependentLeft
+        } else {
+            JoinKind::Left
+        };
+        let query_graph = to_query_graph(subquery.statement.clone())?;
+
+        // -> π[subquery_col AS name]
+        let (subquery_leaf, subquery_col) =
+            self.single_column_subquery_to_mir(query_name, &query_graph)?;
+        let result = self.make_project_node(
+            query_name,
+            format!("{}_result", name.display_unquoted()).into(),
+            subquery_leaf,
+            vec![],
+            vec![(
+                subquery.name.clone(),
+                Expr::Column(nom_sql::Column {
+                    name: subquery_col.name,
+                    table: subquery_col.table,
+                }),
+            )],
+            vec![],
+        );
+
+        // -> ⋉ on: []
+        //
+        // Any join keys get added to the join by decorrelation; if there aren't any, this becomes
+        // a cross join during lowering to dataflow
+        let mut leaf = self.make_join_node(
+            query_name,
+            format!("{}_join", name.display_unquoted()).into(),
+            &[],
+            parent,
+            result,
+            kind,
+        )?;
+
+        if let Some(row_count_column) = &subquery.row_count_column {
+            let count_val: SqlIdentifier = format!("{}_val", row_count_column).into();
+            let count_grp: SqlIdentifier = format!("{}_grp", row_count_column).into();
+
+            // -> π[lit: 0, lit: 0]
+            let (subquery_leaf, _) =
+                self.single_column_subquery_to_mir(query_name, &query_graph)?;
+            let group_proj = self.make_project_node(
+                query_name,
+                format!("{}_prj_hlpr", name.display_unquoted()).into(),
+                subquery_leaf,
+                vec![],
+                vec![],
+                vec![
+                    (count_val.clone(), DfValue::from(0u32)),
+                    (count_grp.clone(), DfValue::from(0u32)),
+                ],
+            );
+            // -> [0, 0] for each row
+
+            // -> |0| γ[1]
+            let row_count = self.make_grouped_node(
+                query_name,
+                format!("{}_rows", name.display_unquoted()).into(),
+                Column::named(row_count_column),
+                (group_proj, Column::named(count_val)),
+                vec![Column::named(count_grp)],
+                GroupedNodeType::Aggregation(Aggregation::Count),
+            );
+            // -> [0, <count>]
+
+            // -> ⋉ on: []
+            leaf = self.make_join_node(
+                query_name,
+                format!("{}_rows_join", name.display_unquoted()).into(),
+                &[],
+                leaf,
+                row_count,
+                kind,
+            )?;
+        }
+
+        Ok(leaf)
+    }
+
     fn predicates_above_group_by<'a>(
         &mut self,
         query_name: &Relation,
@@ -1526,6 +2334,23 @@ impl SqlToMirConverter {
                 }
             };
 
+            // 1a. Left-join the results of any scalar subqueries onto the query. This has to happen
+            // before any predicates, since predicates (including those moved above grouped nodes)
+            // might refer to those results
+            for subquery in &query_graph.scalar_subqueries {
+                prev_node = self.make_scalar_subquery_nodes(
+                    query_name,
+                    format!(
+                        "q_{:x}_n{}",
+                        query_graph.signature().hash,
+                        self.mir_graph.node_count()
+                    )
+                    .into(),
+                    prev_node,
+                    subquery,
+                )?;
+            }
+
             // 2. If we're aggregating on expressions rather than directly on columns, project out
             // those expressions before the aggregate itself
             let expressions_above_grouped = make_expressions_above_grouped(
@@ -1661,9 +2486,74 @@ impl SqlToMirConverter {
                 prev_node = subquery_leaf;
             }
 
+            // 9a. Add window function nodes, which are computed after any filters but before
+            // pagination
+            for window in &query_graph.window_functions {
+                prev_node = self.add_query_node(
+                    query_name.clone(),
+                    MirNode::new(
+                        format!(
+                            "q_{:x}_n{}",
+                            query_graph.signature().hash,
+                            self.mir_graph.node_count()
+                        )
+                        .into(),
+                        MirNodeInner::Window {
+                            function: window.function,
+                            on: window.over.as_ref().map(Column::from),
+                            partition_by: window.partition_by.iter().map(Column::from).collect(),
+                            order_by: window
+                                .order_by
+                                .iter()
+                                .map(|(col, ot)| (Column::from(col), *ot))
+                                .collect(),
+                            output_column: Column::named(window.alias.clone()),
+                        },
+                    ),
+                    &[prev_node],
+                );
+            }
+
             // 10. Get the final node
             let mut final_node = prev_node;
 
+            // view key will have the offset parameter if it exists. We must filter it out of the
+            // group by of any topk or paginate node, because the column originates at that node
+            let key_columns = view_key
+                .columns
+                .iter()
+                .filter(|(col, _)| col.name != *PAGE_NUMBER_COL)
+                .map(|(col, _)| col.clone())
+                .collect::<Vec<_>>();
+
+            // DISTINCT ON keeps the first row of each group within each key, which is exactly a
+            // topk node with k = 1, applied before any LIMIT in the query
+            if let Some(DistinctOn { columns, order }) = &query_graph.distinct_on {
+                let mut group_by = key_columns.clone();
+                for col in columns.iter().map(Column::from) {
+                    if !group_by.contains(&col) {
+                        group_by.push(col);
+                    }
+                }
+
+                let distinct_on_nodes = self.make_paginate_node(
+                    query_name,
+                    format!(
+                        "q_{:x}_n{}",
+                        query_graph.signature().hash,
+                        self.mir_graph.node_count()
+                    )
+                    .into(),
+                    final_node,
+                    group_by,
+                    order,
+                    1,
+                    true,
+                )?;
+                func_nodes.extend(distinct_on_nodes.clone());
+                final_node = *distinct_on_nodes.last().unwrap();
+            }
+
             if let Some(Pagination {
                 order,
                 limit,
@@ -1671,19 +2561,6 @@ impl SqlToMirConverter {
             }) = query_graph.pagination.as_ref()
             {
                 let make_topk = offset.is_none();
-                // view key will have the offset parameter if it exists. We must filter it out
-                // of the group by, because the column originates at this node
-                let group_by = view_key
-                    .columns
-                    .iter()
-                    .filter_map(|(col, _)| {
-                        if col.name != *PAGE_NUMBER_COL {
-                            Some(col.clone())
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
 
                 // Order by expression projections and either a topk or paginate node
                 let paginate_nodes = self.make_paginate_node(
@@ -1695,9 +2572,9 @@ impl SqlToMirConverter {
                     )
                     .into(),
                     final_node,
-                    group_by,
+                    key_columns,
                     order,
-                    *limit,
+                    limit.rows_per_key(&self.config),
                     make_topk,
                 )?;
                 func_nodes.extend(paginate_nodes.clone());
@@ -1763,12 +2640,27 @@ impl SqlToMirConverter {
                 .flatten()
                 .collect();
 
+            let subquery_row_counts = query_graph
+                .scalar_subqueries
+                .iter()
+                .filter_map(|sq| sq.row_count_column.as_ref())
+                .map(Column::named)
+                .collect::<Vec<_>>();
+
             if leaf_behavior.should_make_leaf() {
                 for (column, _) in &view_key.columns {
                     if !projected_columns.contains(column) {
                         projected_columns.push(column.clone())
                     }
                 }
+
+                // The reader checks that none of the scalar subqueries returned more than one row
+                projected_columns.extend(subquery_row_counts.iter().cloned());
+            } else if !subquery_row_counts.is_empty() {
+                unsupported!(
+                    "Scalar subqueries which may return more than one row are only supported in \
+                     the outermost query"
+                );
             }
 
             if query_graph.distinct {
@@ -1868,6 +2760,16 @@ impl SqlToMirConverter {
 
                 let aggregates = if view_key.index_type != IndexType::HashMap {
                     post_lookup_aggregates(query_graph, query_name)?
+                } else if !query_graph.parameters().is_empty() {
+                    // Equality lookups can be for multiple keys at once (eg for parameterized
+                    // `IN`), so re-aggregate their results if we can. If we can't, each key's
+                    // results are returned individually, and the adapter won't look up multiple
+                    // keys at once for the query.
+                    match post_lookup_aggregates(query_graph, query_name) {
+                        Ok(aggregates) => aggregates,
+                        Err(ReadySetError::Unsupported(_)) => None,
+                        Err(e) => return Err(e),
+                    }
                 } else {
                     None
                 };
@@ -1885,12 +2787,19 @@ impl SqlToMirConverter {
                             index_type: view_key.index_type,
                             lowered_to_df: false,
                             order_by: query_graph.order.as_ref().map(|order| {
-                                order.iter().map(|(c, ot)| (Column::from(c), *ot)).collect()
+                                order
+                                    .iter()
+                                    .map(|(c, ot, no)| (Column::from(c), *ot, *no))
+                                    .collect()
                             }),
-                            limit: query_graph.pagination.as_ref().map(|p| p.limit),
+                            limit: query_graph
+                                .pagination
+                                .as_ref()
+                                .map(|p| p.limit.rows_per_key(&self.config)),
                             returned_cols: Some(returned_cols),
                             default_row: query_graph.default_row.clone(),
                             aggregates,
+                            subquery_row_counts,
                         },
                     ),
                     &[leaf_project_reorder_node],
@@ -7,8 +7,8 @@ use ::mir::DfNodeIndex;
 use ::serde::{Deserialize, Serialize};
 use nom_sql::{
     CacheInner, CompoundSelectOperator, CompoundSelectStatement, CreateTableBody,
-    FieldDefinitionExpr, Relation, SelectSpecification, SelectStatement, SqlIdentifier, SqlType,
-    TableExpr,
+    FieldDefinitionExpr, LimitClause, Relation, SelectSpecification, SelectStatement,
+    SqlIdentifier, SqlType, TableExpr,
 };
 use petgraph::graph::NodeIndex;
 use readyset_client::recipe::changelist::{AlterTypeChange, Change};
@@ -18,7 +18,10 @@ use readyset_errors::{
     internal, internal_err, invalid_err, invariant, unsupported, ReadySetError, ReadySetResult,
 };
 use readyset_sql_passes::alias_removal::TableAliasRewrite;
-use readyset_sql_passes::{AliasRemoval, DetectUnsupportedPlaceholders, Rewrite, RewriteContext};
+use readyset_sql_passes::{
+    unroll_recursive_cte, AliasRemoval, DetectUnsupportedPlaceholders, Rewrite, RewriteContext,
+    UnrolledRecursiveCte,
+};
 use readyset_util::redacted::Sensitive;
 use tracing::{debug, error, info, trace, warn};
 use vec1::Vec1;
@@ -120,6 +123,12 @@ pub(crate) struct SqlIncorporator {
 
     /// Whether or to treat failed writes to base tables as no-ops
     permissive_writes: bool,
+
+    /// Map from the name of a recursive common table expression currently being compiled to the
+    /// MIR node for the level of recursion most recently compiled for it. See
+    /// [`Self::recursive_cte_to_mir`].
+    #[serde(skip)]
+    recursive_cte_levels: HashMap<SqlIdentifier, MirNodeIndex>,
 }
 
 impl SqlIncorporator {
@@ -877,16 +886,19 @@ impl SqlIncorporator {
         mig: &mut Migration<'_>,
     ) -> ReadySetResult<MirNodeIndex> {
         let mut subqueries = Vec::with_capacity(query.selects.len());
-        for (_, stmt) in &mut query.selects {
+        for (op, stmt) in &mut query.selects {
             let mut tables = invalidating_tables.is_some().then(Vec::new);
-            subqueries.push(self.select_query_to_mir(
-                query_name.clone(),
-                stmt,
-                search_path,
-                tables.as_mut(),
-                LeafBehavior::Anonymous,
-                mig,
-            )?);
+            subqueries.push((
+                op.clone(),
+                self.select_query_to_mir(
+                    query_name.clone(),
+                    stmt,
+                    search_path,
+                    tables.as_mut(),
+                    LeafBehavior::Anonymous,
+                    mig,
+                )?,
+            ));
             if let Some(ts) = tables {
                 if let Some(its) = invalidating_tables.as_mut() {
                     its.extend(ts);
@@ -897,13 +909,70 @@ impl SqlIncorporator {
         self.mir_converter.compound_query_to_mir(
             &query_name,
             subqueries,
-            CompoundSelectOperator::Union,
             &query.order,
             &query.limit_clause,
             leaf_behavior,
         )
     }
 
+    /// Compile the recursive common table expression named `name`, which has been unrolled by
+    /// [`unroll_recursive_cte`], to MIR, returning the index of the MIR node for the union of all
+    /// of its levels.
+    ///
+    /// Each level of recursion is compiled separately, with references to the CTE in its recursive
+    /// term bound to the MIR node for the previous level.
+    fn recursive_cte_to_mir(
+        &mut self,
+        query_name: &Relation,
+        name: SqlIdentifier,
+        unrolled: UnrolledRecursiveCte,
+        search_path: &[SqlIdentifier],
+        mig: &mut Migration<'_>,
+    ) -> ReadySetResult<MirNodeIndex> {
+        let UnrolledRecursiveCte {
+            mut base,
+            recursive_term,
+            depth,
+        } = unrolled;
+
+        let mut previous_level = self.select_query_to_mir(
+            query_name.clone(),
+            &mut base,
+            search_path,
+            None,
+            LeafBehavior::Anonymous,
+            mig,
+        )?;
+        let mut levels = vec![(None, previous_level)];
+        for _ in 0..depth {
+            let shadowed = self
+                .recursive_cte_levels
+                .insert(name.clone(), previous_level);
+            let res = self.select_query_to_mir(
+                query_name.clone(),
+                &mut recursive_term.clone(),
+                search_path,
+                None,
+                LeafBehavior::Anonymous,
+                mig,
+            );
+            match shadowed {
+                Some(level) => self.recursive_cte_levels.insert(name.clone(), level),
+                None => self.recursive_cte_levels.remove(&name),
+            };
+            previous_level = res?;
+            levels.push((Some(CompoundSelectOperator::Union), previous_level));
+        }
+
+        self.mir_converter.compound_query_to_mir(
+            query_name,
+            levels,
+            &None,
+            &LimitClause::default(),
+            LeafBehavior::Anonymous,
+        )
+    }
+
     /// Add a new SelectStatement to the given migration, returning the index of the leaf MIR node
     /// that was added
     fn select_query_to_mir(
@@ -1017,9 +1086,18 @@ impl SqlIncorporator {
                     )?;
                     anon_queries.insert(to_view, subquery_leaf);
                 }
+                TableAliasRewrite::Cte {
+                    from,
+                    to_view,
+                    recursive_term: None,
+                    ..
+                } if self.recursive_cte_levels.contains_key(&from) => {
+                    anon_queries.insert(to_view, self.recursive_cte_levels[&from]);
+                }
                 TableAliasRewrite::Cte {
                     to_view,
                     mut for_statement,
+                    recursive_term: None,
                     ..
                 } => {
                     let subquery_leaf = self.select_query_to_mir(
@@ -1032,6 +1110,26 @@ impl SqlIncorporator {
                     )?;
                     anon_queries.insert(to_view, subquery_leaf);
                 }
+                TableAliasRewrite::Cte {
+                    from,
+                    to_view,
+                    for_statement,
+                    recursive_term: Some((operator, recursive_term)),
+                } => {
+                    if !self.mir_converter.config.allow_recursive_ctes {
+                        unsupported!("Recursive CTEs are not enabled");
+                    }
+                    let unrolled = unroll_recursive_cte(
+                        &from,
+                        *for_statement,
+                        operator,
+                        *recursive_term,
+                        self.mir_converter.config.max_recursive_cte_depth,
+                    )?;
+                    let subquery_leaf =
+                        self.recursive_cte_to_mir(query_name, from, unrolled, search_path, mig)?;
+                    anon_queries.insert(to_view, subquery_leaf);
+                }
                 TableAliasRewrite::Table { .. } => {}
             }
         }
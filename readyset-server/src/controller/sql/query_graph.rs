@@ -7,19 +7,22 @@ use std::vec::Vec;
 use std::{iter, mem};
 
 use common::{DfValue, IndexType};
+use dataflow::ops::window::WindowFunction;
 use nom_sql::analysis::visit_mut::{walk_expr, VisitorMut};
 use nom_sql::analysis::ReferredColumns;
 use nom_sql::{
     BinaryOperator, Column, Expr, FieldDefinitionExpr, FieldReference, FunctionExpr, InValue,
-    ItemPlaceholder, JoinConstraint, JoinOperator, JoinRightSide, LimitClause, Literal, OrderType,
-    Relation, SelectStatement, SqlIdentifier, TableExpr, TableExprInner,
+    ItemPlaceholder, JoinConstraint, JoinOperator, JoinRightSide, LimitClause, Literal, NullOrder,
+    OrderType, Relation, SelectStatement, SqlIdentifier, TableExpr, TableExprInner,
 };
 use readyset_client::{PlaceholderIdx, ViewPlaceholder};
 use readyset_errors::{
     internal, invalid, invalid_err, invariant, invariant_eq, no_table_for_col, unsupported,
     unsupported_err, ReadySetResult,
 };
-use readyset_sql_passes::{is_aggregate, is_correlated, is_predicate, map_aggregates, LogicalOp};
+use readyset_sql_passes::{
+    contains_aggregate, is_aggregate, is_correlated, is_predicate, map_aggregates, LogicalOp,
+};
 use serde::{Deserialize, Serialize};
 
 use super::mir::{self, PAGE_NUMBER_COL};
@@ -166,13 +169,26 @@ pub struct JoinRef {
     pub dst: Relation,
 }
 
-/// An equality predicate on two expressions, used as the key for a join
+/// A comparison between two expressions, used as a join condition.
+///
+/// Equality predicates are used as the key for the join, and ordering comparisons (`<`, `<=`, `>`
+/// and `>=`) as additional non-equality conditions.
 #[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct JoinPredicate {
     pub left: Expr,
+    pub op: BinaryOperator,
     pub right: Expr,
 }
 
+impl JoinPredicate {
+    /// Swap the left and right sides of this predicate, flipping the comparison so that the
+    /// predicate keeps the same meaning
+    fn swap_sides(&mut self) {
+        mem::swap(&mut self.left, &mut self.right);
+        self.op = self.op.flip_ordering_comparison().unwrap_or_else(|op| op);
+    }
+}
+
 /// An individual column on which a query is parameterized
 #[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Parameter {
@@ -194,17 +210,87 @@ pub struct QueryGraphNode {
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum QueryGraphEdge {
-    Join { on: Vec<JoinPredicate> },
-    LeftJoin { on: Vec<JoinPredicate> },
+    Join {
+        on: Vec<JoinPredicate>,
+    },
+    LeftJoin {
+        on: Vec<JoinPredicate>,
+    },
+    FullJoin {
+        on: Vec<JoinPredicate>,
+    },
+    /// An inner join whose condition is a disjunction (`OR`) - a pair of rows is joined if the
+    /// condition of any of the `branches` holds for it.
+    DisjunctiveJoin {
+        branches: Vec<JoinBranch>,
+    },
+}
+
+/// One branch of a [`QueryGraphEdge::DisjunctiveJoin`], which is lowered to an inner join on `on`
+/// followed by a filter on `predicates`
+#[derive(Clone, Debug, Default, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JoinBranch {
+    /// Join predicates, all of which must hold for a pair of rows to be joined by this branch
+    pub on: Vec<JoinPredicate>,
+    /// Other predicates on the joined rows, all of which must also hold
+    pub predicates: Vec<Expr>,
+}
+
+/// The number of rows kept per key for a query with a `LIMIT` clause
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Limit {
+    /// The query has a constant `LIMIT`. If the query also has a constant `OFFSET`, this is the
+    /// sum of the two, and the offset is applied post-lookup.
+    Constant(usize),
+    /// The query has a parametrized `LIMIT` (eg `LIMIT ?`), or a parametrized `OFFSET` along with
+    /// range parameters. The concrete limit, and any `OFFSET`, are applied post-lookup, so
+    /// [`Config::max_parametrized_limit`] rows are kept per key.
+    ///
+    /// [`Config::max_parametrized_limit`]: mir::Config::max_parametrized_limit
+    Parametrized,
+}
+
+impl Limit {
+    /// Returns the number of rows to keep per key for this limit, given the configuration for
+    /// converting SQL to MIR
+    pub fn rows_per_key(self, config: &mir::Config) -> usize {
+        match self {
+            Limit::Constant(limit) => limit,
+            Limit::Parametrized => config.max_parametrized_limit,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Pagination {
-    pub order: Option<Vec<(Expr, OrderType)>>,
-    pub limit: usize,
+    pub order: Option<Vec<(Expr, OrderType, NullOrder)>>,
+    pub limit: Limit,
     pub offset: Option<ViewPlaceholder>,
 }
 
+/// The `DISTINCT ON` clause of a query, which keeps only the first row (according to `order`) of
+/// each group of rows with the same values for `columns`
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DistinctOn {
+    pub columns: Vec<Column>,
+    pub order: Option<Vec<(Expr, OrderType, NullOrder)>>,
+}
+
+/// A window function projected by the query, eg `ROW_NUMBER() OVER (PARTITION BY x ORDER BY y)`
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WindowColumn {
+    /// The window function to compute
+    pub function: WindowFunction,
+    /// The column the function is computed over, if it takes an argument
+    pub over: Option<Column>,
+    /// The columns the rows are partitioned by
+    pub partition_by: Vec<Column>,
+    /// The ordering of rows within each partition
+    pub order_by: Vec<(Column, OrderType)>,
+    /// The name of the column holding the result of the window function
+    pub alias: SqlIdentifier,
+}
+
 /// Description of the lookup key for a view
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ViewKey {
@@ -216,6 +302,23 @@ pub struct ViewKey {
     pub index_type: IndexType,
 }
 
+/// A subquery used as a value, such as `(SELECT max(t2.y) FROM t2 WHERE t2.k = t1.k)`, in the
+/// fields list or WHERE clause of a query.
+///
+/// When building the query graph, each scalar subquery is replaced with a reference to the column
+/// named by [`name`](Self::name), and during compilation to MIR the results of the subquery are
+/// left-joined onto the rest of the query as that column.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ScalarSubquery {
+    /// The name of the column for the result of the subquery
+    pub name: SqlIdentifier,
+    /// The subquery itself
+    pub statement: SelectStatement,
+    /// If the subquery might return more than one row, the name of the column for the number of
+    /// rows it returns, which must be checked by the reader
+    pub row_count_column: Option<SqlIdentifier>,
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 // NOTE: Keep in mind this struct has a custom Hash impl - when changing it, remember to update that
 // as well!
@@ -228,14 +331,22 @@ pub struct QueryGraph {
     pub edges: HashMap<(Relation, Relation), QueryGraphEdge>,
     /// Whether the query has a `DISTINCT` in the `SELECT` clause
     pub distinct: bool,
+    /// The `DISTINCT ON` clause of the query, if any
+    pub distinct_on: Option<DistinctOn>,
     /// Aggregates in the query, represented as a map from the aggregate function to the alias for
     /// that aggregate function
     ///
     /// If a single aggregate is projected as multiple aliases, only one will appear in this map,
     /// but both will appear in `self.columns` as [`OutputColumn::Data`] referencing that alias
     pub aggregates: HashMap<FunctionExpr, SqlIdentifier>,
+    /// Window functions in the query, in the order they're projected
+    pub window_functions: Vec<WindowColumn>,
     /// Set of columns that appear in the GROUP BY clause
     pub group_by: HashSet<Column>,
+    /// Expressions other than column references that appear in the GROUP BY clause, which must
+    /// be projected before the grouped operators. Each of these also appears in `group_by` as a
+    /// table-less column with the same name.
+    pub group_by_exprs: Vec<ExprColumn>,
     /// Final set of projected columns in this query; may include literals in addition to the
     /// columns reflected in individual relations' `QueryGraphNode` structures.
     pub columns: Vec<OutputColumn>,
@@ -252,12 +363,17 @@ pub struct QueryGraph {
     pub global_predicates: Vec<Expr>,
     /// HAVING predicates (like global predicates, but applied after aggregate functions)
     pub having_predicates: Vec<Expr>,
-    /// The list of columns and directionsj that the query is ordering by, if any
-    pub order: Option<Vec<(Column, OrderType)>>,
+    /// The list of columns, directions, and placements of NULLs that the query is ordering by, if
+    /// any
+    pub order: Option<Vec<(Column, OrderType, NullOrder)>>,
     /// The pagination (order, limit, offset) for the query, if any
     pub pagination: Option<Pagination>,
     /// True if the query is correlated (is a subquery that refers to columns in an outer query)
     pub is_correlated: bool,
+    /// Scalar subqueries in the fields list or WHERE clause of the query, in the order they
+    /// appear. These have been replaced in `columns`, `fields`, and `global_predicates` with
+    /// references to the columns for their results
+    pub scalar_subqueries: Vec<ScalarSubquery>,
 }
 
 impl QueryGraph {
@@ -388,7 +504,7 @@ impl QueryGraph {
 
             if let Some(offset) = offset {
                 if index_type == Some(IndexType::BTreeMap) {
-                    unsupported!("ReadySet does not support Pagination and range queries")
+                    internal!("Paginated range queries should apply their OFFSET post-lookup")
                 } else {
                     columns.push((mir::Column::named(PAGE_NUMBER_COL.clone()), offset));
                 }
@@ -418,16 +534,19 @@ impl Hash for QueryGraph {
         edges.hash(state);
 
         self.distinct.hash(state);
+        self.distinct_on.hash(state);
 
         let mut group_by = self.group_by.iter().collect::<Vec<_>>();
         group_by.sort();
         group_by.hash(state);
+        self.group_by_exprs.hash(state);
 
         let mut aggregates = self.aggregates.iter().collect::<Vec<_>>();
         aggregates.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
         aggregates.hash(state);
 
         // these fields are Vecs, so already ordered
+        self.window_functions.hash(state);
         self.columns.hash(state);
         self.fields.hash(state);
         self.default_row.hash(state);
@@ -437,9 +556,77 @@ impl Hash for QueryGraph {
         self.order.hash(state);
         self.pagination.hash(state);
         self.is_correlated.hash(state);
+        self.scalar_subqueries.hash(state);
     }
 }
 
+/// Convert the given window function expression into a [`WindowColumn`] named `alias`, returning
+/// an unsupported error if the window function or any of its arguments can't be computed by a
+/// window node
+fn window_column(
+    function: &FunctionExpr,
+    partition_by: &[Expr],
+    order_by: &[(Expr, OrderType)],
+    alias: SqlIdentifier,
+) -> ReadySetResult<WindowColumn> {
+    let column_arg = |expr: &Expr| -> ReadySetResult<Column> {
+        match expr {
+            Expr::Column(c) => Ok(c.clone()),
+            _ => unsupported!(
+                "Only column references are currently supported as arguments to window functions"
+            ),
+        }
+    };
+
+    let (function, over) = match function {
+        FunctionExpr::Call { name, arguments } if arguments.is_empty() => {
+            let function = match name.to_ascii_lowercase().as_str() {
+                "row_number" => WindowFunction::RowNumber,
+                "rank" => WindowFunction::Rank,
+                "dense_rank" => WindowFunction::DenseRank,
+                _ => unsupported!("Unsupported window function {}", name),
+            };
+            (function, None)
+        }
+        FunctionExpr::CountStar => (WindowFunction::Count, None),
+        FunctionExpr::Count {
+            expr,
+            distinct: false,
+        } => (WindowFunction::Count, Some(column_arg(expr)?)),
+        FunctionExpr::Sum {
+            expr,
+            distinct: false,
+        } => (WindowFunction::Sum, Some(column_arg(expr)?)),
+        // FIXME(ENG-2499): Use correct dialect.
+        f => unsupported!(
+            "Unsupported window function {}",
+            f.display(nom_sql::Dialect::MySQL)
+        ),
+    };
+
+    Ok(WindowColumn {
+        function,
+        over,
+        partition_by: partition_by
+            .iter()
+            .map(|expr| match expr {
+                Expr::Column(c) => Ok(c.clone()),
+                _ => unsupported!("Only column references are currently supported in PARTITION BY"),
+            })
+            .collect::<ReadySetResult<_>>()?,
+        order_by: order_by
+            .iter()
+            .map(|(expr, ot)| match expr {
+                Expr::Column(c) => Ok((c.clone(), *ot)),
+                _ => unsupported!(
+                    "Only column references are currently supported in window ORDER BY"
+                ),
+            })
+            .collect::<ReadySetResult<_>>()?,
+        alias,
+    })
+}
+
 /// Splits top level conjunctions into multiple predicates
 fn split_conjunctions<'a, T>(ces: T) -> Vec<Expr>
 where
@@ -469,21 +656,22 @@ where
 //    nodes, since we cannot instantiate the parameters inside the data flow graph (except for
 //    non-materialized nodes).
 // 2. Extract local predicates
-// 3. Extract join predicates
+// 3. Extract join predicates, and disjunctions (ORs) containing join predicates
 // 4. Collect remaining predicates as global predicates
 fn classify_conditionals(
     ce: &Expr,
     inner_join_rels: &HashSet<Relation>,
     local: &mut HashMap<Relation, Vec<Expr>>,
     join: &mut Vec<JoinPredicate>,
+    disjunctive_join: &mut Vec<Expr>,
     global: &mut Vec<Expr>,
     params: &mut Vec<Parameter>,
 ) -> ReadySetResult<()> {
     // Handling OR and AND expressions requires some care as there are some corner cases.
     //    a) we don't support OR expressions with predicates with placeholder parameters,
     //       because these expressions are meaningless in the Soup context.
-    //    b) we don't support OR expressions with join predicates because they are weird and
-    //       too hard.
+    //    b) OR expressions with join predicates are only supported between exactly two tables,
+    //       in which case they're lowered to a union of joins.
     //    c) we don't support OR expressions between different tables (e.g table1.x = 1 OR
     //       table2.y= 42). this is a global predicate according to finkelstein algorithm
     //       and we don't support these yet.
@@ -500,6 +688,7 @@ fn classify_conditionals(
                 //     remain a local predicate) or over several (so it must be a global predicate)
                 let mut new_params = Vec::new();
                 let mut new_join = Vec::new();
+                let mut new_disjunctive_join = Vec::new();
                 let mut new_local = HashMap::new();
                 let mut new_global = Vec::new();
 
@@ -508,6 +697,7 @@ fn classify_conditionals(
                     inner_join_rels,
                     &mut new_local,
                     &mut new_join,
+                    &mut new_disjunctive_join,
                     &mut new_global,
                     &mut new_params,
                 )?;
@@ -516,6 +706,7 @@ fn classify_conditionals(
                     inner_join_rels,
                     &mut new_local,
                     &mut new_join,
+                    &mut new_disjunctive_join,
                     &mut new_global,
                     &mut new_params,
                 )?;
@@ -548,17 +739,31 @@ fn classify_conditionals(
                         // one side of the AND might be a global predicate, so we need to keep
                         // new_global around
                         global.extend(new_global);
+                        join.extend(new_join);
+                        disjunctive_join.extend(new_disjunctive_join);
                     }
                     LogicalOp::Or => {
-                        if !new_join.is_empty() {
-                            unsupported!("can't handle OR expressions between JOIN predicates")
-                        }
                         if !new_params.is_empty() {
                             unsupported!(
                                 "can't handle OR expressions between query parameter predicates"
                             );
                         }
-                        if new_local.keys().len() == 1 && new_global.is_empty() {
+                        if !new_join.is_empty() || !new_disjunctive_join.is_empty() {
+                            // OR involving a join predicate => the whole expression is the
+                            // condition of a join between the two tables it refers to
+                            let tables = ce
+                                .referred_columns()
+                                .map(|col| col.table.as_ref())
+                                .collect::<Option<HashSet<_>>>()
+                                .ok_or_else(no_table_for_col)?;
+                            if tables.len() != 2 {
+                                unsupported!(
+                                    "can't handle OR expressions between JOIN predicates on more \
+                                     than two tables"
+                                )
+                            }
+                            disjunctive_join.push(ce.clone());
+                        } else if new_local.keys().len() == 1 && new_global.is_empty() {
                             // OR over a single table => local predicate
                             // just checked that new_local has one entry
                             #[allow(clippy::unwrap_used)]
@@ -582,7 +787,6 @@ fn classify_conditionals(
                     }
                 }
 
-                join.extend(new_join);
                 params.extend(new_params);
             } else if is_predicate(op) {
                 // atomic selection predicate
@@ -601,20 +805,21 @@ fn classify_conditionals(
                             {
                                 // both columns' tables appear in table list and the tables are
                                 // different --> inner join predicate appearing in the WHERE clause
-                                if *op == BinaryOperator::Equal {
-                                    // equi-join between two tables
+                                if *op == BinaryOperator::Equal || op.is_ordering_comparison() {
+                                    // equi-join or theta join between two tables
                                     let mut jp = JoinPredicate {
                                         left: (**lhs).clone(),
+                                        op: *op,
                                         right: (**rhs).clone(),
                                     };
                                     if let Ordering::Less =
                                         rf.table.as_ref().cmp(&lf.table.as_ref())
                                     {
-                                        mem::swap(&mut jp.left, &mut jp.right);
+                                        jp.swap_sides();
                                     }
                                     join.push(jp);
                                 } else {
-                                    // non-equi-join?
+                                    // some other comparison that can't be evaluated by the join
                                     global.push(ce.clone());
                                 }
                             }
@@ -660,7 +865,7 @@ fn classify_conditionals(
                         }
                     }
                     Expr::NestedSelect(_) => {
-                        unsupported!("nested SELECTs are unsupported")
+                        internal!("Scalar subqueries should have been extracted earlier")
                     }
                     Expr::Call(_)
                     | Expr::BinaryOp { .. }
@@ -672,8 +877,10 @@ fn classify_conditionals(
                     | Expr::Exists(_)
                     | Expr::Between { .. }
                     | Expr::Cast { .. }
+                    | Expr::Interval { .. }
                     | Expr::In { .. }
-                    | Expr::Variable(_) => {
+                    | Expr::Variable(_)
+                    | Expr::WindowFunction { .. } => {
                         unsupported!(
                             "Unsupported right-hand side of condition expression: {}",
                             // FIXME(ENG-2499): Use correct dialect.
@@ -711,7 +918,11 @@ fn classify_conditionals(
                 }
             }
         }
-        Expr::Exists(_) => {
+        Expr::Exists(_)
+        | Expr::In {
+            rhs: InValue::Subquery(..),
+            ..
+        } => {
             // TODO(grfn): Look into the query for correlated references to see if it's actually a
             // local predicate in disguise
             global.push(ce.clone())
@@ -719,10 +930,6 @@ fn classify_conditionals(
         Expr::Between { .. } => {
             internal!("Between should have been removed earlier")
         }
-        Expr::In {
-            rhs: InValue::Subquery(..),
-            ..
-        } => unsupported!("IN with subqueries is not yet supported"),
         Expr::Call(_)
         | Expr::Literal(_)
         | Expr::UnaryOp { .. }
@@ -733,23 +940,140 @@ fn classify_conditionals(
         | Expr::Column(_)
         | Expr::NestedSelect(_)
         | Expr::Cast { .. }
+        | Expr::Interval { .. }
         | Expr::Array(_)
         | Expr::Variable(_) => global.push(ce.clone()),
+        Expr::WindowFunction { .. } => {
+            unsupported!("Window functions are not supported in filter conditions")
+        }
     }
     Ok(())
 }
 
-/// Convert the given `Expr`, which should be a set of AND-ed together direct
-/// comparison predicates, into a list of predicate expressions
-fn collect_join_predicates(cond: Expr, out: &mut Vec<JoinPredicate>) -> ReadySetResult<()> {
+/// The maximum number of branches a join condition containing `OR` may be split into. Each branch
+/// becomes a separate join feeding into a union, and every `OR` under an `AND` doubles the number
+/// of branches, so this bounds the size of the resulting graph.
+const MAX_JOIN_BRANCHES: usize = 16;
+
+/// Returns true if the given condition has an `OR` at the top level (possibly nested in `AND`s)
+fn has_disjunction(cond: &Expr) -> bool {
     match cond {
         Expr::BinaryOp {
-            op: BinaryOperator::Equal,
+            op: BinaryOperator::Or,
+            ..
+        } => true,
+        Expr::BinaryOp {
             lhs,
+            op: BinaryOperator::And,
+            rhs,
+        } => has_disjunction(lhs) || has_disjunction(rhs),
+        _ => false,
+    }
+}
+
+/// Convert the given condition to disjunctive normal form, returning a list of disjuncts, each of
+/// which is a list of conjuncts. Anything other than `AND` and `OR` is treated as an atom.
+///
+/// Returns an error if the result would have more than [`MAX_JOIN_BRANCHES`] disjuncts.
+fn disjunctive_normal_form(cond: &Expr) -> ReadySetResult<Vec<Vec<Expr>>> {
+    let disjuncts = match cond {
+        Expr::BinaryOp {
+            lhs,
+            op: BinaryOperator::Or,
+            rhs,
+        } => {
+            let mut disjuncts = disjunctive_normal_form(lhs)?;
+            disjuncts.extend(disjunctive_normal_form(rhs)?);
+            disjuncts
+        }
+        Expr::BinaryOp {
+            lhs,
+            op: BinaryOperator::And,
             rhs,
         } => {
+            let lhs = disjunctive_normal_form(lhs)?;
+            let rhs = disjunctive_normal_form(rhs)?;
+            check_join_branch_count(lhs.len().saturating_mul(rhs.len()))?;
+            lhs.into_iter()
+                .flat_map(|l| {
+                    rhs.iter()
+                        .map(move |r| l.iter().chain(r).cloned().collect())
+                })
+                .collect()
+        }
+        _ => vec![vec![cond.clone()]],
+    };
+    check_join_branch_count(disjuncts.len())?;
+    Ok(disjuncts)
+}
+
+fn check_join_branch_count(branches: usize) -> ReadySetResult<()> {
+    if branches > MAX_JOIN_BRANCHES {
+        unsupported!(
+            "Join condition expands to {branches} branches, more than the maximum of \
+             {MAX_JOIN_BRANCHES}"
+        );
+    }
+    Ok(())
+}
+
+/// Split a join condition containing `OR` between `left_table` and `right_table` into the branches
+/// of a [`QueryGraphEdge::DisjunctiveJoin`].
+///
+/// Comparisons between a column in each of the two tables become join predicates of their branch
+/// (oriented so that the column from `left_table` is on the left), and everything else becomes a
+/// predicate on the joined rows.
+fn collect_join_branches(
+    cond: &Expr,
+    left_table: &Relation,
+    right_table: &Relation,
+) -> ReadySetResult<Vec<JoinBranch>> {
+    Ok(disjunctive_normal_form(cond)?
+        .into_iter()
+        .map(|conjuncts| {
+            let mut branch = JoinBranch::default();
+            for expr in conjuncts {
+                if let Expr::BinaryOp { lhs, op, rhs } = &expr {
+                    if let (Expr::Column(l), Expr::Column(r)) = (lhs.as_ref(), rhs.as_ref()) {
+                        if *op == BinaryOperator::Equal || op.is_ordering_comparison() {
+                            let mut jp = JoinPredicate {
+                                left: (**lhs).clone(),
+                                op: *op,
+                                right: (**rhs).clone(),
+                            };
+                            if l.table.as_ref() == Some(left_table)
+                                && r.table.as_ref() == Some(right_table)
+                            {
+                                branch.on.push(jp);
+                                continue;
+                            }
+                            if l.table.as_ref() == Some(right_table)
+                                && r.table.as_ref() == Some(left_table)
+                            {
+                                jp.swap_sides();
+                                branch.on.push(jp);
+                                continue;
+                            }
+                        }
+                    }
+                }
+                branch.predicates.push(expr);
+            }
+            branch
+        })
+        .collect())
+}
+
+/// Convert the given `Expr`, which should be a set of AND-ed together direct
+/// comparison predicates, into a list of predicate expressions
+fn collect_join_predicates(cond: Expr, out: &mut Vec<JoinPredicate>) -> ReadySetResult<()> {
+    match cond {
+        Expr::BinaryOp { op, lhs, rhs }
+            if op == BinaryOperator::Equal || op.is_ordering_comparison() =>
+        {
             out.push(JoinPredicate {
                 left: *lhs,
+                op,
                 right: *rhs,
             });
             Ok(())
@@ -824,11 +1148,179 @@ fn extract_having_aggregates(
     having_predicates
 }
 
-/// Convert limit and offset fields to an optional constant numeric limit and optional placeholder
-/// for the offset
+/// Replaces all scalar subqueries in `expr` (but not those inside other subqueries) with references
+/// to the columns for their results, adding the extracted subqueries to `scalar_subqueries`.
+///
+/// Since the results of scalar subqueries are left-joined onto the rest of the query, subqueries
+/// which return no rows will have a result of NULL. That's correct for every aggregate other than
+/// `COUNT`, so `COUNT` subqueries are replaced with `coalesce(<column>, 0)` instead.
+fn extract_scalar_subqueries(expr: &mut Expr, scalar_subqueries: &mut Vec<ScalarSubquery>) {
+    struct ScalarSubqueryFinder<'a> {
+        result: &'a mut Vec<ScalarSubquery>,
+    }
+
+    impl<'ast, 'a> VisitorMut<'ast> for ScalarSubqueryFinder<'a> {
+        type Error = !;
+
+        fn visit_expr(&mut self, expr: &'ast mut Expr) -> Result<(), Self::Error> {
+            if matches!(expr, Expr::NestedSelect(_)) {
+                let name: SqlIdentifier = format!("__scalar_subquery_{}", self.result.len()).into();
+                let col_expr = Expr::Column(nom_sql::Column {
+                    name: name.clone(),
+                    table: None,
+                });
+                let subquery_expr = mem::replace(expr, col_expr);
+                let Expr::NestedSelect(statement) = subquery_expr else { unreachable!("Checked matches above") };
+
+                // Aggregating without a GROUP BY always returns exactly one row
+                let returns_one_row =
+                    statement.group_by.is_none() && statement.contains_aggregate_select();
+                if returns_one_row
+                    && matches!(
+                        statement.fields.as_slice(),
+                        [FieldDefinitionExpr::Expr {
+                            expr: Expr::Call(FunctionExpr::Count { .. } | FunctionExpr::CountStar),
+                            ..
+                        }]
+                    )
+                {
+                    let col_expr = mem::replace(expr, Expr::Literal(Literal::Null));
+                    *expr = Expr::Call(FunctionExpr::Call {
+                        name: "coalesce".into(),
+                        arguments: vec![col_expr, Expr::Literal(Literal::Integer(0))],
+                    });
+                }
+
+                self.result.push(ScalarSubquery {
+                    row_count_column: (!returns_one_row).then(|| format!("{}_rows", name).into()),
+                    name,
+                    statement: *statement,
+                });
+                Ok(())
+            } else {
+                walk_expr(self, expr)
+            }
+        }
+
+        fn visit_select_statement(
+            &mut self,
+            _: &'ast mut SelectStatement,
+        ) -> Result<(), Self::Error> {
+            // Scalar subqueries inside of other subqueries get extracted when we build the query
+            // graph for those subqueries
+            Ok(())
+        }
+    }
+
+    let _ = ScalarSubqueryFinder {
+        result: scalar_subqueries,
+    }
+    .visit_expr(expr);
+}
+
+/// Replaces every expression other than a column reference in the GROUP BY clause of `stmt` with a
+/// reference to a (table-less) column named after that expression, returning the replaced
+/// expressions so they can be projected before the grouped operators.
+///
+/// Occurrences of those expressions in the fields list and the HAVING clause (other than inside
+/// aggregates, which are computed below the grouped operators) are replaced with the same column
+/// references, since the columns the expressions are computed from don't make it through the
+/// grouped operators.
+fn extract_group_by_expressions(stmt: &mut SelectStatement) -> ReadySetResult<Vec<ExprColumn>> {
+    struct GroupByExprReplacer<'a> {
+        exprs: &'a [ExprColumn],
+        replaced: bool,
+    }
+
+    impl<'ast, 'a> VisitorMut<'ast> for GroupByExprReplacer<'a> {
+        type Error = !;
+
+        fn visit_expr(&mut self, expr: &'ast mut Expr) -> Result<(), Self::Error> {
+            if let Some(ec) = self.exprs.iter().find(|ec| ec.expression == *expr) {
+                *expr = Expr::Column(Column {
+                    name: ec.name.clone(),
+                    table: None,
+                });
+                self.replaced = true;
+                Ok(())
+            } else if matches!(expr, Expr::Call(f) if is_aggregate(f)) {
+                Ok(())
+            } else {
+                walk_expr(self, expr)
+            }
+        }
+
+        fn visit_select_statement(
+            &mut self,
+            _: &'ast mut SelectStatement,
+        ) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    let mut exprs: Vec<ExprColumn> = vec![];
+    let Some(group_by) = &mut stmt.group_by else { return Ok(exprs) };
+    for field in group_by.fields.iter_mut() {
+        let FieldReference::Expr(expr) = field else { continue };
+        if matches!(expr, Expr::Column(_)) {
+            continue;
+        }
+        if contains_aggregate(expr) {
+            unsupported!("Aggregates are not allowed in GROUP BY");
+        }
+
+        // FIXME(ENG-2499): Use correct dialect.
+        let name: SqlIdentifier = expr.display(nom_sql::Dialect::MySQL).to_string().into();
+        let expression = mem::replace(
+            expr,
+            Expr::Column(Column {
+                name: name.clone(),
+                table: None,
+            }),
+        );
+        if !exprs.iter().any(|ec| ec.name == name) {
+            exprs.push(ExprColumn {
+                name,
+                table: None,
+                expression,
+            });
+        }
+    }
+
+    if exprs.is_empty() {
+        return Ok(exprs);
+    }
+
+    let mut replacer = GroupByExprReplacer {
+        exprs: &exprs,
+        replaced: false,
+    };
+    for field in stmt.fields.iter_mut() {
+        if let FieldDefinitionExpr::Expr { expr, alias } = field {
+            // FIXME(ENG-2499): Use correct dialect.
+            let name = expr.display(nom_sql::Dialect::MySQL).to_string();
+            replacer.replaced = false;
+            let _ = replacer.visit_expr(expr);
+            if replacer.replaced && alias.is_none() {
+                // Keep the name of the field the same as it was in the original query
+                *alias = Some(name.into());
+            }
+        }
+    }
+    if let Some(having) = &mut stmt.having {
+        let _ = replacer.visit_expr(having);
+    }
+
+    Ok(exprs)
+}
+
+/// Convert limit and offset fields to an optional limit and optional placeholder for the offset.
+///
+/// A placeholder for the offset is only returned if the limit is constant - constant offsets, and
+/// offsets for queries with a parametrized limit, are applied post-lookup.
 pub(crate) fn extract_limit_offset(
     limit_clause: &LimitClause,
-) -> ReadySetResult<Option<(usize, Option<ViewPlaceholder>)>> {
+) -> ReadySetResult<Option<(Limit, Option<ViewPlaceholder>)>> {
     if limit_clause.limit().is_none() && limit_clause.offset().is_some() {
         unsupported!("ReadySet does not support OFFSET without LIMIT");
     }
@@ -840,12 +1332,12 @@ pub(crate) fn extract_limit_offset(
     };
 
     let limit = match limit {
-        Literal::UnsignedInteger(val) => *val,
-        Literal::Integer(val) => u64::try_from(*val)
-            .map_err(|_| unsupported_err!("LIMIT field cannot have a negative value"))?,
-        Literal::Placeholder(_) => {
-            unsupported!("ReadySet does not support parametrized LIMIT fields")
-        }
+        Literal::UnsignedInteger(val) => Some(*val),
+        Literal::Integer(val) => Some(
+            u64::try_from(*val)
+                .map_err(|_| unsupported_err!("LIMIT field cannot have a negative value"))?,
+        ),
+        Literal::Placeholder(_) => None,
         _ => unsupported!("Invalid LIMIT statement"),
     };
 
@@ -853,21 +1345,43 @@ pub(crate) fn extract_limit_offset(
         .offset()
         .as_ref()
         // For now, remove offset if it is a literal 0
-        .filter(|offset| !matches!(offset, Literal::UnsignedInteger(0)))
-        .map(|offset| -> ReadySetResult<ViewPlaceholder> {
-            match offset {
-                Literal::Placeholder(ItemPlaceholder::DollarNumber(idx)) => {
-                    Ok(ViewPlaceholder::PageNumber {
-                        offset_placeholder: *idx as _,
-                        limit,
-                    })
-                }
-                _ => unsupported!("Numeric OFFSETs must be parametrized"),
-            }
-        })
-        .transpose()?;
+        .filter(|offset| !matches!(offset, Literal::UnsignedInteger(0)));
+
+    let Some(limit) = limit else {
+        if offset.map_or(false, |offset| {
+            !matches!(
+                offset,
+                Literal::Placeholder(_) | Literal::UnsignedInteger(_) | Literal::Integer(_)
+            )
+        }) {
+            unsupported!("Invalid OFFSET statement");
+        }
+        return Ok(Some((Limit::Parametrized, None)));
+    };
 
-    Ok(Some((limit as _, offset)))
+    match offset {
+        None => Ok(Some((Limit::Constant(limit as _), None))),
+        Some(Literal::Placeholder(ItemPlaceholder::DollarNumber(idx))) => Ok(Some((
+            Limit::Constant(limit as _),
+            Some(ViewPlaceholder::PageNumber {
+                offset_placeholder: *idx as _,
+                limit,
+            }),
+        ))),
+        Some(Literal::UnsignedInteger(offset)) => Ok(Some((
+            Limit::Constant(limit.saturating_add(*offset) as _),
+            None,
+        ))),
+        Some(Literal::Integer(offset)) => {
+            let offset = u64::try_from(*offset)
+                .map_err(|_| unsupported_err!("OFFSET field cannot have a negative value"))?;
+            Ok(Some((
+                Limit::Constant(limit.saturating_add(offset) as _),
+                None,
+            )))
+        }
+        _ => unsupported!("Invalid OFFSET statement"),
+    }
 }
 
 fn table_expr_name(table_expr: &TableExpr) -> ReadySetResult<Relation> {
@@ -904,7 +1418,21 @@ fn default_row_for_select(st: &SelectStatement) -> Option<Vec<DfValue>> {
                     FunctionExpr::Max(..) => DfValue::None,
                     FunctionExpr::Min(..) => DfValue::None,
                     FunctionExpr::GroupConcat { .. } => DfValue::None,
-                    FunctionExpr::Call { .. } | FunctionExpr::Substring { .. } => DfValue::None,
+                    FunctionExpr::StdDev { .. } | FunctionExpr::Variance { .. } => DfValue::None,
+                    FunctionExpr::BoolAnd(..)
+                    | FunctionExpr::BoolOr(..)
+                    | FunctionExpr::BitAnd(..)
+                    | FunctionExpr::BitOr(..)
+                    | FunctionExpr::BitXor(..) => DfValue::None,
+                    FunctionExpr::ApproxCountDistinct(..) => DfValue::Int(0),
+                    FunctionExpr::ApproxPercentile { .. } => DfValue::None,
+                    FunctionExpr::ArrayAgg { .. }
+                    | FunctionExpr::JsonAgg { .. }
+                    | FunctionExpr::JsonObjectAgg { .. } => DfValue::None,
+                    FunctionExpr::Call { .. }
+                    | FunctionExpr::Substring { .. }
+                    | FunctionExpr::Extract { .. }
+                    | FunctionExpr::DateAdd { .. } => DfValue::None,
                 },
                 _ => DfValue::None,
             })
@@ -913,7 +1441,69 @@ fn default_row_for_select(st: &SelectStatement) -> Option<Vec<DfValue>> {
 }
 
 #[allow(clippy::cognitive_complexity)]
-pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
+pub fn to_query_graph(mut stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
+    // 0. Replace any scalar subqueries in the fields list and WHERE clause with references to the
+    //    columns for their results, which get joined in when compiling to MIR
+    let mut scalar_subqueries = Vec::new();
+    for field in stmt.fields.iter_mut() {
+        if let FieldDefinitionExpr::Expr { expr, alias } = field {
+            let num_subqueries = scalar_subqueries.len();
+            // FIXME(ENG-2499): Use correct dialect.
+            let name = expr.display(nom_sql::Dialect::MySQL).to_string();
+            extract_scalar_subqueries(expr, &mut scalar_subqueries);
+            if scalar_subqueries.len() > num_subqueries && alias.is_none() {
+                // Keep the name of the field the same as it was in the original query
+                *alias = Some(name.into());
+            }
+        }
+    }
+    let num_field_subqueries = scalar_subqueries.len();
+
+    // Predicates containing scalar subqueries always become global predicates, since they refer
+    // to the (table-less) columns for the results of those subqueries
+    let mut subquery_predicates = Vec::new();
+    if let Some(where_clause) = &stmt.where_clause {
+        let mut other_predicates = Vec::new();
+        for mut pred in split_conjunctions(iter::once(where_clause)) {
+            let num_subqueries = scalar_subqueries.len();
+            extract_scalar_subqueries(&mut pred, &mut scalar_subqueries);
+            if scalar_subqueries.len() == num_subqueries {
+                other_predicates.push(pred);
+                continue;
+            }
+
+            // If any of the subqueries return more than one row the reader will return an error,
+            // so make sure the rows for which that happens don't get filtered out
+            #[allow(clippy::indexing_slicing)] // Just pushed these subqueries
+            let pred = scalar_subqueries[num_subqueries..]
+                .iter()
+                .filter_map(|sq| sq.row_count_column.clone())
+                .fold(pred, |pred, row_count_column| Expr::BinaryOp {
+                    lhs: Box::new(Expr::BinaryOp {
+                        lhs: Box::new(Expr::Column(nom_sql::Column {
+                            name: row_count_column,
+                            table: None,
+                        })),
+                        op: BinaryOperator::Greater,
+                        rhs: Box::new(Expr::Literal(Literal::Integer(1))),
+                    }),
+                    op: BinaryOperator::Or,
+                    rhs: Box::new(pred),
+                });
+            subquery_predicates.push(pred);
+        }
+
+        if !subquery_predicates.is_empty() {
+            stmt.where_clause = other_predicates
+                .into_iter()
+                .reduce(|lhs, rhs| Expr::BinaryOp {
+                    lhs: Box::new(lhs),
+                    op: BinaryOperator::And,
+                    rhs: Box::new(rhs),
+                });
+        }
+    }
+
     // a handy closure for making new relation nodes
     let new_node =
         |rel: Relation, preds: Vec<Expr>, st: &SelectStatement| -> ReadySetResult<QueryGraphNode> {
@@ -1052,8 +1642,10 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
         // will be defined by join constraint
         let left_table;
         let right_table;
+        // set if the join condition contains OR
+        let mut join_branches = None;
 
-        let join_preds = match &jc.constraint {
+        let mut join_preds = match &jc.constraint {
             JoinConstraint::On(cond) => {
                 use nom_sql::analysis::ReferredTables;
 
@@ -1062,9 +1654,6 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
                 let mut tables_mentioned: Vec<Relation> =
                     cond.referred_tables().into_iter().collect();
 
-                let mut join_preds = vec![];
-                collect_join_predicates(cond.clone(), &mut join_preds)?;
-
                 if tables_mentioned.len() == 2 {
                     // tables can appear in any order in the join predicate, but
                     // we cannot just rely on that order, since it may lead us to
@@ -1085,27 +1674,35 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
                     unsupported!("more than 2 tables mentioned in join condition!");
                 };
 
-                for pred in join_preds.iter_mut() {
-                    // the condition tree might specify tables in opposite order to
-                    // their join order in the query; if so, flip them
-                    // TODO(malte): this only deals with simple, flat join
-                    // conditions for now.
-                    let l = match &pred.left {
-                        Expr::Column(f) => f,
-                        ref x => unsupported!("join condition not supported: {:?}", x),
-                    };
-                    let r = match &pred.right {
-                        Expr::Column(f) => f,
-                        ref x => unsupported!("join condition not supported: {:?}", x),
-                    };
-                    if *l.table.as_ref().ok_or_else(|| no_table_for_col())? == right_table
-                        && *r.table.as_ref().ok_or_else(|| no_table_for_col())? == left_table
-                    {
-                        mem::swap(&mut pred.left, &mut pred.right);
+                if has_disjunction(cond) {
+                    join_branches = Some(collect_join_branches(cond, &left_table, &right_table)?);
+                    vec![]
+                } else {
+                    let mut join_preds = vec![];
+                    collect_join_predicates(cond.clone(), &mut join_preds)?;
+
+                    for pred in join_preds.iter_mut() {
+                        // the condition tree might specify tables in opposite order to
+                        // their join order in the query; if so, flip them
+                        // TODO(malte): this only deals with simple, flat join
+                        // conditions for now.
+                        let l = match &pred.left {
+                            Expr::Column(f) => f,
+                            ref x => unsupported!("join condition not supported: {:?}", x),
+                        };
+                        let r = match &pred.right {
+                            Expr::Column(f) => f,
+                            ref x => unsupported!("join condition not supported: {:?}", x),
+                        };
+                        if *l.table.as_ref().ok_or_else(|| no_table_for_col())? == right_table
+                            && *r.table.as_ref().ok_or_else(|| no_table_for_col())? == left_table
+                        {
+                            pred.swap_sides();
+                        }
                     }
-                }
 
-                join_preds
+                    join_preds
+                }
             }
             JoinConstraint::Using(cols) => {
                 invariant_eq!(cols.len(), 1);
@@ -1117,6 +1714,7 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
 
                 vec![JoinPredicate {
                     left: col_expr(&left_table, &col.name),
+                    op: BinaryOperator::Equal,
                     right: col_expr(&right_table, &col.name),
                 }]
             }
@@ -1128,16 +1726,40 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
             }
         };
 
+        // A right join is just a left join with the sides swapped
+        let (left_table, right_table) = match jc.operator {
+            JoinOperator::RightJoin | JoinOperator::RightOuterJoin => {
+                for pred in join_preds.iter_mut() {
+                    pred.swap_sides();
+                }
+                (right_table, left_table)
+            }
+            _ => (left_table, right_table),
+        };
+
         // add edge for join
         // FIXME(eta): inefficient cloning!
         if let std::collections::hash_map::Entry::Vacant(e) =
             edges.entry((left_table.clone(), right_table.clone()))
         {
-            e.insert(match jc.operator {
-                JoinOperator::LeftJoin | JoinOperator::LeftOuterJoin => {
-                    QueryGraphEdge::LeftJoin { on: join_preds }
+            e.insert(match (join_branches, jc.operator) {
+                (Some(branches), JoinOperator::Join | JoinOperator::InnerJoin) => {
+                    QueryGraphEdge::DisjunctiveJoin { branches }
                 }
-                JoinOperator::Join | JoinOperator::InnerJoin => {
+                (Some(_), _) => {
+                    unsupported!("OR in join conditions is only supported for inner joins")
+                }
+                (
+                    None,
+                    JoinOperator::LeftJoin
+                    | JoinOperator::LeftOuterJoin
+                    | JoinOperator::RightJoin
+                    | JoinOperator::RightOuterJoin,
+                ) => QueryGraphEdge::LeftJoin { on: join_preds },
+                (None, JoinOperator::FullJoin | JoinOperator::FullOuterJoin) => {
+                    QueryGraphEdge::FullJoin { on: join_preds }
+                }
+                (None, JoinOperator::Join | JoinOperator::InnerJoin) => {
                     QueryGraphEdge::Join { on: join_preds }
                 }
                 _ => unsupported!("join operator not supported"),
@@ -1146,6 +1768,7 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
     }
 
     let mut local_predicates = HashMap::new();
+    let mut disjunctive_join_predicates = Vec::new();
     let mut global_predicates = Vec::new();
     let mut query_parameters = Vec::new();
     if let Some(ref cond) = stmt.where_clause {
@@ -1155,6 +1778,7 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
             &inner_join_rels,
             &mut local_predicates,
             &mut join_predicates,
+            &mut disjunctive_join_predicates,
             &mut global_predicates,
             &mut query_parameters,
         )?;
@@ -1206,12 +1830,69 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
                         .or_insert_with(|| QueryGraphEdge::Join { on: vec![] });
                     match *e {
                         QueryGraphEdge::Join { on: ref mut preds } => preds.push(jp.clone()),
+                        QueryGraphEdge::DisjunctiveJoin { ref mut branches } => {
+                            for branch in branches {
+                                branch.on.push(jp.clone());
+                            }
+                        }
                         _ => internal!("Expected join edge for join condition {:#?}", jp),
                     };
                 }
             }
         }
 
+        // 2a. Add disjunctions of join conditions (ORs containing join predicates) that were
+        //     specified in the WHERE clause. Each of these refers to exactly two tables.
+        for cond in disjunctive_join_predicates {
+            let mut tables = cond
+                .referred_columns()
+                .map(|col| col.table.clone().ok_or_else(no_table_for_col))
+                .collect::<ReadySetResult<Vec<_>>>()?;
+            tables.sort();
+            tables.dedup();
+            let (left_table, right_table) = match tables.as_slice() {
+                [t1, t2] if edges.contains_key(&(t2.clone(), t1.clone())) => {
+                    (t2.clone(), t1.clone())
+                }
+                [t1, t2] => (t1.clone(), t2.clone()),
+                _ => internal!("Expected disjunctive join condition to refer to two tables"),
+            };
+            let new_branches = collect_join_branches(&cond, &left_table, &right_table)?;
+
+            let e = edges
+                .entry((left_table, right_table))
+                .or_insert_with(|| QueryGraphEdge::Join { on: vec![] });
+            let branches = match e {
+                QueryGraphEdge::Join { on } => new_branches
+                    .into_iter()
+                    .map(|mut branch| {
+                        branch.on.splice(0..0, on.iter().cloned());
+                        branch
+                    })
+                    .collect(),
+                // Both conditions must hold, so take every combination of their branches
+                QueryGraphEdge::DisjunctiveJoin { branches } => {
+                    check_join_branch_count(branches.len().saturating_mul(new_branches.len()))?;
+                    branches
+                        .iter()
+                        .flat_map(|existing| {
+                            new_branches.iter().map(move |new| JoinBranch {
+                                on: existing.on.iter().chain(&new.on).cloned().collect(),
+                                predicates: existing
+                                    .predicates
+                                    .iter()
+                                    .chain(&new.predicates)
+                                    .cloned()
+                                    .collect(),
+                            })
+                        })
+                        .collect()
+                }
+                _ => unsupported!("OR in join conditions is only supported for inner joins"),
+            };
+            *e = QueryGraphEdge::DisjunctiveJoin { branches };
+        }
+
         // 3. Add any columns that are query parameters, and which therefore must appear in the leaf
         //    node for this query. Such columns will be carried all the way through the operators
         //    implementing the query (unlike in a traditional query plan, where the predicates on
@@ -1236,6 +1917,10 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
         }
     }
 
+    global_predicates.extend(subquery_predicates);
+
+    let group_by_exprs = extract_group_by_expressions(&mut stmt)?;
+
     // Add HAVING predicates and aggregates. Note that unlike below for selected columns, we don't
     // add any found aggregate functions in the HAVING clause to qg.columns, since we don't want to
     // necessarily return these in the query results.
@@ -1246,6 +1931,7 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
         vec![]
     };
 
+    let mut window_functions = Vec::new();
     let mut columns = Vec::with_capacity(stmt.fields.len());
     for field in stmt.fields.iter() {
         match field {
@@ -1269,6 +1955,24 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
                             column: c.clone(),
                         });
                     }
+                    Expr::WindowFunction {
+                        function,
+                        partition_by,
+                        order_by,
+                    } => {
+                        window_functions.push(window_column(
+                            function,
+                            partition_by,
+                            order_by,
+                            name.clone(),
+                        )?);
+                        // Like aggregates, the value of the window function will already have been
+                        // projected by the window node by the time we project the result set
+                        columns.push(OutputColumn::Data {
+                            alias: name.clone(),
+                            column: Column { name, table: None },
+                        })
+                    }
                     Expr::Call(function) if is_aggregate(function) => {
                         let agg_name = aggregates
                             .entry(function.clone())
@@ -1305,6 +2009,46 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
         }
     }
 
+    if !aggregates.is_empty() || stmt.group_by.is_some() {
+        // The columns for the results of scalar subqueries (and the number of rows they return)
+        // don't make it through the aggregates
+        if num_field_subqueries > 0 {
+            unsupported!(
+                "Scalar subqueries in the SELECT list of aggregated queries are unsupported"
+            );
+        }
+        if scalar_subqueries
+            .iter()
+            .any(|sq| sq.row_count_column.is_some())
+        {
+            unsupported!(
+                "Scalar subqueries which may return more than one row are unsupported in \
+                 aggregated queries"
+            );
+        }
+    }
+
+    if !window_functions.is_empty() {
+        if !aggregates.is_empty() || stmt.group_by.is_some() {
+            unsupported!("Window functions cannot currently be combined with aggregates");
+        }
+
+        // Lookups into the reader filter rows *after* the window function has been computed, so
+        // that's only correct if every row in a partition has the same value for each parameter
+        for param in relations.values().flat_map(|rel| rel.parameters.iter()) {
+            if param.op != BinaryOperator::Equal
+                || !window_functions
+                    .iter()
+                    .all(|wf| wf.partition_by.contains(&param.col))
+            {
+                unsupported!(
+                    "Window functions are only supported in queries whose parameters are \
+                     equality comparisons against columns in every PARTITION BY"
+                );
+            }
+        }
+    }
+
     let group_by = if let Some(group_by_clause) = &stmt.group_by {
         group_by_clause
             .fields
@@ -1315,7 +2059,7 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
                 }
                 FieldReference::Expr(Expr::Column(c)) => Ok(c.clone()),
                 FieldReference::Expr(_) => {
-                    unsupported!("Only column references are currently supported in GROUP BY")
+                    internal!("GROUP BY expressions should have been extracted earlier")
                 }
             })
             .collect::<ReadySetResult<HashSet<_>>>()?
@@ -1328,7 +2072,7 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
         order
             .order_by
             .iter()
-            .for_each(|(ord_expr, _)| match ord_expr {
+            .for_each(|(ord_expr, _, _)| match ord_expr {
                 FieldReference::Expr(Expr::Column(Column { table: None, .. })) => {
                     // This is a reference to a projected column, otherwise the table value
                     // would be assigned in the `rewrite_selection` pass
@@ -1381,7 +2125,8 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
                 .order_by
                 .iter()
                 .cloned()
-                .map(|(expr, ot)| {
+                .map(|(expr, ot, no)| {
+                    let ot = ot.unwrap_or(OrderType::OrderAscending);
                     Ok((
                         match expr {
                             FieldReference::Expr(Expr::Column(col)) => col,
@@ -1394,46 +2139,148 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
                                 internal!("Numeric field references should have been removed")
                             }
                         },
-                        ot.unwrap_or(OrderType::OrderAscending),
+                        ot,
+                        no.unwrap_or_else(|| NullOrder::default_for(ot)),
                     ))
                 })
                 .collect::<ReadySetResult<_>>()
         })
         .transpose()?;
 
+    // Range lookups read rows for many keys at once, so they can't be paginated by page number in
+    // the dataflow graph. Instead, keep a bounded number of rows per key, and apply the concrete
+    // LIMIT and OFFSET post-lookup to the combined results.
+    let has_range_parameters = relations
+        .values()
+        .flat_map(|qgn| qgn.parameters.iter())
+        .any(|param| IndexType::for_operator(param.op) == Some(IndexType::BTreeMap));
+
+    // Range and multi-key lookups re-aggregate the results for each key post-lookup, but averages
+    // can't be re-aggregated from their values alone. Carry the sum and count of each average's
+    // argument as additional projected columns, so the average can be recomputed from those
+    // instead. Distinct averages can't be recomputed this way, since the same value may be counted
+    // for more than one key, so they aren't supported as post-lookup aggregates at all.
+    let has_parameters = relations.values().any(|qgn| !qgn.parameters.is_empty());
+    if has_parameters {
+        let averages = aggregates
+            .keys()
+            .filter_map(|function| match function {
+                FunctionExpr::Avg {
+                    expr,
+                    distinct: false,
+                } => Some(expr.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        for expr in averages {
+            for function in [
+                FunctionExpr::Sum {
+                    expr: expr.clone(),
+                    distinct: false,
+                },
+                FunctionExpr::Count {
+                    expr,
+                    distinct: false,
+                },
+            ] {
+                let name = aggregates
+                    .entry(function)
+                    .or_insert_with_key(|function| {
+                        // FIXME(ENG-2499): Use correct dialect.
+                        function.display(nom_sql::Dialect::MySQL).to_string().into()
+                    })
+                    .clone();
+                let projected = columns.iter().any(|c| match c {
+                    OutputColumn::Data { column, .. } => {
+                        column.table.is_none() && column.name == name
+                    }
+                    _ => false,
+                });
+                if !projected {
+                    columns.push(OutputColumn::Data {
+                        alias: name.clone(),
+                        column: Column { name, table: None },
+                    });
+                }
+            }
+        }
+    }
+
+    let order_exprs = stmt
+        .order
+        .as_ref()
+        .map(|o| {
+            o.order_by
+                .iter()
+                .cloned()
+                .map(|(field, ot, no)| {
+                    let ot = ot.unwrap_or(OrderType::OrderAscending);
+                    Ok((
+                        match field {
+                            FieldReference::Numeric(_) => {
+                                internal!("Numeric field references should have been removed")
+                            }
+                            FieldReference::Expr(expr) => expr,
+                        },
+                        ot,
+                        no.unwrap_or_else(|| NullOrder::default_for(ot)),
+                    ))
+                })
+                .collect::<ReadySetResult<Vec<_>>>()
+        })
+        .transpose()?;
+
     // Extract pagination parameters
     let pagination = extract_limit_offset(&stmt.limit_clause)?
         .map(|(limit, offset)| -> ReadySetResult<Pagination> {
+            let (limit, offset) = match offset {
+                Some(_) if has_range_parameters => (Limit::Parametrized, None),
+                offset => (limit, offset),
+            };
             Ok(Pagination {
-                order: stmt
-                    .order
-                    .as_ref()
-                    .map(|o| {
-                        o.order_by
-                            .iter()
-                            .cloned()
-                            .map(|(field, ot)| {
-                                Ok((
-                                    match field {
-                                        FieldReference::Numeric(_) => {
-                                            internal!(
-                                                "Numeric field references should have been removed"
-                                            )
-                                        }
-                                        FieldReference::Expr(expr) => expr,
-                                    },
-                                    ot.unwrap_or(OrderType::OrderAscending),
-                                ))
-                            })
-                            .collect::<ReadySetResult<_>>()
-                    })
-                    .transpose()?,
+                order: order_exprs.clone(),
                 limit,
                 offset,
             })
         })
         .transpose()?;
 
+    let distinct_on = if stmt.distinct_on.is_empty() {
+        None
+    } else {
+        if has_range_parameters {
+            unsupported!("DISTINCT ON is not supported for queries with range parameters");
+        }
+
+        // As in PostgreSQL, the DISTINCT ON expressions must match the leftmost ORDER BY
+        // expressions, so that the ordering determines which row is kept for each group
+        if let Some(order) = &order_exprs {
+            let leading = order
+                .iter()
+                .take(stmt.distinct_on.len())
+                .map(|(expr, _, _)| expr)
+                .collect::<HashSet<_>>();
+            if leading != stmt.distinct_on.iter().collect::<HashSet<_>>() {
+                invalid!("SELECT DISTINCT ON expressions must match initial ORDER BY expressions");
+            }
+        }
+
+        Some(DistinctOn {
+            columns: stmt
+                .distinct_on
+                .iter()
+                .map(|expr| match expr {
+                    Expr::Column(col) => Ok(col.clone()),
+                    expr => unsupported!(
+                        "Only columns are supported in DISTINCT ON, got {}",
+                        expr.display(nom_sql::Dialect::PostgreSQL)
+                    ),
+                })
+                .collect::<ReadySetResult<_>>()?,
+            order: order_exprs,
+        })
+    };
+
     // create initial join order
     let join_order = {
         let mut sorted_edges: Vec<(&(Relation, Relation), &QueryGraphEdge)> =
@@ -1452,10 +2299,13 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
 
     Ok(QueryGraph {
         distinct: stmt.distinct,
+        distinct_on,
         relations,
         edges,
         aggregates,
+        window_functions,
         group_by,
+        group_by_exprs,
         columns,
         fields: stmt.fields.clone(),
         default_row: default_row_for_select(&stmt),
@@ -1465,6 +2315,7 @@ pub fn to_query_graph(stmt: SelectStatement) -> ReadySetResult<QueryGraph> {
         pagination,
         order,
         is_correlated: is_correlated(&stmt),
+        scalar_subqueries,
     })
 }
 
@@ -1505,6 +2356,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parametrized_avg_carries_sum_and_count() {
+        let qg = make_query_graph("SELECT avg(t1.x) AS avg_x FROM t1 WHERE t1.y > ?");
+        let x = || Box::new(Expr::Column("t1.x".into()));
+        assert_eq!(
+            qg.aggregates,
+            HashMap::from([
+                (
+                    FunctionExpr::Avg {
+                        expr: x(),
+                        distinct: false
+                    },
+                    "avg_x".into()
+                ),
+                (
+                    FunctionExpr::Sum {
+                        expr: x(),
+                        distinct: false
+                    },
+                    "sum(`t1`.`x`)".into()
+                ),
+                (
+                    FunctionExpr::Count {
+                        expr: x(),
+                        distinct: false
+                    },
+                    "count(`t1`.`x`)".into()
+                ),
+            ])
+        );
+        assert!(qg.columns.iter().any(
+            |c| matches!(c, OutputColumn::Data { alias, .. } if alias.as_str() == "sum(`t1`.`x`)")
+        ));
+
+        // Equality lookups may be for multiple keys at once
+        let qg = make_query_graph("SELECT avg(t1.x) AS avg_x FROM t1 WHERE t1.y = ?");
+        assert_eq!(qg.aggregates.len(), 3);
+
+        // Unparametrized queries are never re-aggregated post-lookup
+        let qg = make_query_graph("SELECT avg(t1.x) AS avg_x FROM t1");
+        assert_eq!(qg.aggregates.len(), 1);
+
+        // Distinct averages can't be recomputed from a sum and count
+        let qg = make_query_graph("SELECT avg(DISTINCT t1.x) AS avg_x FROM t1 WHERE t1.y > ?");
+        assert_eq!(qg.aggregates.len(), 1);
+    }
+
     #[test]
     fn aggregates_with_alias() {
         let qg = make_query_graph("SELECT max(t1.x) AS max_x FROM t1 JOIN t2 ON t1.id = t2.id");
@@ -1681,6 +2579,398 @@ mod tests {
         assert_eq!(qg.global_predicates, vec![Expr::Literal(1u64.into())])
     }
 
+    #[test]
+    fn theta_join() {
+        let qg = make_query_graph("SELECT t1.x FROM t1 JOIN t2 ON t1.id = t2.id AND t1.ts < t2.ts");
+        assert_eq!(
+            qg.edges[&(Relation::from("t1"), Relation::from("t2"))],
+            QueryGraphEdge::Join {
+                on: vec![
+                    JoinPredicate {
+                        left: Expr::Column("t1.id".into()),
+                        op: BinaryOperator::Equal,
+                        right: Expr::Column("t2.id".into()),
+                    },
+                    JoinPredicate {
+                        left: Expr::Column("t1.ts".into()),
+                        op: BinaryOperator::Less,
+                        right: Expr::Column("t2.ts".into()),
+                    }
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn theta_join_flips_reversed_condition() {
+        let qg = make_query_graph("SELECT t1.x FROM t1 JOIN t2 ON t2.ts >= t1.ts");
+        assert_eq!(
+            qg.edges[&(Relation::from("t1"), Relation::from("t2"))],
+            QueryGraphEdge::Join {
+                on: vec![JoinPredicate {
+                    left: Expr::Column("t1.ts".into()),
+                    op: BinaryOperator::LessOrEqual,
+                    right: Expr::Column("t2.ts".into()),
+                }]
+            }
+        );
+    }
+
+    #[test]
+    fn theta_join_in_where_clause() {
+        let qg = make_query_graph("SELECT t1.x FROM t1, t2 WHERE t2.b > t1.a");
+        assert_eq!(
+            qg.edges[&(Relation::from("t1"), Relation::from("t2"))],
+            QueryGraphEdge::Join {
+                on: vec![JoinPredicate {
+                    left: Expr::Column("t1.a".into()),
+                    op: BinaryOperator::Less,
+                    right: Expr::Column("t2.b".into()),
+                }]
+            }
+        );
+        assert!(qg.global_predicates.is_empty());
+    }
+
+    #[test]
+    fn right_join() {
+        let qg = make_query_graph("SELECT t1.x, t2.y FROM t1 RIGHT JOIN t2 ON t1.a = t2.b");
+        assert!(!qg
+            .edges
+            .contains_key(&(Relation::from("t1"), Relation::from("t2"))));
+        assert_eq!(
+            qg.edges[&(Relation::from("t2"), Relation::from("t1"))],
+            QueryGraphEdge::LeftJoin {
+                on: vec![JoinPredicate {
+                    left: Expr::Column("t2.b".into()),
+                    op: BinaryOperator::Equal,
+                    right: Expr::Column("t1.a".into()),
+                }]
+            }
+        );
+    }
+
+    #[test]
+    fn right_outer_join_equivalent_to_swapped_left_join() {
+        let right =
+            make_query_graph("SELECT t1.x, t2.y FROM t1 RIGHT OUTER JOIN t2 ON t1.a = t2.b");
+        let left = make_query_graph("SELECT t1.x, t2.y FROM t2 LEFT JOIN t1 ON t2.b = t1.a");
+        assert_eq!(right.edges, left.edges);
+        assert_eq!(right.join_order, left.join_order);
+    }
+
+    #[test]
+    fn full_join() {
+        let qg = make_query_graph("SELECT t1.x, t2.y FROM t1 FULL OUTER JOIN t2 ON t2.b = t1.a");
+        assert_eq!(
+            qg.edges[&(Relation::from("t1"), Relation::from("t2"))],
+            QueryGraphEdge::FullJoin {
+                on: vec![JoinPredicate {
+                    left: Expr::Column("t1.a".into()),
+                    op: BinaryOperator::Equal,
+                    right: Expr::Column("t2.b".into()),
+                }]
+            }
+        );
+    }
+
+    #[test]
+    fn disjunctive_join() {
+        let qg = make_query_graph(
+            "SELECT t1.x FROM t1 JOIN t2 ON t1.id = t2.id AND (t1.a = t2.a OR t2.b = t1.b)",
+        );
+        let pred = |l: &str, r: &str| JoinPredicate {
+            left: Expr::Column(l.into()),
+            op: BinaryOperator::Equal,
+            right: Expr::Column(r.into()),
+        };
+        assert_eq!(
+            qg.edges[&(Relation::from("t1"), Relation::from("t2"))],
+            QueryGraphEdge::DisjunctiveJoin {
+                branches: vec![
+                    JoinBranch {
+                        on: vec![pred("t1.id", "t2.id"), pred("t1.a", "t2.a")],
+                        predicates: vec![],
+                    },
+                    JoinBranch {
+                        on: vec![pred("t1.id", "t2.id"), pred("t1.b", "t2.b")],
+                        predicates: vec![],
+                    },
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn disjunctive_join_with_filter() {
+        let qg = make_query_graph("SELECT t1.x FROM t1 JOIN t2 ON t1.a = t2.a OR t1.b = 1");
+        assert_eq!(
+            qg.edges[&(Relation::from("t1"), Relation::from("t2"))],
+            QueryGraphEdge::DisjunctiveJoin {
+                branches: vec![
+                    JoinBranch {
+                        on: vec![JoinPredicate {
+                            left: Expr::Column("t1.a".into()),
+                            op: BinaryOperator::Equal,
+                            right: Expr::Column("t2.a".into()),
+                        }],
+                        predicates: vec![],
+                    },
+                    JoinBranch {
+                        on: vec![],
+                        predicates: vec![Expr::BinaryOp {
+                            lhs: Box::new(Expr::Column("t1.b".into())),
+                            op: BinaryOperator::Equal,
+                            rhs: Box::new(Expr::Literal(1u64.into())),
+                        }],
+                    },
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn disjunctive_join_in_where_clause() {
+        let qg = make_query_graph(
+            "SELECT t1.x FROM t1, t2 WHERE t1.id = t2.id AND (t1.a = t2.a OR t1.b = t2.b) \
+             AND t1.c = 1",
+        );
+        let pred = |l: &str, r: &str| JoinPredicate {
+            left: Expr::Column(l.into()),
+            op: BinaryOperator::Equal,
+            right: Expr::Column(r.into()),
+        };
+        assert_eq!(
+            qg.edges[&(Relation::from("t1"), Relation::from("t2"))],
+            QueryGraphEdge::DisjunctiveJoin {
+                branches: vec![
+                    JoinBranch {
+                        on: vec![pred("t1.id", "t2.id"), pred("t1.a", "t2.a")],
+                        predicates: vec![],
+                    },
+                    JoinBranch {
+                        on: vec![pred("t1.id", "t2.id"), pred("t1.b", "t2.b")],
+                        predicates: vec![],
+                    },
+                ]
+            }
+        );
+        assert!(qg.global_predicates.is_empty());
+        assert_eq!(qg.relations[&Relation::from("t1")].predicates.len(), 1);
+    }
+
+    #[test]
+    fn disjunctive_join_branch_limit() {
+        // 2^4 = 16 branches is the most we allow
+        let query = parse_select_statement(
+            Dialect::MySQL,
+            "SELECT t1.x FROM t1 JOIN t2 ON (t1.a = t2.a OR t1.b = t2.b) \
+             AND (t1.c = t2.c OR t1.d = t2.d) AND (t1.e = t2.e OR t1.f = t2.f) \
+             AND (t1.g = t2.g OR t1.h = t2.h)",
+        )
+        .unwrap();
+        to_query_graph(query).unwrap();
+
+        let query = parse_select_statement(
+            Dialect::MySQL,
+            "SELECT t1.x FROM t1 JOIN t2 ON (t1.a = t2.a OR t1.b = t2.b) \
+             AND (t1.c = t2.c OR t1.d = t2.d) AND (t1.e = t2.e OR t1.f = t2.f) \
+             AND (t1.g = t2.g OR t1.h = t2.h) AND (t1.i = t2.i OR t1.j = t2.j)",
+        )
+        .unwrap();
+        to_query_graph(query).unwrap_err();
+    }
+
+    #[test]
+    fn disjunctive_left_join_unsupported() {
+        let query = parse_select_statement(
+            Dialect::MySQL,
+            "SELECT t1.x FROM t1 LEFT JOIN t2 ON t1.a = t2.a OR t1.b = t2.b",
+        )
+        .unwrap();
+        to_query_graph(query).unwrap_err();
+    }
+
+    #[test]
+    fn window_function() {
+        let qg = make_query_graph(
+            "SELECT t.x, row_number() OVER (PARTITION BY t.x ORDER BY t.y DESC) AS rn \
+             FROM t WHERE t.x = $1",
+        );
+        assert_eq!(
+            qg.window_functions,
+            vec![WindowColumn {
+                function: WindowFunction::RowNumber,
+                over: None,
+                partition_by: vec!["t.x".into()],
+                order_by: vec![("t.y".into(), OrderType::OrderDescending)],
+                alias: "rn".into(),
+            }]
+        );
+        assert!(qg.columns.contains(&OutputColumn::Data {
+            alias: "rn".into(),
+            column: Column::from("rn"),
+        }));
+    }
+
+    #[test]
+    fn window_function_parameter_not_in_partition() {
+        let query = parse_select_statement(
+            Dialect::MySQL,
+            "SELECT t.x, sum(t.z) OVER (PARTITION BY t.x ORDER BY t.y) AS s FROM t WHERE t.y = $1",
+        )
+        .unwrap();
+        to_query_graph(query).unwrap_err();
+    }
+
+    #[test]
+    fn scalar_subquery_in_fields() {
+        let qg =
+            make_query_graph("SELECT t1.x, (SELECT max(t2.y) FROM t2 WHERE t2.k = t1.k) FROM t1");
+        assert_eq!(qg.scalar_subqueries.len(), 1);
+        let subquery = &qg.scalar_subqueries[0];
+        assert_eq!(subquery.name, "__scalar_subquery_0");
+        // Aggregating without a GROUP BY always returns one row
+        assert!(subquery.row_count_column.is_none());
+
+        assert!(qg.columns.iter().any(|c| matches!(
+            c,
+            OutputColumn::Data { column, .. } if column.name == "__scalar_subquery_0"
+        )));
+        // The name of the field shouldn't change
+        assert!(matches!(
+            &qg.fields[1],
+            FieldDefinitionExpr::Expr { alias: Some(alias), .. } if alias.starts_with("(SELECT")
+        ));
+    }
+
+    #[test]
+    fn count_scalar_subquery() {
+        let qg = make_query_graph("SELECT t1.x, (SELECT count(*) FROM t2) AS c FROM t1");
+        assert_eq!(qg.scalar_subqueries.len(), 1);
+        assert!(qg.columns.contains(&OutputColumn::Expr(ExprColumn {
+            name: "c".into(),
+            table: None,
+            expression: Expr::Call(FunctionExpr::Call {
+                name: "coalesce".into(),
+                arguments: vec![
+                    Expr::Column("__scalar_subquery_0".into()),
+                    Expr::Literal(Literal::Integer(0)),
+                ],
+            }),
+        })));
+    }
+
+    #[test]
+    fn scalar_subquery_in_where() {
+        let qg = make_query_graph(
+            "SELECT t1.x FROM t1 \
+             WHERE t1.y = (SELECT t2.y FROM t2 WHERE t2.k = t1.k) AND t1.z = 1",
+        );
+        assert_eq!(qg.scalar_subqueries.len(), 1);
+        assert_eq!(
+            qg.scalar_subqueries[0].row_count_column,
+            Some("__scalar_subquery_0_rows".into())
+        );
+        assert_eq!(qg.relations[&Relation::from("t1")].predicates.len(), 1);
+        assert_eq!(
+            qg.global_predicates,
+            vec![Expr::BinaryOp {
+                lhs: Box::new(Expr::BinaryOp {
+                    lhs: Box::new(Expr::Column("__scalar_subquery_0_rows".into())),
+                    op: BinaryOperator::Greater,
+                    rhs: Box::new(Expr::Literal(Literal::Integer(1))),
+                }),
+                op: BinaryOperator::Or,
+                rhs: Box::new(Expr::BinaryOp {
+                    lhs: Box::new(Expr::Column("t1.y".into())),
+                    op: BinaryOperator::Equal,
+                    rhs: Box::new(Expr::Column("__scalar_subquery_0".into())),
+                }),
+            }]
+        );
+    }
+
+    #[test]
+    fn scalar_subquery_in_aggregated_query_unsupported() {
+        let query = parse_select_statement(
+            Dialect::MySQL,
+            "SELECT count(t1.x), (SELECT max(t2.y) FROM t2) FROM t1",
+        )
+        .unwrap();
+        to_query_graph(query).unwrap_err();
+    }
+
+    #[test]
+    fn group_by_expression() {
+        let qg = make_query_graph(
+            "SELECT lower(t.a), count(t.b) FROM t GROUP BY lower(t.a) HAVING lower(t.a) <> 'x'",
+        );
+        let expression = Expr::Call(FunctionExpr::Call {
+            name: "lower".into(),
+            arguments: vec![Expr::Column("t.a".into())],
+        });
+        let name: SqlIdentifier = expression.display(Dialect::MySQL).to_string().into();
+        let col = Column {
+            name: name.clone(),
+            table: None,
+        };
+
+        assert_eq!(
+            qg.group_by_exprs,
+            vec![ExprColumn {
+                name: name.clone(),
+                table: None,
+                expression,
+            }]
+        );
+        assert_eq!(qg.group_by, HashSet::from([col.clone()]));
+        assert_eq!(
+            qg.columns[0],
+            OutputColumn::Data {
+                alias: name,
+                column: col.clone(),
+            }
+        );
+        assert_eq!(
+            qg.having_predicates,
+            vec![Expr::BinaryOp {
+                lhs: Box::new(Expr::Column(col)),
+                op: BinaryOperator::NotEqual,
+                rhs: Box::new(Expr::Literal("x".into())),
+            }]
+        );
+    }
+
+    #[test]
+    fn parametrized_limit() {
+        let qg = make_query_graph("SELECT t.x FROM t WHERE t.x = $1 ORDER BY t.y LIMIT $2");
+        let pagination = qg.pagination.unwrap();
+        assert_eq!(pagination.limit, Limit::Parametrized);
+        assert_eq!(pagination.offset, None);
+
+        let qg =
+            make_query_graph("SELECT t.x FROM t WHERE t.x = $1 ORDER BY t.y LIMIT $2 OFFSET $3");
+        let pagination = qg.pagination.unwrap();
+        assert_eq!(pagination.limit, Limit::Parametrized);
+        assert_eq!(pagination.offset, None);
+        assert_eq!(
+            pagination.limit.rows_per_key(&mir::Config {
+                max_parametrized_limit: 42,
+                ..Default::default()
+            }),
+            42
+        );
+    }
+
+    #[test]
+    fn constant_offset() {
+        let qg = make_query_graph("SELECT t.x FROM t WHERE t.x = $1 ORDER BY t.y LIMIT 3 OFFSET 5");
+        let pagination = qg.pagination.unwrap();
+        assert_eq!(pagination.limit, Limit::Constant(8));
+        assert_eq!(pagination.offset, None);
+    }
+
     mod view_key {
         use super::*;
 
@@ -1956,5 +3246,39 @@ mod tests {
                 ]
             );
         }
+
+        #[test]
+        fn paginated_range() {
+            let qg = make_query_graph(
+                "SELECT t.x FROM t WHERE t.x > $1 ORDER BY t.x ASC LIMIT 3 OFFSET $2",
+            );
+            let pagination = qg.pagination.as_ref().unwrap();
+            assert_eq!(pagination.limit, Limit::Parametrized);
+            assert_eq!(pagination.offset, None);
+
+            let key = qg
+                .view_key(&mir::Config {
+                    allow_paginate: true,
+                    ..Default::default()
+                })
+                .unwrap();
+
+            assert_eq!(key.index_type, IndexType::BTreeMap);
+            assert_eq!(
+                key.columns,
+                vec![(
+                    mir::Column::new(Some("t"), "x"),
+                    ViewPlaceholder::OneToOne(1, BinaryOperator::Greater)
+                )]
+            );
+        }
+
+        #[test]
+        fn topk_range() {
+            let qg = make_query_graph("SELECT t.x FROM t WHERE t.x > $1 ORDER BY t.x ASC LIMIT 3");
+            let pagination = qg.pagination.as_ref().unwrap();
+            assert_eq!(pagination.limit, Limit::Constant(3));
+            assert_eq!(pagination.offset, None);
+        }
     }
 }
@@ -75,12 +75,25 @@ impl Signature for QueryGraph {
 
         for e in self.edges.values() {
             match e {
-                QueryGraphEdge::Join { on } | QueryGraphEdge::LeftJoin { on } => {
+                QueryGraphEdge::Join { on }
+                | QueryGraphEdge::LeftJoin { on }
+                | QueryGraphEdge::FullJoin { on } => {
                     on.iter()
                         .flat_map(|p| vec![&p.left, &p.right])
                         .flat_map(|p| p.referred_columns())
                         .for_each(&mut record_column);
                 }
+                QueryGraphEdge::DisjunctiveJoin { branches } => {
+                    for branch in branches {
+                        branch
+                            .on
+                            .iter()
+                            .flat_map(|p| vec![&p.left, &p.right])
+                            .chain(&branch.predicates)
+                            .flat_map(|p| p.referred_columns())
+                            .for_each(&mut record_column);
+                    }
+                }
             }
         }
 
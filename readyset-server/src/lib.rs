@@ -619,6 +619,32 @@ pub struct WorkerOptions {
     #[clap(long, env = "EXPERIMENTAL_MIXED_COMPARISONS_SUPPORT", hide = true)]
     pub enable_experimental_mixed_comparisons: bool,
 
+    /// Enable experimental support for recursive common table expressions (`WITH RECURSIVE`),
+    /// by unrolling them to a bounded depth
+    #[clap(long, env = "EXPERIMENTAL_RECURSIVE_CTES_SUPPORT", hide = true)]
+    pub enable_experimental_recursive_ctes: bool,
+
+    /// The maximum depth to which recursive common table expressions (`WITH RECURSIVE`) are
+    /// unrolled, if enabled. Rows which can only be reached by recursing deeper than this will not
+    /// be returned.
+    #[clap(
+        long,
+        env = "MAX_RECURSIVE_CTE_DEPTH",
+        default_value_t = sql::mir::DEFAULT_MAX_RECURSIVE_CTE_DEPTH,
+        hide = true
+    )]
+    pub max_recursive_cte_depth: usize,
+
+    /// The number of rows to cache per key for queries with a parametrized LIMIT (eg `LIMIT ?`).
+    /// Reads of these queries whose LIMIT and OFFSET add up to more than this will fail.
+    #[clap(
+        long,
+        env = "MAX_PARAMETRIZED_LIMIT",
+        default_value_t = sql::mir::DEFAULT_MAX_PARAMETRIZED_LIMIT,
+        hide = true
+    )]
+    pub max_parametrized_limit: usize,
+
     /// Directory in which to store replicated table data. If not specified, defaults to the
     /// current working directory.
     #[clap(long, env = "DB_DIR")]
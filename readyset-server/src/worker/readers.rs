@@ -205,6 +205,10 @@ impl ReadRequestHandler {
             Err(e) => reply_with_error!(e),
         };
 
+        if let Err(e) = reader.post_lookup.check_limit(limit, offset) {
+            reply_with_error!(e);
+        }
+
         let consistency_miss = !has_sufficient_timestamp(reader, &timestamp);
 
         let (keys_to_replay, receiver) = match reader.get_multi_with_notifier(&key_comparisons) {
@@ -222,6 +226,12 @@ impl ReadRequestHandler {
                 // immediately
                 self.hit_ctr.increment(1);
 
+                if let Err(e) = reader.post_lookup.check_subquery_row_counts(
+                    hit.iter().flat_map(|rows| rows.iter()).map(|r| &**r),
+                ) {
+                    reply_with_error!(e);
+                }
+
                 let results = ResultIterator::new(hit, &reader.post_lookup, limit, offset, filter);
 
                 let results = if raw_result {
@@ -502,6 +512,15 @@ impl BlockingRead {
             Err(_) => return Poll::Ready(Err(ReadySetError::ServerShuttingDown)),
             Ok(hit) => {
                 // We hit on all keys, and there is no consistency miss, can return results
+                if let Err(e) = reader.post_lookup.check_subquery_row_counts(
+                    hit.iter().flat_map(|rows| rows.iter()).map(|r| &**r),
+                ) {
+                    return Poll::Ready(Ok(Tagged {
+                        tag: self.tag,
+                        v: ReadReply::Normal(Err(e)),
+                    }));
+                }
+
                 let results = ResultIterator::new(
                     hit,
                     &reader.post_lookup,
@@ -323,6 +323,7 @@ async fn check_query(
                         | DfValue::Time(_)
                         // These types are PostgreSQL specific
                         | DfValue::BitVector(_)
+                        | DfValue::Interval(_)
                         | DfValue::PassThrough(_)
                         | DfValue::Array(_) => {
                             unimplemented!()
@@ -4,8 +4,8 @@ use std::mem;
 use itertools::Itertools;
 use nom_sql::analysis::visit_mut::{self, walk_select_statement, VisitorMut};
 use nom_sql::{
-    Column, CommonTableExpr, JoinRightSide, Relation, SelectStatement, SqlIdentifier, SqlQuery,
-    TableExpr, TableExprInner,
+    Column, CommonTableExpr, CompoundSelectOperator, JoinRightSide, Relation, SelectStatement,
+    SqlIdentifier, SqlQuery, TableExpr, TableExprInner,
 };
 
 #[derive(Debug, PartialEq, Eq)]
@@ -28,6 +28,8 @@ pub enum TableAliasRewrite {
         from: SqlIdentifier,
         to_view: Relation,
         for_statement: Box<SelectStatement>, // box for perf
+        /// The recursive term of the common table expression, if it's recursive
+        recursive_term: Option<(CompoundSelectOperator, Box<SelectStatement>)>,
     },
 }
 
@@ -68,48 +70,52 @@ impl<'ast, 'a> VisitorMut<'ast> for RemoveAliasesVisitor<'a> {
             .into_group_map();
 
         // Use the map of unique table references to identify any necessary alias rewrites.
-        let table_alias_rewrites: Vec<TableAliasRewrite> =
-            table_refs
-                .into_iter()
-                .flat_map(|(table, aliases)| match aliases[..] {
-                    [None] => {
-                        // The table is never referred to by an alias. No rewrite is needed.
-                        vec![]
-                    }
-
-                    [Some(ref alias)] => {
-                        // The table is only ever referred to using one specific alias. Rewrite
-                        // to remove the alias and refer to the table itself.
-                        vec![TableAliasRewrite::Table {
+        let table_alias_rewrites: Vec<TableAliasRewrite> = table_refs
+            .into_iter()
+            .flat_map(|(table, aliases)| match aliases[..] {
+                [None] => {
+                    // The table is never referred to by an alias. No rewrite is needed.
+                    vec![]
+                }
+
+                [Some(ref alias)] => {
+                    // The table is only ever referred to using one specific alias. Rewrite
+                    // to remove the alias and refer to the table itself.
+                    vec![TableAliasRewrite::Table {
+                        from: alias.clone(),
+                        to_table: table,
+                    }]
+                }
+
+                _ => aliases
+                    .into_iter()
+                    .flatten()
+                    .map(|alias| {
+                        // The alias is one among multiple distinct references to the
+                        // table. Create a globally unique view name, derived from the
+                        // query name, and rewrite to remove the alias and refer to this
+                        // view.
+                        TableAliasRewrite::View {
                             from: alias.clone(),
-                            to_table: table,
-                        }]
-                    }
-
-                    _ => aliases
-                        .into_iter()
-                        .flatten()
-                        .map(|alias| {
-                            // The alias is one among multiple distinct references to the
-                            // table. Create a globally unique view name, derived from the
-                            // query name, and rewrite to remove the alias and refer to this
-                            // view.
-                            TableAliasRewrite::View {
-                                from: alias.clone(),
-                                to_view: format!("__{}__{}", self.query_name, alias).into(),
-                                for_table: table.clone(),
-                            }
-                        })
-                        .collect(),
-                })
-                .chain(select_statement.ctes.drain(..).map(
-                    |CommonTableExpr { name, statement }| TableAliasRewrite::Cte {
-                        to_view: format!("__{}__{}", self.query_name, name).into(),
-                        from: name,
-                        for_statement: Box::new(statement),
-                    },
-                ))
-                .collect();
+                            to_view: format!("__{}__{}", self.query_name, alias).into(),
+                            for_table: table.clone(),
+                        }
+                    })
+                    .collect(),
+            })
+            .chain(select_statement.ctes.drain(..).map(
+                |CommonTableExpr {
+                     name,
+                     statement,
+                     recursive_term,
+                 }| TableAliasRewrite::Cte {
+                    to_view: format!("__{}__{}", self.query_name, name).into(),
+                    from: name,
+                    for_statement: Box::new(statement),
+                    recursive_term,
+                },
+            ))
+            .collect();
 
         // Extract remappings for FROM and JOIN table references from the alias rewrites.
         let new_table_remap = self
@@ -526,7 +532,8 @@ mod tests {
                 {
                     SqlQuery::Select(stmt) => Box::new(stmt),
                     _ => panic!(),
-                }
+                },
+                recursive_term: None,
             }]
         );
         assert_eq!(
@@ -39,6 +39,14 @@ fn negate_expr(expr: &mut Expr) -> bool {
                 | BinaryOperator::HashSubtract
                 | BinaryOperator::Multiply
                 | BinaryOperator::Divide
+                | BinaryOperator::Modulo
+                | BinaryOperator::IntegerDivide
+                | BinaryOperator::BitwiseAnd
+                | BinaryOperator::BitwiseOr
+                | BinaryOperator::Caret
+                | BinaryOperator::Hash
+                | BinaryOperator::ShiftLeft
+                | BinaryOperator::ShiftRight
                 | BinaryOperator::DoublePipe
                 | BinaryOperator::Arrow1
                 | BinaryOperator::Arrow2
@@ -20,11 +20,13 @@ mod key_def_coalescing;
 mod normalize_topk_with_aggregate;
 mod order_limit_removal;
 mod remove_numeric_field_references;
+mod resolve_null_order;
 mod resolve_schemas;
 mod rewrite_between;
 mod star_expansion;
 mod strip_literals;
 mod strip_post_filters;
+mod unroll_recursive_ctes;
 mod util;
 
 use std::collections::{HashMap, HashSet};
@@ -49,11 +51,13 @@ pub use crate::key_def_coalescing::KeyDefinitionCoalescing;
 pub use crate::normalize_topk_with_aggregate::NormalizeTopKWithAggregate;
 pub use crate::order_limit_removal::OrderLimitRemoval;
 pub use crate::remove_numeric_field_references::RemoveNumericFieldReferences;
+pub use crate::resolve_null_order::ResolveNullOrder;
 pub use crate::resolve_schemas::ResolveSchemas;
 pub use crate::rewrite_between::RewriteBetween;
 pub use crate::star_expansion::StarExpansion;
 pub use crate::strip_literals::{SelectStatementSkeleton, StripLiterals};
 pub use crate::strip_post_filters::StripPostFilters;
+pub use crate::unroll_recursive_ctes::{unroll_recursive_cte, UnrolledRecursiveCte};
 pub use crate::util::{
     is_correlated, is_logical_op, is_predicate, map_aggregates, outermost_table_exprs, LogicalOp,
 };
@@ -161,6 +165,7 @@ impl Rewrite for SelectStatement {
     fn rewrite(self, context: &mut RewriteContext) -> ReadySetResult<Self> {
         self.rewrite_between()
             .scalar_optimize_expressions(context.dialect)
+            .resolve_null_order(context.dialect)
             .strip_post_filters()
             .resolve_schemas(
                 context.tables(),
@@ -180,13 +185,14 @@ impl Rewrite for SelectStatement {
 
 impl Rewrite for CompoundSelectStatement {
     fn rewrite(self, context: &mut RewriteContext) -> ReadySetResult<Self> {
+        let stmt = self.resolve_null_order(context.dialect);
         Ok(CompoundSelectStatement {
-            selects: self
+            selects: stmt
                 .selects
                 .into_iter()
                 .map(|(op, sq)| Ok((op, sq.rewrite(context)?)))
                 .collect::<ReadySetResult<_>>()?,
-            ..self
+            ..stmt
         })
     }
 }
@@ -32,7 +32,7 @@ impl NormalizeTopKWithAggregate for SelectStatement {
                 match &self.group_by {
                     Some(group_by) => {
                         // Each field in the order clause...
-                        for (order_field, _) in &order.order_by {
+                        for (order_field, _, _) in &order.order_by {
                             // ...must either appear in the group by clause...
                             let in_group_by_clause = group_by
                                 .fields
@@ -179,7 +179,8 @@ mod tests {
                     Some(OrderClause {
                         order_by: vec![(
                             FieldReference::Expr(Expr::Column("column_3".into())),
-                            Some(OrderType::OrderAscending)
+                            Some(OrderType::OrderAscending),
+                            None
                         )]
                     })
                 );
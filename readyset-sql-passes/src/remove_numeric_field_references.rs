@@ -40,7 +40,7 @@ impl RemoveNumericFieldReferences for SelectStatement {
         }
 
         if let Some(order) = &mut self.order {
-            for (field, _) in &mut order.order_by {
+            for (field, _, _) in &mut order.order_by {
                 if let FieldReference::Numeric(n) = field {
                     *field = FieldReference::Expr(lookup_field(*n as _)?);
                 }
@@ -94,7 +94,8 @@ mod tests {
             Some(OrderClause {
                 order_by: vec![(
                     FieldReference::Expr(Expr::Column("id".into())),
-                    Some(OrderType::OrderAscending)
+                    Some(OrderType::OrderAscending),
+                    None
                 )]
             })
         )
@@ -0,0 +1,124 @@
+use dataflow_expression::Dialect;
+use nom_sql::analysis::visit_mut::{self, VisitorMut};
+use nom_sql::{CompoundSelectStatement, OrderClause, OrderType, SelectStatement};
+
+pub trait ResolveNullOrder {
+    /// Recursively make the placement of NULLs explicit for every field in the `ORDER BY` clauses
+    /// of the given query that doesn't specify `NULLS FIRST` or `NULLS LAST`, using the default
+    /// placement of NULLs for the given [`Dialect`].
+    ///
+    /// For example, in PostgreSQL the following query:
+    ///
+    /// ```sql
+    /// SELECT * FROM t ORDER BY x DESC, y;
+    /// ```
+    ///
+    /// becomes:
+    ///
+    /// ```sql
+    /// SELECT * FROM t ORDER BY x DESC NULLS FIRST, y NULLS LAST;
+    /// ```
+    #[must_use]
+    fn resolve_null_order(self, dialect: Dialect) -> Self;
+}
+
+struct ResolveNullOrderVisitor {
+    dialect: Dialect,
+}
+
+impl<'ast> VisitorMut<'ast> for ResolveNullOrderVisitor {
+    type Error = !;
+
+    fn visit_order_clause(&mut self, order: &'ast mut OrderClause) -> Result<(), Self::Error> {
+        for (_, order_type, null_order) in &mut order.order_by {
+            if null_order.is_none() {
+                *null_order = Some(
+                    self.dialect
+                        .default_null_order(order_type.unwrap_or(OrderType::OrderAscending)),
+                );
+            }
+        }
+        visit_mut::walk_order_clause(self, order)
+    }
+}
+
+impl ResolveNullOrder for SelectStatement {
+    fn resolve_null_order(mut self, dialect: Dialect) -> Self {
+        let Ok(()) = ResolveNullOrderVisitor { dialect }.visit_select_statement(&mut self);
+        self
+    }
+}
+
+impl ResolveNullOrder for CompoundSelectStatement {
+    fn resolve_null_order(mut self, dialect: Dialect) -> Self {
+        let Ok(()) = ResolveNullOrderVisitor { dialect }.visit_compound_select_statement(&mut self);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom_sql::{parse_query, SqlQuery};
+
+    use super::*;
+    use crate::util::parse_select_statement;
+
+    #[test]
+    fn mysql_defaults() {
+        let query = parse_select_statement("SELECT * FROM t ORDER BY x DESC, y")
+            .resolve_null_order(Dialect::DEFAULT_MYSQL);
+        assert_eq!(
+            query,
+            parse_select_statement("SELECT * FROM t ORDER BY x DESC NULLS LAST, y NULLS FIRST")
+        );
+    }
+
+    #[test]
+    fn postgresql_defaults() {
+        let query = parse_select_statement("SELECT * FROM t ORDER BY x DESC, y")
+            .resolve_null_order(Dialect::DEFAULT_POSTGRESQL);
+        assert_eq!(
+            query,
+            parse_select_statement("SELECT * FROM t ORDER BY x DESC NULLS FIRST, y NULLS LAST")
+        );
+    }
+
+    #[test]
+    fn keeps_explicit_null_order() {
+        let query = parse_select_statement("SELECT * FROM t ORDER BY x NULLS FIRST")
+            .resolve_null_order(Dialect::DEFAULT_POSTGRESQL);
+        assert_eq!(
+            query,
+            parse_select_statement("SELECT * FROM t ORDER BY x NULLS FIRST")
+        );
+    }
+
+    #[test]
+    fn subqueries() {
+        let query = parse_select_statement(
+            "SELECT * FROM (SELECT x FROM t ORDER BY x LIMIT 3) sq ORDER BY x DESC",
+        )
+        .resolve_null_order(Dialect::DEFAULT_POSTGRESQL);
+        assert_eq!(
+            query,
+            parse_select_statement(
+                "SELECT * FROM (SELECT x FROM t ORDER BY x NULLS LAST LIMIT 3) sq \
+                 ORDER BY x DESC NULLS FIRST"
+            )
+        );
+    }
+
+    #[test]
+    fn compound_select() {
+        let parse_compound = |q: &str| match parse_query(nom_sql::Dialect::MySQL, q).unwrap() {
+            SqlQuery::CompoundSelect(stmt) => stmt,
+            _ => panic!(),
+        };
+        let query = parse_compound("SELECT x FROM t UNION SELECT x FROM u ORDER BY x")
+            .resolve_null_order(Dialect::DEFAULT_POSTGRESQL);
+        assert_eq!(
+            query,
+            parse_compound("SELECT x FROM t UNION SELECT x FROM u ORDER BY x NULLS LAST")
+        );
+    }
+}
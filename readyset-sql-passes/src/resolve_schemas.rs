@@ -94,8 +94,11 @@ impl<'ast, 'schema> VisitorMut<'ast> for ResolveSchemaVisitor<'schema> {
         &mut self,
         cte: &'ast mut nom_sql::CommonTableExpr,
     ) -> Result<(), Self::Error> {
-        // Walk first, since the alias for the CTE is not visible inside the CTE itself (TODO:
-        // except in the case of `WITH RECURSIVE`, which we don't even parse yet).
+        // The alias for the CTE is visible inside the CTE itself only if it's recursive -
+        // otherwise, walk first.
+        if cte.recursive_term.is_some() {
+            self.insert_alias(cte.name.clone());
+        }
         visit_mut::walk_common_table_expr(self, cte)?;
         self.insert_alias(cte.name.clone());
         Ok(())
@@ -311,6 +314,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ignores_recursive_cte_self_reference() {
+        select_rewrites_to(
+            "with recursive t1 as (select x from t3 union all select t1.x from t1) select x from t1",
+            "with recursive t1 as (select x from s2.t3 union all select t1.x from t1) select x from t1",
+        );
+    }
+
     #[test]
     fn ignores_table_expr_alias_reference() {
         select_rewrites_to("select t2.* from t1 as t2", "select t2.* from s1.t1 as t2");
@@ -0,0 +1,206 @@
+//! Support for recursive common table expressions (`WITH RECURSIVE`) by unrolling them to a
+//! bounded depth.
+//!
+//! See [`unroll_recursive_cte`] for more information.
+
+use nom_sql::analysis::visit::{self, Visitor};
+use nom_sql::{CommonTableExpr, CompoundSelectOperator, Relation, SelectStatement, SqlIdentifier};
+use readyset_errors::{unsupported, ReadySetResult};
+
+struct ReferencesCteVisitor<'a> {
+    name: &'a SqlIdentifier,
+    found: bool,
+}
+
+impl<'ast, 'a> Visitor<'ast> for ReferencesCteVisitor<'a> {
+    type Error = !;
+
+    fn visit_table(&mut self, table: &'ast Relation) -> Result<(), Self::Error> {
+        if table.schema.is_none() && table.name == *self.name {
+            self.found = true;
+        }
+        visit::walk_relation(self, table)
+    }
+}
+
+/// Returns true if the given statement references a table with the given (unqualified) name
+fn references_cte(stmt: &SelectStatement, name: &SqlIdentifier) -> bool {
+    let mut visitor = ReferencesCteVisitor { name, found: false };
+    let Ok(()) = visitor.visit_select_statement(stmt);
+    visitor.found
+}
+
+/// A recursive common table expression which has been prepared for unrolling by
+/// [`unroll_recursive_cte`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnrolledRecursiveCte {
+    /// The non-recursive term of the CTE, which computes the first level of rows
+    pub base: SelectStatement,
+
+    /// The statement which computes each subsequent level of rows from the level before it.
+    ///
+    /// This is the recursive term of the CTE, with the reference to the CTE itself bound (via a
+    /// non-recursive CTE of the same name) to the non-recursive term. That binding only serves to
+    /// describe the columns of the CTE - when compiling a level, the CTE should instead be bound
+    /// to the result of the previous level.
+    pub recursive_term: SelectStatement,
+
+    /// The number of levels computed by `recursive_term`
+    pub depth: usize,
+}
+
+/// Prepare the recursive common table expression named `name`, with the given non-recursive term
+/// (`base`) and recursive term, for unrolling into a union of the rows that can be reached using
+/// at most `max_depth` applications of the recursive term.
+///
+/// The first level of the unrolled CTE is the non-recursive term, and each subsequent level is the
+/// recursive term applied to the level before it. Since the resulting query graph is acyclic, each
+/// level is maintained incrementally like any other query, but rows which can only be reached via
+/// more than `max_depth` applications of the recursive term will not be returned.
+///
+/// Only `UNION ALL` is supported for combining the terms, since unrolling a `UNION` (which
+/// discards rows already produced by a previous level, and so terminates on cycles) would require
+/// set semantics across levels.
+pub fn unroll_recursive_cte(
+    name: &SqlIdentifier,
+    base: SelectStatement,
+    operator: CompoundSelectOperator,
+    recursive_term: SelectStatement,
+    max_depth: usize,
+) -> ReadySetResult<UnrolledRecursiveCte> {
+    if operator != CompoundSelectOperator::Union {
+        unsupported!("Recursive CTEs must combine their terms with UNION ALL");
+    }
+
+    if references_cte(&base, name) {
+        unsupported!("The non-recursive term of a recursive CTE cannot reference the CTE itself");
+    }
+
+    // If the recursive term doesn't actually reference the CTE, there's nothing to unroll
+    if !references_cte(&recursive_term, name) {
+        return Ok(UnrolledRecursiveCte {
+            base,
+            recursive_term,
+            depth: 1,
+        });
+    }
+
+    let mut level = recursive_term;
+    level.ctes.insert(
+        0,
+        CommonTableExpr {
+            name: name.clone(),
+            statement: base.clone(),
+            recursive_term: None,
+        },
+    );
+
+    Ok(UnrolledRecursiveCte {
+        base,
+        recursive_term: level,
+        depth: max_depth,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use nom_sql::{parse_query, Dialect, SqlQuery};
+
+    use super::*;
+
+    fn recursive_cte(
+        query: &str,
+    ) -> (
+        SqlIdentifier,
+        SelectStatement,
+        CompoundSelectOperator,
+        SelectStatement,
+    ) {
+        match parse_query(Dialect::MySQL, query).unwrap() {
+            SqlQuery::Select(mut stmt) => {
+                let cte = stmt.ctes.remove(0);
+                let (operator, recursive_term) = cte.recursive_term.unwrap();
+                (cte.name, cte.statement, operator, *recursive_term)
+            }
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn unrolls_to_depth() {
+        let (name, base, operator, recursive_term) = recursive_cte(
+            "WITH RECURSIVE r AS (
+                 SELECT id FROM t WHERE parent_id IS NULL
+                 UNION ALL
+                 SELECT t.id FROM t JOIN r ON t.parent_id = r.id
+             )
+             SELECT id FROM r",
+        );
+        let res = unroll_recursive_cte(&name, base.clone(), operator, recursive_term, 2).unwrap();
+        let expected = match parse_query(
+            Dialect::MySQL,
+            "WITH r AS (SELECT id FROM t WHERE parent_id IS NULL)
+             SELECT t.id FROM t JOIN r ON t.parent_id = r.id",
+        )
+        .unwrap()
+        {
+            SqlQuery::Select(stmt) => stmt,
+            _ => panic!(),
+        };
+        assert_eq!(res.base, base);
+        assert_eq!(
+            res.recursive_term,
+            expected,
+            "\n{}\n!=\n{}",
+            res.recursive_term.display(Dialect::MySQL),
+            expected.display(Dialect::MySQL)
+        );
+        assert_eq!(res.depth, 2);
+    }
+
+    #[test]
+    fn non_recursive_union() {
+        let (name, base, operator, recursive_term) = recursive_cte(
+            "WITH RECURSIVE r AS (SELECT id FROM t1 UNION ALL SELECT id FROM t2) SELECT id FROM r",
+        );
+        let res =
+            unroll_recursive_cte(&name, base.clone(), operator, recursive_term.clone(), 5).unwrap();
+        assert_eq!(
+            res,
+            UnrolledRecursiveCte {
+                base,
+                recursive_term,
+                depth: 1
+            }
+        );
+    }
+
+    #[test]
+    fn distinct_union_unsupported() {
+        let (name, base, operator, recursive_term) = recursive_cte(
+            "WITH RECURSIVE r AS (
+                 SELECT id FROM t WHERE parent_id IS NULL
+                 UNION
+                 SELECT t.id FROM t JOIN r ON t.parent_id = r.id
+             )
+             SELECT id FROM r",
+        );
+        unroll_recursive_cte(&name, base, operator, recursive_term, 5).unwrap_err();
+    }
+
+    #[test]
+    fn self_reference_in_base_unsupported() {
+        let (name, base, operator, recursive_term) = recursive_cte(
+            "WITH RECURSIVE r AS (SELECT id FROM r UNION ALL SELECT id FROM r) SELECT id FROM r",
+        );
+        unroll_recursive_cte(&name, base, operator, recursive_term, 5).unwrap_err();
+    }
+
+    #[test]
+    fn intersect_unsupported() {
+        let (name, base, operator, recursive_term) = recursive_cte(
+            "WITH RECURSIVE r AS (SELECT id FROM t INTERSECT SELECT id FROM r) SELECT id FROM r",
+        );
+        unroll_recursive_cte(&name, base, operator, recursive_term, 5).unwrap_err();
+    }
+}
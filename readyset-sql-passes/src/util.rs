@@ -114,7 +114,9 @@ pub fn map_aggregates(expr: &mut Expr) -> Vec<(FunctionExpr, SqlIdentifier)> {
             ret.append(&mut map_aggregates(lhs));
             ret.append(&mut map_aggregates(rhs));
         }
-        Expr::UnaryOp { rhs: expr, .. } | Expr::Cast { expr, .. } => {
+        Expr::UnaryOp { rhs: expr, .. }
+        | Expr::Cast { expr, .. }
+        | Expr::Interval { value: expr, .. } => {
             ret.append(&mut map_aggregates(expr));
         }
         Expr::Exists(_) => {}
@@ -138,6 +140,8 @@ pub fn map_aggregates(expr: &mut Expr) -> Vec<(FunctionExpr, SqlIdentifier)> {
             }
         }
         Expr::Array(exprs) => ret.extend(exprs.iter_mut().flat_map(map_aggregates)),
+        // Window functions are computed by their own node, and are never aggregates themselves
+        Expr::WindowFunction { .. } => {}
     }
     ret
 }
@@ -57,6 +57,7 @@ pub enum TableErrorKind {
     TimeParseError(mysql_time::ConvertError),
     NumericParseError(rust_decimal::Error),
     BitVectorParseError(String),
+    IntervalParseError(String),
     InvalidMapping(String),
     UnsupportedTypeConversion { type_oid: u32 },
     UnknownEnumVariant(Bytes),
@@ -5,7 +5,7 @@ use std::sync::Arc;
 use bit_vec::BitVec;
 use mysql_time::MySqlTime;
 use postgres_types::Kind;
-use readyset_data::{Array, Collation, DfType, DfValue, Dialect};
+use readyset_data::{Array, Collation, DfType, DfValue, Dialect, Interval};
 use readyset_errors::{unsupported, ReadySetError};
 use rust_decimal::prelude::FromStr;
 use rust_decimal::Decimal;
@@ -513,6 +513,7 @@ impl wal::TupleData {
                                     PGType::JSONB => DfType::Jsonb,
                                     PGType::DATE => DfType::Date,
                                     PGType::TIME => DfType::Time { subsecond_digits },
+                                    PGType::INTERVAL => DfType::Interval,
                                     PGType::NUMERIC => DfType::DEFAULT_NUMERIC,
                                     PGType::BYTEA => DfType::Blob,
                                     PGType::MACADDR => DfType::MacAddr,
@@ -693,6 +694,14 @@ impl wal::TupleData {
 
                                     DfValue::Time(result?)
                                 }
+                                PGType::INTERVAL => str
+                                    .parse::<Interval>()
+                                    .map_err(|e| WalError::TableError {
+                                        kind: TableErrorKind::IntervalParseError(e.to_string()),
+                                        schema: relation.schema_name_lossy(),
+                                        table: relation.relation_name_lossy(),
+                                    })
+                                    .map(DfValue::from)?,
                                 PGType::BIT | PGType::VARBIT => {
                                     let mut bits = BitVec::with_capacity(str.len());
                                     for c in str.chars() {